@@ -282,6 +282,7 @@ impl ButtonStateMachine {
 
         // Special case: Disable can be triggered from any state
         // This ensures buttons can be disabled regardless of current interaction
+        #[allow(clippy::collapsible_match)]
         match event {
             ButtonEvent::Disable => {
                 if self.current_state != ButtonState::Disabled {
@@ -5,14 +5,20 @@
 // - Layer2: Component variables (input-vars.scss)
 // - Custom: Runtime overrides via text_color, border_color, animation_id
 
+use std::sync::atomic::{AtomicU64, Ordering};
+
 use hikari_palette::classes::{ClassesBuilder, InputClass, UtilityClass};
 
+use crate::basic::InputFormatter;
 use crate::feedback::{Glow, GlowBlur, GlowColor, GlowIntensity, GlowProps};
+use crate::platform;
 use crate::prelude::*;
 use crate::styled::StyledComponent;
 
 pub struct InputComponent;
 
+static INPUT_ID_COUNTER: AtomicU64 = AtomicU64::new(0);
+
 #[derive(Clone, Copy, PartialEq, Debug, Default)]
 pub enum InputSize {
     Small,
@@ -91,6 +97,10 @@ pub struct InputProps {
     pub css_vars: Option<Vec<(&'static str, String)>>,
 
     pub status: InputStatus,
+
+    /// Mask or format/parse hooks. When set, `value` and `oninput` carry the
+    /// raw value while the input shows the formatted text.
+    pub formatter: Option<InputFormatter>,
 }
 
 ///
@@ -157,6 +167,53 @@ pub fn Input(props: InputProps) -> Element {
 
     let style_attr = Some(css_vars_string);
 
+    let input_id = use_signal(|| {
+        format!(
+            "hi-input-{}",
+            INPUT_ID_COUNTER.fetch_add(1, Ordering::Relaxed)
+        )
+    })
+    .get();
+    let display = use_signal(|| match (&props.formatter, &props.value) {
+        (Some(formatter), Some(raw)) => formatter.format(raw),
+        _ => String::new(),
+    });
+
+    // Keep the in-progress display text while it still parses to the
+    // controlled raw value; reformat when the value changed from outside.
+    let shown_value = match &props.formatter {
+        Some(formatter) => {
+            let current = display.get();
+            match &props.value {
+                Some(raw) if formatter.parse(&current) != *raw => Some(formatter.format(raw)),
+                _ => Some(current),
+            }
+        }
+        None => props.value.clone(),
+    };
+
+    let handle_input = {
+        let formatter = props.formatter.clone();
+        let oninput = props.oninput.clone();
+        let input_id = input_id.clone();
+        move |e: InputEvent| {
+            let value = match formatter.as_ref() {
+                Some(formatter) => {
+                    let caret = platform::get_input_caret_by_id(&input_id)
+                        .unwrap_or_else(|| e.data.chars().count());
+                    let masked = formatter.apply(&display.get(), &e.data, caret);
+                    platform::set_input_value_by_id(&input_id, &masked.display, masked.caret);
+                    display.set(masked.display);
+                    masked.raw
+                }
+                None => e.data.clone(),
+            };
+            if let Some(handler) = oninput.as_ref() {
+                handler.call(value);
+            }
+        }
+    };
+
     let input_content = rsx! {
         div {
             class: wrapper_classes,
@@ -168,18 +225,15 @@ pub fn Input(props: InputProps) -> Element {
             }
 
             input {
+                id: input_id,
                 class: input_classes,
                 r#type: props.input_type.unwrap_or("text".to_string()),
                 autofocus: props.autofocus,
                 disabled: props.disabled,
                 readonly: props.readonly,
                 placeholder: props.placeholder,
-                value: props.value,
-                oninput: move |e: InputEvent| {
-                    if let Some(handler) = props.oninput.as_ref() {
-                        handler.call(e.data.clone());
-                    }
-                },
+                value: shown_value,
+                oninput: handle_input,
                 onfocus: move |e: FocusEvent| {
                     if let Some(handler) = props.onfocus.as_ref() {
                         handler.call(e);
@@ -0,0 +1,397 @@
+// hi-components/src/basic/input_mask.rs
+// Input masks and formatters for the Input component
+//
+// Pure string logic, no DOM access: the Input component feeds it the previous
+// display value, the edited text and the caret, and writes the result back.
+
+use std::fmt;
+use std::rc::Rc;
+
+/// One position of an [`InputMask`] pattern.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MaskSlot {
+    /// `9` - an ASCII digit
+    Digit,
+    /// `a` - an alphabetic character
+    Letter,
+    /// `*` - a letter or digit
+    Alphanumeric,
+    /// `?` - any character
+    Any,
+    /// Fixed character inserted by the mask
+    Literal(char),
+}
+
+impl MaskSlot {
+    pub fn is_literal(self) -> bool {
+        matches!(self, MaskSlot::Literal(_))
+    }
+
+    pub fn accepts(self, c: char) -> bool {
+        match self {
+            MaskSlot::Digit => c.is_ascii_digit(),
+            MaskSlot::Letter => c.is_alphabetic(),
+            MaskSlot::Alphanumeric => c.is_alphanumeric(),
+            MaskSlot::Any => true,
+            MaskSlot::Literal(_) => false,
+        }
+    }
+}
+
+/// Declarative fixed-width mask such as `(999) 999-9999`.
+///
+/// Pattern syntax: `9` digit, `a` letter, `*` letter or digit, `?` any
+/// character, `\` escapes the next character; everything else is a literal.
+/// The raw value holds only the characters typed into editable slots.
+#[derive(Clone, PartialEq, Debug)]
+pub struct InputMask {
+    slots: Vec<MaskSlot>,
+    placeholder: Option<char>,
+    uppercase: bool,
+}
+
+impl InputMask {
+    pub fn new(pattern: &str) -> Self {
+        let mut slots = Vec::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            slots.push(match c {
+                '9' => MaskSlot::Digit,
+                'a' => MaskSlot::Letter,
+                '*' => MaskSlot::Alphanumeric,
+                '?' => MaskSlot::Any,
+                '\\' => MaskSlot::Literal(chars.next().unwrap_or('\\')),
+                other => MaskSlot::Literal(other),
+            });
+        }
+        Self {
+            slots,
+            placeholder: None,
+            uppercase: false,
+        }
+    }
+
+    /// Show unfilled slots with `placeholder` (e.g. `__/__/____`).
+    pub fn with_placeholder(mut self, placeholder: char) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Upper-case letters as they are typed.
+    pub fn uppercase(mut self) -> Self {
+        self.uppercase = true;
+        self
+    }
+
+    /// `(555) 123-4567`
+    pub fn phone() -> Self {
+        Self::new("(999) 999-9999")
+    }
+
+    /// `4111 1111 1111 1111`
+    pub fn credit_card() -> Self {
+        Self::new("9999 9999 9999 9999")
+    }
+
+    /// `DE89 3704 0044 0532 0130 00` (up to 34 characters)
+    pub fn iban() -> Self {
+        Self::new("aa99 **** **** **** **** **** **** **").uppercase()
+    }
+
+    /// `31/12/2024`
+    pub fn date() -> Self {
+        Self::new("99/99/9999")
+    }
+
+    /// `23:59`
+    pub fn time() -> Self {
+        Self::new("99:99")
+    }
+
+    pub fn placeholder(&self) -> Option<char> {
+        self.placeholder
+    }
+
+    pub fn slots(&self) -> &[MaskSlot] {
+        &self.slots
+    }
+
+    /// Number of editable slots, i.e. the maximum raw length.
+    pub fn capacity(&self) -> usize {
+        self.slots.iter().filter(|slot| !slot.is_literal()).count()
+    }
+
+    /// Whether `raw` fills every editable slot.
+    pub fn is_complete(&self, raw: &str) -> bool {
+        self.parse(raw).chars().count() == self.capacity()
+    }
+
+    /// Extract the raw value from (possibly hand-edited) display text.
+    ///
+    /// Literals are matched in place when present and skipped otherwise;
+    /// characters no slot accepts are dropped.
+    pub fn parse(&self, text: &str) -> String {
+        let mut raw = String::new();
+        let mut pos = 0;
+
+        for c in text.chars() {
+            if Some(c) == self.placeholder {
+                continue;
+            }
+            if self.slots.get(pos) == Some(&MaskSlot::Literal(c)) {
+                pos += 1;
+                continue;
+            }
+            while self.slots.get(pos).is_some_and(|slot| slot.is_literal()) {
+                pos += 1;
+            }
+            let Some(slot) = self.slots.get(pos) else {
+                break;
+            };
+            let c = self.transform(c);
+            if slot.accepts(c) {
+                raw.push(c);
+                pos += 1;
+            }
+        }
+
+        raw
+    }
+
+    /// Lay `raw` out over the mask.
+    ///
+    /// Literals directly after the last typed character are included so the
+    /// caret can move past them; with a placeholder the rest of the mask is
+    /// shown.
+    pub fn format(&self, raw: &str) -> String {
+        let mut out = String::new();
+        let mut chars = raw.chars().map(|c| self.transform(c)).peekable();
+        let mut typed_any = false;
+
+        for slot in &self.slots {
+            match slot {
+                MaskSlot::Literal(l) => {
+                    if chars.peek().is_none() && !typed_any {
+                        break;
+                    }
+                    out.push(*l);
+                }
+                slot => {
+                    let next = chars.by_ref().find(|c| slot.accepts(*c));
+                    match (next, self.placeholder) {
+                        (Some(c), _) => {
+                            out.push(c);
+                            typed_any = true;
+                        }
+                        (None, Some(placeholder)) if typed_any => out.push(placeholder),
+                        (None, _) => break,
+                    }
+                }
+            }
+        }
+
+        out
+    }
+
+    fn transform(&self, c: char) -> char {
+        if self.uppercase {
+            c.to_ascii_uppercase()
+        } else {
+            c
+        }
+    }
+}
+
+/// Result of running an edit through an [`InputFormatter`].
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct MaskedValue {
+    /// Text shown in the input
+    pub display: String,
+    /// Value reported to the application
+    pub raw: String,
+    /// Caret position in `display`, in characters
+    pub caret: usize,
+}
+
+pub type TextTransform = dyn Fn(&str) -> String;
+
+/// Pair of `format` (raw -> display) and `parse` (display -> raw) hooks.
+///
+/// Build one from an [`InputMask`], use [`InputFormatter::ipv4`], or supply
+/// custom hooks. The caret is kept after the same number of letters and digits
+/// of the raw value, so literals inserted or removed by `format` never move it
+/// backwards.
+#[derive(Clone)]
+pub struct InputFormatter {
+    format: Rc<TextTransform>,
+    parse: Rc<TextTransform>,
+    placeholder: Option<char>,
+}
+
+impl InputFormatter {
+    pub fn new(
+        format: impl Fn(&str) -> String + 'static,
+        parse: impl Fn(&str) -> String + 'static,
+    ) -> Self {
+        Self {
+            format: Rc::new(format),
+            parse: Rc::new(parse),
+            placeholder: None,
+        }
+    }
+
+    pub fn mask(mask: InputMask) -> Self {
+        let placeholder = mask.placeholder();
+        let parse_mask = mask.clone();
+        Self {
+            placeholder,
+            ..Self::new(
+                move |raw| mask.format(raw),
+                move |text| parse_mask.parse(text),
+            )
+        }
+    }
+
+    /// Dotted IPv4 address. Octets advance automatically after three digits;
+    /// the raw value keeps the dots.
+    pub fn ipv4() -> Self {
+        Self::new(format_ipv4, |text| {
+            text.chars()
+                .filter(|c| c.is_ascii_digit() || *c == '.')
+                .collect()
+        })
+    }
+
+    pub fn format(&self, raw: &str) -> String {
+        (self.format)(raw)
+    }
+
+    pub fn parse(&self, text: &str) -> String {
+        (self.parse)(text)
+    }
+
+    /// Apply an edit that turned `previous` into `next`, with the caret at
+    /// character offset `caret` in `next`.
+    pub fn apply(&self, previous: &str, next: &str, caret: usize) -> MaskedValue {
+        let deleting = next.chars().count() < previous.chars().count();
+        let caret_prefix: String = next.chars().take(caret).collect();
+        let mut target = self.significant_count(&caret_prefix);
+        let mut raw = self.parse(next);
+
+        // Deleting only a literal would be undone by `format`; remove the
+        // character in front of it instead.
+        if deleting && self.significant(&raw) == self.significant(previous) && target > 0 {
+            raw = remove_significant(&raw, target - 1);
+            target -= 1;
+        }
+
+        let display = self.format(&raw);
+        let mut caret = self.position_of(&display, target);
+        if !deleting {
+            let chars: Vec<char> = display.chars().collect();
+            // Step over literals, stopping at the first unfilled slot.
+            while caret < chars.len()
+                && Some(chars[caret]) != self.placeholder
+                && !self.is_significant_at(&chars, caret)
+            {
+                caret += 1;
+            }
+        }
+
+        MaskedValue {
+            raw: self.parse(&display),
+            display,
+            caret,
+        }
+    }
+
+    fn significant(&self, text: &str) -> String {
+        self.parse(text)
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .collect()
+    }
+
+    fn significant_count(&self, text: &str) -> usize {
+        self.significant(text).chars().count()
+    }
+
+    /// Smallest character offset in `display` preceded by `count` significant
+    /// characters.
+    fn position_of(&self, display: &str, count: usize) -> usize {
+        let chars: Vec<char> = display.chars().collect();
+        (0..=chars.len())
+            .find(|&i| self.significant_count(&chars[..i].iter().collect::<String>()) >= count)
+            .unwrap_or(chars.len())
+    }
+
+    fn is_significant_at(&self, chars: &[char], index: usize) -> bool {
+        let before: String = chars[..index].iter().collect();
+        let after: String = chars[..=index].iter().collect();
+        self.significant_count(&after) > self.significant_count(&before)
+    }
+}
+
+impl From<InputMask> for InputFormatter {
+    fn from(mask: InputMask) -> Self {
+        Self::mask(mask)
+    }
+}
+
+impl PartialEq for InputFormatter {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.format, &other.format)
+            && Rc::ptr_eq(&self.parse, &other.parse)
+            && self.placeholder == other.placeholder
+    }
+}
+
+impl fmt::Debug for InputFormatter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("InputFormatter(..)")
+    }
+}
+
+fn format_ipv4(raw: &str) -> String {
+    let mut out = String::new();
+    let mut dots = 0;
+    let mut digits = 0;
+
+    for c in raw.chars() {
+        if c.is_ascii_digit() {
+            if digits == 3 {
+                if dots == 3 {
+                    break;
+                }
+                out.push('.');
+                dots += 1;
+                digits = 0;
+            }
+            out.push(c);
+            digits += 1;
+        } else if c == '.' && digits > 0 && dots < 3 {
+            out.push('.');
+            dots += 1;
+            digits = 0;
+        }
+    }
+
+    if digits == 3 && dots < 3 {
+        out.push('.');
+    }
+
+    out
+}
+
+fn remove_significant(raw: &str, index: usize) -> String {
+    let mut seen = 0;
+    raw.chars()
+        .filter(|c| {
+            if !c.is_alphanumeric() {
+                return true;
+            }
+            seen += 1;
+            seen != index + 1
+        })
+        .collect()
+}
@@ -0,0 +1,299 @@
+// hi-components/src/basic/mention.rs
+// Mention, channel and slash-command model for the Textarea component
+//
+// Offsets are in characters. Mentions are atomic: an edit that touches a
+// mention removes the whole token, so spans always match the text.
+
+use std::fmt;
+use std::rc::Rc;
+
+/// An entity that can be mentioned (a user, a channel, ...).
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct MentionEntity {
+    pub id: String,
+    pub label: String,
+    pub description: Option<String>,
+}
+
+impl MentionEntity {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            description: None,
+        }
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+pub type MentionLookup = dyn Fn(&str) -> Vec<MentionEntity>;
+
+/// Looks up entities for the text typed after a trigger.
+#[derive(Clone)]
+pub struct MentionSource(Rc<MentionLookup>);
+
+impl MentionSource {
+    pub fn new(f: impl Fn(&str) -> Vec<MentionEntity> + 'static) -> Self {
+        Self(Rc::new(f))
+    }
+
+    /// Static list, matched case-insensitively against the label.
+    pub fn from_entities(entities: Vec<MentionEntity>) -> Self {
+        Self::new(move |query| {
+            let query = query.to_lowercase();
+            entities
+                .iter()
+                .filter(|entity| entity.label.to_lowercase().contains(&query))
+                .cloned()
+                .collect()
+        })
+    }
+
+    pub fn search(&self, query: &str) -> Vec<MentionEntity> {
+        (self.0)(query)
+    }
+}
+
+impl PartialEq for MentionSource {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for MentionSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MentionSource(..)")
+    }
+}
+
+/// A trigger character (`@`, `#`, ...) and where its suggestions come from.
+#[derive(Clone, PartialEq, Debug)]
+pub struct MentionTrigger {
+    pub trigger: char,
+    pub source: MentionSource,
+}
+
+impl MentionTrigger {
+    pub fn new(trigger: char, source: MentionSource) -> Self {
+        Self { trigger, source }
+    }
+}
+
+/// Entry of the `/command` menu.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct SlashCommand {
+    pub name: String,
+    pub description: String,
+}
+
+impl SlashCommand {
+    pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+        }
+    }
+}
+
+/// Commands whose name starts with `query` (case-insensitive).
+pub fn filter_commands(commands: &[SlashCommand], query: &str) -> Vec<SlashCommand> {
+    let query = query.to_lowercase();
+    commands
+        .iter()
+        .filter(|command| command.name.to_lowercase().starts_with(&query))
+        .cloned()
+        .collect()
+}
+
+/// A mention token in the text, covering the trigger and the label.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct MentionSpan {
+    pub start: usize,
+    pub end: usize,
+    pub trigger: char,
+    pub id: String,
+    pub label: String,
+}
+
+/// Trigger being typed at the caret: `text[start..end]` is the trigger
+/// followed by `query`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TriggerQuery {
+    pub trigger: char,
+    pub start: usize,
+    pub end: usize,
+    pub query: String,
+}
+
+/// Find a trigger before `caret` that starts a word and is followed only by
+/// non-whitespace characters.
+pub fn find_trigger(text: &str, caret: usize, triggers: &[char]) -> Option<TriggerQuery> {
+    let chars: Vec<char> = text.chars().collect();
+    let caret = caret.min(chars.len());
+
+    let mut start = caret;
+    while start > 0 {
+        start -= 1;
+        let c = chars[start];
+        if c.is_whitespace() {
+            return None;
+        }
+        if triggers.contains(&c) && (start == 0 || chars[start - 1].is_whitespace()) {
+            return Some(TriggerQuery {
+                trigger: c,
+                start,
+                end: caret,
+                query: chars[start + 1..caret].iter().collect(),
+            });
+        }
+    }
+
+    None
+}
+
+/// Like [`find_trigger`] for `/`, but only at the start of a line.
+pub fn find_command(text: &str, caret: usize) -> Option<TriggerQuery> {
+    let query = find_trigger(text, caret, &['/'])?;
+    let at_line_start = query.start == 0 || text.chars().nth(query.start - 1) == Some('\n');
+    at_line_start.then_some(query)
+}
+
+/// Rows needed to show `text` without scrolling, clamped to the given range.
+///
+/// Only hard line breaks are counted, so this is the fallback for when the
+/// textarea cannot be measured.
+pub fn auto_resize_rows(text: &str, min_rows: u32, max_rows: Option<u32>) -> u32 {
+    clamp_rows(text.split('\n').count() as u32, min_rows, max_rows)
+}
+
+/// Clamp a content height in `lines` to `min_rows..=max_rows`.
+pub fn clamp_rows(lines: u32, min_rows: u32, max_rows: Option<u32>) -> u32 {
+    let rows = lines.max(min_rows);
+    max_rows.map_or(rows, |max| rows.min(max.max(min_rows)))
+}
+
+/// Textarea value: plain text plus the mentions it contains.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct MentionValue {
+    pub text: String,
+    pub mentions: Vec<MentionSpan>,
+}
+
+impl MentionValue {
+    pub fn new(text: impl Into<String>, mentions: Vec<MentionSpan>) -> Self {
+        Self {
+            text: text.into(),
+            mentions,
+        }
+    }
+
+    pub fn plain(text: impl Into<String>) -> Self {
+        Self::new(text, Vec::new())
+    }
+
+    /// Reconcile an edit that turned `self.text` into `new_text`.
+    ///
+    /// Mentions after the edit are shifted. A deletion that touches a mention
+    /// removes the whole token; typing inside one turns it into plain text.
+    /// Returns the new value and the caret position after the edit.
+    pub fn apply_edit(&self, new_text: &str) -> (MentionValue, usize) {
+        let old: Vec<char> = self.text.chars().collect();
+        let new: Vec<char> = new_text.chars().collect();
+
+        let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+        let max_suffix = old.len().min(new.len()) - prefix;
+        let suffix = old
+            .iter()
+            .rev()
+            .zip(new.iter().rev())
+            .take(max_suffix)
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        let removed_end = old.len() - suffix;
+        let inserted: Vec<char> = new[prefix..new.len() - suffix].to_vec();
+        let deleting = removed_end > prefix;
+
+        let mut start = prefix;
+        let mut end = removed_end;
+        let mut kept = Vec::new();
+        for span in &self.mentions {
+            let overlaps = if deleting {
+                span.start < removed_end && span.end > prefix
+            } else {
+                span.start < prefix && span.end > prefix
+            };
+            if overlaps {
+                if deleting {
+                    start = start.min(span.start);
+                    end = end.max(span.end);
+                }
+            } else {
+                kept.push(span.clone());
+            }
+        }
+
+        let text: String = old[..start]
+            .iter()
+            .chain(&inserted)
+            .chain(&old[end..])
+            .collect();
+        let caret = start + inserted.len();
+        let shift = caret as isize - end as isize;
+        let mentions = kept
+            .into_iter()
+            .map(|mut span| {
+                if span.start >= end {
+                    span.start = (span.start as isize + shift) as usize;
+                    span.end = (span.end as isize + shift) as usize;
+                }
+                span
+            })
+            .collect();
+
+        (MentionValue { text, mentions }, caret)
+    }
+
+    /// Replace the trigger `query` with a mention of `entity` followed by a
+    /// space. Returns the new value and the caret position after the space.
+    pub fn insert_mention(
+        &self,
+        query: &TriggerQuery,
+        entity: &MentionEntity,
+    ) -> (MentionValue, usize) {
+        let token = format!("{}{}", query.trigger, entity.label);
+        let token_len = token.chars().count();
+        let (mut value, _) = self.replace_range(query.start, query.end, &format!("{token} "));
+
+        value.mentions.push(MentionSpan {
+            start: query.start,
+            end: query.start + token_len,
+            trigger: query.trigger,
+            id: entity.id.clone(),
+            label: entity.label.clone(),
+        });
+        value.mentions.sort_by_key(|span| span.start);
+
+        (value, query.start + token_len + 1)
+    }
+
+    /// Remove the typed trigger `query` (e.g. after running a slash command).
+    pub fn remove_query(&self, query: &TriggerQuery) -> (MentionValue, usize) {
+        self.replace_range(query.start, query.end, "")
+    }
+
+    fn replace_range(&self, start: usize, end: usize, replacement: &str) -> (MentionValue, usize) {
+        let text: String = self
+            .text
+            .chars()
+            .take(start)
+            .chain(replacement.chars())
+            .chain(self.text.chars().skip(end))
+            .collect();
+        self.apply_edit(&text)
+    }
+}
@@ -14,6 +14,7 @@
 //! - [`Card`] - Content container with optional header
 //! - [`Image`] - Image with configurable fit modes
 //! - [`Input`] - Text input with styling support
+//! - [`InputMask`] / [`InputFormatter`] - Masks and format/parse hooks for `Input`
 //! - [`InputWrapper`] - Generic wrapper for input with left/right icons
 //! - [`Select`] - Dropdown selection
 //! - [`Checkbox`] - Checkbox with animations
@@ -21,7 +22,7 @@
 //! - [`RadioButton`] - Radio button for use with RadioGroup
 //! - [`Switch`] - Toggle switch
 //! - [`Slider`] - Range slider
-//! - [`Textarea`] - Multi-line text input
+//! - [`Textarea`] - Multi-line text input with mentions and slash commands
 //! - [`FileUpload`] - File upload with drag-drop
 //! - [`FormField`] - Form field wrapper with label and error
 //! - [`DatePicker`] - Date picker component
@@ -40,8 +41,10 @@ pub mod form_field;
 pub mod icon_button;
 pub mod image;
 pub mod input;
+pub mod input_mask;
 pub mod input_wrapper;
 pub mod link;
+pub mod mention;
 pub mod radio_group;
 pub mod select;
 pub mod slider;
@@ -63,8 +66,10 @@ pub use form_field::*;
 pub use icon_button::*;
 pub use image::*;
 pub use input::*;
+pub use input_mask::*;
 pub use input_wrapper::*;
 pub use link::*;
+pub use mention::*;
 pub use radio_group::*;
 pub use select::*;
 pub use slider::*;
@@ -5,6 +5,9 @@ use hikari_palette::classes::{ClassesBuilder, RadioClass};
 
 use crate::prelude::*;
 use crate::styled::StyledComponent;
+use crate::utils::{FocusAction, Orientation, RovingFocus, use_roving_id};
+
+const RADIO_SELECTOR: &str = "input[type=radio]";
 
 #[derive(Clone)]
 pub struct RadioContext {
@@ -30,6 +33,8 @@ pub fn RadioButton(props: RadioButtonProps) -> Element {
     let ctx = use_context::<RadioContext>().expect("RadioContext not found");
     let ctx = ctx.get();
     let is_checked = *ctx.selected_value.read() == props.value;
+    // Until a value is chosen every radio stays reachable with Tab.
+    let is_tab_stop = is_checked || ctx.selected_value.read().is_empty();
     let disabled = props.disabled || ctx.disabled;
 
     let radio_name = ctx.name.to_string();
@@ -58,6 +63,7 @@ pub fn RadioButton(props: RadioButtonProps) -> Element {
                 value: "{props.value}",
                 checked: is_checked,
                 disabled,
+                tabindex: if is_tab_stop { 0 } else { -1 },
                 onchange: handle_change,
             }
             div { class: "hk-radio-indicator",
@@ -99,7 +105,7 @@ pub fn RadioGroup(props: RadioGroupProps) -> Element {
         .clone()
         .unwrap_or_else(|| EventHandler::new(|_| {}));
 
-    let _ctx = use_context_provider(move || RadioContext {
+    let _ctx = use_context_provider(RadioContext {
         name,
         selected_value: selected_value.inner().clone(),
         on_change,
@@ -111,6 +117,25 @@ pub fn RadioGroup(props: RadioGroupProps) -> Element {
         RadioDirection::Horizontal => RadioClass::RadioGroupHorizontal,
     };
 
+    // Arrow keys move focus and check the focused radio; Space is left to
+    // the native input.
+    let orientation = match props.direction {
+        RadioDirection::Vertical => Orientation::Vertical,
+        RadioDirection::Horizontal => Orientation::Horizontal,
+    };
+    let group_id = use_roving_id("hi-radio-group");
+    let handle_keydown = {
+        let group_id = group_id.clone();
+        move |e: KeyboardEvent| {
+            let roving = RovingFocus::new(Orientation::Both).without_activation();
+            if let Some(FocusAction::Focus(index)) =
+                roving.handle_keydown(&e, &group_id, RADIO_SELECTOR)
+            {
+                crate::platform::click_item(&group_id, RADIO_SELECTOR, index, None);
+            }
+        }
+    };
+
     let group_classes = ClassesBuilder::new()
         .add(RadioClass::RadioGroup)
         .add(direction_class)
@@ -118,7 +143,15 @@ pub fn RadioGroup(props: RadioGroupProps) -> Element {
         .build();
 
     rsx! {
-        div { class: group_classes, {props.children} }
+        div {
+            id: group_id,
+            class: group_classes,
+            role: "radiogroup",
+            "aria-orientation": orientation.as_str(),
+            "aria-disabled": disabled,
+            onkeydown: handle_keydown,
+            {props.children}
+        }
     }
 }
 
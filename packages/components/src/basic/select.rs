@@ -125,6 +125,7 @@ pub fn Select(props: SelectProps) -> Element {
     let dropdown_id_for_click = dropdown_id.clone();
     let portal_remove = portal.remove_entry.clone();
     let portal_add = portal.add_entry.clone();
+    let theme_scope = portal.theme_scope.clone();
     let internal_value_for_click = internal_value.clone();
     let dropdown_id_for_click2 = dropdown_id.clone();
     let handle_trigger_click = move |e: MouseEvent| {
@@ -219,6 +220,7 @@ pub fn Select(props: SelectProps) -> Element {
 
             let entry = PortalEntry::Dropdown {
                 id,
+                theme_scope: theme_scope.clone(),
                 strategy: PortalPositionStrategy::TriggerBased {
                     placement: TriggerPlacement::BottomLeft,
                 },
@@ -1,11 +1,27 @@
 // hi-components/src/basic/textarea.rs
 // Textarea component with Arknights + FUI styling
 
+use std::sync::atomic::{AtomicU64, Ordering};
+
 use hikari_palette::classes::{ClassesBuilder, InputClass};
 
+use crate::basic::{
+    MentionEntity, MentionSpan, MentionTrigger, MentionValue, SlashCommand, TriggerQuery,
+    auto_resize_rows, clamp_rows, filter_commands, find_command, find_trigger,
+};
+use crate::platform;
+use crate::portal::{
+    PortalContext, PortalEntry, PortalMaskMode, PortalPositionStrategy, TriggerPlacement,
+    generate_portal_id, try_use_portal,
+};
 use crate::prelude::*;
 use crate::styled::StyledComponent;
 
+static TEXTAREA_ID_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Width of the suggestion list opened at the caret
+const SUGGESTION_MENU_WIDTH: f64 = 240.0;
+
 #[derive(Clone, Copy, PartialEq, Debug, Default)]
 pub enum TextareaSize {
     #[default]
@@ -52,6 +68,259 @@ pub struct TextareaProps {
     pub class: String,
 
     pub status: TextareaStatus,
+
+    /// `@mention` / `#channel` style triggers. Picking a suggestion inserts an
+    /// atomic mention token.
+    #[default]
+    pub triggers: Vec<MentionTrigger>,
+
+    /// Commands offered when `/` is typed at the start of a line
+    #[default]
+    pub commands: Vec<SlashCommand>,
+
+    #[default]
+    pub on_command: Option<EventHandler<SlashCommand>>,
+
+    /// Mentions contained in `value`
+    #[default]
+    pub mentions: Vec<MentionSpan>,
+
+    /// Called with the text and its mention spans on every change
+    #[default]
+    pub on_change: Option<EventHandler<MentionValue>>,
+
+    /// Grow with the content between `rows` and `max_rows`
+    #[default]
+    pub auto_resize: bool,
+
+    #[default]
+    pub max_rows: Option<u32>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum SuggestionItem {
+    Mention(MentionEntity),
+    Command(SlashCommand),
+}
+
+#[derive(Clone, PartialEq, Debug)]
+struct SuggestionMenu {
+    portal_id: String,
+    query: TriggerQuery,
+    items: Vec<SuggestionItem>,
+    highlighted: usize,
+}
+
+/// Shared state behind the textarea's event handlers.
+#[derive(Clone)]
+struct MentionController {
+    textarea_id: String,
+    value: Signal<MentionValue>,
+    menu: Signal<Option<SuggestionMenu>>,
+    /// `(rows, max_rows)` when the height follows the content
+    auto_rows: Option<(u32, Option<u32>)>,
+    portal: Option<PortalContext>,
+    triggers: Vec<MentionTrigger>,
+    commands: Vec<SlashCommand>,
+    oninput: Option<EventHandler<String>>,
+    on_change: Option<EventHandler<MentionValue>>,
+    on_command: Option<EventHandler<SlashCommand>>,
+}
+
+impl MentionController {
+    fn handle_input(&self, text: &str) {
+        let (next, caret) = self.value.get().apply_edit(text);
+        let caret = if next.text == text {
+            platform::get_input_caret_by_id(&self.textarea_id).unwrap_or(caret)
+        } else {
+            // A mention was removed as a whole; reflect that in the DOM.
+            platform::set_input_value_by_id(&self.textarea_id, &next.text, caret);
+            caret
+        };
+        self.commit(next);
+        self.update_menu(caret);
+        self.fit_rows();
+    }
+
+    /// Resize to the measured content once the DOM has caught up.
+    fn fit_rows(&self) {
+        let Some((min_rows, max_rows)) = self.auto_rows else {
+            return;
+        };
+        let id = self.textarea_id.clone();
+        platform::request_animation_frame(move || {
+            platform::fit_textarea_rows_by_id(&id, |lines| clamp_rows(lines, min_rows, max_rows));
+        });
+    }
+
+    fn commit(&self, next: MentionValue) {
+        self.value.set(next.clone());
+        if let Some(handler) = self.oninput.as_ref() {
+            handler.call(next.text.clone());
+        }
+        if let Some(handler) = self.on_change.as_ref() {
+            handler.call(next);
+        }
+    }
+
+    fn update_menu(&self, caret: usize) {
+        let text = self.value.get().text;
+        let trigger_chars: Vec<char> = self.triggers.iter().map(|t| t.trigger).collect();
+
+        let found = match find_trigger(&text, caret, &trigger_chars) {
+            Some(query) => {
+                let items: Vec<SuggestionItem> = self
+                    .triggers
+                    .iter()
+                    .find(|t| t.trigger == query.trigger)
+                    .map(|t| t.source.search(&query.query))
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(SuggestionItem::Mention)
+                    .collect();
+                Some((query, items))
+            }
+            None if !self.commands.is_empty() => find_command(&text, caret).map(|query| {
+                let items = filter_commands(&self.commands, &query.query)
+                    .into_iter()
+                    .map(SuggestionItem::Command)
+                    .collect();
+                (query, items)
+            }),
+            None => None,
+        };
+
+        match found {
+            Some((query, items)) if !items.is_empty() => {
+                let portal_id = self
+                    .menu
+                    .get()
+                    .map(|menu| menu.portal_id)
+                    .unwrap_or_else(generate_portal_id);
+                self.show_menu(SuggestionMenu {
+                    portal_id,
+                    query,
+                    items,
+                    highlighted: 0,
+                });
+            }
+            _ => self.close_menu(),
+        }
+    }
+
+    fn show_menu(&self, menu: SuggestionMenu) {
+        let Some(portal) = self.portal.as_ref() else {
+            self.menu.set(Some(menu));
+            return;
+        };
+        if self.menu.get().is_some() {
+            portal.remove_entry.call(menu.portal_id.clone());
+        }
+
+        let trigger_rect = platform::get_caret_rect_by_id(&self.textarea_id)
+            .map(|rect| (rect.x, rect.y, SUGGESTION_MENU_WIDTH, rect.height));
+        portal.add_entry.call(PortalEntry::Dropdown {
+            id: menu.portal_id.clone(),
+            theme_scope: portal.theme_scope.clone(),
+            strategy: PortalPositionStrategy::TriggerBased {
+                placement: TriggerPlacement::BottomLeft,
+            },
+            mask_mode: PortalMaskMode::Transparent,
+            children: self.render_menu(&menu),
+            trigger_rect,
+            close_on_select: true,
+        });
+        self.menu.set(Some(menu));
+    }
+
+    fn close_menu(&self) {
+        if let Some(menu) = self.menu.get() {
+            if let Some(portal) = self.portal.as_ref() {
+                portal.remove_entry.call(menu.portal_id);
+            }
+            self.menu.set(None);
+        }
+    }
+
+    fn move_highlight(&self, forward: bool) {
+        if let Some(mut menu) = self.menu.get() {
+            let len = menu.items.len();
+            menu.highlighted = if forward {
+                (menu.highlighted + 1) % len
+            } else {
+                (menu.highlighted + len - 1) % len
+            };
+            self.show_menu(menu);
+        }
+    }
+
+    fn select(&self, index: usize) {
+        let Some(menu) = self.menu.get() else {
+            return;
+        };
+        let Some(item) = menu.items.get(index).cloned() else {
+            return;
+        };
+        self.close_menu();
+
+        let current = self.value.get();
+        let (next, caret) = match &item {
+            SuggestionItem::Mention(entity) => current.insert_mention(&menu.query, entity),
+            SuggestionItem::Command(_) => current.remove_query(&menu.query),
+        };
+        platform::set_input_value_by_id(&self.textarea_id, &next.text, caret);
+        self.commit(next);
+
+        if let SuggestionItem::Command(command) = item
+            && let Some(handler) = self.on_command.as_ref()
+        {
+            handler.call(command);
+        }
+    }
+
+    #[allow(unused_braces)]
+    fn render_menu(&self, menu: &SuggestionMenu) -> Element {
+        let items: Vec<Element> = menu
+            .items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let (label, description) = match item {
+                    SuggestionItem::Mention(entity) => (
+                        format!("{}{}", menu.query.trigger, entity.label),
+                        entity.description.clone().unwrap_or_default(),
+                    ),
+                    SuggestionItem::Command(command) => {
+                        (format!("/{}", command.name), command.description.clone())
+                    }
+                };
+                let class = if index == menu.highlighted {
+                    "hk-textarea-suggestion hk-textarea-suggestion-active"
+                } else {
+                    "hk-textarea-suggestion"
+                };
+                let controller = self.clone();
+                rsx! {
+                    div {
+                        class,
+                        role: "option",
+                        "aria-selected": index == menu.highlighted,
+                        onmousedown: move |e: MouseEvent| {
+                            // Keep focus in the textarea.
+                            e.prevent_default();
+                            controller.select(index);
+                        },
+                        span { class: "hk-textarea-suggestion-label", {label} }
+                        span { class: "hk-textarea-suggestion-description", {description} }
+                    }
+                }
+            })
+            .collect();
+
+        rsx! {
+            div { class: "hk-textarea-suggestions", role: "listbox", ..items }
+        }
+    }
 }
 
 ///
@@ -79,20 +348,83 @@ pub fn Textarea(props: TextareaProps) -> Element {
         .add_raw(&props.class)
         .build();
 
+    let textarea_id = use_signal(|| {
+        format!(
+            "hi-textarea-{}",
+            TEXTAREA_ID_COUNTER.fetch_add(1, Ordering::Relaxed)
+        )
+    })
+    .get();
+    // Built from the props on every render, so when the parent changes
+    // `value` or `mentions` the handlers reconcile edits against the new spans
+    let value = Signal::new(MentionValue::new(
+        props.value.clone(),
+        props.mentions.clone(),
+    ));
+    let menu = use_signal(|| None::<SuggestionMenu>);
+
+    let auto_rows = props.auto_resize.then_some((props.rows, props.max_rows));
+    let controller = MentionController {
+        textarea_id: textarea_id.clone(),
+        value,
+        menu: menu.inner().clone(),
+        auto_rows,
+        portal: try_use_portal(),
+        triggers: props.triggers.clone(),
+        commands: props.commands.clone(),
+        oninput: props.oninput.clone(),
+        on_change: props.on_change.clone(),
+        on_command: props.on_command.clone(),
+    };
+
+    // Counting line breaks is the fallback; on the web the rendered textarea
+    // is measured so soft-wrapped lines count too
+    let rows = if props.auto_resize {
+        controller.fit_rows();
+        auto_resize_rows(&props.value, props.rows, props.max_rows)
+    } else {
+        props.rows
+    };
+    let style = props.auto_resize.then_some("resize: none;");
+
+    let controller_for_input = controller.clone();
+    let controller_for_keydown = controller.clone();
+    let controller_for_blur = controller;
+
     rsx! {
         textarea {
+            id: textarea_id,
             class: textarea_classes,
+            style,
             disabled: props.disabled,
             readonly: props.readonly,
             placeholder: props.placeholder.unwrap_or_default(),
             value: "{props.value}",
-            rows: props.rows,
+            rows,
             maxlength: props.maxlength.unwrap_or(0),
-            oninput: move |e: InputEvent| {
-                if let Some(handler) = props.oninput.as_ref() {
-                    handler.call(e.data.clone());
+            oninput: move |e: InputEvent| controller_for_input.handle_input(&e.data),
+            onkeydown: move |e: KeyboardEvent| {
+                let Some(menu) = controller_for_keydown.menu.get() else {
+                    return;
+                };
+                match e.get_key() {
+                    Key::ArrowDown => {
+                        e.prevent_default();
+                        controller_for_keydown.move_highlight(true);
+                    }
+                    Key::ArrowUp => {
+                        e.prevent_default();
+                        controller_for_keydown.move_highlight(false);
+                    }
+                    Key::Enter | Key::Tab => {
+                        e.prevent_default();
+                        controller_for_keydown.select(menu.highlighted);
+                    }
+                    Key::Escape => controller_for_keydown.close_menu(),
+                    _ => {}
                 }
             },
+            onblur: move |_| controller_for_blur.close_menu(),
         }
     }
 }
@@ -166,6 +498,37 @@ impl StyledComponent for TextareaComponent {
 [data-theme="dark"] .hk-input-disabled {
   background: var(--hi-surface);
 }
+
+.hk-textarea-suggestions {
+  max-height: 240px;
+  overflow-y: auto;
+  padding: 4px;
+}
+
+.hk-textarea-suggestion {
+  display: flex;
+  align-items: baseline;
+  gap: 8px;
+  padding: 6px 10px;
+  border-radius: 4px;
+  cursor: pointer;
+  font-size: 14px;
+  color: var(--hi-text-primary);
+}
+
+.hk-textarea-suggestion:hover,
+.hk-textarea-suggestion-active {
+  background: var(--hi-surface-light);
+  color: var(--hi-color-primary);
+}
+
+.hk-textarea-suggestion-description {
+  font-size: 12px;
+  color: var(--hi-text-secondary);
+  overflow: hidden;
+  text-overflow: ellipsis;
+  white-space: nowrap;
+}
 "#
     }
 
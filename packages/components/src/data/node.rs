@@ -37,6 +37,10 @@ pub struct TreeNodeProps {
     #[default]
     pub level: usize,
 
+    /// Takes the tree's tab stop while no node is selected
+    #[default]
+    pub tab_stop: bool,
+
     #[default]
     pub class: String,
 
@@ -96,8 +100,10 @@ pub fn TreeNode(props: TreeNodeProps) -> Element {
             role: "treeitem",
             "data-node-key": node_key,
             "data-level": level,
-            aria_expanded: if has_children { is_expanded.get().to_string() } else { "false".to_string() },
+            "aria-level": level + 1,
+            "aria-expanded": has_children.then(|| is_expanded.get().to_string()),
             aria_selected: props.selected.to_string(),
+            tabindex: if props.selected || props.tab_stop { 0 } else { -1 },
             aria_disabled: props.disabled.to_string(),
 
             TreeNodeContent {
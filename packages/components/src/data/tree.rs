@@ -6,6 +6,9 @@ use hikari_palette::classes::{ClassesBuilder, TreeClassNew};
 use crate::data::node::{TreeNode, TreeNodeData, TreeNodeProps};
 use crate::prelude::*;
 use crate::styled::StyledComponent;
+use crate::utils::{RovingFocus, use_roving_id};
+
+const TREE_ITEM_SELECTOR: &str = "[role=treeitem]";
 
 pub struct TreeComponent;
 
@@ -39,7 +42,6 @@ pub struct TreeProps {
 pub fn Tree(props: TreeProps) -> Element {
     let expanded_keys = use_signal(|| props.default_expanded_keys.clone());
     let selected_keys = use_signal(|| props.default_selected_keys.clone());
-    let mut _focused_key = use_signal(String::new);
 
     let line_class = if props.show_line {
         "hk-tree-show-line"
@@ -47,29 +49,38 @@ pub fn Tree(props: TreeProps) -> Element {
         ""
     };
 
-    let handle_keydown = move |e: KeyboardEvent| match e.get_key() {
-        Key::ArrowUp | Key::ArrowDown | Key::ArrowLeft | Key::ArrowRight => {
-            e.prevent_default();
-        }
-        Key::Enter | Key::Character(_) => {
-            e.prevent_default();
+    // WAI-ARIA tree view: Up/Down walk the visible nodes, Right/Left expand,
+    // collapse or move between parent and child, Enter toggles.
+    let roving = RovingFocus::tree().click_target(".hk-tree-node-content");
+    let tree_id = use_roving_id("hi-tree");
+    let handle_keydown = {
+        let tree_id = tree_id.clone();
+        move |e: KeyboardEvent| {
+            roving.handle_keydown(&e, &tree_id, TREE_ITEM_SELECTOR);
         }
-        _ => {}
     };
 
     // Build tree nodes by calling TreeNode function directly with props struct
+    // The items are the tab stop: the selected one, else the first enabled
+    let selected = selected_keys.read();
+    let tab_stop = match props.data.iter().any(|item| selected.contains(&item.key)) {
+        true => None,
+        false => props.data.iter().position(|item| !item.disabled),
+    };
     let tree_nodes: Vec<Element> = props
         .data
         .iter()
-        .map(|item| {
+        .enumerate()
+        .map(|(index, item)| {
             TreeNode(TreeNodeProps {
                 node_key: item.key.clone(),
                 label: item.label.clone(),
                 node_children: item.children.clone(),
                 disabled: item.disabled,
                 expanded: expanded_keys.read().contains(&item.key),
-                selected: selected_keys.read().contains(&item.key),
+                selected: selected.contains(&item.key),
                 level: 0,
+                tab_stop: tab_stop == Some(index),
                 ..TreeNodeProps::default()
             })
         })
@@ -89,7 +100,7 @@ pub fn Tree(props: TreeProps) -> Element {
     rsx! {
         div {
             class: container_classes,
-            tabindex: 0,
+            id: tree_id,
             role: "tree",
             aria_multiselectable: "false",
             onkeydown: handle_keydown,
@@ -9,6 +9,7 @@
 //! - [`Skeleton`] / [`Empty`] - Skeleton loading states
 //! - [`Comment`] - Comment/feedback display
 //! - [`QRCode`] - QR code display
+//! - [`ShortcutHint`] - Keyboard shortcut key caps
 //! - [`Calendar`] - Date picker with calendar grid
 //! - [`Timeline`] - Vertical timeline for events
 //! - [`UserGuide`] - Step-by-step user onboarding
@@ -21,6 +22,7 @@ pub mod comment;
 pub mod drag_layer;
 pub mod empty;
 pub mod qrcode;
+pub mod shortcut_hint;
 pub mod skeleton;
 pub mod tag;
 pub mod timeline;
@@ -33,6 +35,7 @@ pub use comment::*;
 pub use drag_layer::*;
 pub use empty::*;
 pub use qrcode::*;
+pub use shortcut_hint::*;
 pub use skeleton::*;
 pub use tag::*;
 pub use timeline::*;
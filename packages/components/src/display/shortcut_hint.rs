@@ -0,0 +1,108 @@
+// packages/components/src/display/shortcut_hint.rs
+// ShortcutHint component: renders a keyboard shortcut as key caps
+
+use hikari_palette::classes::{ClassesBuilder, ShortcutHintClass, UtilityClass};
+
+use crate::platform;
+use crate::prelude::*;
+use crate::styled::StyledComponent;
+use crate::utils::Shortcut;
+
+pub struct ShortcutHintComponent;
+
+#[define_props]
+pub struct ShortcutHintProps {
+    /// Shortcut in [`Shortcut::parse`] syntax, e.g. `"Mod+Shift+P"` or
+    /// `"g then i"`; shown verbatim when it does not parse
+    pub shortcut: String,
+
+    #[default]
+    pub class: String,
+}
+
+/// Key caps of a shortcut, using ⌘/⌥/⇧ on macOS and Ctrl/Alt/Shift elsewhere.
+#[component]
+pub fn ShortcutHint(props: ShortcutHintProps) -> Element {
+    let classes = ClassesBuilder::new()
+        .add(ShortcutHintClass::Hint)
+        .add_raw(&props.class)
+        .build();
+
+    let Ok(shortcut) = Shortcut::parse(&props.shortcut) else {
+        return rsx! {
+            span { class: classes,
+                kbd { class: ShortcutHintClass::Key.as_class(), "{props.shortcut}" }
+            }
+        };
+    };
+    let mac = platform::is_mac();
+
+    let mut parts: Vec<Element> = Vec::new();
+    for (index, caps) in shortcut.caps(mac).into_iter().enumerate() {
+        if index > 0 {
+            parts.push(rsx! {
+                span { class: ShortcutHintClass::Then.as_class(), "then" }
+            });
+        }
+        let keys: Vec<Element> = caps
+            .into_iter()
+            .map(|cap| {
+                rsx! {
+                    kbd { class: ShortcutHintClass::Key.as_class(), "{cap}" }
+                }
+            })
+            .collect();
+        parts.push(rsx! {
+            span { class: ShortcutHintClass::Chord.as_class(), ..keys }
+        });
+    }
+
+    rsx! {
+        span {
+            class: classes,
+            "aria-label": shortcut.label(mac),
+            ..parts
+        }
+    }
+}
+
+impl StyledComponent for ShortcutHintComponent {
+    fn styles() -> &'static str {
+        r#"
+.hk-shortcut-hint {
+    display: inline-flex;
+    align-items: center;
+    gap: 6px;
+    color: var(--hi-color-text-secondary);
+    font-size: 0.75rem;
+    white-space: nowrap;
+}
+
+.hk-shortcut-hint-chord {
+    display: inline-flex;
+    gap: 2px;
+}
+
+.hk-shortcut-hint-key {
+    min-width: 1.25rem;
+    padding: 1px 5px;
+    border: 1px solid var(--hi-color-border);
+    border-bottom-width: 2px;
+    border-radius: 4px;
+    background-color: var(--hi-color-surface);
+    font-family: inherit;
+    font-size: inherit;
+    line-height: 1.4;
+    text-align: center;
+}
+
+.hk-shortcut-hint-then {
+    font-size: 0.7rem;
+}
+"#
+    }
+
+    fn name() -> &'static str {
+        "shortcut-hint"
+    }
+}
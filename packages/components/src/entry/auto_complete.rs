@@ -1,6 +1,9 @@
 // packages/components/src/entry/auto_complete.rs
 // AutoComplete component with Arknights + FUI styling
 
+use std::fmt;
+use std::rc::Rc;
+
 use hikari_palette::classes::{AutoCompleteClass, ClassesBuilder, UtilityClass};
 
 use crate::prelude::*;
@@ -8,6 +11,53 @@ use crate::styled::StyledComponent;
 
 pub struct AutoCompleteComponent;
 
+pub type SuggestFn = dyn Fn(&str) -> Vec<String>;
+
+/// Source of completion suggestions, shared by `AutoComplete` and `TagInput`.
+///
+/// Wraps a `query -> suggestions` function so callers can plug in fuzzy
+/// matching, remote lookups backed by a cache, or a plain option list.
+#[derive(Clone)]
+pub struct SuggestionProvider(Rc<SuggestFn>);
+
+impl SuggestionProvider {
+    pub fn new(f: impl Fn(&str) -> Vec<String> + 'static) -> Self {
+        Self(Rc::new(f))
+    }
+
+    /// Case-insensitive prefix matching over a fixed option list (the default
+    /// `AutoComplete` behaviour).
+    pub fn from_options(options: Vec<String>) -> Self {
+        Self::new(move |query| filter_options(&options, query))
+    }
+
+    pub fn suggest(&self, query: &str) -> Vec<String> {
+        (self.0)(query)
+    }
+}
+
+impl PartialEq for SuggestionProvider {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for SuggestionProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SuggestionProvider(..)")
+    }
+}
+
+/// Options starting with `query`, ignoring case; all options for an empty query.
+pub fn filter_options(options: &[String], query: &str) -> Vec<String> {
+    let query_lower = query.to_lowercase();
+    options
+        .iter()
+        .filter(|option| query_lower.is_empty() || option.to_lowercase().starts_with(&query_lower))
+        .cloned()
+        .collect()
+}
+
 /// Props for the AutoComplete component
 #[define_props]
 pub struct AutoCompleteProps {
@@ -15,6 +65,9 @@ pub struct AutoCompleteProps {
 
     pub options: Vec<String>,
 
+    /// Overrides prefix filtering of `options` when set.
+    pub provider: Option<SuggestionProvider>,
+
     #[default(EventHandler::new(|_: String| {}))]
     pub on_select: EventHandler<String>,
 
@@ -39,22 +92,17 @@ pub fn AutoComplete(props: AutoCompleteProps) -> Element {
 
     // Clone props values before using in effects
     let props_value = props.value.clone();
-    let props_options = props.options.clone();
+    let provider = props
+        .provider
+        .clone()
+        .unwrap_or_else(|| SuggestionProvider::from_options(props.options.clone()));
 
     // Clone signals for use in multiple closures
     let filtered_options_for_effect = filtered_options.clone();
 
     // Update filtered options when props change
     use_effect(move || {
-        let value_lower = props_value.to_lowercase();
-        let filtered = props_options
-            .iter()
-            .filter(|option| {
-                option.to_lowercase().starts_with(&value_lower) || value_lower.is_empty()
-            })
-            .cloned()
-            .collect::<Vec<_>>();
-        filtered_options_for_effect.set(filtered);
+        filtered_options_for_effect.set(provider.suggest(&props_value));
     });
 
     // Handle input change
@@ -0,0 +1,1025 @@
+// packages/components/src/entry/command_palette.rs
+// CommandPalette component with Arknights + FUI styling
+// Features: Ctrl/Cmd+K overlay on the Portal system, fuzzy search over registered
+// commands, nested pages, recent commands and async result providers
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use hikari_icons::{Icon, MdiIcon};
+use hikari_palette::classes::{ClassesBuilder, CommandPaletteClass, UtilityClass};
+use tairitsu_vdom::runtime;
+
+use crate::display::{ShortcutHint, ShortcutHintProps};
+use crate::modal::{MaskMode, ModalPosition, ModalSize};
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use crate::platform::set_timeout;
+use crate::portal::{ModalAnimationState, PortalContext, PortalEntry, use_portal};
+use crate::prelude::*;
+use crate::styled::StyledComponent;
+use crate::utils::{Shortcut, ShortcutBinding, unregister_shortcut, use_shortcut};
+
+pub struct CommandPaletteComponent;
+
+/// Portal entry id of the palette; there is one palette per application.
+pub const COMMAND_PALETTE_ID: &str = "hk-command-palette";
+
+/// Group label of the recent commands shown for an empty query.
+pub const RECENT_GROUP: &str = "Recent";
+
+/// Shortcut that opens and closes the palette.
+pub const TOGGLE_SHORTCUT: &str = "Mod+K";
+
+const TOGGLE_SHORTCUT_ID: &str = "command-palette.toggle";
+const CLOSE_SHORTCUT_ID: &str = "command-palette.close";
+
+/// Number of recently run commands that are remembered.
+pub const MAX_RECENT: usize = 5;
+
+pub type CommandFn = dyn Fn();
+
+/// What running a [`Command`] does.
+#[derive(Clone)]
+pub struct CommandAction(Rc<CommandFn>);
+
+impl CommandAction {
+    pub fn new(f: impl Fn() + 'static) -> Self {
+        Self(Rc::new(f))
+    }
+
+    pub fn run(&self) {
+        (self.0)()
+    }
+}
+
+impl PartialEq for CommandAction {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for CommandAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CommandAction(..)")
+    }
+}
+
+/// An entry of the command palette.
+///
+/// A command either runs its `action` or, when it has `children`, opens them
+/// as a nested page.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Command {
+    /// Unique id, also used to remember recent commands
+    pub id: String,
+    pub title: String,
+    /// Extra search terms that do not appear in the title
+    pub keywords: Vec<String>,
+    pub group: Option<String>,
+    pub icon: Option<MdiIcon>,
+    /// Shortcut hint shown next to the title, e.g. `"Ctrl+S"`
+    pub shortcut: Option<String>,
+    pub children: Vec<Command>,
+    pub action: Option<CommandAction>,
+}
+
+impl Command {
+    pub fn new(id: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn keywords<S: Into<String>>(mut self, keywords: impl IntoIterator<Item = S>) -> Self {
+        self.keywords = keywords.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+
+    pub fn icon(mut self, icon: MdiIcon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    pub fn children(mut self, children: Vec<Command>) -> Self {
+        self.children = children;
+        self
+    }
+
+    pub fn on_run(mut self, f: impl Fn() + 'static) -> Self {
+        self.action = Some(CommandAction::new(f));
+        self
+    }
+
+    /// Whether selecting the command opens a nested page.
+    pub fn is_page(&self) -> bool {
+        !self.children.is_empty()
+    }
+}
+
+/// Fuzzy score of `query` against `text`, or `None` when the characters of
+/// `query` do not all appear in `text` in order.
+///
+/// Matching ignores case and whitespace. Consecutive characters and matches
+/// at the start of a word score higher; leading unmatched text scores lower.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let query: Vec<char> = query
+        .to_lowercase()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut matched = 0;
+    let mut first = None;
+    let mut previous: Option<usize> = None;
+    for (index, c) in text.iter().enumerate() {
+        if matched == query.len() {
+            break;
+        }
+        if *c != query[matched] {
+            continue;
+        }
+        score += 1;
+        if previous.is_some_and(|p| p + 1 == index) {
+            score += 5;
+        }
+        if index == 0 || !text[index - 1].is_alphanumeric() {
+            score += 8;
+        }
+        first.get_or_insert(index);
+        previous = Some(index);
+        matched += 1;
+    }
+
+    if matched < query.len() {
+        return None;
+    }
+    Some(score - first.unwrap_or(0).min(10) as i32)
+}
+
+/// Best fuzzy score of `query` against a command's title, keywords and group.
+/// Title matches are preferred.
+pub fn command_score(query: &str, command: &Command) -> Option<i32> {
+    let title = fuzzy_score(query, &command.title).map(|score| score + 10);
+    let keywords = command
+        .keywords
+        .iter()
+        .filter_map(|keyword| fuzzy_score(query, keyword))
+        .max();
+    let group = command
+        .group
+        .as_deref()
+        .and_then(|group| fuzzy_score(query, group));
+    [title, keywords, group].into_iter().flatten().max()
+}
+
+/// Commands matching `query`, best first. An empty query keeps all commands
+/// in their original order.
+pub fn search_commands(commands: &[Command], query: &str) -> Vec<Command> {
+    if query.trim().is_empty() {
+        return commands.to_vec();
+    }
+    let mut scored: Vec<(i32, &Command)> = commands
+        .iter()
+        .filter_map(|command| command_score(query, command).map(|score| (score, command)))
+        .collect();
+    scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    scored
+        .into_iter()
+        .map(|(_, command)| command.clone())
+        .collect()
+}
+
+/// Commands listed for `query` on a page.
+///
+/// For an empty query the `recent` commands come first, under
+/// [`RECENT_GROUP`], followed by the remaining commands.
+pub fn palette_items(commands: &[Command], query: &str, recent: &[Command]) -> Vec<Command> {
+    if !query.trim().is_empty() {
+        return search_commands(commands, query);
+    }
+    let mut items: Vec<Command> = recent
+        .iter()
+        .map(|command| Command {
+            group: Some(RECENT_GROUP.to_string()),
+            ..command.clone()
+        })
+        .collect();
+    items.extend(
+        commands
+            .iter()
+            .filter(|command| !recent.iter().any(|r| r.id == command.id))
+            .cloned(),
+    );
+    items
+}
+
+/// Groups `items` by their group, ordered by first appearance.
+pub fn group_commands(items: &[Command]) -> Vec<(Option<String>, Vec<Command>)> {
+    let mut groups: Vec<(Option<String>, Vec<Command>)> = Vec::new();
+    for item in items {
+        match groups.iter_mut().find(|(group, _)| *group == item.group) {
+            Some((_, commands)) => commands.push(item.clone()),
+            None => groups.push((item.group.clone(), vec![item.clone()])),
+        }
+    }
+    groups
+}
+
+/// Finds a command by id, searching nested pages too.
+pub fn find_command_by_id(commands: &[Command], id: &str) -> Option<Command> {
+    commands.iter().find_map(|command| {
+        if command.id == id {
+            Some(command.clone())
+        } else {
+            find_command_by_id(&command.children, id)
+        }
+    })
+}
+
+thread_local! {
+    static REGISTRY: RefCell<Vec<Command>> = const { RefCell::new(Vec::new()) };
+    static RECENT: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    static OPENER: RefCell<Option<Rc<dyn Fn()>>> = const { RefCell::new(None) };
+}
+
+/// Clears the palette opener when the palette that set it unmounts.
+struct OpenerRegistration(Rc<dyn Fn()>);
+
+impl Drop for OpenerRegistration {
+    fn drop(&mut self) {
+        // Hook slots may outlive the opener when the thread exits
+        let _ = OPENER.try_with(|opener| {
+            let mut opener = opener.borrow_mut();
+            if opener
+                .as_ref()
+                .is_some_and(|open| Rc::ptr_eq(open, &self.0))
+            {
+                *opener = None;
+            }
+        });
+    }
+}
+
+/// Makes `open` what [`toggle_command_palette`] calls while the calling
+/// component is mounted
+fn use_opener(open: Rc<dyn Fn()>) {
+    let Some(component) = runtime::active_component_id() else {
+        OPENER.with(|opener| *opener.borrow_mut() = Some(open));
+        return;
+    };
+    // Hook slots are cleared on unmount, dropping the registration with them.
+    let (latest, _) = runtime::hook_slot(component, "hk-command-palette:opener", || {
+        let latest = Rc::new(RefCell::new(open.clone()));
+        let forward = latest.clone();
+        let opener: Rc<dyn Fn()> = Rc::new(move || {
+            let open = forward.borrow().clone();
+            open()
+        });
+        OPENER.with(|slot| *slot.borrow_mut() = Some(opener.clone()));
+        (latest, Rc::new(OpenerRegistration(opener)))
+    });
+    *latest.borrow_mut() = open;
+}
+
+/// Adds `command` to the global registry, replacing one with the same id.
+pub fn register_command(command: Command) {
+    REGISTRY.with(|registry| {
+        let mut registry = registry.borrow_mut();
+        match registry.iter_mut().find(|c| c.id == command.id) {
+            Some(existing) => *existing = command,
+            None => registry.push(command),
+        }
+    });
+}
+
+pub fn unregister_command(id: &str) {
+    REGISTRY.with(|registry| registry.borrow_mut().retain(|c| c.id != id));
+}
+
+/// Commands registered with [`register_command`] / [`use_command`].
+pub fn registered_commands() -> Vec<Command> {
+    REGISTRY.with(|registry| registry.borrow().clone())
+}
+
+/// Remembers `id` as the most recently run command.
+pub fn record_recent(id: &str) {
+    RECENT.with(|recent| {
+        let mut recent = recent.borrow_mut();
+        recent.retain(|r| r != id);
+        recent.insert(0, id.to_string());
+        recent.truncate(MAX_RECENT);
+    });
+}
+
+/// Ids of recently run commands, most recent first.
+pub fn recent_command_ids() -> Vec<String> {
+    RECENT.with(|recent| recent.borrow().clone())
+}
+
+/// Unregisters a [`use_command`] command when its component unmounts.
+struct CommandRegistration(String);
+
+impl Drop for CommandRegistration {
+    fn drop(&mut self) {
+        unregister_command(&self.0);
+    }
+}
+
+/// Shortcut binding that runs `command`, if it has both a parseable
+/// shortcut and an action.
+pub fn command_shortcut(command: &Command) -> Option<ShortcutBinding> {
+    let shortcut = Shortcut::parse(command.shortcut.as_deref()?).ok()?;
+    let action = command.action.clone()?;
+    Some(
+        ShortcutBinding::new(format!("command:{}", command.id), shortcut, move || {
+            action.run()
+        })
+        .description(command.title.clone()),
+    )
+}
+
+/// Register `command` with the palette while the calling component is mounted.
+///
+/// Re-registering on every render keeps the command's action up to date. A
+/// command with a shortcut is also bound in the global shortcut scope.
+pub fn use_command(command: Command) {
+    if let Some(component) = runtime::active_component_id() {
+        // Hook slots are cleared when the component unmounts, dropping the
+        // registration guard with them.
+        let id = command.id.clone();
+        let key = format!("hk-command:{}", command.id);
+        runtime::hook_slot(component, &key, move || Rc::new(CommandRegistration(id)));
+    }
+    if let Some(binding) = command_shortcut(&command) {
+        use_shortcut(binding);
+    }
+    register_command(command);
+}
+
+/// Opens the mounted [`CommandPalette`], or closes it when open.
+///
+/// Returns `false` when no palette is mounted.
+pub fn toggle_command_palette() -> bool {
+    let opener = OPENER.with(|opener| opener.borrow().clone());
+    match opener {
+        Some(open) => {
+            open();
+            true
+        }
+        None => false,
+    }
+}
+
+pub type ResolveFn = dyn Fn(Vec<Command>);
+
+/// Hands the results of a [`CommandProvider`] back to the palette.
+///
+/// It may be resolved later, e.g. from a fetch callback; results for a query
+/// that has changed in the meantime are dropped.
+#[derive(Clone)]
+pub struct CommandSink(Rc<ResolveFn>);
+
+impl CommandSink {
+    pub fn new(f: impl Fn(Vec<Command>) + 'static) -> Self {
+        Self(Rc::new(f))
+    }
+
+    pub fn resolve(&self, results: Vec<Command>) {
+        (self.0)(results)
+    }
+}
+
+impl PartialEq for CommandSink {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for CommandSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CommandSink(..)")
+    }
+}
+
+pub type ProvideFn = dyn Fn(&str, CommandSink);
+
+/// Source of extra results for a non-empty query on the root page, e.g.
+/// documents found by a server search.
+#[derive(Clone)]
+pub struct CommandProvider(Rc<ProvideFn>);
+
+impl CommandProvider {
+    pub fn new(f: impl Fn(&str, CommandSink) + 'static) -> Self {
+        Self(Rc::new(f))
+    }
+
+    /// Provider that answers synchronously.
+    pub fn from_fn(f: impl Fn(&str) -> Vec<Command> + 'static) -> Self {
+        Self::new(move |query, sink| sink.resolve(f(query)))
+    }
+
+    pub fn query(&self, query: &str, sink: CommandSink) {
+        (self.0)(query, sink)
+    }
+}
+
+impl PartialEq for CommandProvider {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for CommandProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CommandProvider(..)")
+    }
+}
+
+/// Results collected from the providers for the current query.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ProviderResults {
+    pub generation: u64,
+    /// Providers that have not answered yet
+    pub pending: usize,
+    pub commands: Vec<Command>,
+}
+
+impl ProviderResults {
+    /// Starts a new query answered by `providers` providers and returns its
+    /// generation.
+    pub fn start(&mut self, providers: usize) -> u64 {
+        self.generation += 1;
+        self.pending = providers;
+        self.commands.clear();
+        self.generation
+    }
+
+    /// Adds the answer of one provider. Answers to an older generation are
+    /// ignored; returns whether `results` was accepted.
+    pub fn accept(&mut self, generation: u64, results: Vec<Command>) -> bool {
+        if generation != self.generation {
+            return false;
+        }
+        self.pending = self.pending.saturating_sub(1);
+        self.commands.extend(results);
+        true
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.pending > 0
+    }
+}
+
+#[define_props]
+pub struct CommandPaletteProps {
+    /// Commands in addition to those registered with [`use_command`]
+    pub commands: Vec<Command>,
+
+    pub providers: Vec<CommandProvider>,
+
+    #[default("Type a command or search...".to_string())]
+    pub placeholder: String,
+
+    #[default("No results".to_string())]
+    pub empty_text: String,
+
+    /// Toggle the palette with Ctrl+K (Cmd+K on macOS)
+    #[default(true)]
+    pub shortcut: bool,
+
+    pub class: String,
+}
+
+/// Command palette overlay.
+///
+/// Mount it once, inside the `PortalProvider`; it renders nothing until opened
+/// with Ctrl/Cmd+K or [`toggle_command_palette`].
+#[component]
+pub fn CommandPalette(props: CommandPaletteProps) -> Element {
+    let portal = use_portal();
+
+    let open = {
+        let props = props.clone();
+        move || toggle_palette(&portal, &props)
+    };
+    use_opener(Rc::new(open));
+
+    if props.shortcut {
+        let toggle = Shortcut::parse(TOGGLE_SHORTCUT).expect("valid shortcut");
+        use_shortcut(
+            ShortcutBinding::new(TOGGLE_SHORTCUT_ID, toggle.clone(), || {
+                toggle_command_palette();
+            })
+            .description("Command palette"),
+        );
+        // The open palette is a modal scope, which hides the binding above.
+        use_shortcut(
+            ShortcutBinding::new(CLOSE_SHORTCUT_ID, toggle, || {
+                toggle_command_palette();
+            })
+            .scope(COMMAND_PALETTE_ID)
+            .in_inputs(),
+        );
+    } else {
+        unregister_shortcut(TOGGLE_SHORTCUT_ID);
+        unregister_shortcut(CLOSE_SHORTCUT_ID);
+    }
+    for command in &props.commands {
+        if let Some(binding) = command_shortcut(command) {
+            use_shortcut(binding);
+        }
+    }
+
+    VNode::empty()
+}
+
+fn is_palette_open(portal: &PortalContext) -> bool {
+    portal
+        .entries
+        .read()
+        .iter()
+        .any(|entry| matches!(entry, PortalEntry::Modal { id, .. } if id == COMMAND_PALETTE_ID))
+}
+
+fn toggle_palette(portal: &PortalContext, props: &CommandPaletteProps) {
+    if is_palette_open(portal) {
+        close_palette(portal);
+        return;
+    }
+
+    portal.add_entry.call(PortalEntry::Modal {
+        id: COMMAND_PALETTE_ID.to_string(),
+        theme_scope: portal.theme_scope.clone(),
+        title: None,
+        position: ModalPosition::Top,
+        mask_mode: MaskMode::Opaque,
+        closable: false,
+        mask_closable: true,
+        size: ModalSize::Md,
+        children: rsx! {
+            CommandPalettePanel {
+                commands: props.commands.clone(),
+                providers: props.providers.clone(),
+                placeholder: props.placeholder.clone(),
+                empty_text: props.empty_text.clone(),
+                class: props.class.clone(),
+            }
+        },
+        animation_state: ModalAnimationState::Appearing,
+    });
+}
+
+fn close_palette(portal: &PortalContext) {
+    let id = COMMAND_PALETTE_ID.to_string();
+    portal.start_close_animation.call(id.clone());
+
+    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+    {
+        let remove = portal.remove_entry.clone();
+        set_timeout(move || remove.call(id), 200);
+    }
+
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    {
+        portal.remove_entry.call(id);
+    }
+}
+
+type CommandPalettePanelProps = CommandPaletteProps;
+
+/// Contents of the open palette; `shortcut` is unused here.
+#[component]
+fn CommandPalettePanel(props: CommandPalettePanelProps) -> Element {
+    let portal = use_portal();
+    let query = use_signal(String::new);
+    let active = use_signal(|| 0usize);
+    let pages = use_signal(Vec::<Command>::new);
+    let provided = use_signal(ProviderResults::default);
+
+    let mut all = props.commands.clone();
+    for command in registered_commands() {
+        if !all.iter().any(|c| c.id == command.id) {
+            all.push(command);
+        }
+    }
+
+    let stack = pages.get();
+    let is_root = stack.is_empty();
+    let current_query = query.get();
+    let page_commands = stack
+        .last()
+        .map(|page| page.children.clone())
+        .unwrap_or_else(|| all.clone());
+    let recent: Vec<Command> = if is_root {
+        recent_command_ids()
+            .iter()
+            .filter_map(|id| find_command_by_id(&all, id))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let mut items = palette_items(&page_commands, &current_query, &recent);
+    let provider_state = provided.get();
+    if is_root && !current_query.trim().is_empty() {
+        items.extend(provider_state.commands.clone());
+    }
+    let groups = group_commands(&items);
+    let flat: Vec<Command> = groups
+        .iter()
+        .flat_map(|(_, commands)| commands.iter().cloned())
+        .collect();
+    let active_index = active.get().min(flat.len().saturating_sub(1));
+
+    let go_back = {
+        let pages = pages.clone();
+        let query = query.clone();
+        let active = active.clone();
+        move || {
+            let mut stack = pages.get();
+            let popped = stack.pop().is_some();
+            if popped {
+                pages.set(stack);
+                query.set(String::new());
+                active.set(0);
+            }
+            popped
+        }
+    };
+
+    let select = {
+        let pages = pages.clone();
+        let query = query.clone();
+        let active = active.clone();
+        let portal = portal.clone();
+        move |command: Command| {
+            if command.is_page() {
+                let mut stack = pages.get();
+                stack.push(command);
+                pages.set(stack);
+                query.set(String::new());
+                active.set(0);
+                return;
+            }
+            record_recent(&command.id);
+            close_palette(&portal);
+            if let Some(action) = command.action.as_ref() {
+                action.run();
+            }
+        }
+    };
+
+    let handle_input = {
+        let query = query.clone();
+        let active = active.clone();
+        let provided = provided.clone();
+        let providers = props.providers.clone();
+        move |e: InputEvent| {
+            query.set(e.data.clone());
+            active.set(0);
+            if !is_root || providers.is_empty() {
+                return;
+            }
+            let searching = !e.data.trim().is_empty();
+            let mut state = provided.get();
+            let generation = state.start(if searching { providers.len() } else { 0 });
+            provided.set(state);
+            if !searching {
+                return;
+            }
+            for provider in &providers {
+                let provided = provided.clone();
+                provider.query(
+                    &e.data,
+                    CommandSink::new(move |results| {
+                        let mut state = provided.get();
+                        if state.accept(generation, results) {
+                            provided.set(state);
+                        }
+                    }),
+                );
+            }
+        }
+    };
+
+    let handle_keydown = {
+        let active = active.clone();
+        let query = query.clone();
+        let flat = flat.clone();
+        let select = select.clone();
+        let go_back = go_back.clone();
+        let portal = portal.clone();
+        move |e: KeyboardEvent| match e.get_key() {
+            Key::ArrowDown if !flat.is_empty() => {
+                e.prevent_default();
+                active.set((active_index + 1) % flat.len());
+            }
+            Key::ArrowUp if !flat.is_empty() => {
+                e.prevent_default();
+                let len = flat.len();
+                active.set((active_index + len - 1) % len);
+            }
+            Key::Enter => {
+                e.prevent_default();
+                if let Some(command) = flat.get(active_index) {
+                    select(command.clone());
+                }
+            }
+            Key::Escape => {
+                e.prevent_default();
+                e.stop_propagation();
+                if !go_back() {
+                    close_palette(&portal);
+                }
+            }
+            Key::Backspace if query.get().is_empty() && go_back() => {
+                e.prevent_default();
+            }
+            _ => {}
+        }
+    };
+
+    let mut group_elements: Vec<Element> = Vec::new();
+    let mut index = 0usize;
+    for (group, commands) in groups {
+        let mut rows: Vec<Element> = Vec::new();
+        if let Some(label) = group.as_ref() {
+            rows.push(rsx! {
+                div { class: CommandPaletteClass::GroupLabel.as_class(), "{label}" }
+            });
+        }
+
+        for command in commands {
+            let row_index = index;
+            index += 1;
+
+            let item_classes = ClassesBuilder::new()
+                .add(CommandPaletteClass::Item)
+                .add_if(CommandPaletteClass::ItemActive, || {
+                    row_index == active_index
+                })
+                .build();
+
+            let icon_el = match command.icon {
+                Some(icon) => rsx! {
+                    span { class: CommandPaletteClass::ItemIcon.as_class(),
+                        Icon { icon, size: 16 }
+                    }
+                },
+                None => VNode::empty(),
+            };
+            let shortcut_el = match command.shortcut.as_ref() {
+                Some(shortcut) => rsx! {
+                    ShortcutHint {
+                        shortcut: shortcut.clone(),
+                        class: CommandPaletteClass::ItemShortcut.as_class(),
+                    }
+                },
+                None => VNode::empty(),
+            };
+            let chevron_el = if command.is_page() {
+                rsx! {
+                    span { class: CommandPaletteClass::ItemChevron.as_class(),
+                        Icon { icon: MdiIcon::ChevronRight, size: 16 }
+                    }
+                }
+            } else {
+                VNode::empty()
+            };
+
+            let title = command.title.clone();
+            let select = select.clone();
+            let active = active.clone();
+            rows.push(rsx! {
+                div {
+                    id: format!("{COMMAND_PALETTE_ID}-option-{row_index}"),
+                    class: item_classes,
+                    role: "option",
+                    "aria-selected": row_index == active_index,
+                    "data-command": command.id.clone(),
+                    onmouseenter: move |_| active.set(row_index),
+                    onclick: move |_| select(command.clone()),
+                    {icon_el}
+                    span { class: CommandPaletteClass::ItemTitle.as_class(), "{title}" }
+                    {shortcut_el}
+                    {chevron_el}
+                }
+            });
+        }
+
+        group_elements.push(rsx! {
+            div {
+                class: CommandPaletteClass::Group.as_class(),
+                role: "group",
+                "aria-label": group.clone(),
+                ..rows
+            }
+        });
+    }
+
+    let status_el = if is_root && provider_state.is_loading() && !current_query.trim().is_empty() {
+        rsx! {
+            div { class: CommandPaletteClass::Loading.as_class(),
+                Icon { icon: MdiIcon::Loading, size: 16 }
+            }
+        }
+    } else if flat.is_empty() {
+        rsx! {
+            div { class: CommandPaletteClass::Empty.as_class(), "{props.empty_text}" }
+        }
+    } else {
+        VNode::empty()
+    };
+
+    let header_el = match stack.last() {
+        Some(page) => {
+            let go_back = go_back.clone();
+            let page_title = page.title.clone();
+            rsx! {
+                button {
+                    class: CommandPaletteClass::Back.as_class(),
+                    r#type: "button",
+                    "aria-label": "Back",
+                    onclick: move |_| {
+                        go_back();
+                    },
+                    Icon { icon: MdiIcon::ChevronLeft, size: 16 }
+                }
+                span { class: CommandPaletteClass::Breadcrumb.as_class(), "{page_title}" }
+            }
+        }
+        None => rsx! {
+            Icon { icon: MdiIcon::Magnify, size: 16 }
+        },
+    };
+
+    let panel_classes = ClassesBuilder::new()
+        .add(CommandPaletteClass::Panel)
+        .add_raw(&props.class)
+        .build();
+    let list_id = format!("{COMMAND_PALETTE_ID}-list");
+    let active_descendant =
+        (!flat.is_empty()).then(|| format!("{COMMAND_PALETTE_ID}-option-{active_index}"));
+
+    rsx! {
+        div { class: panel_classes, onkeydown: handle_keydown,
+            div { class: CommandPaletteClass::Header.as_class(),
+                {header_el}
+                input {
+                    class: CommandPaletteClass::Input.as_class(),
+                    r#type: "text",
+                    role: "combobox",
+                    value: current_query,
+                    placeholder: props.placeholder,
+                    autofocus: true,
+                    "aria-expanded": "true",
+                    "aria-controls": list_id.clone(),
+                    "aria-activedescendant": active_descendant,
+                    oninput: handle_input,
+                }
+            }
+            div {
+                id: list_id,
+                class: CommandPaletteClass::List.as_class(),
+                role: "listbox",
+                ..group_elements
+            }
+            {status_el}
+        }
+    }
+}
+
+impl StyledComponent for CommandPaletteComponent {
+    fn styles() -> &'static str {
+        r#"
+.hk-command-palette {
+    display: flex;
+    flex-direction: column;
+    width: 100%;
+    max-height: 60vh;
+    margin: -16px;
+    color: var(--hi-color-text-primary);
+}
+
+.hk-command-palette-header {
+    display: flex;
+    align-items: center;
+    gap: 8px;
+    padding: 12px 16px;
+    border-bottom: 1px solid var(--hi-color-border);
+    color: var(--hi-color-text-secondary);
+}
+
+.hk-command-palette-back {
+    display: inline-flex;
+    align-items: center;
+    padding: 2px;
+    border: none;
+    border-radius: 4px;
+    background: transparent;
+    color: inherit;
+    cursor: pointer;
+}
+
+.hk-command-palette-back:hover {
+    background-color: var(--hi-color-background);
+}
+
+.hk-command-palette-breadcrumb {
+    padding: 2px 8px;
+    border-radius: 4px;
+    background-color: var(--hi-color-background);
+    font-size: 0.75rem;
+    white-space: nowrap;
+}
+
+.hk-command-palette-input {
+    flex: 1;
+    border: none;
+    outline: none;
+    background: transparent;
+    color: var(--hi-color-text-primary);
+    font-size: 1rem;
+}
+
+.hk-command-palette-list {
+    overflow-y: auto;
+    padding: 4px 8px 8px;
+}
+
+.hk-command-palette-group-label {
+    padding: 8px 8px 4px;
+    font-size: 0.75rem;
+    font-weight: 600;
+    color: var(--hi-color-text-secondary);
+}
+
+.hk-command-palette-item {
+    display: flex;
+    align-items: center;
+    gap: 8px;
+    padding: 8px;
+    border-radius: 6px;
+    font-size: 0.875rem;
+    cursor: pointer;
+}
+
+.hk-command-palette-item-active {
+    background-color: rgba(var(--hi-color-primary-rgb), 0.12);
+    color: var(--hi-color-primary);
+}
+
+.hk-command-palette-item-icon {
+    display: inline-flex;
+    color: var(--hi-color-text-secondary);
+}
+
+.hk-command-palette-item-title {
+    flex: 1;
+    overflow: hidden;
+    text-overflow: ellipsis;
+    white-space: nowrap;
+}
+
+.hk-command-palette-item-shortcut {
+    flex-shrink: 0;
+}
+
+.hk-command-palette-item-chevron {
+    display: inline-flex;
+    color: var(--hi-color-text-secondary);
+}
+
+.hk-command-palette-empty,
+.hk-command-palette-loading {
+    display: flex;
+    justify-content: center;
+    padding: 16px;
+    font-size: 0.875rem;
+    color: var(--hi-color-text-secondary);
+}
+"#
+    }
+
+    fn name() -> &'static str {
+        "command-palette"
+    }
+}
@@ -1,11 +1,15 @@
 pub mod auto_complete;
 pub mod cascader;
+pub mod command_palette;
 pub mod number_input;
 pub mod search;
+pub mod tag_input;
 pub mod transfer;
 
 pub use auto_complete::*;
 pub use cascader::*;
+pub use command_palette::*;
 pub use number_input::*;
 pub use search::*;
+pub use tag_input::*;
 pub use transfer::{SelectChangeEvent, *};
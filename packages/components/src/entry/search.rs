@@ -200,6 +200,7 @@ pub fn Search(props: SearchProps) -> Element {
                             .add_entry
                             .call(PortalEntry::Dropdown {
                                 id: new_id,
+                                theme_scope: portal_for_onfocus.theme_scope.clone(),
                                 strategy: PortalPositionStrategy::TriggerBased {
                                     placement: TriggerPlacement::BottomLeft,
                                 },
@@ -0,0 +1,583 @@
+// packages/components/src/entry/tag_input.rs
+// TagInput (token field) component with Arknights + FUI styling
+
+use std::fmt;
+use std::rc::Rc;
+
+use hikari_palette::classes::{AutoCompleteClass, ClassesBuilder, TagInputClass, UtilityClass};
+
+use crate::display::{Tag, TagVariant};
+use crate::entry::SuggestionProvider;
+use crate::prelude::*;
+use crate::styled::StyledComponent;
+
+pub struct TagInputComponent;
+
+pub type ValidateFn = dyn Fn(&str) -> Result<(), String>;
+
+/// Per-token validation; `Err` carries the message shown on the token.
+#[derive(Clone)]
+pub struct TagValidator(Rc<ValidateFn>);
+
+impl TagValidator {
+    pub fn new(f: impl Fn(&str) -> Result<(), String> + 'static) -> Self {
+        Self(Rc::new(f))
+    }
+
+    pub fn validate(&self, token: &str) -> Result<(), String> {
+        (self.0)(token)
+    }
+}
+
+impl PartialEq for TagValidator {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for TagValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TagValidator(..)")
+    }
+}
+
+/// Props for the TagInput component
+#[define_props]
+pub struct TagInputProps {
+    pub value: Vec<String>,
+
+    pub on_change: Option<EventHandler<Vec<String>>>,
+
+    pub placeholder: String,
+
+    /// Characters that split typed or pasted text into tokens. Enter always
+    /// commits the current draft.
+    #[default(vec![',', ';', '\n', '\t'])]
+    pub delimiters: Vec<char>,
+
+    pub validator: Option<TagValidator>,
+
+    pub max_count: Option<usize>,
+
+    #[default(false)]
+    pub allow_duplicates: bool,
+
+    /// Suggestions for the current draft, same provider type as `AutoComplete`.
+    pub provider: Option<SuggestionProvider>,
+
+    /// Double-click a token to edit it in place.
+    #[default(true)]
+    pub editable: bool,
+
+    /// Drag tokens to reorder them.
+    #[default(true)]
+    pub draggable: bool,
+
+    #[default(false)]
+    pub disabled: bool,
+
+    pub class: String,
+
+    pub style: String,
+}
+
+/// Split `text` on any of `delimiters`.
+///
+/// Returns the complete tokens (trimmed, empty ones dropped) and the trailing
+/// remainder after the last delimiter, which stays in the draft.
+pub fn split_tokens(text: &str, delimiters: &[char]) -> (Vec<String>, String) {
+    let mut parts: Vec<&str> = text.split(|c| delimiters.contains(&c)).collect();
+    let remainder = parts.pop().unwrap_or_default().to_string();
+    let tokens = parts
+        .into_iter()
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .map(str::to_string)
+        .collect();
+    (tokens, remainder)
+}
+
+/// Append `incoming` to `existing`, dropping duplicates (unless allowed) and
+/// anything beyond `max_count`.
+pub fn merge_tokens(
+    existing: &[String],
+    incoming: Vec<String>,
+    max_count: Option<usize>,
+    allow_duplicates: bool,
+) -> Vec<String> {
+    let mut merged = existing.to_vec();
+    for token in incoming {
+        if max_count.is_some_and(|max| merged.len() >= max) {
+            break;
+        }
+        if allow_duplicates || !merged.contains(&token) {
+            merged.push(token);
+        }
+    }
+    merged
+}
+
+/// Move the token at `from` so it ends up at index `to`.
+pub fn move_token(tokens: &[String], from: usize, to: usize) -> Vec<String> {
+    let mut moved = tokens.to_vec();
+    if from >= moved.len() || from == to {
+        return moved;
+    }
+    let token = moved.remove(from);
+    moved.insert(to.min(moved.len()), token);
+    moved
+}
+
+#[component]
+pub fn TagInput(props: TagInputProps) -> Element {
+    let draft = use_signal(String::new);
+    let focused = use_signal(|| false);
+    // Suggestion picked with the arrow keys; Enter adds the draft otherwise
+    let highlighted = use_signal(|| None::<usize>);
+    let editing = use_signal(|| None::<usize>);
+    let edit_draft = use_signal(String::new);
+    let drag_index = use_signal(|| None::<usize>);
+    let drag_over = use_signal(|| None::<usize>);
+
+    let tags = props.value.clone();
+    let at_limit = props.max_count.is_some_and(|max| tags.len() >= max);
+
+    let suggestions: Vec<String> = match (&props.provider, focused.get()) {
+        (Some(provider), true) if !at_limit => provider
+            .suggest(&draft.get())
+            .into_iter()
+            .filter(|s| props.allow_duplicates || !tags.contains(s))
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    let emit = {
+        let on_change = props.on_change.clone();
+        move |next: Vec<String>| {
+            if let Some(handler) = on_change.as_ref() {
+                handler.call(next);
+            }
+        }
+    };
+
+    // Adds `incoming` tokens to the current value and clears the draft. At
+    // `max_count` new tokens are rejected and the draft is left as typed; the
+    // input itself stays enabled so Backspace can still remove tokens.
+    let commit = {
+        let tags = tags.clone();
+        let draft = draft.clone();
+        let highlighted = highlighted.clone();
+        let emit = emit.clone();
+        let max_count = props.max_count;
+        let allow_duplicates = props.allow_duplicates;
+        move |incoming: Vec<String>, remainder: String| {
+            if max_count.is_some_and(|max| tags.len() >= max) {
+                return;
+            }
+            let next = merge_tokens(&tags, incoming, max_count, allow_duplicates);
+            draft.set(remainder);
+            highlighted.set(None);
+            if next != tags {
+                emit(next);
+            }
+        }
+    };
+
+    let handle_input = {
+        let draft = draft.clone();
+        let highlighted = highlighted.clone();
+        let commit = commit.clone();
+        let delimiters = props.delimiters.clone();
+        move |e: InputEvent| {
+            if e.data.contains(|c| delimiters.contains(&c)) {
+                let (mut tokens, mut remainder) = split_tokens(&e.data, &delimiters);
+                // A pasted list ends at the paste, so its last token is complete
+                // too; while typing, the text after the delimiter stays a draft.
+                if e.input_type == "insertFromPaste" {
+                    let last = std::mem::take(&mut remainder);
+                    if !last.trim().is_empty() {
+                        tokens.push(last.trim().to_string());
+                    }
+                }
+                commit(tokens, remainder);
+            } else {
+                draft.set(e.data.clone());
+                highlighted.set(None);
+            }
+        }
+    };
+
+    let handle_keydown = {
+        let draft = draft.clone();
+        let highlighted = highlighted.clone();
+        let focused = focused.clone();
+        let commit = commit.clone();
+        let emit = emit.clone();
+        let tags = tags.clone();
+        let suggestions = suggestions.clone();
+        let disabled = props.disabled;
+        move |e: KeyboardEvent| {
+            if disabled {
+                return;
+            }
+            match e.get_key() {
+                Key::Enter => {
+                    e.prevent_default();
+                    let current = draft.get();
+                    if let Some(suggestion) =
+                        highlighted.get().and_then(|index| suggestions.get(index))
+                    {
+                        commit(vec![suggestion.clone()], String::new());
+                    } else if !current.trim().is_empty() {
+                        commit(vec![current.trim().to_string()], String::new());
+                    }
+                }
+                Key::Backspace if draft.get().is_empty() && !tags.is_empty() => {
+                    e.prevent_default();
+                    emit(tags[..tags.len() - 1].to_vec());
+                }
+                Key::ArrowDown if !suggestions.is_empty() => {
+                    e.prevent_default();
+                    let next = highlighted.get().map_or(0, |index| index + 1);
+                    highlighted.set(Some(next % suggestions.len()));
+                }
+                Key::ArrowUp if !suggestions.is_empty() => {
+                    e.prevent_default();
+                    let len = suggestions.len();
+                    let previous = highlighted.get().map_or(len - 1, |index| index + len - 1);
+                    highlighted.set(Some(previous % len));
+                }
+                Key::Escape => {
+                    focused.set(false);
+                }
+                _ => {}
+            }
+        }
+    };
+
+    let handle_focus = {
+        let focused = focused.clone();
+        let disabled = props.disabled;
+        move |_| {
+            if !disabled {
+                focused.set(true);
+            }
+        }
+    };
+
+    let handle_blur = {
+        let focused = focused.clone();
+        move |_| {
+            focused.set(false);
+        }
+    };
+
+    // Replaces (or, when emptied, removes) the token being edited in place.
+    let finish_edit = {
+        let editing = editing.clone();
+        let edit_draft = edit_draft.clone();
+        let tags = tags.clone();
+        let emit = emit.clone();
+        move |apply: bool| {
+            let Some(index) = editing.get() else {
+                return;
+            };
+            editing.set(None);
+            if !apply || index >= tags.len() {
+                return;
+            }
+            let edited = edit_draft.get().trim().to_string();
+            let mut next = tags.clone();
+            if edited.is_empty() {
+                next.remove(index);
+            } else {
+                next[index] = edited;
+            }
+            if next != tags {
+                emit(next);
+            }
+        }
+    };
+
+    let tokens: Vec<Element> = tags
+        .iter()
+        .enumerate()
+        .map(|(index, tag)| {
+            let error = props
+                .validator
+                .as_ref()
+                .and_then(|validator| validator.validate(tag).err());
+
+            if editing.get() == Some(index) {
+                let edit_draft_for_input = edit_draft.clone();
+                let finish_for_key = finish_edit.clone();
+                let finish_for_blur = finish_edit.clone();
+                return rsx! {
+                    input {
+                        class: TagInputClass::TokenEditor.as_class(),
+                        r#type: "text",
+                        value: edit_draft.get(),
+                        autofocus: true,
+                        oninput: move |e: InputEvent| edit_draft_for_input.set(e.data.clone()),
+                        onkeydown: move |e: KeyboardEvent| match e.get_key() {
+                            Key::Enter => {
+                                e.prevent_default();
+                                finish_for_key(true);
+                            }
+                            Key::Escape => finish_for_key(false),
+                            _ => {}
+                        },
+                        onblur: move |_| finish_for_blur(true),
+                    }
+                };
+            }
+
+            let token_classes = ClassesBuilder::new()
+                .add(TagInputClass::Token)
+                .add_if(TagInputClass::TokenInvalid, || error.is_some())
+                .add_if(TagInputClass::TokenDragging, || {
+                    drag_index.get() == Some(index)
+                })
+                .add_if(TagInputClass::TokenDragOver, || {
+                    drag_over.get() == Some(index)
+                })
+                .build();
+
+            let remove = {
+                let tags = tags.clone();
+                let emit = emit.clone();
+                EventHandler::new(move |_: MouseEvent| {
+                    let mut next = tags.clone();
+                    if index < next.len() {
+                        next.remove(index);
+                        emit(next);
+                    }
+                })
+            };
+
+            let start_edit = {
+                let editing = editing.clone();
+                let edit_draft = edit_draft.clone();
+                let tag = tag.clone();
+                let editable = props.editable && !props.disabled;
+                move |_| {
+                    if editable {
+                        edit_draft.set(tag.clone());
+                        editing.set(Some(index));
+                    }
+                }
+            };
+
+            let drag_index_for_start = drag_index.clone();
+            let drag_index_for_end = drag_index.clone();
+            let drag_over_for_end = drag_over.clone();
+            let drag_over_for_over = drag_over.clone();
+            let drag_index_for_drop = drag_index.clone();
+            let drag_over_for_drop = drag_over.clone();
+            let tags_for_drop = tags.clone();
+            let emit_for_drop = emit.clone();
+
+            let variant = if error.is_some() {
+                TagVariant::Danger
+            } else {
+                TagVariant::Default
+            };
+            let label = tag.clone();
+
+            rsx! {
+                span {
+                    class: token_classes,
+                    title: error.unwrap_or_default(),
+                    draggable: props.draggable && !props.disabled,
+                    ondblclick: start_edit,
+                    ondragstart: move |_: DragEvent| drag_index_for_start.set(Some(index)),
+                    ondragend: move |_: DragEvent| {
+                        drag_index_for_end.set(None);
+                        drag_over_for_end.set(None);
+                    },
+                    ondragover: move |e: DragEvent| {
+                        e.prevent_default();
+                        drag_over_for_over.set(Some(index));
+                    },
+                    ondrop: move |e: DragEvent| {
+                        e.prevent_default();
+                        if let Some(from) = drag_index_for_drop.get() {
+                            let next = move_token(&tags_for_drop, from, index);
+                            if next != tags_for_drop {
+                                emit_for_drop(next);
+                            }
+                        }
+                        drag_index_for_drop.set(None);
+                        drag_over_for_drop.set(None);
+                    },
+                    Tag {
+                        variant,
+                        closable: !props.disabled,
+                        on_close: Some(remove),
+                        "{label}"
+                    }
+                }
+            }
+        })
+        .collect();
+
+    let suggestion_list = if suggestions.is_empty() {
+        VNode::empty()
+    } else {
+        let highlighted_index = highlighted.get();
+        let items: Vec<Element> = suggestions
+            .iter()
+            .enumerate()
+            .map(|(index, suggestion)| {
+                let commit = commit.clone();
+                let suggestion_value = suggestion.clone();
+                let label = suggestion.clone();
+                rsx! {
+                    div {
+                        class: ClassesBuilder::new()
+                            .add(AutoCompleteClass::Option)
+                            .add_if(AutoCompleteClass::OptionFocused, || Some(index) == highlighted_index)
+                            .build(),
+                        onmousedown: move |e: MouseEvent| {
+                            // Fires before the input blurs and closes the list.
+                            e.prevent_default();
+                            commit(vec![suggestion_value.clone()], String::new());
+                        },
+                        "{label}"
+                    }
+                }
+            })
+            .collect();
+        rsx! {
+            div {
+                class: ClassesBuilder::new()
+                    .add(AutoCompleteClass::Dropdown)
+                    .add(AutoCompleteClass::Show)
+                    .build(),
+                role: "listbox",
+                ..items
+            }
+        }
+    };
+
+    let count_el = match props.max_count {
+        Some(max) => rsx! {
+            span { class: TagInputClass::Count.as_class(), "{tags.len()}/{max}" }
+        },
+        None => VNode::empty(),
+    };
+
+    let wrapper_classes = ClassesBuilder::new()
+        .add(TagInputClass::Wrapper)
+        .add_if(TagInputClass::Focused, || focused.get())
+        .add_if(TagInputClass::Disabled, || props.disabled)
+        .add_raw(&props.class)
+        .build();
+
+    let placeholder = if tags.is_empty() {
+        props.placeholder.clone()
+    } else {
+        String::new()
+    };
+
+    rsx! {
+        div { class: wrapper_classes, style: props.style,
+            ..tokens,
+
+            input {
+                class: TagInputClass::Input.as_class(),
+                r#type: "text",
+                value: draft.get(),
+                placeholder,
+                disabled: props.disabled,
+                oninput: handle_input,
+                onkeydown: handle_keydown,
+                onfocus: handle_focus,
+                onblur: handle_blur,
+            }
+
+            {count_el}
+            {suggestion_list}
+        }
+    }
+}
+
+impl StyledComponent for TagInputComponent {
+    fn styles() -> &'static str {
+        r#"
+.hk-tag-input {
+    position: relative;
+    display: flex;
+    flex-wrap: wrap;
+    align-items: center;
+    gap: 4px;
+    width: 100%;
+    min-height: 36px;
+    padding: 4px 8px;
+    border: 1px solid var(--hi-color-border);
+    border-radius: 8px;
+    background-color: var(--hi-color-surface);
+    cursor: text;
+    transition: all 0.2s ease;
+}
+
+.hk-tag-input-focused {
+    border-color: var(--hi-color-primary);
+    box-shadow: 0 0 0 2px rgba(59, 130, 246, 0.1);
+}
+
+.hk-tag-input-disabled {
+    opacity: 0.6;
+    cursor: not-allowed;
+}
+
+.hk-tag-input-token {
+    display: inline-flex;
+    cursor: grab;
+    transition: opacity 0.15s ease, transform 0.15s ease;
+}
+
+.hk-tag-input-token-invalid .hk-tag {
+    text-decoration: underline wavy var(--hi-color-danger, #ef4444);
+}
+
+.hk-tag-input-token-dragging {
+    opacity: 0.4;
+}
+
+.hk-tag-input-token-drag-over {
+    transform: translateX(4px);
+}
+
+.hk-tag-input-token-editor,
+.hk-tag-input-field {
+    flex: 1;
+    min-width: 80px;
+    border: none;
+    outline: none;
+    background: transparent;
+    color: var(--hi-color-text-primary);
+    font-size: 0.875rem;
+    padding: 4px 0;
+}
+
+.hk-tag-input-token-editor {
+    flex: 0 1 auto;
+    min-width: 40px;
+    padding: 2px 6px;
+    border: 1px dashed var(--hi-color-primary);
+    border-radius: 4px;
+}
+
+.hk-tag-input-count {
+    margin-left: auto;
+    font-size: 0.75rem;
+    color: var(--hi-color-text-secondary);
+}
+"#
+    }
+
+    fn name() -> &'static str {
+        "tag-input"
+    }
+}
@@ -0,0 +1,172 @@
+// hi-components/src/feedback/hover_intent.rs
+// Hover intent and delay groups shared by Tooltip and Popover
+
+use crate::platform::set_timeout;
+use crate::prelude::*;
+
+/// Default window during which a tooltip group stays "warm" after its last
+/// tooltip closed, so the next one opens instantly.
+pub const DEFAULT_SKIP_DELAY_MS: u64 = 300;
+
+/// Debounced open/close tracking for hover-driven overlays.
+///
+/// Every `enter`/`leave` bumps a generation counter; a pending timer only fires
+/// when no newer pointer transition happened in the meantime. Moving from the
+/// trigger into an interactive overlay (or back) therefore cancels the pending
+/// close instead of flickering.
+#[derive(Clone)]
+pub struct HoverIntent {
+    generation: Signal<u64>,
+    open: Signal<bool>,
+}
+
+impl Default for HoverIntent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HoverIntent {
+    pub fn new() -> Self {
+        Self {
+            generation: Signal::new(0),
+            open: Signal::new(false),
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open.get()
+    }
+
+    /// Pointer entered the trigger or overlay. Runs `on_open` after `delay_ms`
+    /// unless the overlay is already open.
+    pub fn enter(&self, delay_ms: u64, on_open: impl FnOnce() + 'static) {
+        let generation = self.bump();
+        if self.open.get() {
+            return;
+        }
+
+        if delay_ms == 0 {
+            self.open.set(true);
+            on_open();
+            return;
+        }
+
+        let current = self.generation.clone();
+        let open = self.open.clone();
+        set_timeout(
+            move || {
+                if current.get() == generation && !open.get() {
+                    open.set(true);
+                    on_open();
+                }
+            },
+            delay_ms as i32,
+        );
+    }
+
+    /// Pointer left the trigger or overlay. Runs `on_close` after `delay_ms`
+    /// unless the pointer came back first.
+    pub fn leave(&self, delay_ms: u64, on_close: impl FnOnce() + 'static) {
+        let generation = self.bump();
+        if !self.open.get() {
+            return;
+        }
+
+        if delay_ms == 0 {
+            self.open.set(false);
+            on_close();
+            return;
+        }
+
+        let current = self.generation.clone();
+        let open = self.open.clone();
+        set_timeout(
+            move || {
+                if current.get() == generation && open.get() {
+                    open.set(false);
+                    on_close();
+                }
+            },
+            delay_ms as i32,
+        );
+    }
+
+    /// Drop any pending transition and mark the overlay closed without
+    /// running callbacks (e.g. when it was dismissed by other means).
+    pub fn reset(&self) {
+        self.bump();
+        self.open.set(false);
+    }
+
+    fn bump(&self) -> u64 {
+        let next = self.generation.get().wrapping_add(1);
+        self.generation.set(next);
+        next
+    }
+}
+
+pub fn use_hover_intent() -> HoverIntent {
+    let intent = use_signal(HoverIntent::new);
+    intent.get()
+}
+
+/// Shared timing state for a [`TooltipDelayGroup`](crate::feedback::TooltipDelayGroup).
+///
+/// The first tooltip in a group waits for the full delay; while any tooltip is
+/// open, or within `skip_delay_ms` after the last one closed, the next tooltip
+/// opens immediately.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct DelayGroupState {
+    pub delay: u64,
+    pub close_delay: u64,
+    pub skip_delay_ms: u64,
+    open_count: usize,
+    last_closed_at: Option<f64>,
+}
+
+impl Default for DelayGroupState {
+    fn default() -> Self {
+        Self::new(0, 0, DEFAULT_SKIP_DELAY_MS)
+    }
+}
+
+impl DelayGroupState {
+    pub fn new(delay: u64, close_delay: u64, skip_delay_ms: u64) -> Self {
+        Self {
+            delay,
+            close_delay,
+            skip_delay_ms,
+            open_count: 0,
+            last_closed_at: None,
+        }
+    }
+
+    pub fn is_warm(&self, now: f64) -> bool {
+        if self.open_count > 0 {
+            return true;
+        }
+        match self.last_closed_at {
+            Some(closed_at) => now - closed_at <= self.skip_delay_ms as f64,
+            None => false,
+        }
+    }
+
+    /// Delay to apply before opening, given the member's own delay (if any).
+    pub fn open_delay(&self, own_delay: Option<u64>, now: f64) -> u64 {
+        if self.is_warm(now) {
+            0
+        } else {
+            own_delay.unwrap_or(self.delay)
+        }
+    }
+
+    pub fn mark_open(&mut self) {
+        self.open_count += 1;
+    }
+
+    pub fn mark_closed(&mut self, now: f64) {
+        self.open_count = self.open_count.saturating_sub(1);
+        self.last_closed_at = Some(now);
+    }
+}
@@ -1,5 +1,6 @@
 //! hi-components/src/feedback/mod.rs
 //! Feedback components: Alert, Toast, Tooltip, Glow, Popover, Drawer, Spin, Progress
+//! (plus the hover-intent helpers shared by Tooltip and Popover)
 //!
 //! Arknights + FUI styling:
 //! - Shimmer animations
@@ -10,6 +11,7 @@
 pub mod alert;
 pub mod drawer;
 pub mod glow;
+pub mod hover_intent;
 pub mod modal;
 pub mod popover;
 pub mod progress;
@@ -20,6 +22,7 @@ pub mod tooltip;
 pub use alert::*;
 pub use drawer::*;
 pub use glow::*;
+pub use hover_intent::*;
 // Re-exports for backward compatibility
 pub use glow::{Glow as Acrylic, GlowBlur as AcrylicBlur, GlowColor as AcrylicMode};
 pub use modal::*;
@@ -76,11 +76,13 @@ pub fn use_modal(initial_config: ModalConfig) -> ModalController {
 
     let open = {
         let add_entry = portal.add_entry;
+        let theme_scope = portal.theme_scope.clone();
         let cfg = config.clone();
         Callback::new(move |content: ModalContent| {
             let current_cfg = cfg.read();
             let entry = PortalEntry::Modal {
                 id: current_cfg.id.clone(),
+                theme_scope: theme_scope.clone(),
                 title: content.title,
                 position: current_cfg.position,
                 mask_mode: current_cfg.mask_mode,
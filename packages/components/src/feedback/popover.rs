@@ -1,12 +1,27 @@
 // hi-components/src/feedback/popover.rs
 // Popover component with smart positioning via Portal system
 
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use hikari_palette::classes::{ClassesBuilder, Display, Position};
+use tairitsu_vdom::runtime;
 
-use crate::portal::{PortalEntry, generate_portal_id, use_portal};
+use crate::feedback::hover_intent::use_hover_intent;
+use crate::portal::{PortalContext, PortalEntry, VirtualAnchor, generate_portal_id, use_portal};
 use crate::prelude::*;
 use crate::styled::StyledComponent;
 
+type Rect = (f64, f64, f64, f64);
+
+/// Portal entry of a popover opened by its `open` prop
+#[derive(Clone)]
+struct ShownPopover {
+    id: String,
+    rect: Option<Rect>,
+    close_requested: Signal<bool>,
+}
+
 #[derive(Clone, Copy, PartialEq, Debug, Default)]
 pub enum PopoverPlacement {
     #[default]
@@ -16,6 +31,18 @@ pub enum PopoverPlacement {
     Right,
 }
 
+/// How a popover is opened.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum PopoverTriggerMode {
+    /// Toggle on trigger click.
+    #[default]
+    Click,
+    /// Open with hover intent; stays open while the popover itself is hovered.
+    Hover,
+    /// Only the `open` prop controls visibility (typically with an `anchor`).
+    Manual,
+}
+
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum PopoverAbsolutePosition {
     Center,
@@ -64,6 +91,16 @@ pub struct PopoverProps {
     pub open: bool,
     pub on_open_change: Option<Callback<bool>>,
     pub positioning: PopoverPositioning,
+    pub trigger_mode: PopoverTriggerMode,
+    /// Position against this rect instead of the trigger element, e.g. a text
+    /// selection or a point on a canvas. Combine with `open` to show it.
+    pub anchor: Option<VirtualAnchor>,
+    /// Hover-intent open delay for `PopoverTriggerMode::Hover`, in milliseconds.
+    #[default(150)]
+    pub open_delay: u64,
+    /// Hover-intent close delay for `PopoverTriggerMode::Hover`, in milliseconds.
+    #[default(200)]
+    pub close_delay: u64,
     #[default(true)]
     pub close_on_click_outside: bool,
     #[default(true)]
@@ -77,8 +114,19 @@ pub struct PopoverProps {
 
 #[component]
 pub fn Popover(props: PopoverProps) -> Element {
-    let open = use_signal(|| props.open);
+    // Anchored and manual popovers are shown by the `open` prop itself, so they
+    // start closed and let the sync effect below open them.
+    let shows_on_open_prop =
+        props.anchor.is_some() || props.trigger_mode == PopoverTriggerMode::Manual;
+    let open = use_signal(|| props.open && !shows_on_open_prop);
     let popover_id = use_signal(String::new);
+    let shown = match runtime::active_component_id() {
+        // Hook slots are cleared on unmount, forgetting the entry with them.
+        Some(component) => runtime::hook_slot(component, "hk-popover:shown", || {
+            Rc::new(RefCell::new(None::<ShownPopover>))
+        }),
+        None => Rc::new(RefCell::new(None)),
+    };
 
     let close_requested = use_signal(|| false);
 
@@ -95,55 +143,141 @@ pub fn Popover(props: PopoverProps) -> Element {
         PopoverPositioning::Absolute(_) => vec![PopoverPlacement::Bottom],
     };
 
-    let on_open_change = props.on_open_change.clone();
     let on_open_change_for_close = props.on_open_change.clone();
+    let intent = use_hover_intent();
 
     let open_for_close = open.clone();
     let close_requested_for_close = close_requested.clone();
+    let intent_for_close = intent.clone();
+    let shown_for_close = shown.clone();
     let on_close = Callback::new(move |_| {
+        shown_for_close.borrow_mut().take();
         open_for_close.set(false);
         close_requested_for_close.set(true);
+        intent_for_close.reset();
         if let Some(handler) = on_open_change_for_close.as_ref() {
             handler.call(false);
         }
     });
 
+    let hover_mode = props.trigger_mode == PopoverTriggerMode::Hover;
+
+    // Keeps the popover open while the pointer is over it in hover mode.
+    let on_hover = hover_mode.then(|| {
+        let intent = intent.clone();
+        let on_close = on_close.clone();
+        let close_delay = props.close_delay;
+        Callback::new(move |hovered: bool| {
+            if hovered {
+                intent.enter(0, || {});
+            } else {
+                let on_close = on_close.clone();
+                intent.leave(close_delay, move || on_close.call(()));
+            }
+        })
+    });
+
+    // Adds the portal entry positioned against `rect`.
+    let show = {
+        let popover_id = popover_id.clone();
+        let close_requested = close_requested.clone();
+        let portal = portal.clone();
+        let preferred_placements = preferred_placements.clone();
+        let on_close = on_close.clone();
+        let on_hover = on_hover.clone();
+        let offset = props.offset;
+        let width = props.width.clone();
+        let title = props.title.clone();
+        let close_on_click_outside = props.close_on_click_outside && !hover_mode;
+        let close_on_select = props.close_on_select;
+        let children = props.children.clone();
+        let shown = shown.clone();
+        Callback::new(move |rect: Option<Rect>| {
+            let id = generate_portal_id();
+            popover_id.set(id.clone());
+            close_requested.set(false);
+            if shows_on_open_prop {
+                *shown.borrow_mut() = Some(ShownPopover {
+                    id: id.clone(),
+                    rect,
+                    close_requested: close_requested.inner().clone(),
+                });
+            }
+
+            portal.add_entry.call(PortalEntry::Popover {
+                id,
+                theme_scope: portal.theme_scope.clone(),
+                trigger_rect: rect,
+                preferred_placements: preferred_placements.clone(),
+                offset,
+                width: width.clone(),
+                title: title.clone(),
+                close_on_click_outside,
+                close_on_select,
+                on_close: Some(on_close.clone()),
+                close_requested: close_requested.inner().clone(),
+                on_hover: on_hover.clone(),
+                children: children.clone(),
+            });
+        })
+    };
+
     // Sync external open prop with internal state
     let props_open = props.open;
+    let anchor_rect = props.anchor.map(|anchor| anchor.as_rect());
     let open_for_effect = open.clone();
     let close_requested_for_effect = close_requested.clone();
+    let trigger_rect_for_effect = trigger_rect.clone();
+    let show_for_effect = show.clone();
+    let portal_for_effect = portal.clone();
+    let shown_for_effect = shown.clone();
     use_effect(move || {
-        if props_open != open_for_effect.get() {
-            open_for_effect.set(props_open);
-            if props_open {
-                close_requested_for_effect.set(false);
-            } else {
-                close_requested_for_effect.set(true);
+        if shows_on_open_prop {
+            // The open prop shows and hides the entry; an open one follows
+            // its anchor as it moves.
+            let current = shown_for_effect.borrow().clone();
+            match (props_open, current) {
+                (true, None) => {
+                    open_for_effect.set(true);
+                    show_for_effect.call(anchor_rect.or_else(|| trigger_rect_for_effect.get()));
+                }
+                (true, Some(entry)) if anchor_rect.is_some() && anchor_rect != entry.rect => {
+                    move_popover(&portal_for_effect, &entry.id, anchor_rect);
+                    if let Some(entry) = shown_for_effect.borrow_mut().as_mut() {
+                        entry.rect = anchor_rect;
+                    }
+                }
+                (false, Some(entry)) => {
+                    shown_for_effect.borrow_mut().take();
+                    open_for_effect.set(false);
+                    entry.close_requested.set(true);
+                }
+                _ => {}
             }
+        } else if props_open != open_for_effect.get() {
+            open_for_effect.set(props_open);
+            close_requested_for_effect.set(!props_open);
         }
     });
 
     let handle_trigger_click = {
         let open = open.clone();
-        let popover_id = popover_id.clone();
         let close_requested = close_requested.clone();
         let trigger_rect = trigger_rect.clone();
-        let portal = portal.clone();
-        let preferred_placements = preferred_placements.clone();
-        let on_open_change = on_open_change;
-        let on_close = on_close.clone();
+        let on_open_change = props.on_open_change.clone();
+        let show = show.clone();
+        let trigger_mode = props.trigger_mode;
 
         move |e: MouseEvent| {
+            if trigger_mode != PopoverTriggerMode::Click {
+                return;
+            }
             e.stop_propagation();
 
             let new_state = !open.get();
             open.set(new_state);
 
             if new_state {
-                let id = generate_portal_id();
-                popover_id.set(id.clone());
-                close_requested.set(false);
-
                 #[cfg(target_arch = "wasm32")]
                 {
                     if let Some(drag_event) = e.as_any().downcast_ref::<MouseEvent>() {
@@ -157,19 +291,7 @@ pub fn Popover(props: PopoverProps) -> Element {
                     }
                 }
 
-                portal.add_entry.call(PortalEntry::Popover {
-                    id,
-                    trigger_rect: trigger_rect.read(),
-                    preferred_placements: preferred_placements.clone(),
-                    offset: props.offset,
-                    width: props.width.clone(),
-                    title: props.title.clone(),
-                    close_on_click_outside: props.close_on_click_outside,
-                    close_on_select: props.close_on_select,
-                    on_close: Some(on_close.clone()),
-                    close_requested: close_requested.inner().clone(),
-                    children: props.children.clone(),
-                });
+                show.call(anchor_rect.or_else(|| trigger_rect.read()));
             } else {
                 close_requested.set(true);
             }
@@ -180,6 +302,47 @@ pub fn Popover(props: PopoverProps) -> Element {
         }
     };
 
+    let handle_mouse_enter = {
+        let open = open.clone();
+        let trigger_rect = trigger_rect.clone();
+        let intent = intent.clone();
+        let show = show.clone();
+        let on_open_change = props.on_open_change.clone();
+        let open_delay = props.open_delay;
+        move |e: MouseEvent| {
+            if !hover_mode {
+                return;
+            }
+            #[cfg(target_arch = "wasm32")]
+            trigger_rect.set(Some((e.client_x as f64, e.client_y as f64, 100.0, 30.0)));
+            #[cfg(not(target_arch = "wasm32"))]
+            let _ = &e;
+
+            let open = open.clone();
+            let trigger_rect = trigger_rect.clone();
+            let show = show.clone();
+            let on_open_change = on_open_change.clone();
+            intent.enter(open_delay, move || {
+                open.set(true);
+                show.call(anchor_rect.or_else(|| trigger_rect.get()));
+                if let Some(handler) = on_open_change.as_ref() {
+                    handler.call(true);
+                }
+            });
+        }
+    };
+
+    let handle_mouse_leave = {
+        let close_delay = props.close_delay;
+        move |_: MouseEvent| {
+            if !hover_mode {
+                return;
+            }
+            let on_close = on_close.clone();
+            intent.leave(close_delay, move || on_close.call(()));
+        }
+    };
+
     let container_classes = ClassesBuilder::new()
         .add(Position::Relative)
         .add(Display::InlineBlock)
@@ -187,10 +350,33 @@ pub fn Popover(props: PopoverProps) -> Element {
         .build();
 
     rsx! {
-        div { class: container_classes, onclick: handle_trigger_click, {props.trigger} }
+        div {
+            class: container_classes,
+            onclick: handle_trigger_click,
+            onmouseenter: handle_mouse_enter,
+            onmouseleave: handle_mouse_leave,
+            {props.trigger}
+        }
     }
 }
 
+/// Places the open popover `id` against `rect`
+fn move_popover(portal: &PortalContext, id: &str, rect: Option<Rect>) {
+    portal.entries.update(|entries| {
+        for entry in entries.iter_mut() {
+            if let PortalEntry::Popover {
+                id: entry_id,
+                trigger_rect,
+                ..
+            } = entry
+                && entry_id == id
+            {
+                *trigger_rect = rect;
+            }
+        }
+    });
+}
+
 pub struct PopoverComponent;
 
 impl StyledComponent for PopoverComponent {
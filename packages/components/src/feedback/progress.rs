@@ -1,6 +1,9 @@
 // packages/components/src/feedback/progress.rs
 // Progress component with Arknights + FUI styling
 
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use hikari_palette::Gradient;
 use hikari_palette::classes::{ClassesBuilder, ProgressClass};
 
 use crate::prelude::*;
@@ -8,6 +11,23 @@ use crate::styled::StyledComponent;
 
 pub struct ProgressComponent;
 
+/// Circumference of the r=54 circle used by circular and dashboard variants.
+const CIRCLE_CIRCUMFERENCE: f64 = 339.292;
+
+/// Angle left open at the bottom of the dashboard variant, in degrees.
+const DASHBOARD_GAP_DEGREES: f64 = 75.0;
+
+static GRADIENT_ID_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Fallback segment colors, cycled when a segment has no explicit color.
+const SEGMENT_COLORS: [&str; 5] = [
+    "var(--hi-primary)",
+    "var(--hi-secondary)",
+    "var(--hi-success)",
+    "var(--hi-warning)",
+    "var(--hi-danger)",
+];
+
 #[define_props]
 pub struct ProgressProps {
     pub value: f64,
@@ -22,6 +42,30 @@ pub struct ProgressProps {
     #[default(false)]
     pub show_info: bool,
 
+    /// Stacked sections for the linear variant; replaces `value` when non-empty.
+    pub segments: Vec<ProgressSegment>,
+
+    /// Render a color legend under segmented progress.
+    #[default(false)]
+    pub show_legend: bool,
+
+    /// Fill (linear) or stroke (circle/dashboard) gradient.
+    pub gradient: Option<Gradient>,
+
+    /// Unknown total: animate a sliding bar instead of showing `value`.
+    #[default(false)]
+    pub indeterminate: bool,
+
+    /// Secondary "buffered" value drawn behind the main bar (e.g. streamed data).
+    pub buffer: Option<f64>,
+
+    /// Number of blocks for `ProgressType::Steps`.
+    #[default(5)]
+    pub steps: u32,
+
+    /// Remaining-time label, e.g. from [`ProgressRateEstimator::eta_label`].
+    pub eta: Option<String>,
+
     pub class: String,
 
     pub style: String,
@@ -32,6 +76,10 @@ pub enum ProgressType {
     #[default]
     Linear,
     Circular,
+    /// Circular arc with an open gap at the bottom.
+    Dashboard,
+    /// Discrete blocks, filled up to the current value.
+    Steps,
 }
 
 #[derive(Clone, Copy, PartialEq, Debug, Default)]
@@ -41,8 +89,204 @@ pub enum ProgressStatus {
     Active,
 }
 
+/// One stacked section of a segmented progress bar.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ProgressSegment {
+    pub value: f64,
+    pub label: String,
+    /// Any CSS color; empty picks from the theme palette.
+    pub color: String,
+}
+
+impl ProgressSegment {
+    pub fn new(value: f64, label: impl Into<String>) -> Self {
+        Self {
+            value,
+            label: label.into(),
+            color: String::new(),
+        }
+    }
+
+    pub fn with_color(mut self, color: impl Into<String>) -> Self {
+        self.color = color.into();
+        self
+    }
+}
+
+/// Offset and width (both in percent) of each segment, clamped so the stack
+/// never exceeds 100%.
+pub fn segment_layout(segments: &[ProgressSegment], max: f64) -> Vec<(f64, f64)> {
+    let mut offset = 0.0_f64;
+    segments
+        .iter()
+        .map(|segment| {
+            let width = if max > 0.0 {
+                (segment.value / max * 100.0).max(0.0)
+            } else {
+                0.0
+            };
+            let width = width.min(100.0 - offset);
+            let start = offset;
+            offset += width;
+            (start, width)
+        })
+        .collect()
+}
+
+/// CSS `linear-gradient(...)` for a palette gradient.
+pub fn gradient_css(gradient: &Gradient, angle_deg: f64) -> String {
+    let stops: Vec<String> = gradient
+        .stops
+        .iter()
+        .map(|stop| format!("{} {:.1}%", stop.color.hex(), stop.position * 100.0))
+        .collect();
+    format!("linear-gradient({angle_deg}deg, {})", stops.join(", "))
+}
+
+/// `stroke-dasharray` / `stroke-dashoffset` for the dashboard arc at `percentage`.
+pub fn dashboard_dash(percentage: f64) -> (String, String) {
+    let arc = CIRCLE_CIRCUMFERENCE * (1.0 - DASHBOARD_GAP_DEGREES / 360.0);
+    let offset = arc * (1.0 - percentage.clamp(0.0, 100.0) / 100.0);
+    (
+        format!("{arc:.3} {CIRCLE_CIRCUMFERENCE:.3}"),
+        format!("{offset:.3}"),
+    )
+}
+
+/// Number of filled blocks for the steps variant.
+pub fn filled_steps(percentage: f64, steps: u32) -> u32 {
+    ((percentage.clamp(0.0, 100.0) / 100.0) * steps as f64).round() as u32
+}
+
+/// Turns a stream of `(value, timestamp)` progress updates into a smoothed
+/// rate and remaining-time estimate.
+///
+/// The rate is an exponential moving average, so a single stalled or bursty
+/// update does not make the ETA jump around.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ProgressRateEstimator {
+    /// Weight of the newest sample, in `(0, 1]`.
+    pub smoothing: f64,
+    last: Option<(f64, f64)>,
+    rate: Option<f64>,
+}
+
+impl Default for ProgressRateEstimator {
+    fn default() -> Self {
+        Self::new(0.3)
+    }
+}
+
+impl ProgressRateEstimator {
+    pub fn new(smoothing: f64) -> Self {
+        Self {
+            smoothing: smoothing.clamp(f64::EPSILON, 1.0),
+            last: None,
+            rate: None,
+        }
+    }
+
+    /// Record `value` observed at `now_ms` (e.g. `platform::now_timestamp()`).
+    pub fn update(&mut self, value: f64, now_ms: f64) {
+        if let Some((last_value, last_time)) = self.last {
+            let elapsed = now_ms - last_time;
+            if elapsed <= 0.0 {
+                return;
+            }
+            let sample = (value - last_value) / elapsed;
+            self.rate = Some(match self.rate {
+                Some(rate) => rate + self.smoothing * (sample - rate),
+                None => sample,
+            });
+        }
+        self.last = Some((value, now_ms));
+    }
+
+    pub fn reset(&mut self) {
+        self.last = None;
+        self.rate = None;
+    }
+
+    /// Smoothed rate in units per second.
+    pub fn rate_per_second(&self) -> Option<f64> {
+        self.rate.map(|rate| rate * 1000.0)
+    }
+
+    /// Estimated milliseconds until `target` is reached; `None` while the rate
+    /// is unknown or not moving forward.
+    pub fn eta_ms(&self, target: f64) -> Option<f64> {
+        let (value, _) = self.last?;
+        let rate = self.rate?;
+        if value >= target {
+            return Some(0.0);
+        }
+        if rate <= 0.0 {
+            return None;
+        }
+        Some((target - value) / rate)
+    }
+
+    /// Human-readable remaining time, e.g. `"2m 05s"`.
+    pub fn eta_label(&self, target: f64) -> Option<String> {
+        self.eta_ms(target).map(format_duration)
+    }
+}
+
+/// Format milliseconds as `"45s"`, `"3m 05s"` or `"1h 02m"`.
+pub fn format_duration(ms: f64) -> String {
+    let total_seconds = (ms.max(0.0) / 1000.0).ceil() as u64;
+    let hours = total_seconds / 3600;
+    let minutes = (total_seconds % 3600) / 60;
+    let seconds = total_seconds % 60;
+    if hours > 0 {
+        format!("{hours}h {minutes:02}m")
+    } else if minutes > 0 {
+        format!("{minutes}m {seconds:02}s")
+    } else {
+        format!("{seconds}s")
+    }
+}
+
+fn segment_color(segment: &ProgressSegment, index: usize) -> String {
+    if segment.color.is_empty() {
+        SEGMENT_COLORS[index % SEGMENT_COLORS.len()].to_string()
+    } else {
+        segment.color.clone()
+    }
+}
+
+/// SVG `<defs>` with a linear gradient for circle strokes.
+fn svg_gradient_defs(gradient: &Gradient, id: &str) -> Element {
+    let stops: Vec<Element> = gradient
+        .stops
+        .iter()
+        .map(|stop| {
+            rsx! {
+                stop {
+                    offset: format!("{:.1}%", stop.position * 100.0),
+                    stop_color: stop.color.hex(),
+                }
+            }
+        })
+        .collect();
+
+    rsx! {
+        defs {
+            linearGradient { id: id.to_string(), x1: "0%", y1: "0%", x2: "100%", y2: "0%",
+                ..stops,
+            }
+        }
+    }
+}
+
 #[component]
 pub fn Progress(props: ProgressProps) -> Element {
+    let gradient_id = use_signal(|| {
+        format!(
+            "hk-progress-gradient-{}",
+            GRADIENT_ID_COUNTER.fetch_add(1, Ordering::Relaxed)
+        )
+    });
     let percentage = (props.value / props.max * 100.0).clamp(0.0, 100.0);
 
     let wrapper_classes = ClassesBuilder::new()
@@ -55,76 +299,247 @@ pub fn Progress(props: ProgressProps) -> Element {
         ProgressStatus::Active => "hk-progress-active",
     };
 
-    let combined_classes = format!("{wrapper_classes} {status_class}");
-    let width_style = format!("width: {percentage:.0}%;");
-    let stroke_dasharray_val = "339.292";
-    let stroke_dashoffset_val = format!("{:.3}", 339.292 * (1.0 - percentage / 100.0));
+    let combined_classes = if props.indeterminate {
+        format!("{wrapper_classes} {status_class} hk-progress-indeterminate")
+    } else {
+        format!("{wrapper_classes} {status_class}")
+    };
     let percentage_text = format!("{percentage:.0}%");
 
-    rsx! {
-        div {
-            class: combined_classes,
-            style: props.style,
+    let info_el = if props.show_info || props.eta.is_some() {
+        let text = match (&props.eta, props.show_info && !props.indeterminate) {
+            (Some(eta), true) => format!("{percentage_text} · {eta}"),
+            (Some(eta), false) => eta.clone(),
+            (None, _) => percentage_text.clone(),
+        };
+        Some(text)
+    } else {
+        None
+    };
 
-            {if props.progress_type == ProgressType::Linear {
-                rsx! {
+    let body = match props.progress_type {
+        ProgressType::Linear if !props.segments.is_empty() => {
+            render_segmented(&props.segments, props.max, props.show_legend, info_el)
+        }
+        ProgressType::Linear => {
+            let fill_style = match (&props.gradient, props.indeterminate) {
+                (_, true) => String::new(),
+                (Some(gradient), false) => format!(
+                    "width: {percentage:.0}%; background: {};",
+                    gradient_css(gradient, 90.0)
+                ),
+                (None, false) => format!("width: {percentage:.0}%;"),
+            };
+            let buffer_el = match props.buffer {
+                Some(buffer) if !props.indeterminate => {
+                    let buffer_pct = (buffer / props.max * 100.0).clamp(0.0, 100.0);
+                    rsx! {
+                        div {
+                            class: "hk-progress-buffer",
+                            style: format!("width: {buffer_pct:.0}%;"),
+                        }
+                    }
+                }
+                _ => VNode::empty(),
+            };
+
+            rsx! {
+                div {
+                    class: "hk-progress-outer",
+                    role: "progressbar",
+                    "aria-valuemin": "0",
+                    "aria-valuemax": format!("{}", props.max),
+                    "aria-valuenow": if props.indeterminate { String::new() } else { format!("{}", props.value) },
                     div {
-                        class: "hk-progress-outer",
+                        class: "hk-progress-inner",
+                        {buffer_el}
                         div {
-                            class: "hk-progress-inner",
-                            div {
-                                class: "hk-progress-bg",
-                                style: width_style,
-                            }
+                            class: "hk-progress-bg",
+                            style: fill_style,
                         }
+                    }
 
-                        if props.show_info {
-                            span { class: "hk-progress-text",
-                                percentage_text
-                            }
-                        }
+                    if let Some(text) = info_el {
+                        span { class: "hk-progress-text", {text} }
+                    }
+                }
+            }
+        }
+        ProgressType::Steps => {
+            let filled = filled_steps(percentage, props.steps);
+            let blocks: Vec<Element> = (0..props.steps)
+                .map(|index| {
+                    let class = if index < filled {
+                        "hk-progress-step hk-progress-step-filled"
+                    } else {
+                        "hk-progress-step"
+                    };
+                    rsx! {
+                        div { class: class }
+                    }
+                })
+                .collect();
+
+            rsx! {
+                div {
+                    class: "hk-progress-outer",
+                    role: "progressbar",
+                    "aria-valuemin": "0",
+                    "aria-valuemax": format!("{}", props.max),
+                    "aria-valuenow": format!("{}", props.value),
+                    div { class: "hk-progress-steps", ..blocks }
+
+                    if let Some(text) = info_el {
+                        span { class: "hk-progress-text", {text} }
                     }
                 }
+            }
+        }
+        ProgressType::Circular | ProgressType::Dashboard => {
+            let (dasharray, dashoffset, rotation) =
+                if props.progress_type == ProgressType::Dashboard {
+                    let (dasharray, dashoffset) = dashboard_dash(percentage);
+                    (
+                        dasharray,
+                        dashoffset,
+                        format!("rotate({} 60 60)", 90.0 + DASHBOARD_GAP_DEGREES / 2.0),
+                    )
+                } else {
+                    (
+                        format!("{CIRCLE_CIRCUMFERENCE}"),
+                        format!("{:.3}", CIRCLE_CIRCUMFERENCE * (1.0 - percentage / 100.0)),
+                        "rotate(-90 60 60)".to_string(),
+                    )
+                };
+            let trail_dasharray = if props.progress_type == ProgressType::Dashboard {
+                dashboard_dash(100.0).0
             } else {
-                rsx! {
-                    div { class: "hk-progress-circle-wrapper",
-                        svg {
-                            class: "hk-progress-circle",
-                            width: "120",
-                            height: "120",
-                            view_box: "0 0 120 120",
-
-                            circle {
-                                class: "hk-progress-circle-trail",
-                                cx: "60",
-                                cy: "60",
-                                r: "54",
-                                stroke_width: "6",
-                                fill: "none",
-                            }
-
-                            circle {
-                                class: "hk-progress-circle-path",
-                                cx: "60",
-                                cy: "60",
-                                r: "54",
-                                stroke_width: "6",
-                                fill: "none",
-                                stroke_linecap: "round",
-                                stroke_dasharray: stroke_dasharray_val,
-                                stroke_dashoffset: stroke_dashoffset_val,
-                                transform: "rotate(-90 60 60)",
-                            }
+                "none".to_string()
+            };
+
+            let gradient_id = gradient_id.get();
+            let (defs_el, path_style) = match &props.gradient {
+                Some(gradient) => (
+                    svg_gradient_defs(gradient, &gradient_id),
+                    format!("stroke: url(#{gradient_id});"),
+                ),
+                None => (VNode::empty(), String::new()),
+            };
+
+            rsx! {
+                div { class: "hk-progress-circle-wrapper",
+                    svg {
+                        class: "hk-progress-circle",
+                        width: "120",
+                        height: "120",
+                        view_box: "0 0 120 120",
+
+                        {defs_el}
+
+                        circle {
+                            class: "hk-progress-circle-trail",
+                            cx: "60",
+                            cy: "60",
+                            r: "54",
+                            stroke_width: "6",
+                            fill: "none",
+                            stroke_linecap: "round",
+                            stroke_dasharray: trail_dasharray,
+                            transform: rotation.clone(),
                         }
 
-                        if props.show_info {
-                            span { class: "hk-progress-circle-text",
-                                percentage_text
-                            }
+                        circle {
+                            class: "hk-progress-circle-path",
+                            style: path_style,
+                            cx: "60",
+                            cy: "60",
+                            r: "54",
+                            stroke_width: "6",
+                            fill: "none",
+                            stroke_linecap: "round",
+                            stroke_dasharray: dasharray,
+                            stroke_dashoffset: dashoffset,
+                            transform: rotation,
                         }
                     }
+
+                    if let Some(text) = info_el {
+                        span { class: "hk-progress-circle-text", {text} }
+                    }
+                }
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            class: combined_classes,
+            style: props.style,
+            {body}
+        }
+    }
+}
+
+#[allow(unused_braces)]
+fn render_segmented(
+    segments: &[ProgressSegment],
+    max: f64,
+    show_legend: bool,
+    info: Option<String>,
+) -> Element {
+    let layout = segment_layout(segments, max);
+
+    let bars: Vec<Element> = segments
+        .iter()
+        .zip(layout.iter())
+        .enumerate()
+        .map(|(index, (segment, (offset, width)))| {
+            let style = format!(
+                "left: {offset:.2}%; width: {width:.2}%; background: {};",
+                segment_color(segment, index)
+            );
+            rsx! {
+                div {
+                    class: "hk-progress-segment",
+                    style: style,
+                    title: segment.label.clone(),
+                }
+            }
+        })
+        .collect();
+
+    let legend = if show_legend {
+        let items: Vec<Element> = segments
+            .iter()
+            .enumerate()
+            .map(|(index, segment)| {
+                let swatch = format!("background: {};", segment_color(segment, index));
+                let label = segment.label.clone();
+                rsx! {
+                    span { class: "hk-progress-legend-item",
+                        span { class: "hk-progress-legend-swatch", style: swatch }
+                        label
+                    }
+                }
+            })
+            .collect();
+        rsx! {
+            div { class: "hk-progress-legend", ..items }
+        }
+    } else {
+        VNode::empty()
+    };
+
+    rsx! {
+        div { class: "hk-progress-segmented",
+            div { class: "hk-progress-outer",
+                div { class: "hk-progress-inner hk-progress-inner-segmented", ..bars }
+
+                if let Some(text) = info {
+                    span { class: "hk-progress-text", {text} }
                 }
-            }}
+            }
+            {legend}
         }
     }
 }
@@ -212,6 +627,92 @@ impl StyledComponent for ProgressComponent {
     100% { opacity: 1; }
 }
 
+/* Buffer (secondary value) */
+.hk-progress-inner {
+    position: relative;
+}
+
+.hk-progress-buffer {
+    position: absolute;
+    top: 0;
+    left: 0;
+    height: 100%;
+    border-radius: 100px;
+    background: var(--hi-component-selection-bg);
+    opacity: 0.3;
+    transition: width 0.3s ease;
+}
+
+.hk-progress-inner .hk-progress-bg {
+    position: relative;
+}
+
+/* Indeterminate */
+.hk-progress-indeterminate .hk-progress-bg {
+    width: 30%;
+    animation: hi-progress-indeterminate 1.4s ease-in-out infinite;
+}
+
+@keyframes hi-progress-indeterminate {
+    0% { transform: translateX(-100%); }
+    100% { transform: translateX(340%); }
+}
+
+/* Segmented */
+.hk-progress-segmented {
+    display: flex;
+    flex-direction: column;
+    gap: 6px;
+    width: 100%;
+}
+
+.hk-progress-segment {
+    position: absolute;
+    top: 0;
+    height: 100%;
+    transition: width 0.3s ease, left 0.3s ease;
+}
+
+.hk-progress-legend {
+    display: flex;
+    flex-wrap: wrap;
+    gap: 12px;
+    font-size: 12px;
+    color: var(--hi-text-secondary);
+}
+
+.hk-progress-legend-item {
+    display: inline-flex;
+    align-items: center;
+    gap: 6px;
+}
+
+.hk-progress-legend-swatch {
+    width: 8px;
+    height: 8px;
+    border-radius: 2px;
+}
+
+/* Steps */
+.hk-progress-steps {
+    flex: 1;
+    display: flex;
+    gap: 4px;
+}
+
+.hk-progress-step {
+    flex: 1;
+    height: 8px;
+    border-radius: 2px;
+    background-color: var(--hi-component-selection-surface);
+    border: 1px solid var(--hi-component-selection-border);
+    transition: background-color 0.3s ease;
+}
+
+.hk-progress-step-filled {
+    background: var(--hi-component-selection-bg);
+}
+
 .hk-progress-circle-text {
     position: absolute;
     top: 50%;
@@ -1,8 +1,10 @@
 // hi-components/src/feedback/tooltip.rs
-// Tooltip component with Arknights + FUI styling - Portal-based rendering with animation
+// Tooltip component with Arknights + FUI styling - Portal-based rendering with hover intent
 
 use hikari_palette::classes::{ClassesBuilder, TooltipClass, UtilityClass};
 
+use crate::feedback::hover_intent::{DEFAULT_SKIP_DELAY_MS, DelayGroupState, use_hover_intent};
+use crate::platform::now_timestamp;
 use crate::portal::provider::{generate_portal_id, use_portal};
 #[cfg(target_arch = "wasm32")]
 use crate::portal::{PortalEntry, TriggerPlacement};
@@ -35,61 +37,185 @@ impl TooltipPlacement {
 #[define_props]
 pub struct TooltipProps {
     pub content: String,
+    /// Element content; takes precedence over `content` when set.
+    pub rich_content: Option<Element>,
     pub placement: TooltipPlacement,
+    /// Hover-intent delay before opening, in milliseconds.
     pub delay: Option<u64>,
+    /// Hover-intent delay before closing, in milliseconds.
+    pub close_delay: Option<u64>,
+    /// Keep the tooltip open while the pointer is over it, so its content can
+    /// be selected or clicked.
+    #[default(false)]
+    pub interactive: bool,
     #[default(true)]
     pub arrow: bool,
     pub class: String,
     pub children: Element,
 }
 
+#[derive(Clone)]
+pub struct TooltipGroupContext {
+    pub state: Signal<DelayGroupState>,
+}
+
+#[define_props]
+pub struct TooltipDelayGroupProps {
+    /// Open delay for the first tooltip of the group, in milliseconds.
+    #[default(500)]
+    pub delay: u64,
+    /// Close delay applied to members without their own `close_delay`.
+    #[default(0)]
+    pub close_delay: u64,
+    /// How long the group stays warm after its last tooltip closed.
+    #[default(DEFAULT_SKIP_DELAY_MS)]
+    pub skip_delay: u64,
+    pub children: Element,
+}
+
+/// Groups adjacent tooltips (toolbars, icon rows): once one tooltip has opened,
+/// moving to a neighbour shows its tooltip without waiting for the delay.
+#[component]
+pub fn TooltipDelayGroup(props: TooltipDelayGroupProps) -> Element {
+    let state =
+        use_signal(|| DelayGroupState::new(props.delay, props.close_delay, props.skip_delay));
+
+    use_context_provider(TooltipGroupContext {
+        state: state.inner().clone(),
+    });
+
+    rsx! {
+        {props.children}
+    }
+}
+
 #[component]
 pub fn Tooltip(props: TooltipProps) -> Element {
     let portal = use_portal();
     let tooltip_id = use_signal(generate_portal_id);
+    let intent = use_hover_intent();
+    let group = use_context::<TooltipGroupContext>().map(|ctx| ctx.get().clone());
     #[allow(unused_mut)]
     let mut trigger_rect = use_signal(|| None::<(f64, f64, f64, f64)>);
 
+    let close_delay = props
+        .close_delay
+        .or_else(|| group.as_ref().map(|g| g.state.get().close_delay))
+        .unwrap_or(0);
+
     let wrapper_classes = ClassesBuilder::new()
         .add(TooltipClass::TooltipWrapper)
         .add_raw(&props.class)
         .build();
 
+    let close_tooltip = {
+        let tooltip_id = tooltip_id.clone();
+        let portal_remove_entry = portal.remove_entry.clone();
+        let group = group.clone();
+        Callback::new(move |_: ()| {
+            portal_remove_entry(tooltip_id.get());
+            if let Some(group) = group.as_ref() {
+                group.state.write().mark_closed(now_timestamp());
+            }
+        })
+    };
+
+    // Pointer presence on an interactive tooltip feeds the same hover intent as
+    // the trigger, so crossing the gap between them does not close it.
+    let on_tooltip_hover = {
+        let intent = intent.clone();
+        let close_tooltip = close_tooltip.clone();
+        Callback::new(move |hovered: bool| {
+            if hovered {
+                intent.enter(0, || {});
+            } else {
+                let close_tooltip = close_tooltip.clone();
+                intent.leave(close_delay, move || close_tooltip.call(()));
+            }
+        })
+    };
+
     let handle_mouse_enter = {
         let tooltip_id = tooltip_id.clone();
         let trigger_rect = trigger_rect.clone();
         let portal_add_entry = portal.add_entry.clone();
+        let intent = intent.clone();
+        let group = group.clone();
         let content = props.content.clone();
+        let rich_content = props.rich_content.clone();
+        let own_delay = props.delay;
         let arrow = props.arrow;
+        let interactive = props.interactive;
         #[cfg(target_arch = "wasm32")]
         let placement = props.placement.to_trigger_placement();
+        #[cfg(target_arch = "wasm32")]
+        let theme_scope = portal.theme_scope.clone();
         move |event: MouseEvent| {
+            let open_delay = match group.as_ref() {
+                Some(group) => group.state.get().open_delay(own_delay, now_timestamp()),
+                None => own_delay.unwrap_or(0),
+            };
+
             #[cfg(target_arch = "wasm32")]
             {
                 // Use clientX/clientY from MouseEvent to approximate trigger position
                 // For precise element bounds, a ref-based approach would be needed
                 let rect_tuple = (event.client_x as f64, event.client_y as f64, 100.0, 30.0);
                 trigger_rect.set(Some(rect_tuple));
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                let _ = (&trigger_rect, &event);
+            }
+
+            let tooltip_id = tooltip_id.clone();
+            let trigger_rect = trigger_rect.clone();
+            let portal_add_entry = portal_add_entry.clone();
+            let group = group.clone();
+            let content = content.clone();
+            let rich_content = rich_content.clone();
+            let on_hover = interactive.then(|| on_tooltip_hover.clone());
+            #[cfg(target_arch = "wasm32")]
+            let theme_scope = theme_scope.clone();
+            intent.enter(open_delay, move || {
+                if let Some(group) = group.as_ref() {
+                    group.state.write().mark_open();
+                }
 
+                #[cfg(target_arch = "wasm32")]
                 portal_add_entry(PortalEntry::Tooltip {
                     id: tooltip_id.get(),
-                    trigger_rect: Some(rect_tuple),
+                    theme_scope,
+                    trigger_rect: trigger_rect.get(),
                     placement,
-                    content: content.clone(),
+                    content,
+                    rich_content,
                     arrow,
+                    interactive,
+                    on_hover,
                 });
-            }
-            #[cfg(not(target_arch = "wasm32"))]
-            {
-                let _ = (&tooltip_id, &trigger_rect, &event, &content, arrow);
-            }
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    let _ = (
+                        &tooltip_id,
+                        &trigger_rect,
+                        &portal_add_entry,
+                        &content,
+                        &rich_content,
+                        arrow,
+                        &on_hover,
+                    );
+                }
+            });
         }
     };
 
-    let tooltip_id_for_leave = tooltip_id.clone();
-    let portal_remove_entry = portal.remove_entry.clone();
-    let handle_mouse_leave = move |_| {
-        portal_remove_entry(tooltip_id_for_leave.get());
+    let handle_mouse_leave = {
+        let intent = intent.clone();
+        move |_| {
+            let close_tooltip = close_tooltip.clone();
+            intent.leave(close_delay, move || close_tooltip.call(()));
+        }
     };
 
     rsx! {
@@ -0,0 +1,1164 @@
+// hikari-components/src/layout/dock.rs
+//! Dock component - Dockable panel / window manager layout
+//!
+//! Renders a [`DockLayout`] model: tabbed panel groups docked to the center
+//! or to a collapsible side (left, right, bottom), floating in-app windows
+//! and a maximized panel.
+//!
+//! - Drag a tab onto one of the highlighted drop zones to dock it there, or
+//!   onto a window to join its tabs; dropping it outside the dock floats it
+//! - Drag a window by its title bar and resize it from its corner
+//! - Side zones collapse to a strip and resize with their edge handle
+//! - Give the dock a `persist_key` to keep the layout (as JSON) in local
+//!   storage, or listen to `on_change` and store [`DockLayout::to_json`]
+//!   yourself
+//!
+//! Floating windows are rendered through the portal layer when a
+//! `PortalProvider` is present, so they stay above the rest of the page;
+//! otherwise they are rendered inline.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::rc::Rc;
+//! use hikari_components::layout::Dock;
+//! use hikari_extra_components::extra::{DockLayout, DockPanel, DockZone};
+//!
+//! let layout = DockLayout::new()
+//!     .with_panel(DockPanel::new("editor", "Editor").with_closable(false), DockZone::Center)
+//!     .with_panel(DockPanel::new("files", "Files"), DockZone::Left)
+//!     .with_panel(DockPanel::new("console", "Console"), DockZone::Bottom);
+//!
+//! rsx! {
+//!     Dock {
+//!         layout,
+//!         persist_key: Some("workspace".to_string()),
+//!         render_panel: Some(Rc::new(|id: &str| match id {
+//!             "editor" => rsx! { Editor {} },
+//!             "files" => rsx! { FileTree {} },
+//!             _ => rsx! { Console {} },
+//!         })),
+//!     }
+//! }
+//! ```
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use hikari_extra_components::extra::{
+    DockGroup, DockLayout, DockLocation, DockPanel, DockRect, DockTarget, DockZone, FloatingWindow,
+};
+use hikari_palette::classes::{ClassesBuilder, DockClass, UtilityClass};
+use tairitsu_vdom::runtime;
+
+use crate::navigation::{TabPane, TabPaneProps, TabReorder, Tabs, TabsOverflow, TabsProps};
+use crate::platform;
+use crate::portal::{PortalContext, PortalEntry, try_use_portal};
+use crate::prelude::*;
+use crate::theme::use_layout_direction;
+use crate::utils::use_roving_id;
+
+const STORAGE_PREFIX: &str = "hk-dock:";
+
+/// Offset between newly floated windows, in px
+const CASCADE_STEP: f64 = 24.0;
+
+/// Renders the content of a panel, given its id
+pub type DockPanelRenderer = Rc<dyn Fn(&str) -> Element>;
+
+#[derive(Clone, Props, Default)]
+pub struct DockProps {
+    /// Initial layout (replaced by the persisted one, if any)
+    #[props(default)]
+    pub layout: DockLayout,
+
+    /// Called with the new layout after every change
+    #[props(default)]
+    pub on_change: Option<Callback<DockLayout, ()>>,
+
+    #[props(default)]
+    pub render_panel: Option<DockPanelRenderer>,
+
+    /// Remembers the layout in local storage under this key
+    #[props(default)]
+    pub persist_key: Option<String>,
+
+    #[props(default)]
+    pub class: String,
+}
+
+impl PartialEq for DockProps {
+    fn eq(&self, other: &Self) -> bool {
+        self.layout == other.layout
+            && self.on_change == other.on_change
+            && match (&self.render_panel, &other.render_panel) {
+                (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+            && self.persist_key == other.persist_key
+            && self.class == other.class
+    }
+}
+
+impl std::fmt::Debug for DockProps {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DockProps")
+            .field("layout", &self.layout)
+            .field("on_change", &self.on_change.is_some())
+            .field("render_panel", &self.render_panel.is_some())
+            .field("persist_key", &self.persist_key)
+            .field("class", &self.class)
+            .finish()
+    }
+}
+
+fn load_layout(persist_key: Option<&str>, layout: DockLayout) -> DockLayout {
+    persist_key
+        .and_then(|key| platform::local_storage_get(&format!("{STORAGE_PREFIX}{key}")))
+        .and_then(|json| DockLayout::from_json(&json).ok())
+        .unwrap_or(layout)
+}
+
+fn save_layout(persist_key: Option<&str>, layout: &DockLayout) {
+    if let Some(key) = persist_key
+        && let Ok(json) = layout.to_json()
+    {
+        platform::local_storage_set(&format!("{STORAGE_PREFIX}{key}"), &json);
+    }
+}
+
+/// What a floating window was rendered from, apart from its geometry and
+/// stacking order, which are restyled in place
+#[derive(Clone, PartialEq)]
+struct WindowSnapshot {
+    id: String,
+    group: DockGroup,
+    panels: Vec<DockPanel>,
+    dragging: bool,
+}
+
+impl WindowSnapshot {
+    fn of(window: &FloatingWindow, layout: &DockLayout, dragging: bool) -> Self {
+        Self {
+            id: window.id.clone(),
+            group: window.group.clone(),
+            panels: window
+                .group
+                .panels
+                .iter()
+                .filter_map(|id| layout.panel(id).cloned())
+                .collect(),
+            dragging,
+        }
+    }
+}
+
+/// Removes the dock's floating windows from the portal when it unmounts.
+struct PortalWindows {
+    portal: Option<PortalContext>,
+    /// Portal entry holding the windows
+    entry_id: String,
+    /// Windows in the portal entry, if it is shown
+    shown: RefCell<Option<Vec<WindowSnapshot>>>,
+}
+
+impl PortalWindows {
+    fn clear(&self) {
+        if let Some(portal) = self.portal.as_ref()
+            && self.shown.take().is_some()
+        {
+            portal.remove_entry.call(self.entry_id.clone());
+        }
+    }
+}
+
+impl Drop for PortalWindows {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+/// Everything the dock's event handlers need, cheap to clone
+#[derive(Clone)]
+struct DockHandle {
+    id: String,
+    layout: Signal<DockLayout>,
+    /// Panel whose tab is being dragged
+    drag_panel: Signal<Option<String>>,
+    on_change: Option<Callback<DockLayout, ()>>,
+    render_panel: Option<DockPanelRenderer>,
+    persist_key: Option<String>,
+    windows: Rc<PortalWindows>,
+    /// Side zones are mirrored in RTL layouts
+    rtl: bool,
+}
+
+#[allow(unused_braces)]
+impl DockHandle {
+    /// Applies a change and reports it
+    fn update(&self, change: impl FnOnce(&mut DockLayout)) {
+        let before = self.layout.get();
+        self.preview(change);
+        if self.layout.get() != before {
+            self.commit();
+        }
+    }
+
+    /// Applies a change without reporting it, e.g. while dragging
+    fn preview(&self, change: impl FnOnce(&mut DockLayout)) {
+        let mut next = self.layout.get();
+        change(&mut next);
+        self.layout.set(next.clone());
+        self.sync_windows(&next);
+    }
+
+    /// Persists the current layout and calls `on_change`
+    fn commit(&self) {
+        let layout = self.layout.get();
+        save_layout(self.persist_key.as_deref(), &layout);
+        if let Some(handler) = self.on_change.as_ref() {
+            handler.call(layout);
+        }
+    }
+
+    fn set_drag(&self, panel_id: Option<String>) {
+        self.drag_panel.set(panel_id);
+        // Windows show drop targets while a tab is dragged
+        self.sync_windows(&self.layout.get());
+    }
+
+    /// Brings the floating windows in the portal up to date
+    ///
+    /// The windows share one portal entry, re-added only when a window opens
+    /// or closes or its panels change. Moving, resizing and raising a window
+    /// only restyle it, so its content is not rebuilt.
+    fn sync_windows(&self, layout: &DockLayout) {
+        let Some(portal) = self.windows.portal.as_ref() else {
+            return;
+        };
+        let dragging = self.drag_panel.get().is_some();
+        let next: Vec<WindowSnapshot> = windows_in_dom_order(layout)
+            .into_iter()
+            .map(|window| WindowSnapshot::of(window, layout, dragging))
+            .collect();
+
+        let mut shown = self.windows.shown.borrow_mut();
+        if shown.as_ref() == Some(&next) {
+            for window in &layout.windows {
+                platform::set_style_by_id(
+                    &self.window_element_id(&window.id),
+                    &window_style(window, layout),
+                );
+            }
+            return;
+        }
+        if shown.take().is_some() {
+            portal.remove_entry.call(self.windows.entry_id.clone());
+        }
+        if next.is_empty() {
+            return;
+        }
+        portal.add_entry.call(PortalEntry::Floating {
+            id: self.windows.entry_id.clone(),
+            theme_scope: portal.theme_scope.clone(),
+            children: VNode::Fragment(self.windows_view(layout)),
+        });
+        *shown = Some(next);
+    }
+
+    fn windows_view(&self, layout: &DockLayout) -> Vec<Element> {
+        windows_in_dom_order(layout)
+            .into_iter()
+            .map(|window| self.window(window, layout))
+            .collect()
+    }
+
+    fn window_element_id(&self, window_id: &str) -> String {
+        format!("{}-{window_id}", self.id)
+    }
+
+    fn panel_content(&self, panel_id: &str) -> Element {
+        match self.render_panel.as_ref() {
+            Some(render) => render(panel_id),
+            None => VNode::empty(),
+        }
+    }
+
+    fn title(&self, layout: &DockLayout, panel_id: &str) -> String {
+        layout
+            .panel(panel_id)
+            .map(|panel| panel.title.clone())
+            .unwrap_or_else(|| panel_id.to_string())
+    }
+
+    fn action(&self, label: &str, icon: MdiIcon, on_click: impl Fn() + 'static) -> Element {
+        rsx! {
+            button {
+                class: DockClass::Action.as_class(),
+                r#type: "button",
+                title: label.to_string(),
+                "aria-label": label.to_string(),
+                onclick: move |e: MouseEvent| {
+                    e.stop_propagation();
+                    on_click();
+                },
+                Icon { icon: icon, size: 14 }
+            }
+        }
+    }
+
+    /// A tabbed panel group with its action buttons
+    fn group(&self, layout: &DockLayout, group: &DockGroup, location: DockLocation) -> Element {
+        let active = group.active_panel().map(str::to_string);
+
+        let panes: Vec<Element> = group
+            .panels
+            .iter()
+            .map(|panel_id| {
+                let closable = layout.panel(panel_id).is_some_and(|panel| panel.closable);
+                rsx! {
+                    TabPane {
+                        item_key: panel_id.clone(),
+                        tab: self.title(layout, panel_id),
+                        closable,
+                        { self.panel_content(panel_id) }
+                    }
+                }
+            })
+            .collect();
+
+        let on_change = {
+            let dock = self.clone();
+            EventHandler::new(move |key: String| {
+                dock.update(|layout| {
+                    layout.activate(&key);
+                })
+            })
+        };
+        let on_close = {
+            let dock = self.clone();
+            EventHandler::new(move |key: String| {
+                dock.update(|layout| {
+                    layout.close_panel(&key);
+                })
+            })
+        };
+        let on_reorder = {
+            let dock = self.clone();
+            let location = location.clone();
+            EventHandler::new(move |reorder: TabReorder| {
+                dock.set_drag(None);
+                dock.update(|layout| reorder_group(layout, &location, &reorder));
+            })
+        };
+        let on_drag = {
+            let dock = self.clone();
+            EventHandler::new(move |key: Option<String>| match key {
+                Some(key) => {
+                    dock.set_drag(Some(key.clone()));
+                    // Drops inside the dock and its windows stop propagating,
+                    // so only one outside them tears the tab out. A drag
+                    // cancelled with Escape drops nowhere.
+                    let dock = dock.clone();
+                    platform::track_page_drop(move || {
+                        dock.update(|layout| {
+                            let rect = cascade_rect(layout);
+                            layout.move_panel(&key, DockTarget::Float(rect));
+                        })
+                    });
+                }
+                None => dock.set_drag(None),
+            })
+        };
+
+        let mut actions = Vec::new();
+        if let Some(panel_id) = active.clone() {
+            let floatable = layout.panel(&panel_id).is_some_and(|panel| panel.floatable);
+            if floatable && matches!(location, DockLocation::Zone(_)) {
+                let dock = self.clone();
+                let panel_id = panel_id.clone();
+                actions.push(self.action("Float", MdiIcon::Layers, move || {
+                    dock.update(|layout| {
+                        let rect = cascade_rect(layout);
+                        layout.move_panel(&panel_id, DockTarget::Float(rect));
+                    })
+                }));
+            }
+            let dock = self.clone();
+            actions.push(self.action("Maximize", MdiIcon::Fullscreen, move || {
+                dock.update(|layout| layout.toggle_maximize(&panel_id))
+            }));
+        }
+        if let DockLocation::Zone(zone) = location
+            && zone != DockZone::Center
+        {
+            let dock = self.clone();
+            actions.push(
+                self.action("Collapse", collapse_icon(zone, self.rtl), move || {
+                    dock.update(|layout| layout.toggle_side(zone))
+                }),
+            );
+        }
+
+        rsx! {
+            div { class: DockClass::Group.as_class(),
+                Tabs {
+                    default_active: active.clone().unwrap_or_default(),
+                    active,
+                    overflow: TabsOverflow::Scroll,
+                    editable: true,
+                    draggable: true,
+                    on_change: Some(on_change),
+                    on_close: Some(on_close),
+                    on_reorder: Some(on_reorder),
+                    on_drag: Some(on_drag),
+                    { VNode::Fragment(panes) }
+                }
+                div { class: DockClass::GroupActions.as_class(), ..actions }
+            }
+        }
+    }
+
+    /// A side zone: expanded with a resize handle, or collapsed to a strip
+    fn side(&self, layout: &DockLayout, zone: DockZone) -> Element {
+        let Some(side) = layout.side(zone) else {
+            return VNode::empty();
+        };
+        if side.group.is_empty() {
+            return VNode::empty();
+        }
+        let zone_class = match zone {
+            DockZone::Left => DockClass::SideLeft,
+            DockZone::Right => DockClass::SideRight,
+            _ => DockClass::SideBottom,
+        };
+
+        if !side.state.expanded {
+            let titles: Vec<Element> = side
+                .group
+                .panels
+                .iter()
+                .map(|panel_id| {
+                    let dock = self.clone();
+                    let target = panel_id.clone();
+                    let title = self.title(layout, panel_id);
+                    rsx! {
+                        button {
+                            class: DockClass::Action.as_class(),
+                            r#type: "button",
+                            onclick: move |_: MouseEvent| dock.update(|layout| {
+                                layout.activate(&target);
+                            }),
+                            "{title}"
+                        }
+                    }
+                })
+                .collect();
+            let classes = ClassesBuilder::new()
+                .add(DockClass::Side)
+                .add(zone_class)
+                .add(DockClass::SideCollapsed)
+                .build();
+            return rsx! {
+                div { class: classes,
+                    div { class: DockClass::SideStrip.as_class(), ..titles }
+                }
+            };
+        }
+
+        let size = side.state.width;
+        let style = if zone == DockZone::Bottom {
+            format!("height: {size}px;")
+        } else {
+            format!("width: {size}px;")
+        };
+
+        let handle_mousedown = {
+            let dock = self.clone();
+            move |e: MouseEvent| {
+                if e.button != 0 {
+                    return;
+                }
+                e.prevent_default();
+                let (origin_x, origin_y) = (e.client_x as f64, e.client_y as f64);
+                // Left grows to the right, right and bottom grow towards the center
+                let sign = match (zone, dock.rtl) {
+                    (DockZone::Left, false) | (DockZone::Right, true) => 1.0,
+                    _ => -1.0,
+                };
+                let on_move = {
+                    let dock = dock.clone();
+                    move |x: f64, y: f64| {
+                        let delta = if zone == DockZone::Bottom {
+                            y - origin_y
+                        } else {
+                            x - origin_x
+                        };
+                        let next = (size as f64 + sign * delta).max(0.0).round() as u32;
+                        dock.preview(|layout| layout.resize_side(zone, next));
+                    }
+                };
+                let on_end = {
+                    let dock = dock.clone();
+                    move || dock.commit()
+                };
+                platform::track_mouse_drag(on_move, on_end);
+            }
+        };
+
+        let classes = ClassesBuilder::new()
+            .add(DockClass::Side)
+            .add(zone_class)
+            .build();
+        rsx! {
+            div { class: classes, style: style,
+                { self.group(layout, &side.group, DockLocation::Zone(zone)) }
+                div {
+                    class: DockClass::ResizeHandle.as_class(),
+                    role: "separator",
+                    "aria-orientation": if zone == DockZone::Bottom { "horizontal" } else { "vertical" },
+                    onmousedown: handle_mousedown,
+                }
+            }
+        }
+    }
+
+    /// A floating window with a title bar, its panel group and a resize grip
+    fn window(&self, window: &FloatingWindow, layout: &DockLayout) -> Element {
+        let rect = window.rect;
+        let window_id = window.id.clone();
+        let title = window
+            .group
+            .active_panel()
+            .map(|panel_id| self.title(layout, panel_id))
+            .unwrap_or_default();
+
+        let handle_raise = {
+            let dock = self.clone();
+            let window_id = window_id.clone();
+            move |_: MouseEvent| {
+                let is_front = dock.layout.get().windows.last().map(|w| &w.id) == Some(&window_id);
+                if !is_front {
+                    dock.update(|layout| layout.raise_window(&window_id));
+                }
+            }
+        };
+
+        let handle_move = {
+            let dock = self.clone();
+            let window_id = window_id.clone();
+            move |e: MouseEvent| {
+                if e.button != 0 {
+                    return;
+                }
+                e.prevent_default();
+                let (origin_x, origin_y) = (e.client_x as f64, e.client_y as f64);
+                let on_move = {
+                    let dock = dock.clone();
+                    let window_id = window_id.clone();
+                    move |x: f64, y: f64| {
+                        let (x, y) = (rect.x + x - origin_x, rect.y + y - origin_y);
+                        dock.preview(|layout| layout.move_window(&window_id, x, y));
+                    }
+                };
+                let on_end = {
+                    let dock = dock.clone();
+                    move || dock.commit()
+                };
+                platform::track_mouse_drag(on_move, on_end);
+            }
+        };
+
+        let handle_resize = {
+            let dock = self.clone();
+            let window_id = window_id.clone();
+            move |e: MouseEvent| {
+                if e.button != 0 {
+                    return;
+                }
+                e.prevent_default();
+                e.stop_propagation();
+                let (origin_x, origin_y) = (e.client_x as f64, e.client_y as f64);
+                let on_move = {
+                    let dock = dock.clone();
+                    let window_id = window_id.clone();
+                    move |x: f64, y: f64| {
+                        let (width, height) =
+                            (rect.width + x - origin_x, rect.height + y - origin_y);
+                        dock.preview(|layout| layout.resize_window(&window_id, width, height));
+                    }
+                };
+                let on_end = {
+                    let dock = dock.clone();
+                    move || dock.commit()
+                };
+                platform::track_mouse_drag(on_move, on_end);
+            }
+        };
+
+        let dock_back = {
+            let dock = self.clone();
+            let window_id = window_id.clone();
+            self.action("Dock", MdiIcon::ViewDashboard, move || {
+                dock.update(|layout| {
+                    layout.dock_window(&window_id, DockZone::Center);
+                })
+            })
+        };
+        let close = {
+            let dock = self.clone();
+            let window_id = window_id.clone();
+            self.action("Close window", MdiIcon::Close, move || {
+                dock.update(|layout| {
+                    layout.close_window(&window_id);
+                })
+            })
+        };
+
+        let drop_target = self.drop_target(
+            DockClass::DropZone,
+            DockTarget::Window(window_id.clone()),
+            "window",
+        );
+
+        rsx! {
+            div {
+                id: self.window_element_id(&window_id),
+                class: DockClass::Window.as_class(),
+                role: "dialog",
+                "aria-label": title.clone(),
+                "data-window": window_id.clone(),
+                style: window_style(window, layout),
+                onmousedown: handle_raise,
+                ondragover: move |e: DragEvent| e.prevent_default(),
+                ondrop: self.drop_inside(),
+                div { class: DockClass::WindowTitle.as_class(), onmousedown: handle_move,
+                    span { "{title}" }
+                    {dock_back}
+                    {close}
+                }
+                div { class: DockClass::WindowBody.as_class(),
+                    { self.group(layout, &window.group, DockLocation::Window(window_id.clone())) }
+                    {drop_target}
+                }
+                div {
+                    class: DockClass::WindowResize.as_class(),
+                    onmousedown: handle_resize,
+                }
+            }
+        }
+    }
+
+    /// Handler that keeps drops inside the dock or a window from tearing
+    /// the dragged tab out
+    fn drop_inside(&self) -> impl Fn(DragEvent) + 'static {
+        let dock = self.clone();
+        move |e: DragEvent| {
+            e.stop_propagation();
+            if dock.drag_panel.get().is_some() {
+                dock.set_drag(None);
+            }
+        }
+    }
+
+    /// Drop target shown while a tab is dragged
+    fn drop_target(&self, class: DockClass, target: DockTarget, name: &str) -> Element {
+        if self.drag_panel.get().is_none() {
+            return VNode::empty();
+        }
+        let dock = self.clone();
+        rsx! {
+            div {
+                class: class.as_class(),
+                "data-zone": name.to_string(),
+                ondragover: move |e: DragEvent| e.prevent_default(),
+                ondrop: move |e: DragEvent| {
+                    e.prevent_default();
+                    if let Some(panel_id) = dock.drag_panel.get() {
+                        dock.set_drag(None);
+                        let target = target.clone();
+                        dock.update(|layout| {
+                            layout.move_panel(&panel_id, target);
+                        });
+                    }
+                },
+            }
+        }
+    }
+
+    fn maximized(&self, layout: &DockLayout) -> Element {
+        let Some(panel_id) = layout.maximized.clone() else {
+            return VNode::empty();
+        };
+        let restore = {
+            let dock = self.clone();
+            let panel_id = panel_id.clone();
+            self.action("Restore", MdiIcon::FullscreenExit, move || {
+                dock.update(|layout| layout.toggle_maximize(&panel_id))
+            })
+        };
+        let title = self.title(layout, &panel_id);
+        let handle_keydown = {
+            let dock = self.clone();
+            let panel_id = panel_id.clone();
+            move |e: KeyboardEvent| {
+                if e.get_key() == Key::Escape {
+                    e.prevent_default();
+                    dock.update(|layout| layout.toggle_maximize(&panel_id));
+                }
+            }
+        };
+        rsx! {
+            div {
+                class: DockClass::Maximized.as_class(),
+                role: "region",
+                "aria-label": title.clone(),
+                onkeydown: handle_keydown,
+                div { class: DockClass::MaximizedHeader.as_class(),
+                    span { "{title}" }
+                    {restore}
+                }
+                div { class: DockClass::WindowBody.as_class(), { self.panel_content(&panel_id) } }
+            }
+        }
+    }
+}
+
+fn collapse_icon(zone: DockZone, rtl: bool) -> MdiIcon {
+    match (zone, rtl) {
+        (DockZone::Bottom, _) => MdiIcon::ChevronDown,
+        (DockZone::Left, false) | (DockZone::Right, true) => MdiIcon::ChevronLeft,
+        _ => MdiIcon::ChevronRight,
+    }
+}
+
+/// Applies a tab drag-and-drop reorder to the group at `location`
+fn reorder_group(layout: &mut DockLayout, location: &DockLocation, reorder: &TabReorder) {
+    let group = match location {
+        DockLocation::Zone(DockZone::Center) => &mut layout.center,
+        DockLocation::Zone(DockZone::Left) => &mut layout.left.group,
+        DockLocation::Zone(DockZone::Right) => &mut layout.right.group,
+        DockLocation::Zone(DockZone::Bottom) => &mut layout.bottom.group,
+        DockLocation::Window(id) => match layout.windows.iter_mut().find(|w| w.id == *id) {
+            Some(window) => &mut window.group,
+            None => return,
+        },
+    };
+    group.panels = reorder.apply(&group.panels);
+}
+
+/// Windows in a stable order, so raising one does not move the others in
+/// the DOM; `z-index` stacks them
+fn windows_in_dom_order(layout: &DockLayout) -> Vec<&FloatingWindow> {
+    let mut windows: Vec<_> = layout.windows.iter().collect();
+    windows.sort_by(|a, b| a.id.cmp(&b.id));
+    windows
+}
+
+/// Geometry and stacking order of a floating window
+fn window_style(window: &FloatingWindow, layout: &DockLayout) -> String {
+    let rect = window.rect;
+    let z_index = layout
+        .windows
+        .iter()
+        .position(|w| w.id == window.id)
+        .unwrap_or_default();
+    format!(
+        "left: {:.0}px; top: {:.0}px; width: {:.0}px; height: {:.0}px; z-index: {z_index};",
+        rect.x, rect.y, rect.width, rect.height
+    )
+}
+
+/// Rectangle for a newly floated window, cascading from the default one
+fn cascade_rect(layout: &DockLayout) -> DockRect {
+    let offset = CASCADE_STEP * (layout.windows.len() % 8) as f64;
+    let rect = DockRect::default();
+    DockRect::new(rect.x + offset, rect.y + offset, rect.width, rect.height)
+}
+
+#[component]
+pub fn Dock(props: DockProps) -> Element {
+    let id = use_roving_id("hk-dock");
+    let rtl = use_layout_direction().is_rtl();
+
+    let persist_key = props.persist_key.clone();
+    let initial = props.layout.clone();
+    let layout = use_signal(move || load_layout(persist_key.as_deref(), initial));
+    let drag_panel = use_signal(|| None::<String>);
+
+    let portal = try_use_portal();
+    let entry_id = format!("{id}-windows");
+    let windows = match runtime::active_component_id() {
+        // Hook slots are cleared on unmount, dropping the windows with them.
+        Some(component) => runtime::hook_slot(component, "hk-dock:windows", move || {
+            Rc::new(PortalWindows {
+                portal,
+                entry_id,
+                shown: RefCell::new(None),
+            })
+        }),
+        None => Rc::new(PortalWindows {
+            portal: None,
+            entry_id,
+            shown: RefCell::new(None),
+        }),
+    };
+
+    let dock = DockHandle {
+        id: id.clone(),
+        layout: layout.inner().clone(),
+        drag_panel: drag_panel.inner().clone(),
+        on_change: props.on_change.clone(),
+        render_panel: props.render_panel.clone(),
+        persist_key: props.persist_key.clone(),
+        windows,
+        rtl,
+    };
+
+    // Windows of a restored layout go to the portal once the dock is mounted
+    {
+        let dock = dock.clone();
+        use_effect(move || {
+            let dock = dock.clone();
+            platform::set_timeout(
+                move || {
+                    let layout = dock.layout.get();
+                    dock.sync_windows(&layout);
+                },
+                0,
+            );
+        });
+    }
+
+    let current = layout.get();
+    let dragging = drag_panel.get().is_some();
+
+    let inline_windows: Vec<Element> = if dock.windows.portal.is_none() {
+        dock.windows_view(&current)
+    } else {
+        Vec::new()
+    };
+
+    let drop_zones: Vec<Element> = if dragging {
+        DockZone::ALL
+            .into_iter()
+            .map(|zone| {
+                dock.drop_target(DockClass::DropZone, DockTarget::Zone(zone), zone.as_str())
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let center = if current.center.is_empty() {
+        rsx! { div { class: DockClass::Empty.as_class() } }
+    } else {
+        dock.group(
+            &current,
+            &current.center,
+            DockLocation::Zone(DockZone::Center),
+        )
+    };
+
+    // Tabs dropped anywhere inside the dock (e.g. back onto their own tab
+    // bar) are not torn out
+    let handle_drop = dock.drop_inside();
+
+    let classes = ClassesBuilder::new()
+        .add(DockClass::Dock)
+        .add_if(DockClass::Dragging, || dragging)
+        .add_raw(&props.class)
+        .build();
+
+    rsx! {
+        div {
+            id: id,
+            class: classes,
+            ondragover: move |e: DragEvent| {
+                if dragging {
+                    e.prevent_default();
+                }
+            },
+            ondrop: handle_drop,
+            div { class: DockClass::Main.as_class(),
+                { dock.side(&current, DockZone::Left) }
+                div { class: DockClass::Center.as_class(), {center} }
+                { dock.side(&current, DockZone::Right) }
+            }
+            { dock.side(&current, DockZone::Bottom) }
+            if dragging {
+                div { class: DockClass::DropOverlay.as_class(), ..drop_zones }
+            }
+            { dock.maximized(&current) },
+            ..inline_windows,
+        }
+    }
+}
+
+pub struct DockComponent;
+
+impl crate::styled::StyledComponent for DockComponent {
+    fn styles() -> &'static str {
+        r#"
+.hk-dock {
+  position: relative;
+  display: flex;
+  flex-direction: column;
+  width: 100%;
+  height: 100%;
+  min-height: 0;
+  overflow: hidden;
+}
+
+.hk-dock-main {
+  display: flex;
+  flex: 1 1 0;
+  min-height: 0;
+}
+
+.hk-dock-center {
+  position: relative;
+  flex: 1 1 0;
+  min-width: 0;
+  min-height: 0;
+}
+
+.hk-dock-side {
+  position: relative;
+  flex: 0 0 auto;
+  min-width: 0;
+  min-height: 0;
+  background: var(--hi-surface, #fff);
+}
+
+.hk-dock-side-left {
+  border-inline-end: 1px solid var(--hi-border, #e5e7eb);
+}
+
+.hk-dock-side-right {
+  border-inline-start: 1px solid var(--hi-border, #e5e7eb);
+}
+
+.hk-dock-side-bottom {
+  border-top: 1px solid var(--hi-border, #e5e7eb);
+}
+
+.hk-dock-side-collapsed {
+  width: auto;
+  height: auto;
+}
+
+.hk-dock-side-strip {
+  display: flex;
+  gap: 4px;
+  padding: 4px;
+}
+
+.hk-dock-side-left .hk-dock-side-strip,
+.hk-dock-side-right .hk-dock-side-strip {
+  flex-direction: column;
+  writing-mode: vertical-rl;
+}
+
+.hk-dock-resize-handle {
+  position: absolute;
+  z-index: 2;
+  touch-action: none;
+}
+
+.hk-dock-resize-handle:hover {
+  background: var(--hi-color-primary, #4a9eff);
+}
+
+.hk-dock-side-left > .hk-dock-resize-handle,
+.hk-dock-side-right > .hk-dock-resize-handle {
+  top: 0;
+  bottom: 0;
+  width: 4px;
+  cursor: col-resize;
+}
+
+.hk-dock-side-left > .hk-dock-resize-handle {
+  inset-inline-end: -2px;
+}
+
+.hk-dock-side-right > .hk-dock-resize-handle {
+  inset-inline-start: -2px;
+}
+
+.hk-dock-side-bottom > .hk-dock-resize-handle {
+  top: -2px;
+  left: 0;
+  right: 0;
+  height: 4px;
+  cursor: row-resize;
+}
+
+.hk-dock-group {
+  position: relative;
+  display: flex;
+  flex-direction: column;
+  width: 100%;
+  height: 100%;
+  min-height: 0;
+}
+
+.hk-dock-group > .hk-tabs {
+  flex: 1 1 0;
+  min-height: 0;
+}
+
+.hk-dock .hk-tabs-add-button {
+  display: none;
+}
+
+.hk-dock-group-actions {
+  position: absolute;
+  top: 4px;
+  inset-inline-end: 4px;
+  display: flex;
+  gap: 2px;
+}
+
+.hk-dock-action {
+  display: inline-flex;
+  align-items: center;
+  justify-content: center;
+  padding: 2px 4px;
+  border: none;
+  border-radius: 4px;
+  background: transparent;
+  color: inherit;
+  cursor: pointer;
+}
+
+.hk-dock-action:hover {
+  background: var(--hi-surface-hover, rgba(0, 0, 0, 0.06));
+}
+
+.hk-dock-drop-overlay {
+  position: absolute;
+  inset: 0;
+  z-index: 5;
+  pointer-events: none;
+}
+
+.hk-dock-drop-zone {
+  position: absolute;
+  pointer-events: auto;
+  border: 2px dashed transparent;
+  border-radius: 4px;
+  transition: background-color 0.15s ease, border-color 0.15s ease;
+}
+
+.hk-dock-drop-zone:hover,
+.hk-dock-drop-zone:focus-within {
+  border-color: var(--hi-color-primary, #4a9eff);
+  background: rgba(74, 158, 255, 0.12);
+}
+
+.hk-dock-drop-zone[data-zone="left"] {
+  top: 0;
+  bottom: 0;
+  inset-inline-start: 0;
+  width: 25%;
+}
+
+.hk-dock-drop-zone[data-zone="right"] {
+  top: 0;
+  bottom: 0;
+  inset-inline-end: 0;
+  width: 25%;
+}
+
+.hk-dock-drop-zone[data-zone="bottom"] {
+  bottom: 0;
+  left: 25%;
+  right: 25%;
+  height: 25%;
+}
+
+/* The top band stays free so tabs can still be reordered */
+.hk-dock-drop-zone[data-zone="center"] {
+  top: 25%;
+  bottom: 25%;
+  left: 25%;
+  right: 25%;
+}
+
+.hk-dock-drop-zone[data-zone="window"] {
+  inset: 32px 0 0 0;
+}
+
+.hk-dock-window {
+  position: fixed;
+  display: flex;
+  flex-direction: column;
+  border: 1px solid var(--hi-border, #e5e7eb);
+  border-radius: 6px;
+  background: var(--hi-surface, #fff);
+  box-shadow: 0 8px 24px rgba(0, 0, 0, 0.18);
+  overflow: hidden;
+}
+
+.hk-dock-window-title,
+.hk-dock-maximized-header {
+  display: flex;
+  align-items: center;
+  gap: 4px;
+  padding: 4px 8px;
+  border-bottom: 1px solid var(--hi-border, #e5e7eb);
+  font-weight: 600;
+  user-select: none;
+}
+
+.hk-dock-window-title {
+  cursor: move;
+}
+
+.hk-dock-window-title > span,
+.hk-dock-maximized-header > span {
+  flex: 1 1 auto;
+  overflow: hidden;
+  text-overflow: ellipsis;
+  white-space: nowrap;
+}
+
+.hk-dock-window-body {
+  position: relative;
+  flex: 1 1 0;
+  min-height: 0;
+  overflow: auto;
+}
+
+.hk-dock-window-resize {
+  position: absolute;
+  right: 0;
+  bottom: 0;
+  width: 12px;
+  height: 12px;
+  cursor: nwse-resize;
+  touch-action: none;
+}
+
+.hk-dock-maximized {
+  position: absolute;
+  inset: 0;
+  z-index: 6;
+  display: flex;
+  flex-direction: column;
+  background: var(--hi-surface, #fff);
+}
+
+.hk-dock-empty {
+  width: 100%;
+  height: 100%;
+}
+"#
+    }
+
+    fn name() -> &'static str {
+        "dock"
+    }
+}
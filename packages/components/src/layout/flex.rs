@@ -5,6 +5,7 @@ use hikari_palette::classes::{
     AlignItems, ClassesBuilder, Display, Flex as FlexUtil, FlexDirection, FlexWrap, JustifyContent,
 };
 
+use super::responsive::{Responsive, ResponsiveProperty, ResponsiveStyle, Screen};
 use crate::prelude::*;
 use crate::styled::StyledComponent;
 use crate::theme::use_layout_direction;
@@ -18,6 +19,18 @@ pub enum Direction {
     ColumnReverse,
 }
 
+impl Direction {
+    /// CSS `flex-direction` value, mirroring rows in RTL layouts
+    pub fn css_value(&self, rtl: bool) -> &'static str {
+        match (self, rtl) {
+            (Direction::Column, _) => "column",
+            (Direction::ColumnReverse, _) => "column-reverse",
+            (Direction::Row, false) | (Direction::RowReverse, true) => "row",
+            (Direction::Row, true) | (Direction::RowReverse, false) => "row-reverse",
+        }
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Debug, Default)]
 pub enum Align {
     #[default]
@@ -60,10 +73,29 @@ pub enum FlexGap {
     Gap8,
 }
 
+impl FlexGap {
+    /// CSS length matching the `gap-*` utility class
+    pub fn css_value(&self) -> &'static str {
+        match self {
+            FlexGap::None => "0",
+            FlexGap::Gap1 => "0.25rem",
+            FlexGap::Gap2 => "0.5rem",
+            FlexGap::Gap3 => "0.75rem",
+            FlexGap::Gap4 => "1rem",
+            FlexGap::Gap5 => "1.25rem",
+            FlexGap::Gap6 => "1.5rem",
+            FlexGap::Gap8 => "2rem",
+        }
+    }
+}
+
 #[define_props]
 pub struct FlexBoxProps {
     pub direction: Direction,
 
+    /// Direction overrides per breakpoint
+    pub direction_at: Responsive<Direction>,
+
     pub align: Align,
 
     pub justify: Justify,
@@ -72,6 +104,9 @@ pub struct FlexBoxProps {
 
     pub gap: FlexGap,
 
+    /// Gap overrides per breakpoint
+    pub gap_at: Responsive<FlexGap>,
+
     #[default(true)]
     pub flex: bool,
 
@@ -158,6 +193,30 @@ pub fn FlexBox(props: FlexBoxProps) -> Element {
         Display::Flex
     };
 
+    // Responsive overrides start from the base value at `xs`
+    let directions = if props.direction_at.is_empty() {
+        Responsive::default()
+    } else {
+        props
+            .direction_at
+            .map(|d| d.css_value(is_rtl).to_string())
+            .or_at(
+                Screen::Xs,
+                Some(props.direction.css_value(is_rtl).to_string()),
+            )
+    };
+    let gaps = if props.gap_at.is_empty() {
+        Responsive::default()
+    } else {
+        props
+            .gap_at
+            .map(|g| g.css_value().to_string())
+            .or_at(Screen::Xs, Some(props.gap.css_value().to_string()))
+    };
+    let responsive = ResponsiveStyle::new()
+        .set(ResponsiveProperty::FlexDirection, &directions)
+        .set(ResponsiveProperty::Gap, &gaps);
+
     let builder = ClassesBuilder::new()
         .add(display_class)
         .add(direction_class)
@@ -166,11 +225,12 @@ pub fn FlexBox(props: FlexBoxProps) -> Element {
         .add(wrap_class)
         .add_if(FlexUtil::Flex1, || props.flex)
         .add_raw(gap_class)
+        .add_raw(&responsive.classes())
         .add_raw(&props.class);
 
     let classes = builder.build();
 
-    let mut style = props.style.clone();
+    let mut style = format!("{} {}", responsive.style(), props.style);
     if let Some(min_w) = &props.min_width {
         style = format!("{} min-width: {};", style, min_w);
     }
@@ -20,9 +20,30 @@
 use hikari_palette::ClassesBuilder;
 use hikari_palette::classes::components::*;
 
+use super::responsive::{Responsive, ResponsiveProperty, ResponsiveStyle, Screen};
 use crate::prelude::*;
 use crate::theme::use_layout_direction;
 
+/// CSS length for a named gap (`sm`, `md`, `lg`)
+fn gap_value(gap: &str) -> String {
+    match gap {
+        "sm" => "0.5rem",
+        "lg" => "1.5rem",
+        _ => "1rem",
+    }
+    .to_string()
+}
+
+/// Per-breakpoint gap, with the base gap applying below the first override
+fn responsive_gap(gap: &str, gap_at: &Responsive<String>) -> Responsive<String> {
+    if gap_at.is_empty() {
+        return Responsive::default();
+    }
+    gap_at
+        .map(|g| gap_value(g))
+        .or_at(Screen::Xs, Some(gap_value(gap)))
+}
+
 ///
 ///
 #[component]
@@ -33,6 +54,10 @@ pub fn Grid(
 
     #[props(default = "md".to_string())] gap: String,
 
+    /// Gap overrides per breakpoint (`sm`, `md`, `lg`)
+    #[props(default)]
+    gap_at: Responsive<String>,
+
     #[props(default)] class: String,
 ) -> Element {
     let gap_class = match gap.as_str() {
@@ -41,21 +66,46 @@ pub fn Grid(
         _ => GridClass::GapMd, // md (default)
     };
 
+    let responsive =
+        ResponsiveStyle::new().set(ResponsiveProperty::Gap, &responsive_gap(&gap, &gap_at));
+
     let classes = ClassesBuilder::new()
         .add(GridClass::Grid)
         .add(gap_class)
+        .add_raw(&responsive.classes())
         .add_raw(&class)
         .build();
 
     rsx! {
         div {
             class: classes,
-            style: format!("grid-template-columns: repeat({columns}, minmax(0, 1fr));"),
+            style: format!(
+                "grid-template-columns: repeat({columns}, minmax(0, 1fr)); {}",
+                responsive.style()
+            ),
             {children}
         }
     }
 }
 
+/// Resolves `grid-column` values for each breakpoint where span or offset changes
+pub(crate) fn col_grid_column(
+    spans: &Responsive<u8>,
+    offsets: &Responsive<u8>,
+) -> Responsive<String> {
+    Screen::ALL
+        .into_iter()
+        .filter(|s| *s == Screen::Xs || spans.get(*s).is_some() || offsets.get(*s).is_some())
+        .fold(Responsive::default(), |acc, screen| {
+            let span = spans.resolve(screen).copied().unwrap_or(12);
+            let value = match offsets.resolve(screen) {
+                Some(offset) => format!("{} / span {span}", offset + 1),
+                None => format!("auto / span {span}"),
+            };
+            acc.at(screen, value)
+        })
+}
+
 ///
 ///
 #[component]
@@ -70,43 +120,48 @@ pub fn Col(
 
     #[props(default)] span_lg: Option<u8>,
 
-    #[props(default)] offset: Option<u8>,
+    #[props(default)] span_xl: Option<u8>,
 
-    #[props(default)] class: String,
-) -> Element {
-    // Build responsive grid-column style
-    let mut style_parts = Vec::new();
+    #[props(default)] span_xxl: Option<u8>,
 
-    // Base span (mobile first)
-    let base_span = span_sm.or(span).unwrap_or(12);
-    style_parts.push(format!("grid-column: span {base_span};"));
+    #[props(default)] offset: Option<u8>,
 
-    // Responsive spans using media query simulation via CSS custom properties
-    // We'll use a CSS approach with inline styles
-    let md_span = span_md.or(span).unwrap_or(base_span);
-    let lg_span = span_lg.or(span_md).or(span).unwrap_or(md_span);
+    /// Offset overrides per breakpoint
+    #[props(default)]
+    offset_at: Responsive<u8>,
 
-    // Build the style with responsive behavior using CSS grid-column
-    let style = if let Some(o) = offset {
-        format!("grid-column: {} / span {};", o + 1, base_span)
-    } else {
-        format!("grid-column: span {base_span};")
+    /// Visual order per breakpoint
+    #[props(default)]
+    order: Responsive<i32>,
+
+    #[props(default)] class: String,
+) -> Element {
+    // Mobile first: `span` applies everywhere until a larger breakpoint overrides it
+    let spans = Responsive {
+        xs: span,
+        sm: span_sm,
+        md: span_md,
+        lg: span_lg,
+        xl: span_xl,
+        xxl: span_xxl,
     };
+    let offsets = offset_at.clone().or_at(Screen::Xs, offset);
 
-    // Add responsive classes for different breakpoints
-    let responsive_class = format!(
-        "col-responsive col-span-{} md:col-span-{} lg:col-span-{}",
-        base_span, md_span, lg_span
-    );
+    let responsive = ResponsiveStyle::new()
+        .set(
+            ResponsiveProperty::GridColumn,
+            &col_grid_column(&spans, &offsets),
+        )
+        .set(ResponsiveProperty::Order, &order.map(|o| o.to_string()));
 
     let classes = ClassesBuilder::new()
         .add(GridClass::Col)
-        .add_raw(&responsive_class)
+        .add_raw(&responsive.classes())
         .add_raw(&class)
         .build();
 
     rsx! {
-        div { class: classes, style, {children} }
+        div { class: classes, style: responsive.style().to_string(), {children} }
     }
 }
 
@@ -118,6 +173,10 @@ pub fn Row(
 
     #[props(default = "md".to_string())] gap: String,
 
+    /// Gap overrides per breakpoint (`sm`, `md`, `lg`)
+    #[props(default)]
+    gap_at: Responsive<String>,
+
     #[props(default = true)] wrap: bool,
 
     #[props(default = "start".to_string())] justify: String,
@@ -166,9 +225,13 @@ pub fn Row(
         "flex-direction: row;"
     };
 
+    let responsive =
+        ResponsiveStyle::new().set(ResponsiveProperty::Gap, &responsive_gap(&gap, &gap_at));
+
     let classes = ClassesBuilder::new()
         .add(RowClass::Row)
         .add(gap_class)
+        .add_raw(&responsive.classes())
         .add_raw(&class)
         .build();
 
@@ -176,7 +239,8 @@ pub fn Row(
         div {
             class: classes,
             style: format!(
-                "display: flex; {direction_style} {justify_style} {align_style} {wrap_style} {style}",
+                "display: flex; {direction_style} {justify_style} {align_style} {wrap_style} {} {style}",
+                responsive.style()
             ),
             {children}
         }
@@ -224,6 +288,8 @@ impl crate::styled::StyledComponent for GridComponent {
   gap: 1.5rem;
 }
 
+/* Deprecated: `Col` sets its spans through responsive props now. These
+   classes stay for markup that still uses them directly. */
 .col-responsive {
   grid-column: span 12;
 }
@@ -0,0 +1,799 @@
+// hikari-components/src/layout/masonry.rs
+//! Masonry and virtualized grid layouts
+//!
+//! [`Grid`](super::Grid) lays items out on a 24-column row/col grid. The
+//! components here target large, card-based collections instead:
+//!
+//! - [`Masonry`] - balances variable-height cards over columns, placing each
+//!   card in the currently shortest column
+//! - [`VirtualGrid`] - a responsive grid (uniform rows, or masonry) that only
+//!   renders the cards near the viewport, for tens of thousands of items
+//!
+//! Column counts follow the container width (`min_column_width`) unless
+//! `columns` is set. Card heights are measured once rendered; when a card
+//! above the viewport changes height (an image finished loading, say), the
+//! virtual grid shifts its scroll position so the visible cards stay put.
+//!
+//! [`MasonryLayout`] and [`HeightCache`] hold the framework-agnostic maths.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::rc::Rc;
+//! use hikari_components::layout::VirtualGrid;
+//!
+//! rsx! {
+//!     VirtualGrid {
+//!         count: assets.len(),
+//!         masonry: true,
+//!         min_column_width: 220.0,
+//!         estimated_height: 260.0,
+//!         height: "80vh".to_string(),
+//!         render_item: Some(Rc::new(move |index| rsx! { AssetCard { asset: assets[index].clone() } })),
+//!     }
+//! }
+//! ```
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use hikari_palette::classes::{ClassesBuilder, MasonryClass, UtilityClass};
+use tairitsu_vdom::runtime;
+
+use crate::platform;
+use crate::prelude::*;
+use crate::utils::use_roving_id;
+
+/// Column count used before the container has been measured
+const FALLBACK_COLUMNS: usize = 3;
+
+/// Position of one item, in px relative to the grid's content box
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct MasonryRect {
+    pub column: usize,
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl MasonryRect {
+    pub fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+}
+
+/// Placement of a list of items over a number of columns
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct MasonryLayout {
+    pub columns: usize,
+    pub column_width: f64,
+    pub gap: f64,
+    /// Item rectangles, in item order
+    pub items: Vec<MasonryRect>,
+    /// Item indices per column, top to bottom
+    pub column_items: Vec<Vec<usize>>,
+    /// Total content height
+    pub height: f64,
+}
+
+impl MasonryLayout {
+    /// Number of columns of at least `min_column_width` that fit in `width`
+    pub fn columns_for_width(width: f64, min_column_width: f64, gap: f64) -> usize {
+        if width <= 0.0 || min_column_width <= 0.0 {
+            return 1;
+        }
+        (((width + gap) / (min_column_width + gap)).floor() as usize).max(1)
+    }
+
+    fn empty(width: f64, columns: usize, gap: f64) -> Self {
+        let columns = columns.max(1);
+        let column_width = ((width - gap * (columns - 1) as f64) / columns as f64).max(0.0);
+        Self {
+            columns,
+            column_width,
+            gap,
+            items: Vec::new(),
+            column_items: vec![Vec::new(); columns],
+            height: 0.0,
+        }
+    }
+
+    fn place(&mut self, index: usize, column: usize, y: f64, height: f64) {
+        let x = column as f64 * (self.column_width + self.gap);
+        self.items.push(MasonryRect {
+            column,
+            x,
+            y,
+            width: self.column_width,
+            height,
+        });
+        self.column_items[column].push(index);
+        self.height = self.height.max(y + height);
+    }
+
+    /// Masonry placement: each item goes to the shortest column (leftmost on ties)
+    pub fn masonry(
+        heights: impl IntoIterator<Item = f64>,
+        width: f64,
+        columns: usize,
+        gap: f64,
+    ) -> Self {
+        let mut layout = Self::empty(width, columns, gap);
+        let mut tops = vec![0.0_f64; layout.columns];
+        for (index, height) in heights.into_iter().enumerate() {
+            let column = tops
+                .iter()
+                .enumerate()
+                .min_by(|a, b| a.1.total_cmp(b.1))
+                .map(|(column, _)| column)
+                .unwrap_or(0);
+            layout.place(index, column, tops[column], height);
+            tops[column] += height + gap;
+        }
+        layout
+    }
+
+    /// Row-major grid where every row is as tall as its tallest item
+    pub fn rows(
+        heights: impl IntoIterator<Item = f64>,
+        width: f64,
+        columns: usize,
+        gap: f64,
+    ) -> Self {
+        let mut layout = Self::empty(width, columns, gap);
+        let heights: Vec<f64> = heights.into_iter().collect();
+        let mut top = 0.0;
+        for (row, chunk) in heights.chunks(layout.columns).enumerate() {
+            let row_height = chunk.iter().copied().fold(0.0, f64::max);
+            for (column, _) in chunk.iter().enumerate() {
+                layout.place(row * layout.columns + column, column, top, row_height);
+            }
+            top += row_height + gap;
+        }
+        layout
+    }
+
+    /// Indices of the items intersecting `top..bottom`, in item order
+    pub fn visible(&self, top: f64, bottom: f64) -> Vec<usize> {
+        let mut visible: Vec<usize> = self
+            .column_items
+            .iter()
+            .flat_map(|column| {
+                // Items in a column are sorted by position, so binary search
+                let start = column.partition_point(|&i| self.items[i].bottom() < top);
+                column[start..]
+                    .iter()
+                    .take_while(|&&i| self.items[i].y <= bottom)
+                    .copied()
+            })
+            .collect();
+        visible.sort_unstable();
+        visible
+    }
+
+    /// The first item visible at `scroll_top` and its distance from it
+    ///
+    /// Used with [`restore_anchor`](Self::restore_anchor) to keep that item
+    /// in place while the heights above it change.
+    pub fn anchor(&self, scroll_top: f64) -> Option<(usize, f64)> {
+        self.column_items
+            .iter()
+            .filter_map(|column| {
+                let start = column.partition_point(|&i| self.items[i].bottom() <= scroll_top);
+                column.get(start).copied()
+            })
+            .min_by(|&a, &b| self.items[a].y.total_cmp(&self.items[b].y).then(a.cmp(&b)))
+            .map(|index| (index, self.items[index].y - scroll_top))
+    }
+
+    /// Scroll position that puts an anchor back where it was
+    pub fn restore_anchor(&self, (index, offset): (usize, f64)) -> Option<f64> {
+        self.items.get(index).map(|item| (item.y - offset).max(0.0))
+    }
+}
+
+/// Measured item heights, falling back to an estimate
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct HeightCache {
+    pub estimated: f64,
+    measured: Vec<Option<f64>>,
+}
+
+impl HeightCache {
+    pub fn new(estimated: f64) -> Self {
+        Self {
+            estimated,
+            measured: Vec::new(),
+        }
+    }
+
+    pub fn get(&self, index: usize) -> f64 {
+        self.measured
+            .get(index)
+            .copied()
+            .flatten()
+            .unwrap_or(self.estimated)
+    }
+
+    pub fn is_measured(&self, index: usize) -> bool {
+        matches!(self.measured.get(index), Some(Some(_)))
+    }
+
+    /// Records a measured height; returns whether it changed (by ≥ 0.5px)
+    pub fn set(&mut self, index: usize, height: f64) -> bool {
+        if self.measured.len() <= index {
+            self.measured.resize(index + 1, None);
+        }
+        let changed = match self.measured[index] {
+            Some(previous) => (previous - height).abs() >= 0.5,
+            None => true,
+        };
+        if changed {
+            self.measured[index] = Some(height);
+        }
+        changed
+    }
+
+    /// Heights of the first `count` items
+    pub fn heights(&self, count: usize) -> impl Iterator<Item = f64> + '_ {
+        (0..count).map(|index| self.get(index))
+    }
+
+    /// Forgets all measurements, e.g. after the column width changed
+    pub fn clear(&mut self) {
+        self.measured.clear();
+    }
+}
+
+fn column_count(columns: Option<usize>, width: f64, min_column_width: f64, gap: f64) -> usize {
+    match (columns, width > 0.0) {
+        (Some(columns), _) => columns.max(1),
+        (None, true) => MasonryLayout::columns_for_width(width, min_column_width, gap),
+        (None, false) => FALLBACK_COLUMNS,
+    }
+}
+
+fn item_id(grid_id: &str, index: usize) -> String {
+    format!("{grid_id}-item-{index}")
+}
+
+/// Geometry inputs shared by the measuring callbacks
+#[derive(Clone, PartialEq, Debug, Default)]
+struct GridConfig {
+    count: usize,
+    columns: Option<usize>,
+    min_column_width: f64,
+    gap: f64,
+    masonry: bool,
+    /// Uniform rows need no measuring
+    measure: bool,
+}
+
+impl GridConfig {
+    fn layout(&self, heights: &HeightCache, width: f64) -> MasonryLayout {
+        let columns = column_count(self.columns, width, self.min_column_width, self.gap);
+        // Without a measured width, columns are laid out in percent by CSS
+        let width = if width > 0.0 { width } else { 0.0 };
+        if self.masonry {
+            MasonryLayout::masonry(heights.heights(self.count), width, columns, self.gap)
+        } else {
+            MasonryLayout::rows(heights.heights(self.count), width, columns, self.gap)
+        }
+    }
+}
+
+/// Per-instance measuring state; disconnects its resize observer on unmount
+struct MeasureState {
+    observer: u64,
+    config: RefCell<GridConfig>,
+    /// Items currently in the DOM
+    rendered: RefCell<Vec<usize>>,
+}
+
+impl Drop for MeasureState {
+    fn drop(&mut self) {
+        platform::disconnect_resize(self.observer);
+    }
+}
+
+/// Signals and ids the measuring callbacks work on
+#[derive(Clone)]
+struct Measurer {
+    id: String,
+    /// Scroll container; the grid itself for non-virtual layouts
+    viewport_id: String,
+    width: Signal<f64>,
+    scroll: Signal<(f64, f64)>,
+    heights: Signal<HeightCache>,
+}
+
+impl Measurer {
+    /// Reads the container size and rendered item heights, keeping the
+    /// first visible item in place if heights above it changed
+    fn measure(&self, state: &MeasureState) {
+        let Some((scroll_top, width, viewport_height)) =
+            platform::get_viewport_by_id(&self.viewport_id)
+        else {
+            return;
+        };
+        let config = state.config.borrow().clone();
+
+        let previous_width = self.width.get();
+        let mut heights = self.heights.get();
+        let before = config.layout(&heights, previous_width);
+        if (width - previous_width).abs() >= 0.5 {
+            // Cards reflow at a new column width, so old heights are stale
+            if previous_width > 0.0 {
+                heights.clear();
+            }
+            self.width.set(width);
+        }
+
+        if config.measure {
+            for &index in state.rendered.borrow().iter() {
+                if let Some(rect) = platform::get_element_rect_by_id(&item_id(&self.id, index)) {
+                    heights.set(index, rect.height);
+                }
+            }
+        }
+
+        let mut top = scroll_top;
+        if heights != self.heights.get() {
+            let after = config.layout(&heights, width);
+            if let Some(anchored) = before
+                .anchor(scroll_top)
+                .and_then(|anchor| after.restore_anchor(anchor))
+                && (anchored - scroll_top).abs() >= 0.5
+            {
+                platform::set_scroll_top_by_id(&self.viewport_id, anchored);
+                top = anchored;
+            }
+            self.heights.set(heights);
+        }
+        if self.scroll.get() != (top, viewport_height) {
+            self.scroll.set((top, viewport_height));
+        }
+        self.observe_items(state);
+    }
+
+    /// Measures on the next frame if some rendered item has no height yet
+    fn schedule(&self, state: &Rc<MeasureState>) {
+        let config = state.config.borrow().clone();
+        let heights = self.heights.get();
+        let pending = config.measure
+            && state
+                .rendered
+                .borrow()
+                .iter()
+                .any(|&index| !heights.is_measured(index));
+        if pending {
+            let measurer = self.clone();
+            let state = Rc::downgrade(state);
+            platform::request_animation_frame(move || {
+                if let Some(state) = state.upgrade() {
+                    measurer.measure(&state);
+                }
+            });
+        }
+    }
+
+    /// Watches rendered items so late-loading content triggers a re-measure
+    fn observe_items(&self, state: &MeasureState) {
+        for &index in state.rendered.borrow().iter() {
+            if let Some(element) = platform::get_element_by_id(&item_id(&self.id, index)) {
+                platform::observe_resize(state.observer, &element);
+            }
+        }
+    }
+}
+
+/// Creates the measuring state of the calling component once, wiring a
+/// resize observer to `measurer`
+fn use_measure_state(key: &str, measurer: &Measurer) -> Rc<MeasureState> {
+    let create = {
+        let measurer = measurer.clone();
+        move || {
+            let state_cell: Rc<RefCell<Option<std::rc::Weak<MeasureState>>>> =
+                Rc::new(RefCell::new(None));
+            let observer = platform::create_resize_observer({
+                let measurer = measurer.clone();
+                let state_cell = state_cell.clone();
+                move || {
+                    let state = state_cell.borrow().as_ref().and_then(|weak| weak.upgrade());
+                    if let Some(state) = state {
+                        measurer.measure(&state);
+                    }
+                }
+            });
+            let state = Rc::new(MeasureState {
+                observer,
+                config: RefCell::new(GridConfig::default()),
+                rendered: RefCell::new(Vec::new()),
+            });
+            *state_cell.borrow_mut() = Some(Rc::downgrade(&state));
+
+            // Observe the container once it is in the DOM, then measure
+            let mount = {
+                let measurer = measurer.clone();
+                let state = Rc::downgrade(&state);
+                move || {
+                    if let Some(state) = state.upgrade() {
+                        if let Some(element) = platform::get_element_by_id(&measurer.viewport_id) {
+                            platform::observe_resize(state.observer, &element);
+                        }
+                        measurer.measure(&state);
+                    }
+                }
+            };
+            platform::request_animation_frame(mount);
+            state
+        }
+    };
+    match runtime::active_component_id() {
+        // Hook slots are cleared on unmount, dropping the observer with them.
+        Some(component) => runtime::hook_slot(component, key, create),
+        None => create(),
+    }
+}
+
+/// Renders the item at an index
+pub type GridItemRenderer = Rc<dyn Fn(usize) -> Element>;
+
+#[define_props]
+pub struct MasonryProps {
+    pub items: Vec<Element>,
+
+    /// Fixed column count; by default as many `min_column_width` columns fit
+    pub columns: Option<usize>,
+
+    #[default(240.0)]
+    pub min_column_width: f64,
+
+    /// Gap between columns and items, in px
+    #[default(16.0)]
+    pub gap: f64,
+
+    /// Height guess for balancing items before they are measured
+    #[default(240.0)]
+    pub estimated_height: f64,
+
+    pub class: String,
+}
+
+/// Balances variable-height items over columns
+#[component]
+pub fn Masonry(props: MasonryProps) -> Element {
+    let id = use_roving_id("hk-masonry");
+    let width = use_signal(|| 0.0);
+    let scroll = use_signal(|| (0.0, 0.0));
+    let estimated_height = props.estimated_height;
+    let heights = use_signal(move || HeightCache::new(estimated_height));
+
+    let measurer = Measurer {
+        id: id.clone(),
+        viewport_id: id.clone(),
+        width: width.inner().clone(),
+        scroll: scroll.inner().clone(),
+        heights: heights.inner().clone(),
+    };
+    let state = use_measure_state("hk-masonry:measure", &measurer);
+    let config = GridConfig {
+        count: props.items.len(),
+        columns: props.columns,
+        min_column_width: props.min_column_width,
+        gap: props.gap,
+        masonry: true,
+        measure: true,
+    };
+    *state.config.borrow_mut() = config.clone();
+    *state.rendered.borrow_mut() = (0..props.items.len()).collect();
+    measurer.schedule(&state);
+
+    let layout = config.layout(&heights.get(), width.get());
+    let gap = props.gap;
+    let columns: Vec<Element> = layout
+        .column_items
+        .iter()
+        .map(|indices| {
+            let items: Vec<Element> = indices
+                .iter()
+                .map(|&index| {
+                    rsx! {
+                        div {
+                            id: item_id(&id, index),
+                            class: MasonryClass::Item.as_class(),
+                            {props.items[index].clone()}
+                        }
+                    }
+                })
+                .collect();
+            rsx! {
+                div {
+                    class: MasonryClass::Column.as_class(),
+                    style: format!("gap: {gap}px;"),
+                    ..items
+                }
+            }
+        })
+        .collect();
+
+    let classes = ClassesBuilder::new()
+        .add(MasonryClass::Masonry)
+        .add_raw(&props.class)
+        .build();
+
+    rsx! {
+        div {
+            id: id.clone(),
+            class: classes,
+            style: format!("gap: {gap}px;"),
+            ..columns
+        }
+    }
+}
+
+#[derive(Clone, Props)]
+pub struct VirtualGridProps {
+    /// Number of items
+    #[props(default)]
+    pub count: usize,
+
+    #[props(default)]
+    pub render_item: Option<GridItemRenderer>,
+
+    /// Balance variable-height items over columns instead of uniform rows
+    #[props(default)]
+    pub masonry: bool,
+
+    /// Fixed column count; by default as many `min_column_width` columns fit
+    #[props(default)]
+    pub columns: Option<usize>,
+
+    #[props(default = 240.0)]
+    pub min_column_width: f64,
+
+    /// Gap between items, in px
+    #[props(default = 16.0)]
+    pub gap: f64,
+
+    /// Row height; for masonry grids, the height guess for unmeasured items
+    #[props(default = 240.0)]
+    pub estimated_height: f64,
+
+    /// Viewport height (any CSS length)
+    #[props(default = "600px".to_string())]
+    pub height: String,
+
+    /// Extra px rendered above and below the viewport
+    #[props(default = 400.0)]
+    pub overscan: f64,
+
+    #[props(default)]
+    pub on_scroll: Option<EventHandler<f64>>,
+
+    #[props(default)]
+    pub class: String,
+}
+
+impl Default for VirtualGridProps {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            render_item: None,
+            masonry: false,
+            columns: None,
+            min_column_width: 240.0,
+            gap: 16.0,
+            estimated_height: 240.0,
+            height: "600px".to_string(),
+            overscan: 400.0,
+            on_scroll: None,
+            class: String::new(),
+        }
+    }
+}
+
+impl PartialEq for VirtualGridProps {
+    fn eq(&self, other: &Self) -> bool {
+        self.count == other.count
+            && match (&self.render_item, &other.render_item) {
+                (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+            && self.masonry == other.masonry
+            && self.columns == other.columns
+            && self.min_column_width == other.min_column_width
+            && self.gap == other.gap
+            && self.estimated_height == other.estimated_height
+            && self.height == other.height
+            && self.overscan == other.overscan
+            && self.on_scroll == other.on_scroll
+            && self.class == other.class
+    }
+}
+
+impl std::fmt::Debug for VirtualGridProps {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VirtualGridProps")
+            .field("count", &self.count)
+            .field("render_item", &self.render_item.is_some())
+            .field("masonry", &self.masonry)
+            .field("columns", &self.columns)
+            .field("min_column_width", &self.min_column_width)
+            .field("gap", &self.gap)
+            .field("estimated_height", &self.estimated_height)
+            .field("height", &self.height)
+            .field("overscan", &self.overscan)
+            .field("class", &self.class)
+            .finish()
+    }
+}
+
+/// Responsive grid that only renders the items near the viewport
+#[component]
+pub fn VirtualGrid(props: VirtualGridProps) -> Element {
+    let id = use_roving_id("hk-virtual-grid");
+    let viewport_id = format!("{id}-viewport");
+    let width = use_signal(|| 0.0);
+    // (scroll_top, viewport_height)
+    let scroll = use_signal(|| (0.0, 0.0));
+    let estimated_height = props.estimated_height;
+    let heights = use_signal(move || HeightCache::new(estimated_height));
+
+    let measurer = Measurer {
+        id: id.clone(),
+        viewport_id: viewport_id.clone(),
+        width: width.inner().clone(),
+        scroll: scroll.inner().clone(),
+        heights: heights.inner().clone(),
+    };
+    let state = use_measure_state("hk-virtual-grid:measure", &measurer);
+    let config = GridConfig {
+        count: props.count,
+        columns: props.columns,
+        min_column_width: props.min_column_width,
+        gap: props.gap,
+        masonry: props.masonry,
+        measure: props.masonry,
+    };
+    *state.config.borrow_mut() = config.clone();
+
+    let current_width = width.get();
+    let layout = config.layout(&heights.get(), current_width);
+    let (scroll_top, viewport_height) = scroll.get();
+    // Before the first measurement, render roughly one screen of items
+    let viewport_height = if viewport_height > 0.0 {
+        viewport_height
+    } else {
+        props.estimated_height * 4.0
+    };
+    let visible = layout.visible(
+        scroll_top - props.overscan,
+        scroll_top + viewport_height + props.overscan,
+    );
+    *state.rendered.borrow_mut() = visible.clone();
+
+    let columns = layout.columns;
+    let gap = props.gap;
+    let masonry = props.masonry;
+    let count = props.count;
+    let item_elements: Vec<Element> = visible
+        .iter()
+        .map(|&index| {
+            let rect = layout.items[index];
+            // Percentages keep the grid usable before the width is known
+            let (left, item_width) = if current_width > 0.0 {
+                (format!("{:.2}px", rect.x), format!("{:.2}px", rect.width))
+            } else {
+                (
+                    format!("calc((100% + {gap}px) * {} / {columns})", rect.column),
+                    format!("calc((100% + {gap}px) / {columns} - {gap}px)"),
+                )
+            };
+            let height = if masonry {
+                String::new()
+            } else {
+                format!(" height: {:.2}px;", rect.height)
+            };
+            let content = match props.render_item.as_ref() {
+                Some(render) => render(index),
+                None => VNode::empty(),
+            };
+            rsx! {
+                div {
+                    id: item_id(&id, index),
+                    class: MasonryClass::Item.as_class(),
+                    role: "listitem",
+                    "aria-setsize": count.to_string(),
+                    "aria-posinset": (index + 1).to_string(),
+                    style: format!(
+                        "position: absolute; top: {:.2}px; left: {left}; width: {item_width};{height}",
+                        rect.y
+                    ),
+                    {content}
+                }
+            }
+        })
+        .collect();
+
+    // Measure newly rendered items once they are in the DOM
+    measurer.schedule(&state);
+
+    let handle_scroll = {
+        let viewport_id = viewport_id.clone();
+        let scroll = scroll.clone();
+        let on_scroll = props.on_scroll.clone();
+        move |_: Event| {
+            if let Some((top, _, viewport_height)) = platform::get_viewport_by_id(&viewport_id) {
+                scroll.set((top, viewport_height));
+                if let Some(handler) = on_scroll.as_ref() {
+                    handler.call(top);
+                }
+            }
+        }
+    };
+
+    let classes = ClassesBuilder::new()
+        .add(MasonryClass::VirtualGrid)
+        .add_raw(&props.class)
+        .build();
+
+    rsx! {
+        div {
+            id: viewport_id,
+            class: classes,
+            style: format!("height: {};", props.height),
+            role: "list",
+            onscroll: handle_scroll,
+            div {
+                class: MasonryClass::Content.as_class(),
+                style: format!("height: {:.2}px;", layout.height),
+                ..item_elements
+            }
+        }
+    }
+}
+
+pub struct MasonryComponent;
+
+impl crate::styled::StyledComponent for MasonryComponent {
+    fn styles() -> &'static str {
+        r#"
+.hk-masonry {
+  display: flex;
+  align-items: flex-start;
+  width: 100%;
+}
+
+.hk-masonry-column {
+  display: flex;
+  flex: 1 1 0;
+  flex-direction: column;
+  min-width: 0;
+}
+
+.hk-masonry-item {
+  box-sizing: border-box;
+  min-width: 0;
+}
+
+.hk-virtual-grid {
+  position: relative;
+  width: 100%;
+  overflow-y: auto;
+  overflow-anchor: none;
+}
+
+.hk-virtual-grid-content {
+  position: relative;
+  width: 100%;
+}
+"#
+    }
+
+    fn name() -> &'static str {
+        "masonry"
+    }
+}
@@ -8,7 +8,11 @@
 //! - Footer - Footer content
 //! - Container - Responsive content container
 //! - Grid/Row/Col - Grid system components
+//! - Masonry/VirtualGrid - Column-balanced and virtualized card grids
 //! - FlexBox - Flexible box layout component
+//! - SplitPane - Resizable two-pane layout
+//! - Dock - Dockable panels with floating windows
+//! - Show/Hide - Breakpoint visibility helpers (see [`responsive`])
 //! - Section - Content section component
 //! - ScrollbarContainer - Custom scrollbar component
 //! - Divider - Visual divider line
@@ -20,23 +24,31 @@ mod aside;
 pub mod container;
 pub mod content;
 pub mod divider;
+mod dock;
 pub mod flex;
 mod footer;
 mod grid;
 mod header;
+mod masonry;
+pub mod responsive;
 mod scrollbar;
 mod section;
 mod space;
+mod split_pane;
 
 pub use app_layout::*;
 pub use aside::*;
 pub use container::*;
 pub use content::*;
 pub use divider::*;
+pub use dock::*;
 pub use flex::*;
 pub use footer::*;
 pub use grid::*;
 pub use header::*;
+pub use masonry::*;
+pub use responsive::*;
 pub use scrollbar::*;
 pub use section::*;
 pub use space::*;
+pub use split_pane::*;
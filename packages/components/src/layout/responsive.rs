@@ -0,0 +1,523 @@
+// hikari-components/src/layout/responsive.rs
+//! Responsive breakpoint system
+//!
+//! Named breakpoints (`xs`–`xxl`), per-breakpoint prop values and the
+//! [`Show`]/[`Hide`] helpers.
+//!
+//! Responsive props never measure the window. A component writes the values
+//! for each breakpoint into CSS custom properties (`--hk-r-order-md: 2`) and
+//! the stylesheet from [`responsive_styles`] picks them up inside media
+//! queries, so server-rendered markup is already laid out correctly and
+//! nothing moves on hydration.
+//!
+//! Values cascade mobile-first: a value set at `md` also applies at `lg`,
+//! `xl` and `xxl` until another breakpoint overrides it.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use hikari_components::layout::{Col, Grid, Hide, Responsive, Screen};
+//!
+//! rsx! {
+//!     Grid {
+//!         Col { span: 12, span_md: 8, order: Responsive::new(2).md(1), "Main" }
+//!         Col { span: 12, span_md: 4, "Side" }
+//!     }
+//!     Hide { below: Some(Screen::Md), "Only on tablets and larger" }
+//! }
+//! ```
+
+use std::fmt;
+
+use crate::prelude::*;
+use crate::theme::THEME_SCOPE_ATTRIBUTE;
+
+/// Named breakpoint, from the smallest screen upwards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Screen {
+    #[default]
+    Xs,
+    Sm,
+    Md,
+    Lg,
+    Xl,
+    Xxl,
+}
+
+impl Screen {
+    /// All breakpoints, smallest first
+    pub const ALL: [Screen; 6] = [
+        Screen::Xs,
+        Screen::Sm,
+        Screen::Md,
+        Screen::Lg,
+        Screen::Xl,
+        Screen::Xxl,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Screen::Xs => "xs",
+            Screen::Sm => "sm",
+            Screen::Md => "md",
+            Screen::Lg => "lg",
+            Screen::Xl => "xl",
+            Screen::Xxl => "xxl",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The next larger breakpoint, if any
+    pub fn next(self) -> Option<Screen> {
+        Screen::ALL.get(self.index() + 1).copied()
+    }
+}
+
+impl fmt::Display for Screen {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Minimum widths (in px) at which each named breakpoint starts
+///
+/// `xs` always starts at 0. Configure custom values through
+/// `ThemeProvider { breakpoints: ... }`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Breakpoints {
+    pub sm: u32,
+    pub md: u32,
+    pub lg: u32,
+    pub xl: u32,
+    pub xxl: u32,
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self {
+            sm: 640,
+            md: 768,
+            lg: 1024,
+            xl: 1280,
+            xxl: 1536,
+        }
+    }
+}
+
+impl Breakpoints {
+    /// Width at which `screen` starts
+    pub fn min_width(&self, screen: Screen) -> u32 {
+        match screen {
+            Screen::Xs => 0,
+            Screen::Sm => self.sm,
+            Screen::Md => self.md,
+            Screen::Lg => self.lg,
+            Screen::Xl => self.xl,
+            Screen::Xxl => self.xxl,
+        }
+    }
+
+    /// Last width that still belongs to `screen`
+    pub fn max_width(&self, screen: Screen) -> Option<u32> {
+        screen
+            .next()
+            .map(|next| self.min_width(next).saturating_sub(1))
+    }
+
+    /// The breakpoint a viewport of `width` px falls into
+    pub fn screen_for_width(&self, width: u32) -> Screen {
+        Screen::ALL
+            .into_iter()
+            .rev()
+            .find(|screen| width >= self.min_width(*screen))
+            .unwrap_or_default()
+    }
+
+    /// Media query matching `screen` and everything larger
+    ///
+    /// Returns `None` for `xs`, which matches every width.
+    pub fn media_query(&self, screen: Screen) -> Option<String> {
+        match screen {
+            Screen::Xs => None,
+            _ => Some(format!("(min-width: {}px)", self.min_width(screen))),
+        }
+    }
+}
+
+/// A prop value that can change per breakpoint
+///
+/// Unset breakpoints inherit the value of the nearest smaller one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Responsive<T> {
+    pub xs: Option<T>,
+    pub sm: Option<T>,
+    pub md: Option<T>,
+    pub lg: Option<T>,
+    pub xl: Option<T>,
+    pub xxl: Option<T>,
+}
+
+impl<T> Default for Responsive<T> {
+    fn default() -> Self {
+        Self {
+            xs: None,
+            sm: None,
+            md: None,
+            lg: None,
+            xl: None,
+            xxl: None,
+        }
+    }
+}
+
+impl<T> From<T> for Responsive<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> Responsive<T> {
+    /// A value for every breakpoint
+    pub fn new(value: T) -> Self {
+        Self {
+            xs: Some(value),
+            ..Default::default()
+        }
+    }
+
+    pub fn xs(self, value: T) -> Self {
+        self.at(Screen::Xs, value)
+    }
+
+    pub fn sm(self, value: T) -> Self {
+        self.at(Screen::Sm, value)
+    }
+
+    pub fn md(self, value: T) -> Self {
+        self.at(Screen::Md, value)
+    }
+
+    pub fn lg(self, value: T) -> Self {
+        self.at(Screen::Lg, value)
+    }
+
+    pub fn xl(self, value: T) -> Self {
+        self.at(Screen::Xl, value)
+    }
+
+    pub fn xxl(self, value: T) -> Self {
+        self.at(Screen::Xxl, value)
+    }
+
+    /// Sets the value from `screen` upwards
+    pub fn at(mut self, screen: Screen, value: T) -> Self {
+        *self.slot_mut(screen) = Some(value);
+        self
+    }
+
+    /// Sets the value for `screen` unless one is already set
+    pub fn or_at(mut self, screen: Screen, value: Option<T>) -> Self {
+        let slot = self.slot_mut(screen);
+        if slot.is_none() {
+            *slot = value;
+        }
+        self
+    }
+
+    /// The value set exactly at `screen`
+    pub fn get(&self, screen: Screen) -> Option<&T> {
+        match screen {
+            Screen::Xs => self.xs.as_ref(),
+            Screen::Sm => self.sm.as_ref(),
+            Screen::Md => self.md.as_ref(),
+            Screen::Lg => self.lg.as_ref(),
+            Screen::Xl => self.xl.as_ref(),
+            Screen::Xxl => self.xxl.as_ref(),
+        }
+    }
+
+    /// The value in effect at `screen`, inherited from smaller breakpoints
+    pub fn resolve(&self, screen: Screen) -> Option<&T> {
+        Screen::ALL[..=screen.index()]
+            .iter()
+            .rev()
+            .find_map(|s| self.get(*s))
+    }
+
+    /// Returns true if no breakpoint has a value
+    pub fn is_empty(&self) -> bool {
+        Screen::ALL.iter().all(|s| self.get(*s).is_none())
+    }
+
+    /// Maps every set value
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Responsive<U> {
+        Screen::ALL
+            .into_iter()
+            .fold(Responsive::default(), |acc, screen| {
+                match self.get(screen) {
+                    Some(value) => acc.at(screen, f(value)),
+                    None => acc,
+                }
+            })
+    }
+
+    fn slot_mut(&mut self, screen: Screen) -> &mut Option<T> {
+        match screen {
+            Screen::Xs => &mut self.xs,
+            Screen::Sm => &mut self.sm,
+            Screen::Md => &mut self.md,
+            Screen::Lg => &mut self.lg,
+            Screen::Xl => &mut self.xl,
+            Screen::Xxl => &mut self.xxl,
+        }
+    }
+}
+
+/// CSS properties that can vary per breakpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResponsiveProperty {
+    GridColumn,
+    Order,
+    Gap,
+    FlexDirection,
+    Display,
+}
+
+impl ResponsiveProperty {
+    pub const ALL: [ResponsiveProperty; 5] = [
+        ResponsiveProperty::GridColumn,
+        ResponsiveProperty::Order,
+        ResponsiveProperty::Gap,
+        ResponsiveProperty::FlexDirection,
+        ResponsiveProperty::Display,
+    ];
+
+    /// The CSS property name
+    pub fn css_name(&self) -> &'static str {
+        match self {
+            ResponsiveProperty::GridColumn => "grid-column",
+            ResponsiveProperty::Order => "order",
+            ResponsiveProperty::Gap => "gap",
+            ResponsiveProperty::FlexDirection => "flex-direction",
+            ResponsiveProperty::Display => "display",
+        }
+    }
+
+    /// Marker class that opts an element into the responsive rules
+    pub fn class(&self) -> String {
+        format!("hk-r-{}", self.css_name())
+    }
+
+    /// Custom property carrying the value for `screen`
+    pub fn variable(&self, screen: Screen) -> String {
+        format!("--hk-r-{}-{}", self.css_name(), screen)
+    }
+}
+
+/// Classes and inline custom properties for an element's responsive values
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResponsiveStyle {
+    classes: Vec<String>,
+    style: String,
+}
+
+impl ResponsiveStyle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `values` (already CSS values) for `property`
+    ///
+    /// Does nothing when no breakpoint has a value. Custom properties
+    /// inherit, so every breakpoint is declared: unset ones repeat the value
+    /// below them, or are reset with `initial` below the first value, rather
+    /// than picking up a responsive ancestor's values.
+    pub fn set(mut self, property: ResponsiveProperty, values: &Responsive<String>) -> Self {
+        if values.is_empty() {
+            return self;
+        }
+        self.classes.push(property.class());
+        for screen in Screen::ALL {
+            let value = values.resolve(screen).map_or("initial", String::as_str);
+            self.style
+                .push_str(&format!("{}: {value}; ", property.variable(screen)));
+        }
+        self
+    }
+
+    /// Marker classes, space separated
+    pub fn classes(&self) -> String {
+        self.classes.join(" ")
+    }
+
+    /// Inline custom property declarations
+    pub fn style(&self) -> &str {
+        self.style.trim_end()
+    }
+}
+
+/// Builds the media-query stylesheet backing responsive props
+pub fn responsive_styles(breakpoints: &Breakpoints) -> String {
+    build_responsive_styles(breakpoints, "")
+}
+
+/// [`responsive_styles`] limited to the elements inside the theme scope
+/// `scope`, so a provider's breakpoints do not leak out of it
+///
+/// Nested scopes come later in the document, so their rules win inside them.
+pub fn scoped_responsive_styles(breakpoints: &Breakpoints, scope: &str) -> String {
+    build_responsive_styles(
+        breakpoints,
+        &format!("[{THEME_SCOPE_ATTRIBUTE}=\"{scope}\"] "),
+    )
+}
+
+fn build_responsive_styles(breakpoints: &Breakpoints, ancestor: &str) -> String {
+    let mut css = String::new();
+    for screen in Screen::ALL {
+        let mut rules = String::new();
+        for property in ResponsiveProperty::ALL {
+            // Fall back through smaller breakpoints so unset ones inherit
+            let value = Screen::ALL[..=screen.index()]
+                .iter()
+                .fold(String::new(), |fallback, s| {
+                    if fallback.is_empty() {
+                        format!("var({})", property.variable(*s))
+                    } else {
+                        format!("var({}, {fallback})", property.variable(*s))
+                    }
+                });
+            // Doubled class so the rules win over single-class utilities
+            let class = property.class();
+            rules.push_str(&format!(
+                "{ancestor}.{class}.{class} {{ {}: {value}; }}\n",
+                property.css_name()
+            ));
+        }
+        match breakpoints.media_query(screen) {
+            Some(query) => css.push_str(&format!("@media {query} {{\n{rules}}}\n")),
+            None => css.push_str(&rules),
+        }
+    }
+    css
+}
+
+/// Hook returning the breakpoints configured by the nearest `ThemeProvider`
+pub fn use_breakpoints() -> Breakpoints {
+    crate::theme::try_use_theme()
+        .map(|ctx| ctx.breakpoints)
+        .unwrap_or_default()
+}
+
+/// Hook tracking the named breakpoint of the current viewport
+///
+/// Prefer responsive props for layout; this is for behaviour that cannot be
+/// expressed in CSS. Outside the browser it reports `Screen::Lg`.
+pub fn use_screen() -> Signal<Screen> {
+    let breakpoints = use_breakpoints();
+    let screen = use_signal(move || current_screen(&breakpoints));
+
+    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+    {
+        let screen = screen.clone();
+        use_effect(move || {
+            let screen = screen.clone();
+            crate::platform::on_resize(move || {
+                screen.set(current_screen(&breakpoints));
+            });
+        });
+    }
+
+    screen.inner().clone()
+}
+
+fn current_screen(breakpoints: &Breakpoints) -> Screen {
+    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+    {
+        breakpoints.screen_for_width(crate::platform::inner_width() as u32)
+    }
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    {
+        let _ = breakpoints;
+        Screen::Lg
+    }
+}
+
+fn visibility(visible: impl Fn(Screen) -> bool) -> Responsive<String> {
+    Screen::ALL
+        .into_iter()
+        .fold(Responsive::default(), |acc, screen| {
+            let display = if visible(screen) { "contents" } else { "none" };
+            acc.at(screen, display.to_string())
+        })
+}
+
+fn in_range(screen: Screen, above: Option<Screen>, below: Option<Screen>) -> bool {
+    above.is_none_or(|min| screen >= min) && below.is_none_or(|max| screen < max)
+}
+
+#[define_props]
+pub struct ShowProps {
+    /// Show from this breakpoint upwards
+    pub above: Option<Screen>,
+
+    /// Show only below this breakpoint
+    pub below: Option<Screen>,
+
+    pub children: Element,
+}
+
+/// Renders children only on the given breakpoints
+///
+/// With both bounds the children show for `above <= screen < below`.
+#[component]
+pub fn Show(props: ShowProps) -> Element {
+    let (above, below) = (props.above, props.below);
+    let responsive = ResponsiveStyle::new().set(
+        ResponsiveProperty::Display,
+        &visibility(|s| in_range(s, above, below)),
+    );
+
+    rsx! {
+        div {
+            class: format!("hk-show {}", responsive.classes()),
+            style: responsive.style().to_string(),
+            { props.children }
+        }
+    }
+}
+
+#[define_props]
+pub struct HideProps {
+    /// Hide from this breakpoint upwards
+    pub above: Option<Screen>,
+
+    /// Hide below this breakpoint
+    pub below: Option<Screen>,
+
+    pub children: Element,
+}
+
+/// Hides children on the given breakpoints
+///
+/// `Hide { below: Some(Screen::Md) }` hides on `xs` and `sm`;
+/// `Hide { above: Some(Screen::Lg) }` hides on `lg` and larger.
+#[component]
+pub fn Hide(props: HideProps) -> Element {
+    let (above, below) = (props.above, props.below);
+    let hidden =
+        move |s: Screen| above.is_some_and(|min| s >= min) || below.is_some_and(|max| s < max);
+    let responsive =
+        ResponsiveStyle::new().set(ResponsiveProperty::Display, &visibility(|s| !hidden(s)));
+
+    rsx! {
+        div {
+            class: format!("hk-hide {}", responsive.classes()),
+            style: responsive.style().to_string(),
+            { props.children }
+        }
+    }
+}
@@ -0,0 +1,448 @@
+// hikari-components/src/layout/split_pane.rs
+//! SplitPane component - Resizable two-pane layout
+//!
+//! Splits its area between `first` and `second` with a draggable handle.
+//! Nest split panes to build IDE-like screens.
+//!
+//! - Drag the handle, or focus it and use the arrow keys (Shift for larger
+//!   steps, Home/End for the limits)
+//! - Double-click the handle (or press Enter) to collapse the first pane to
+//!   the nearest edge; repeat to restore it
+//! - Give the pane a `persist_key` to remember its size across reloads
+//!
+//! Sizes are percentages of the pane's own width (or height for vertical
+//! splits), so server-rendered markup needs no measuring.
+//!
+//! # Example
+//!
+//! ```rust
+//! use hikari_components::layout::{SplitDirection, SplitPane};
+//!
+//! rsx! {
+//!     SplitPane {
+//!         default_size: 70.0,
+//!         min_size: 20.0,
+//!         persist_key: Some("editor".to_string()),
+//!         first: rsx! {
+//!             SplitPane {
+//!                 direction: SplitDirection::Vertical,
+//!                 first: rsx! { Editor {} },
+//!                 second: rsx! { NodeGraph {} },
+//!             }
+//!         },
+//!         second: rsx! { Inspector {} },
+//!     }
+//! }
+//! ```
+
+use hikari_extra_components::extra::DragConstraints;
+use hikari_palette::classes::{ClassesBuilder, SplitPaneClass, UtilityClass};
+
+use crate::platform;
+use crate::prelude::*;
+use crate::theme::use_layout_direction;
+use crate::utils::use_roving_id;
+
+const STORAGE_PREFIX: &str = "hk-split-pane:";
+
+/// Axis along which the panes are laid out
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum SplitDirection {
+    /// Panes side by side, with a vertical handle
+    #[default]
+    Horizontal,
+    /// Panes stacked, with a horizontal handle
+    Vertical,
+}
+
+/// Size of the first pane, in percent of the split pane
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SplitPaneState {
+    pub size: f64,
+    /// Size to restore when collapsed
+    pub restore: Option<f64>,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl SplitPaneState {
+    /// Creates a state with `size` clamped to `min..=max`
+    pub fn new(size: f64, min: f64, max: f64) -> Self {
+        let min = min.clamp(0.0, 100.0);
+        let max = max.clamp(min, 100.0);
+        let mut state = Self {
+            size,
+            restore: None,
+            min,
+            max,
+        };
+        state.size = state.clamp(size);
+        state
+    }
+
+    /// Limits applied while resizing
+    pub fn constraints(&self) -> DragConstraints {
+        DragConstraints::horizontal(self.min, self.max)
+    }
+
+    pub fn clamp(&self, size: f64) -> f64 {
+        self.constraints().constrain_x(size)
+    }
+
+    pub fn is_collapsed(&self) -> bool {
+        self.restore.is_some()
+    }
+
+    /// Sets the size, expanding a collapsed pane
+    pub fn resize_to(&mut self, size: f64) {
+        self.size = self.clamp(size);
+        self.restore = None;
+    }
+
+    /// Changes the size by `delta` percent
+    pub fn resize_by(&mut self, delta: f64) {
+        let from = self.restore.unwrap_or(self.size);
+        self.resize_to(from + delta);
+    }
+
+    /// Resizes from `start` by a pointer movement of `delta_px` within a
+    /// pane `extent_px` wide (or tall)
+    pub fn drag(&mut self, start: f64, delta_px: f64, extent_px: f64) {
+        if extent_px > 0.0 {
+            self.resize_to(start + delta_px / extent_px * 100.0);
+        }
+    }
+
+    /// Collapses the first pane to the nearest edge, or restores it
+    pub fn toggle_collapse(&mut self) {
+        match self.restore.take() {
+            Some(size) => self.size = self.clamp(size),
+            None => {
+                self.restore = Some(self.size);
+                self.size = if self.size * 2.0 < self.min + self.max {
+                    0.0
+                } else {
+                    100.0
+                };
+            }
+        }
+    }
+
+    /// Serializes the size for storage (`"30"` or `"0;30"` when collapsed)
+    pub fn to_storage(&self) -> String {
+        match self.restore {
+            Some(restore) => format!("{};{restore}", self.size),
+            None => self.size.to_string(),
+        }
+    }
+
+    /// Restores a size saved with [`to_storage`](Self::to_storage)
+    pub fn load(&mut self, stored: &str) -> bool {
+        let mut parts = stored.split(';').map(|p| p.trim().parse::<f64>());
+        match (parts.next(), parts.next()) {
+            (Some(Ok(size)), None) => self.resize_to(size),
+            (Some(Ok(size)), Some(Ok(restore))) if size == 0.0 || size == 100.0 => {
+                self.size = size;
+                self.restore = Some(self.clamp(restore));
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+fn load_state(persist_key: Option<&str>, state: SplitPaneState) -> SplitPaneState {
+    let mut state = state;
+    if let Some(stored) =
+        persist_key.and_then(|key| platform::local_storage_get(&format!("{STORAGE_PREFIX}{key}")))
+    {
+        state.load(&stored);
+    }
+    state
+}
+
+fn save_state(persist_key: Option<&str>, state: &SplitPaneState) {
+    if let Some(key) = persist_key {
+        platform::local_storage_set(&format!("{STORAGE_PREFIX}{key}"), &state.to_storage());
+    }
+}
+
+#[define_props]
+pub struct SplitPaneProps {
+    pub direction: SplitDirection,
+
+    /// Initial size of the first pane, in percent
+    #[default(50.0)]
+    pub default_size: f64,
+
+    #[default(0.0)]
+    pub min_size: f64,
+
+    #[default(100.0)]
+    pub max_size: f64,
+
+    /// Keyboard step, in percent (Shift multiplies it by 5)
+    #[default(2.0)]
+    pub step: f64,
+
+    /// Allow double-click / Enter to collapse the first pane
+    #[default(true)]
+    pub collapsible: bool,
+
+    /// Remembers the size in local storage under this key
+    pub persist_key: Option<String>,
+
+    /// Called with the new size once a resize finishes
+    pub on_resize: Option<Callback<f64, ()>>,
+
+    pub first: Element,
+
+    pub second: Element,
+
+    pub class: String,
+}
+
+#[component]
+pub fn SplitPane(props: SplitPaneProps) -> Element {
+    let id = use_roving_id("hk-split-pane");
+    let horizontal = props.direction == SplitDirection::Horizontal;
+    // The first pane sits on the right in RTL layouts
+    let reversed = horizontal && use_layout_direction().is_rtl();
+
+    let persist_key = props.persist_key.clone();
+    let (default_size, min_size, max_size) = (props.default_size, props.min_size, props.max_size);
+    let state = use_signal(move || {
+        load_state(
+            persist_key.as_deref(),
+            SplitPaneState::new(default_size, min_size, max_size),
+        )
+    });
+    let dragging = use_signal(|| false);
+
+    let commit = {
+        let state = state.clone();
+        let persist_key = props.persist_key.clone();
+        let on_resize = props.on_resize.clone();
+        move |next: SplitPaneState| {
+            state.set(next);
+            save_state(persist_key.as_deref(), &next);
+            if let Some(handler) = on_resize.as_ref() {
+                handler.call(next.size);
+            }
+        }
+    };
+
+    let handle_mousedown = {
+        let id = id.clone();
+        let state = state.clone();
+        let dragging = dragging.clone();
+        let commit = commit.clone();
+        move |e: MouseEvent| {
+            if e.button != 0 {
+                return;
+            }
+            e.prevent_default();
+
+            #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+            let extent = platform::get_element_rect_by_id(&id)
+                .map(|rect| if horizontal { rect.width } else { rect.height });
+            #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+            let extent: Option<f64> = {
+                let _ = &id;
+                None
+            };
+            let Some(extent) = extent else {
+                return;
+            };
+
+            let start = state.get();
+            let origin = if horizontal { e.client_x } else { e.client_y } as f64;
+            dragging.set(true);
+
+            let on_move = {
+                let state = state.clone();
+                move |x: f64, y: f64| {
+                    let mut delta = if horizontal { x } else { y } - origin;
+                    if reversed {
+                        delta = -delta;
+                    }
+                    let mut next = start;
+                    next.drag(start.size, delta, extent);
+                    state.set(next);
+                }
+            };
+            let on_end = {
+                let state = state.clone();
+                let dragging = dragging.clone();
+                let commit = commit.clone();
+                move || {
+                    dragging.set(false);
+                    commit(state.get());
+                }
+            };
+            platform::track_mouse_drag(on_move, on_end);
+        }
+    };
+
+    let collapsible = props.collapsible;
+    let handle_dblclick = {
+        let state = state.clone();
+        let commit = commit.clone();
+        move |_: Box<dyn EventData>| {
+            if collapsible {
+                let mut next = state.get();
+                next.toggle_collapse();
+                commit(next);
+            }
+        }
+    };
+
+    let step = props.step;
+    let handle_keydown = {
+        let state = state.clone();
+        let commit = commit.clone();
+        move |e: KeyboardEvent| {
+            let step = if e.shift_key { step * 5.0 } else { step };
+            let (shrink, grow) = match (horizontal, reversed) {
+                (true, false) => (Key::ArrowLeft, Key::ArrowRight),
+                (true, true) => (Key::ArrowRight, Key::ArrowLeft),
+                (false, _) => (Key::ArrowUp, Key::ArrowDown),
+            };
+            let mut next = state.get();
+            match e.get_key() {
+                key if key == shrink => next.resize_by(-step),
+                key if key == grow => next.resize_by(step),
+                Key::Other(name) if name == "Home" => next.resize_to(next.min),
+                Key::Other(name) if name == "End" => next.resize_to(next.max),
+                Key::Enter if collapsible => next.toggle_collapse(),
+                _ => return,
+            }
+            e.prevent_default();
+            commit(next);
+        }
+    };
+
+    let current = state.get();
+    let is_dragging = dragging.get();
+
+    let classes = ClassesBuilder::new()
+        .add(SplitPaneClass::SplitPane)
+        .add(if horizontal {
+            SplitPaneClass::Horizontal
+        } else {
+            SplitPaneClass::Vertical
+        })
+        .add_if(SplitPaneClass::Dragging, || is_dragging)
+        .add_if(SplitPaneClass::Collapsed, || current.is_collapsed())
+        .add_raw(&props.class)
+        .build();
+
+    let first_classes = ClassesBuilder::new()
+        .add(SplitPaneClass::Pane)
+        .add(SplitPaneClass::First)
+        .build();
+    let second_classes = ClassesBuilder::new()
+        .add(SplitPaneClass::Pane)
+        .add(SplitPaneClass::Second)
+        .build();
+    let first_id = format!("{id}-first");
+
+    rsx! {
+        div { id: id.clone(), class: classes,
+            div {
+                id: first_id.clone(),
+                class: first_classes,
+                style: format!("flex: 0 0 {:.3}%;", current.size),
+                { props.first }
+            }
+            div {
+                class: SplitPaneClass::Handle.as_class(),
+                role: "separator",
+                tabindex: "0",
+                "aria-controls": first_id,
+                // The separator line runs across the split axis
+                "aria-orientation": if horizontal { "vertical" } else { "horizontal" },
+                "aria-valuenow": format!("{:.0}", current.size),
+                "aria-valuemin": format!("{:.0}", current.min),
+                "aria-valuemax": format!("{:.0}", current.max),
+                onmousedown: handle_mousedown,
+                ondblclick: handle_dblclick,
+                onkeydown: handle_keydown,
+            }
+            div { class: second_classes, { props.second } }
+        }
+    }
+}
+
+pub struct SplitPaneComponent;
+
+impl crate::styled::StyledComponent for SplitPaneComponent {
+    fn styles() -> &'static str {
+        r#"
+.hk-split-pane {
+  display: flex;
+  width: 100%;
+  height: 100%;
+  min-width: 0;
+  min-height: 0;
+  overflow: hidden;
+}
+
+.hk-split-pane-horizontal {
+  flex-direction: row;
+}
+
+.hk-split-pane-vertical {
+  flex-direction: column;
+}
+
+.hk-split-pane-pane {
+  position: relative;
+  min-width: 0;
+  min-height: 0;
+  overflow: auto;
+}
+
+.hk-split-pane-second {
+  flex: 1 1 0;
+}
+
+.hk-split-pane-handle {
+  position: relative;
+  flex: 0 0 auto;
+  background: var(--hi-border, #e5e7eb);
+  transition: background-color 0.15s ease;
+  touch-action: none;
+}
+
+.hk-split-pane-horizontal > .hk-split-pane-handle {
+  width: 4px;
+  cursor: col-resize;
+}
+
+.hk-split-pane-vertical > .hk-split-pane-handle {
+  height: 4px;
+  cursor: row-resize;
+}
+
+.hk-split-pane-handle:hover,
+.hk-split-pane-handle:focus-visible,
+.hk-split-pane-dragging > .hk-split-pane-handle {
+  background: var(--hi-primary, #4a9eff);
+  outline: none;
+}
+
+.hk-split-pane-dragging {
+  user-select: none;
+}
+
+.hk-split-pane-dragging > .hk-split-pane-pane {
+  pointer-events: none;
+}
+"#
+    }
+
+    fn name() -> &'static str {
+        "split-pane"
+    }
+}
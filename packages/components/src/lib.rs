@@ -17,6 +17,7 @@ pub mod navigation;
 pub mod platform;
 pub mod portal;
 pub mod production;
+pub mod router;
 #[cfg(target_arch = "wasm32")]
 pub mod scripts;
 pub mod style_builder;
@@ -36,6 +37,9 @@ pub use feedback::*;
 pub use layout::*;
 pub use navigation::*;
 pub use production::*;
+pub use router::{
+    MemoryRouter, Route, Router, RouterContext, RouterHandle, RouterProvider, use_router,
+};
 pub use styled::{StyleRegistry, StyledComponent};
 
 // Theme system
@@ -58,7 +62,8 @@ pub use tairitsu_vdom::{
     create_effect,
 };
 pub use theme::{
-    ComponentOverrides, ComponentPalette, IntoThemeName, LayoutDirection, ThemeContext,
-    ThemePalette, ThemeProvider, get_default_theme, get_registered_theme, prefers_dark_mode,
-    register_theme, use_layout_direction, use_theme,
+    ColorScheme, ComponentOverrides, ComponentPalette, ContrastMode, IntoThemeName,
+    LayoutDirection, ThemeContext, ThemePalette, ThemeProvider, ThemeStore, get_default_theme,
+    get_registered_theme, load_themes, prefers_dark_mode, register_theme, try_use_theme,
+    use_layout_direction, use_theme,
 };
@@ -5,6 +5,7 @@ use hikari_palette::classes::components::BreadcrumbClass;
 use hikari_palette::classes::{ClassesBuilder, Display, FlexDirection, Gap};
 
 use crate::prelude::*;
+use crate::router::{Route, breadcrumb_trail, use_router};
 use crate::styled::StyledComponent;
 
 pub struct BreadcrumbComponent;
@@ -20,6 +21,10 @@ pub struct BreadcrumbItemProps {
     pub class: String,
 
     pub onclick: Option<EventHandler<MouseEvent>>,
+
+    /// Route path; navigates through the `RouterProvider` instead of a full
+    /// page load.
+    pub to: Option<String>,
 }
 
 #[define_props]
@@ -29,6 +34,10 @@ pub struct BreadcrumbProps {
 
     pub class: String,
 
+    /// Route table; when set, items for the current route are generated
+    /// ahead of `children`.
+    pub routes: Vec<Route>,
+
     pub children: Element,
 }
 
@@ -42,11 +51,32 @@ pub fn Breadcrumb(props: BreadcrumbProps) -> Element {
         .add_raw(&props.class)
         .build();
 
+    let generated: Vec<Element> = if props.routes.is_empty() {
+        Vec::new()
+    } else {
+        let path = use_router()
+            .map(|router| router.path.get())
+            .unwrap_or_else(|| "/".to_string());
+        let trail = breadcrumb_trail(&props.routes, &path);
+        let last = trail.len().saturating_sub(1);
+        trail
+            .into_iter()
+            .enumerate()
+            .map(|(index, crumb)| {
+                let to = (index != last).then(|| crumb.path.clone());
+                rsx! {
+                    BreadcrumbItem { item_key: crumb.path, to, "{crumb.title}" }
+                }
+            })
+            .collect()
+    };
+
     rsx! {
         nav {
             class: classes,
             "aria-label": "Breadcrumb",
 
+            ..generated,
             { props.children }
         }
     }
@@ -72,15 +102,22 @@ pub fn BreadcrumbItem(props: BreadcrumbItemProps) -> Element {
         .add_raw(&props.class)
         .build();
 
+    let router = use_router();
+    let href = props.href.or_else(|| props.to.clone());
+
     rsx! {
         div {
             class: classes,
 
-            if let Some(href) = props.href {
+            if let Some(href) = href {
                 a {
                     class: "hk-breadcrumb-link",
                     href: href,
-                    onclick: move |e| {
+                    onclick: move |e: MouseEvent| {
+                        if let (Some(router), Some(to)) = (&router, &props.to) {
+                            e.prevent_default();
+                            router.navigate(to);
+                        }
                         if let Some(handler) = props.onclick.as_ref() {
                             handler.call(e);
                         }
@@ -4,17 +4,41 @@
 use hikari_palette::classes::{ClassesBuilder, MenuClass};
 
 use crate::basic::{Arrow, ArrowDirection};
+use crate::display::{ShortcutHint, ShortcutHintProps};
 use crate::feedback::Glow;
+use crate::navigation::overflow::{OverflowMenu, OverflowMenuProps, use_overflow};
+use crate::platform;
 use crate::prelude::*;
+use crate::router::use_router;
 use crate::style_builder::{CssProperty, StyleStringBuilder};
 use crate::styled::StyledComponent;
+use crate::utils::{
+    RovingFocus, Shortcut, ShortcutBinding, data_key_selector, unregister_shortcut, use_roving_id,
+    use_shortcut,
+};
 use crate::{GlowBlur, GlowColor, GlowIntensity};
 
+const MENU_ITEM_SELECTOR: &str = "[role=menuitem]";
+/// Top-level items of a menu bar, measured for overflow
+const MENU_BAR_ITEM_SELECTOR: &str = "[role=menuitem][data-level=\"0\"]";
+
 #[derive(Clone, Default)]
 pub struct MenuContext {
     pub in_popover: bool,
     pub glow_enabled: bool,
     pub request_close: Option<Callback<()>>,
+    /// Key of the item that starts as the menu's tab stop
+    pub active_key: String,
+    /// Keys of menu bar items moved into the "more" dropdown
+    pub hidden_keys: Vec<String>,
+    /// Element id of the menu, used to activate items by shortcut
+    pub menu_id: String,
+}
+
+impl MenuContext {
+    fn is_hidden(&self, key: &str) -> bool {
+        !key.is_empty() && self.hidden_keys.iter().any(|hidden| hidden == key)
+    }
 }
 
 impl MenuContext {
@@ -77,6 +101,14 @@ pub struct MenuItemProps {
 
     #[default(false)]
     pub glow: bool,
+
+    /// Route path; with a `RouterProvider` the item is active on this path
+    /// (and nested ones) and navigates there on click.
+    pub to: Option<String>,
+
+    /// Shortcut that activates the item while it is mounted, e.g. `"Mod+S"`;
+    /// shown next to the label. Requires an `item_key`.
+    pub shortcut: Option<String>,
 }
 
 #[define_props]
@@ -152,7 +184,6 @@ pub struct MenuProps {
 ///
 #[component]
 pub fn Menu(props: MenuProps) -> Element {
-    let _active_key = use_signal(|| props.default_active.clone());
     let mut _open_submenus = use_signal(Vec::<String>::new);
 
     let mode_class = match props.mode {
@@ -174,14 +205,74 @@ pub fn Menu(props: MenuProps) -> Element {
         builder.build()
     };
 
-    use_context_provider(move || MenuContext {
+    let menu_id = use_roving_id("hi-menu");
+
+    // Menu bar items that do not fit collapse into a "more" dropdown.
+    let horizontal = props.mode == MenuMode::Horizontal;
+    let more_id = format!("{menu_id}-more");
+    let hidden = if horizontal {
+        let keep = (!props.default_active.is_empty()).then(|| props.default_active.clone());
+        use_overflow(&menu_id, MENU_BAR_ITEM_SELECTOR, &more_id, keep).get()
+    } else {
+        Vec::new()
+    };
+    let select_hidden = {
+        let menu_id = menu_id.clone();
+        EventHandler::new(move |key: String| {
+            let key = data_key_selector(&key);
+            let selector = format!("{key}{MENU_ITEM_SELECTOR}, {key} {MENU_ITEM_SELECTOR}");
+            platform::click_item(&menu_id, &selector, 0, None);
+        })
+    };
+
+    use_context_provider(MenuContext {
         in_popover: props.in_popover,
         glow_enabled,
         request_close: props.request_close.clone(),
+        active_key: props.default_active.clone(),
+        hidden_keys: hidden.iter().map(|item| item.key.clone()).collect(),
+        menu_id: menu_id.clone(),
     });
 
+    // Menus follow the WAI-ARIA menu pattern: vertical menus open submenus
+    // with Right and return with Left, horizontal menu bars open with Down.
+    let roving = match props.mode {
+        MenuMode::Vertical => RovingFocus::vertical(),
+        MenuMode::Horizontal => RovingFocus::horizontal(),
+    }
+    .hierarchical(true);
+
+    // Without an active item the menu itself is the tab stop until focus
+    // moves into it.
+    let container_tabindex = props.default_active.is_empty().then_some("0");
+    let handle_focus = {
+        let menu_id = menu_id.clone();
+        move |_: FocusEvent| roving.enter(&menu_id, MENU_ITEM_SELECTOR)
+    };
+    let handle_keydown = {
+        let menu_id = menu_id.clone();
+        move |e: KeyboardEvent| {
+            roving.handle_keydown(&e, &menu_id, MENU_ITEM_SELECTOR);
+        }
+    };
+
     rsx! {
-        ul { class: menu_classes, role: "menu", {props.children} }
+        ul {
+            id: menu_id,
+            class: menu_classes,
+            role: if props.mode == MenuMode::Horizontal { "menubar" } else { "menu" },
+            "aria-orientation": roving.orientation.as_str(),
+            tabindex: container_tabindex,
+            onfocus: handle_focus,
+            onkeydown: handle_keydown,
+            {props.children}
+
+            if horizontal {
+                li { class: "hk-menu-overflow", role: "none",
+                    OverflowMenu { id: more_id, items: hidden, on_select: Some(select_hidden) }
+                }
+            }
+        }
     }
 }
 
@@ -208,24 +299,66 @@ pub fn MenuItem(props: MenuItemProps) -> Element {
         None => props.glow,
     };
 
+    let router = use_router();
+    let is_active = match (&router, &props.to) {
+        (Some(router), Some(to)) => router.is_active(to, false),
+        _ => false,
+    };
+    let is_tab_stop = is_active
+        || menu_context.as_ref().is_some_and(|ctx| {
+            !props.item_key.is_empty() && ctx.get().active_key == props.item_key
+        });
+    let is_hidden = menu_context
+        .as_ref()
+        .is_some_and(|ctx| ctx.get().is_hidden(&props.item_key));
+
     let item_classes = ClassesBuilder::new()
         .add(MenuClass::MenuItem)
+        .add_if(MenuClass::MenuItemActive, || is_active)
         .add_raw(props.height.as_str())
         .add_raw(&props.class)
         .build();
 
+    // Shortcuts click the item, so they behave exactly like a pointer click.
+    if let (Some(shortcut), Some(ctx)) = (&props.shortcut, &menu_context)
+        && !props.item_key.is_empty()
+    {
+        let menu_id = ctx.get().menu_id.clone();
+        let binding_id = format!("menu:{menu_id}:{}", props.item_key);
+        match Shortcut::parse(shortcut) {
+            Ok(parsed) if !props.disabled => {
+                let selector = format!(
+                    "#{menu_id} {}{MENU_ITEM_SELECTOR}",
+                    data_key_selector(&props.item_key)
+                );
+                use_shortcut(ShortcutBinding::new(binding_id, parsed, move || {
+                    platform::click_by_selector(&selector)
+                }));
+            }
+            _ => unregister_shortcut(&binding_id),
+        }
+    }
+    let shortcut_hint = props.shortcut.clone();
+
     let menu_context_for_click = menu_context.clone();
     let item_content = rsx! {
         li {
             class: item_classes,
             role: "menuitem",
             "data-key": props.item_key,
+            "aria-current": is_active.then_some("page"),
             aria_disabled: props.disabled.to_string(),
+            "data-level": props.level,
+            "data-overflow": is_hidden.then_some("true"),
+            tabindex: if is_tab_stop { 0 } else { -1 },
             onclick: move |e| {
                 if !props.disabled {
                     if let Some(handler) = props.onclick.as_ref() {
                         handler.call(e);
                     }
+                    if let (Some(router), Some(to)) = (&router, &props.to) {
+                        router.navigate(to);
+                    }
                     // Request close if in popover mode
                     if let Some(ctx) = &menu_context_for_click {
                         let ctx_val = ctx.get();
@@ -242,6 +375,10 @@ pub fn MenuItem(props: MenuItemProps) -> Element {
                 }
 
                 span { class: "hk-menu-item-content", {props.children} }
+
+                if let Some(shortcut) = shortcut_hint {
+                    ShortcutHint { shortcut, class: "hk-menu-item-shortcut".to_string() }
+                }
             }
         }
     };
@@ -252,6 +389,7 @@ pub fn MenuItem(props: MenuItemProps) -> Element {
             div {
                 class: wrapper_class,
                 style: "width: 100%; position: relative;",
+                "data-overflow": is_hidden.then_some("true"),
                 Glow {
                     block: true,
                     blur: GlowBlur::Light,
@@ -298,7 +436,12 @@ pub fn SubMenu(props: SubMenuProps) -> Element {
     let title_content = rsx! {
         div {
             class: "{props.height.as_str()} hi-menu-submenu-title",
+            role: "menuitem",
+            "aria-haspopup": "menu",
+            "aria-expanded": is_open.get(),
             aria_disabled: props.disabled.to_string(),
+            "data-level": props.level,
+            tabindex: -1,
             onclick: move |_e| {
                 if !props.disabled {
                     is_open_for_click.set(!is_open_for_click.get());
@@ -333,11 +476,15 @@ pub fn SubMenu(props: SubMenuProps) -> Element {
         }
     };
 
+    let is_hidden = try_consume_context::<MenuContext>()
+        .is_some_and(|ctx| ctx.get().is_hidden(&props.item_key));
+
     rsx! {
         li {
             class: submenu_classes,
             role: "none",
             "data-key": props.item_key,
+            "data-overflow": is_hidden.then_some("true"),
 
             {title_with_glow}
 
@@ -1,18 +1,22 @@
 // hi-components/src/navigation/mod.rs
-// Navigation components: Menu, Tabs, Breadcrumb, Sidebar, Steps, Anchor, Stepper
+// Navigation components: Menu, Tabs, Breadcrumb, Sidebar, Steps, Anchor, Stepper, Wizard
 
 pub mod anchor;
 pub mod breadcrumb;
 pub mod menu;
+pub mod overflow;
 pub mod sidebar;
 pub mod stepper;
 pub mod steps;
 pub mod tabs;
+pub mod wizard;
 
 pub use anchor::*;
 pub use breadcrumb::*;
 pub use menu::*;
+pub use overflow::*;
 pub use sidebar::*;
 pub use stepper::*;
 pub use steps::{StepData, StepsComponent, StepsDirection, StepsProps};
 pub use tabs::*;
+pub use wizard::*;
@@ -0,0 +1,255 @@
+// hi-components/src/navigation/overflow.rs
+// Overflow handling for horizontal navigation (Tabs, horizontal Menu)
+//
+// Items that do not fit are kept in the DOM (so they can still be measured)
+// but hidden with `data-overflow`, and listed in a "more" dropdown instead.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use tairitsu_vdom::runtime;
+
+use crate::feedback::{Popover, PopoverPlacement, PopoverPositioning};
+use crate::platform;
+use crate::prelude::*;
+
+/// Measured item of an overflowing navigation bar.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct OverflowItem {
+    pub key: String,
+    pub label: String,
+    /// Outer width in pixels, including the gap to the next item
+    pub width: f64,
+}
+
+impl OverflowItem {
+    pub fn new(key: impl Into<String>, label: impl Into<String>, width: f64) -> Self {
+        Self {
+            key: key.into(),
+            label: label.into(),
+            width,
+        }
+    }
+}
+
+/// Fallback width reserved for the "more" trigger before it is measured.
+pub const DEFAULT_MORE_WIDTH: f64 = 40.0;
+
+/// Items that do not fit into `available` pixels, in their original order.
+///
+/// When anything overflows, `more_width` is reserved for the "more" trigger.
+/// The item with key `keep` (e.g. the active tab) stays visible, displacing
+/// the items right before it.
+pub fn compute_overflow(
+    items: &[OverflowItem],
+    available: f64,
+    more_width: f64,
+    keep: Option<&str>,
+) -> Vec<OverflowItem> {
+    let total: f64 = items.iter().map(|item| item.width).sum();
+    if total <= available {
+        return Vec::new();
+    }
+
+    let budget = available - more_width;
+    let mut used = 0.0;
+    let mut visible: Vec<usize> = Vec::new();
+    for (index, item) in items.iter().enumerate() {
+        if used + item.width > budget {
+            break;
+        }
+        used += item.width;
+        visible.push(index);
+    }
+
+    let kept = keep.and_then(|key| items.iter().position(|item| item.key == key));
+    if let Some(kept) = kept
+        && !visible.contains(&kept)
+    {
+        while used + items[kept].width > budget {
+            let Some(last) = visible.pop() else {
+                break;
+            };
+            used -= items[last].width;
+        }
+        visible.push(kept);
+    }
+
+    items
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| !visible.contains(index))
+        .map(|(_, item)| item.clone())
+        .collect()
+}
+
+/// Which scroll arrows of a scrollable strip are enabled.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct ScrollState {
+    pub can_scroll_start: bool,
+    pub can_scroll_end: bool,
+}
+
+impl ScrollState {
+    pub fn new(scroll_left: f64, viewport: f64, content: f64) -> Self {
+        Self {
+            can_scroll_start: scroll_left > 0.5,
+            can_scroll_end: scroll_left + viewport < content - 0.5,
+        }
+    }
+}
+
+/// Scroll offset after pressing a scroll arrow: one viewport (minus a small
+/// overlap) forwards or backwards, clamped to the content.
+pub fn scroll_offset(scroll_left: f64, viewport: f64, content: f64, forward: bool) -> f64 {
+    let step = (viewport * 0.8).max(1.0);
+    let target = if forward {
+        scroll_left + step
+    } else {
+        scroll_left - step
+    };
+    target.clamp(0.0, (content - viewport).max(0.0))
+}
+
+thread_local! {
+    /// Resize observer per overflow container, so re-renders do not add more.
+    static OVERFLOW_OBSERVERS: RefCell<HashMap<String, u64>> = RefCell::new(HashMap::new());
+}
+
+/// Disconnects the resize observer of an overflow container when dropped.
+struct OverflowObserver(String);
+
+impl Drop for OverflowObserver {
+    fn drop(&mut self) {
+        // Hook slots may outlive the map when the thread exits
+        let observer = OVERFLOW_OBSERVERS
+            .try_with(|map| map.borrow_mut().remove(&self.0))
+            .ok()
+            .flatten();
+        if let Some(observer) = observer {
+            platform::disconnect_resize(observer);
+        }
+    }
+}
+
+/// Track which items of `container_id` overflow.
+///
+/// Items are the elements matching `selector`; their key is read from the
+/// closest `data-key`. The returned signal holds the hidden items and is
+/// refreshed whenever the container resizes. `more_id` is the "more"
+/// trigger, measured to reserve its width.
+pub fn use_overflow(
+    container_id: &str,
+    selector: &str,
+    more_id: &str,
+    keep: Option<String>,
+) -> Signal<Vec<OverflowItem>> {
+    let hidden = use_signal(Vec::<OverflowItem>::new).inner().clone();
+
+    let measure = {
+        let container_id = container_id.to_string();
+        let selector = selector.to_string();
+        let more_id = more_id.to_string();
+        let hidden = hidden.clone();
+        move || {
+            let Some(rect) = platform::get_element_rect_by_id(&container_id) else {
+                return;
+            };
+            let more_width = platform::get_element_rect_by_id(&more_id)
+                .map(|rect| rect.width)
+                .filter(|width| *width > 0.0)
+                .unwrap_or(DEFAULT_MORE_WIDTH);
+            let items = platform::overflow_items(&container_id, &selector);
+            let next = compute_overflow(&items, rect.width, more_width, keep.as_deref());
+            if hidden.get() != next {
+                hidden.set(next);
+            }
+        }
+    };
+
+    if let Some(component) = runtime::active_component_id() {
+        // Hook slots are cleared on unmount, dropping the observer with them.
+        let key = format!("hk-overflow:{container_id}");
+        let container_id = container_id.to_string();
+        runtime::hook_slot(component, &key, move || {
+            Rc::new(OverflowObserver(container_id))
+        });
+    }
+
+    let container_id = container_id.to_string();
+    platform::request_animation_frame(move || {
+        let observed = OVERFLOW_OBSERVERS.with(|map| map.borrow().contains_key(&container_id));
+        if !observed && let Some(container) = platform::get_element_by_id(&container_id) {
+            let observer = platform::create_resize_observer(measure.clone());
+            platform::observe_resize(observer, &container);
+            OVERFLOW_OBSERVERS.with(|map| map.borrow_mut().insert(container_id, observer));
+        }
+        measure();
+    });
+
+    hidden
+}
+
+#[define_props]
+pub struct OverflowMenuProps {
+    /// Element id of the trigger, measured by [`use_overflow`]
+    pub id: String,
+
+    pub items: Vec<OverflowItem>,
+
+    pub on_select: Option<EventHandler<String>>,
+
+    pub class: String,
+}
+
+/// "More" trigger listing the items hidden by [`use_overflow`].
+#[component]
+pub fn OverflowMenu(props: OverflowMenuProps) -> Element {
+    let hidden = props.items.is_empty();
+    let entries: Vec<Element> = props
+        .items
+        .iter()
+        .map(|item| {
+            let key = item.key.clone();
+            let label = item.label.clone();
+            let on_select = props.on_select.clone();
+            rsx! {
+                li {
+                    class: "hk-overflow-menu-item",
+                    role: "menuitem",
+                    "data-key": item.key.clone(),
+                    onclick: move |_| {
+                        if let Some(handler) = on_select.as_ref() {
+                            handler.call(key.clone());
+                        }
+                    },
+                    "{label}"
+                }
+            }
+        })
+        .collect();
+
+    rsx! {
+        div {
+            id: props.id,
+            class: format!("hk-overflow-more {}", props.class),
+            "data-empty": hidden,
+            Popover {
+                positioning: PopoverPositioning::Relative {
+                    preferred: vec![PopoverPlacement::Bottom, PopoverPlacement::Top],
+                },
+                trigger: rsx! {
+                    button {
+                        class: "hk-overflow-more-trigger",
+                        r#type: "button",
+                        "aria-label": "More",
+                        "aria-haspopup": "menu",
+                        Icon { icon: MdiIcon::DotsHorizontal, size: 16 }
+                    }
+                },
+                ul { class: "hk-overflow-menu", role: "menu", ..entries }
+            }
+        }
+    }
+}
@@ -12,6 +12,7 @@ use hikari_palette::classes::{ClassesBuilder, SidebarClass, UtilityClass};
 
 use crate::feedback::{Glow, GlowBlur, GlowColor, GlowIntensity};
 use crate::prelude::*;
+use crate::router::use_router;
 use crate::styled::StyledComponent;
 
 pub struct SidebarComponent;
@@ -172,6 +173,10 @@ pub struct SidebarItemProps {
     pub content: Option<Element>,
 
     pub items: Option<Element>,
+
+    /// Route path; with a `RouterProvider` the item is marked active on this
+    /// path (and nested ones) and navigates there on click.
+    pub to: Option<String>,
 }
 
 #[component]
@@ -203,8 +208,15 @@ pub fn SidebarItem(props: SidebarItemProps) -> Element {
 
     let is_expanded_for_click = is_expanded.clone();
 
+    let router = use_router();
+    let is_active = match (&router, &props.to) {
+        (Some(router), Some(to)) => router.is_active(to, false),
+        _ => false,
+    };
+    let route_target = props.to.clone();
+
     rsx! {
-        div { class: {item_classes}, "data-id": props.id,
+        div { class: {item_classes}, "data-id": props.id, "data-active": is_active,
 
             // Item header (always visible)
             // Make entire header clickable when it has nested items
@@ -222,6 +234,9 @@ pub fn SidebarItem(props: SidebarItemProps) -> Element {
                         if has_items {
                             is_expanded_for_click.set(!is_expanded_for_click.get());
                         }
+                        if let (Some(router), Some(to)) = (&router, &route_target) {
+                            router.navigate(to);
+                        }
                     },
 
                     // Custom content slot - user provides Link or other content
@@ -280,6 +295,10 @@ pub struct SidebarLeafProps {
 
     #[default(VNode::empty())]
     pub children: Element,
+
+    /// Route path; with a `RouterProvider` the leaf is marked active on this
+    /// exact path and navigates there on click.
+    pub to: Option<String>,
 }
 
 #[component]
@@ -292,8 +311,24 @@ pub fn SidebarLeaf(props: SidebarLeafProps) -> Element {
     let leaf_content_class = SidebarClass::LeafContent.as_class();
     let secondary_class = SidebarClass::ItemSecondary.as_class();
 
+    let router = use_router();
+    let is_active = match (&router, &props.to) {
+        (Some(router), Some(to)) => router.is_active(to, true),
+        _ => false,
+    };
+    let route_target = props.to.clone();
+
     rsx! {
-        div { class: leaf_classes, "data-id": props.id,
+        div {
+            class: leaf_classes,
+            "data-id": props.id,
+            "data-active": is_active,
+            "aria-current": is_active.then_some("page"),
+            onclick: move |_| {
+                if let (Some(router), Some(to)) = (&router, &route_target) {
+                    router.navigate(to);
+                }
+            },
 
             div { class: leaf_content_class,
                 {props.children}
@@ -5,6 +5,9 @@ use hikari_palette::classes::{ClassesBuilder, StepsClass, UtilityClass};
 
 use crate::prelude::*;
 use crate::styled::StyledComponent;
+use crate::utils::{Orientation, RovingFocus, use_roving_id};
+
+const STEP_SELECTOR: &str = "[data-step]";
 
 pub struct StepsComponent;
 
@@ -110,7 +113,9 @@ pub fn Steps(props: StepsProps) -> Element {
         .iter()
         .enumerate()
         .map(|(index, step)| {
-            let step_status = if index < props.current {
+            let step_status = if step.status == StepStatus::Error {
+                StepStatus::Error
+            } else if index < props.current {
                 StepStatus::Finish
             } else if index == props.current {
                 StepStatus::Process
@@ -170,8 +175,37 @@ pub fn Steps(props: StepsProps) -> Element {
         })
         .collect();
 
+    // Clickable steps form a single tab stop; arrows move between steps and
+    // Enter / Space jump to the focused one.
+    let orientation = match props.direction {
+        StepsDirection::Horizontal => Orientation::Horizontal,
+        StepsDirection::Vertical => Orientation::Vertical,
+    };
+    let steps_id = use_roving_id("hi-steps");
+    let clickable = props.on_change.is_some();
+    let handle_keydown = {
+        let steps_id = steps_id.clone();
+        move |e: KeyboardEvent| {
+            if clickable {
+                RovingFocus::new(orientation).wrap(false).handle_keydown(
+                    &e,
+                    &steps_id,
+                    STEP_SELECTOR,
+                );
+            }
+        }
+    };
+
     rsx! {
-        div { class: wrapper_classes, style: props.style, ..step_elements }
+        div {
+            id: steps_id,
+            class: wrapper_classes,
+            style: props.style,
+            role: "list",
+            "aria-orientation": orientation.as_str(),
+            onkeydown: handle_keydown,
+            ..step_elements
+        }
     }
 }
 
@@ -243,6 +277,10 @@ fn StepItem(props: StepItemProps) -> Element {
     rsx! {
         div {
             class: props.step_classes,
+            role: "listitem",
+            "data-step": index,
+            "aria-current": (props.step_status == StepStatus::Process).then_some("step"),
+            tabindex: is_clickable.then_some(if props.step_status == StepStatus::Process { "0" } else { "-1" }),
             onclick: move |_e| {
                 if is_clickable && let Some(handler) = on_change.as_ref() {
                     handler.call(index);
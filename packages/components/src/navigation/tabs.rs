@@ -1,10 +1,64 @@
 // hi-components/src/navigation/tabs.rs
 // Tabs component with Arknights + FUI styling
 
+use crate::navigation::overflow::{OverflowMenu, OverflowMenuProps, scroll_offset, use_overflow};
+use crate::platform;
 use crate::prelude::*;
+use crate::router::use_router;
 use crate::styled::StyledComponent;
+use crate::utils::{FocusAction, Orientation, RovingFocus, data_key_selector, use_roving_id};
 
 pub struct TabsComponent;
+
+/// Context shared between `Tabs` and its `TabPane` children
+#[derive(Clone)]
+pub struct TabsContext {
+    pub active_key: Signal<String>,
+    pub on_change: Option<EventHandler<String>>,
+    /// Keys of tabs moved into the "more" dropdown
+    pub hidden_keys: Vec<String>,
+    pub editable: bool,
+    pub on_close: Option<EventHandler<String>>,
+    pub draggable: bool,
+    /// Key of the tab being dragged
+    pub drag_key: Signal<Option<String>>,
+    pub on_reorder: Option<EventHandler<TabReorder>>,
+    pub on_drag: Option<EventHandler<Option<String>>>,
+}
+
+/// What happens to tabs that do not fit into the tab bar.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum TabsOverflow {
+    /// Collapse them into a "more" dropdown
+    #[default]
+    More,
+    /// Keep them in a scrollable strip with scroll arrows
+    Scroll,
+}
+
+/// A tab dropped onto another one: `key` moves to the position of `target`.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct TabReorder {
+    pub key: String,
+    pub target: String,
+}
+
+impl TabReorder {
+    /// Apply the move to an ordered list of tab keys.
+    pub fn apply(&self, keys: &[String]) -> Vec<String> {
+        let mut keys = keys.to_vec();
+        let (Some(from), Some(to)) = (
+            keys.iter().position(|key| *key == self.key),
+            keys.iter().position(|key| *key == self.target),
+        ) else {
+            return keys;
+        };
+        let key = keys.remove(from);
+        keys.insert(to, key);
+        keys
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
 pub enum TabPosition {
     #[default]
@@ -28,12 +82,23 @@ pub struct TabPaneProps {
     pub children: Element,
 
     pub class: String,
+
+    /// Route path; with a `RouterProvider` the pane is active on this path and
+    /// selecting it pushes a history entry.
+    pub to: Option<String>,
+
+    /// Show a close button when the parent `Tabs` is editable
+    #[default(true)]
+    pub closable: bool,
 }
 
 #[define_props]
 pub struct TabsProps {
     pub default_active: String,
 
+    /// Controlled active key; overrides the internal selection when set
+    pub active: Option<String>,
+
     pub tab_position: TabPosition,
 
     #[default(true)]
@@ -44,6 +109,26 @@ pub struct TabsProps {
     pub children: Element,
 
     pub on_change: Option<EventHandler<String>>,
+
+    /// Handling of tabs that do not fit (horizontal tab bars only)
+    pub overflow: TabsOverflow,
+
+    /// Closable tabs plus an add button
+    pub editable: bool,
+
+    /// Called with the key of a closed tab
+    pub on_close: Option<EventHandler<String>>,
+
+    /// Called when the add button is pressed
+    pub on_add: Option<EventHandler<()>>,
+
+    /// Allow reordering tabs by drag and drop
+    pub draggable: bool,
+
+    pub on_reorder: Option<EventHandler<TabReorder>>,
+
+    /// Called with the key of a tab when dragging starts, and `None` when it ends
+    pub on_drag: Option<EventHandler<Option<String>>>,
 }
 
 ///
@@ -66,7 +151,37 @@ pub struct TabsProps {
 pub fn Tabs(props: TabsProps) -> Element {
     // Create and provide the active key signal for child TabPane components
     let active_key = use_signal(|| props.default_active.clone());
-    use_context_provider(|| active_key);
+    let drag_key = use_signal(|| None::<String>);
+
+    // Sync controlled value
+    if let Some(active) = props.active.as_ref()
+        && active_key.get() != *active
+    {
+        active_key.set(active.clone());
+    }
+
+    let horizontal = matches!(props.tab_position, TabPosition::Top | TabPosition::Bottom);
+    let nav_id = use_roving_id("hi-tabs");
+    let more_id = format!("{nav_id}-more");
+    let collapse = horizontal && props.overflow == TabsOverflow::More;
+    let scroll = horizontal && props.overflow == TabsOverflow::Scroll;
+    let hidden = if collapse {
+        use_overflow(&nav_id, TAB_SELECTOR, &more_id, Some(active_key.get())).get()
+    } else {
+        Vec::new()
+    };
+
+    use_context_provider(TabsContext {
+        active_key: active_key.inner().clone(),
+        on_change: props.on_change.clone(),
+        hidden_keys: hidden.iter().map(|item| item.key.clone()).collect(),
+        editable: props.editable,
+        on_close: props.on_close.clone(),
+        draggable: props.draggable,
+        drag_key: drag_key.inner().clone(),
+        on_reorder: props.on_reorder.clone(),
+        on_drag: props.on_drag.clone(),
+    });
 
     let position_class = match props.tab_position {
         TabPosition::Top => "hk-tabs-top",
@@ -75,6 +190,57 @@ pub fn Tabs(props: TabsProps) -> Element {
         TabPosition::Left => "hk-tabs-left",
     };
 
+    // Tabs activate automatically as focus moves (WAI-ARIA tabs pattern).
+    let orientation = match props.tab_position {
+        TabPosition::Top | TabPosition::Bottom => Orientation::Horizontal,
+        TabPosition::Left | TabPosition::Right => Orientation::Vertical,
+    };
+    let handle_keydown = {
+        let nav_id = nav_id.clone();
+        let editable = props.editable;
+        move |e: KeyboardEvent| {
+            // Delete closes the focused tab of an editable tab bar.
+            if editable && e.get_key() == Key::Delete {
+                let items = platform::focus_items(&nav_id, TAB_SELECTOR);
+                if let Some(index) = items.iter().position(|item| item.focused) {
+                    e.prevent_default();
+                    platform::click_item(&nav_id, TAB_SELECTOR, index, Some(TAB_CLOSE_SELECTOR));
+                }
+                return;
+            }
+            let roving = RovingFocus::new(orientation);
+            if let Some(FocusAction::Focus(index)) =
+                roving.handle_keydown(&e, &nav_id, TAB_SELECTOR)
+            {
+                platform::click_item(&nav_id, TAB_SELECTOR, index, None);
+            }
+        }
+    };
+
+    let select_hidden = {
+        let nav_id = nav_id.clone();
+        EventHandler::new(move |key: String| {
+            let selector = format!("{TAB_SELECTOR}{}", data_key_selector(&key));
+            platform::click_item(&nav_id, &selector, 0, None);
+        })
+    };
+
+    let scroll_by = |forward: bool| {
+        let nav_id = nav_id.clone();
+        move |_: MouseEvent| {
+            if let Some((left, viewport, content)) = platform::get_scroll_extent_by_id(&nav_id) {
+                platform::scroll_left_by_id(
+                    &nav_id,
+                    scroll_offset(left, viewport, content, forward),
+                );
+            }
+        }
+    };
+    let scroll_back = scroll_by(false);
+    let scroll_forward = scroll_by(true);
+
+    let on_add = props.on_add.clone();
+
     let animated_class = if props.animated {
         "hk-tabs-animated"
     } else {
@@ -88,13 +254,62 @@ pub fn Tabs(props: TabsProps) -> Element {
             div {
                 class: "hk-tabs-nav",
 
+                if scroll {
+                    button {
+                        class: "hk-tabs-nav-button",
+                        r#type: "button",
+                        tabindex: -1,
+                        "aria-label": "Scroll tabs back",
+                        onclick: scroll_back,
+                        Icon { icon: MdiIcon::ChevronLeft, size: 16 }
+                    }
+                }
+
                 div {
+                    id: nav_id,
                     class: "hk-tabs-nav-list",
+                    "data-overflow-mode": if scroll { "scroll" } else if collapse { "more" } else { "wrap" },
                     role: "tablist",
+                    "aria-orientation": orientation.as_str(),
+                    onkeydown: handle_keydown,
 
                     { props.children.clone() }
                 }
 
+                if scroll {
+                    button {
+                        class: "hk-tabs-nav-button",
+                        r#type: "button",
+                        tabindex: -1,
+                        "aria-label": "Scroll tabs forward",
+                        onclick: scroll_forward,
+                        Icon { icon: MdiIcon::ChevronRight, size: 16 }
+                    }
+                }
+
+                if collapse {
+                    OverflowMenu {
+                        id: more_id,
+                        items: hidden,
+                        on_select: Some(select_hidden),
+                        class: "hk-tabs-more".to_string(),
+                    }
+                }
+
+                if props.editable {
+                    button {
+                        class: "hk-tabs-add-button",
+                        r#type: "button",
+                        "aria-label": "Add tab",
+                        onclick: move |_| {
+                            if let Some(handler) = on_add.as_ref() {
+                                handler.call(());
+                            }
+                        },
+                        Icon { icon: MdiIcon::Plus, size: 16 }
+                    }
+                }
+
                 div {
                     class: "hk-tabs-ink-bar",
                     style: "transform: translateX(...)",
@@ -111,6 +326,9 @@ pub fn Tabs(props: TabsProps) -> Element {
     }
 }
 
+const TAB_SELECTOR: &str = "[role=tab]";
+const TAB_CLOSE_SELECTOR: &str = ".hk-tab-close";
+
 impl StyledComponent for TabsComponent {
     fn styles() -> &'static str {
         tairitsu_macros::scss! { file: "src/styles/components/tabs.scss", no_hash }.0
@@ -126,9 +344,34 @@ pub fn TabPane(props: TabPaneProps) -> Element {
     use hikari_palette::classes::ClassesBuilder;
     use hikari_palette::classes::components::TabsClass;
 
-    let active_key = use_context::<Signal<String>>().expect("TabsContext not found");
+    let tabs = use_context::<TabsContext>()
+        .expect("TabsContext not found")
+        .get()
+        .clone();
+    let router = use_router();
     let item_key = props.item_key.clone();
-    let is_active = active_key.get().get() == item_key;
+    let is_active = match (&router, &props.to) {
+        (Some(router), Some(to)) => router.is_active(to, false),
+        _ => tabs.active_key.get() == item_key,
+    };
+
+    let handle_select = {
+        let item_key = item_key.clone();
+        let to = props.to.clone();
+        let disabled = props.disabled;
+        move |_: MouseEvent| {
+            if disabled {
+                return;
+            }
+            tabs.active_key.set(item_key.clone());
+            if let (Some(router), Some(to)) = (&router, &to) {
+                router.navigate(to);
+            }
+            if let Some(handler) = tabs.on_change.as_ref() {
+                handler.call(item_key.clone());
+            }
+        }
+    };
 
     let tab_classes = ClassesBuilder::new()
         .add(TabsClass::TabsTab)
@@ -143,6 +386,61 @@ pub fn TabPane(props: TabPaneProps) -> Element {
         .build();
 
     let aria_hidden_val = (!is_active).to_string();
+    let is_hidden = tabs.hidden_keys.contains(&item_key);
+    let closable = tabs.editable && props.closable && !props.disabled;
+    let draggable = tabs.draggable && !props.disabled;
+    let is_dragging = tabs.drag_key.get().as_deref() == Some(item_key.as_str());
+
+    let handle_close = {
+        let item_key = item_key.clone();
+        let on_close = tabs.on_close.clone();
+        move |e: MouseEvent| {
+            e.stop_propagation();
+            if let Some(handler) = on_close.as_ref() {
+                handler.call(item_key.clone());
+            }
+        }
+    };
+
+    let handle_drag_start = {
+        let item_key = item_key.clone();
+        let drag_key = tabs.drag_key.clone();
+        let on_drag = tabs.on_drag.clone();
+        move |_: DragEvent| {
+            drag_key.set(Some(item_key.clone()));
+            if let Some(handler) = on_drag.as_ref() {
+                handler.call(Some(item_key.clone()));
+            }
+        }
+    };
+    let handle_drag_end = {
+        let drag_key = tabs.drag_key.clone();
+        let on_drag = tabs.on_drag.clone();
+        move |_: DragEvent| {
+            drag_key.set(None);
+            if let Some(handler) = on_drag.as_ref() {
+                handler.call(None);
+            }
+        }
+    };
+    let handle_drop = {
+        let item_key = item_key.clone();
+        let drag_key = tabs.drag_key.clone();
+        let on_reorder = tabs.on_reorder.clone();
+        move |e: DragEvent| {
+            e.prevent_default();
+            if let Some(key) = drag_key.get()
+                && key != item_key
+                && let Some(handler) = on_reorder.as_ref()
+            {
+                handler.call(TabReorder {
+                    key,
+                    target: item_key.clone(),
+                });
+            }
+            drag_key.set(None);
+        }
+    };
 
     // Tab and TabPane need to be rendered together
     let tab_el = rsx! {
@@ -152,12 +450,31 @@ pub fn TabPane(props: TabPaneProps) -> Element {
             "data-key": item_key.clone(),
             "aria-selected": is_active,
             "aria-disabled": props.disabled,
+            "data-overflow": is_hidden.then_some("true"),
+            "data-dragging": is_dragging.then_some("true"),
+            tabindex: if is_active { 0 } else { -1 },
+            draggable,
+            onclick: handle_select,
+            ondragstart: handle_drag_start,
+            ondragend: handle_drag_end,
+            ondragover: move |e: DragEvent| e.prevent_default(),
+            ondrop: handle_drop,
 
             if let Some(icon) = props.icon {
                 span { class: "hk-tabs-tab-icon", {icon} }
             }
 
             span { class: "hk-tabs-tab-label", "{props.tab}" }
+
+            if closable {
+                span {
+                    class: "hk-tab-close",
+                    role: "button",
+                    "aria-label": "Close tab",
+                    onclick: handle_close,
+                    Icon { icon: MdiIcon::Close, size: 12 }
+                }
+            }
         }
     };
 
@@ -0,0 +1,878 @@
+// hikari-components/src/navigation/wizard.rs
+//! Wizard component - Multi-step flow built on [`Steps`]
+//!
+//! Owns the content panel of each step and the navigation between them:
+//!
+//! - A [`StepGuard`] validates a step before "Next"; it may answer later
+//!   (e.g. after a server check) and can flag individual fields, which the
+//!   step content shows through [`WizardHandle::field_error`] and
+//!   [`WizardHandle::field_status`] on its `FormField`s
+//! - A [`StepCondition`] includes a step only for some data, so flows can
+//!   branch; `optional` steps get a "Skip" button
+//! - Values entered in any step live in one [`WizardData`] map shared by all
+//!   steps
+//! - A `review` step lists the labelled fields of the previous steps, with
+//!   an "Edit" button back to each of them
+//! - Give the wizard a `persist_key` to keep its [`WizardProgress`] in local
+//!   storage, so a reload resumes where the user left
+//!
+//! # Example
+//!
+//! ```rust
+//! use hikari_components::navigation::{StepErrors, StepGuard, Wizard, WizardStep};
+//!
+//! rsx! {
+//!     Wizard {
+//!         persist_key: Some("signup".to_string()),
+//!         steps: vec![
+//!             WizardStep::new("account", "Account")
+//!                 .with_field("email", "Email")
+//!                 .with_guard(StepGuard::from_fn(|data| match data.get("email") {
+//!                     Some(email) if email.contains('@') => Ok(()),
+//!                     _ => Err(StepErrors::new().with_field("email", "Enter a valid email")),
+//!                 }))
+//!                 .with_content(|wizard| rsx! { AccountForm { wizard: wizard.clone() } }),
+//!             WizardStep::new("company", "Company")
+//!                 .when(|data| data.get("type").map(String::as_str) == Some("business"))
+//!                 .with_content(|wizard| rsx! { CompanyForm { wizard: wizard.clone() } }),
+//!             WizardStep::new("review", "Review").review(),
+//!         ],
+//!         on_finish: Some(Callback::new(|data| submit(data))),
+//!     }
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::rc::Rc;
+
+pub use hikari_extra_components::extra::{WizardData, WizardProgress};
+use hikari_palette::classes::{ClassesBuilder, UtilityClass, WizardClass};
+
+use crate::basic::{Button, ButtonProps, ButtonVariant, FormFieldStatus};
+use crate::navigation::steps::{StepData, StepStatus, Steps, StepsDirection, StepsProps};
+use crate::platform;
+use crate::prelude::*;
+
+const STORAGE_PREFIX: &str = "hk-wizard:";
+
+/// Why a step cannot be left yet
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct StepErrors {
+    /// Shown above the step content
+    pub message: Option<String>,
+
+    /// Errors of individual fields, by field name
+    pub fields: BTreeMap<String, String>,
+}
+
+impl StepErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    pub fn with_field(mut self, field: impl Into<String>, error: impl Into<String>) -> Self {
+        self.fields.insert(field.into(), error.into());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.message.is_none() && self.fields.is_empty()
+    }
+}
+
+pub type GuardResolveFn = dyn Fn(Result<(), StepErrors>);
+
+/// Hands the answer of a [`StepGuard`] back to the wizard.
+///
+/// It may be resolved later; answers for a check the user has moved on from
+/// (by editing data or changing step) are dropped.
+#[derive(Clone)]
+pub struct GuardSink(Rc<GuardResolveFn>);
+
+impl GuardSink {
+    pub fn new(f: impl Fn(Result<(), StepErrors>) + 'static) -> Self {
+        Self(Rc::new(f))
+    }
+
+    pub fn resolve(&self, result: Result<(), StepErrors>) {
+        (self.0)(result)
+    }
+}
+
+impl PartialEq for GuardSink {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for GuardSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("GuardSink(..)")
+    }
+}
+
+pub type GuardFn = dyn Fn(&WizardData, GuardSink);
+
+/// Decides whether the wizard can advance past a step
+#[derive(Clone)]
+pub struct StepGuard(Rc<GuardFn>);
+
+impl StepGuard {
+    pub fn new(f: impl Fn(&WizardData, GuardSink) + 'static) -> Self {
+        Self(Rc::new(f))
+    }
+
+    /// Guard that answers synchronously.
+    pub fn from_fn(f: impl Fn(&WizardData) -> Result<(), StepErrors> + 'static) -> Self {
+        Self::new(move |data, sink| sink.resolve(f(data)))
+    }
+
+    pub fn check(&self, data: &WizardData, sink: GuardSink) {
+        (self.0)(data, sink)
+    }
+}
+
+impl PartialEq for StepGuard {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for StepGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("StepGuard(..)")
+    }
+}
+
+/// Includes a step only when it returns true for the current data
+#[derive(Clone)]
+pub struct StepCondition(Rc<dyn Fn(&WizardData) -> bool>);
+
+impl StepCondition {
+    pub fn new(f: impl Fn(&WizardData) -> bool + 'static) -> Self {
+        Self(Rc::new(f))
+    }
+
+    pub fn applies(&self, data: &WizardData) -> bool {
+        (self.0)(data)
+    }
+}
+
+impl PartialEq for StepCondition {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for StepCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("StepCondition(..)")
+    }
+}
+
+/// Renders the content panel of a step
+pub type WizardStepRenderer = Rc<dyn Fn(&WizardHandle) -> Element>;
+
+/// One step of a [`Wizard`]
+#[derive(Clone)]
+pub struct WizardStep {
+    /// Unique key, used for persistence and [`WizardHandle::goto`]
+    pub key: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub content: Option<WizardStepRenderer>,
+    pub guard: Option<StepGuard>,
+    pub condition: Option<StepCondition>,
+    /// Shows a "Skip" button that advances without the guard
+    pub optional: bool,
+    /// Lists the fields of the previous steps before its content
+    pub review: bool,
+    /// `(field, label)` pairs shown by the review step
+    pub fields: Vec<(String, String)>,
+}
+
+impl WizardStep {
+    pub fn new(key: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            title: title.into(),
+            description: None,
+            content: None,
+            guard: None,
+            condition: None,
+            optional: false,
+            review: false,
+            fields: Vec::new(),
+        }
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn with_content(mut self, render: impl Fn(&WizardHandle) -> Element + 'static) -> Self {
+        self.content = Some(Rc::new(render));
+        self
+    }
+
+    pub fn with_guard(mut self, guard: StepGuard) -> Self {
+        self.guard = Some(guard);
+        self
+    }
+
+    /// Includes the step only when `condition` holds
+    pub fn when(mut self, condition: impl Fn(&WizardData) -> bool + 'static) -> Self {
+        self.condition = Some(StepCondition::new(condition));
+        self
+    }
+
+    pub fn optional(mut self) -> Self {
+        self.optional = true;
+        self
+    }
+
+    pub fn review(mut self) -> Self {
+        self.review = true;
+        self
+    }
+
+    pub fn with_field(mut self, field: impl Into<String>, label: impl Into<String>) -> Self {
+        self.fields.push((field.into(), label.into()));
+        self
+    }
+
+    pub fn is_active(&self, data: &WizardData) -> bool {
+        self.condition
+            .as_ref()
+            .is_none_or(|condition| condition.applies(data))
+    }
+}
+
+impl PartialEq for WizardStep {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+            && self.title == other.title
+            && self.description == other.description
+            && match (&self.content, &other.content) {
+                (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+            && self.guard == other.guard
+            && self.condition == other.condition
+            && self.optional == other.optional
+            && self.review == other.review
+            && self.fields == other.fields
+    }
+}
+
+impl fmt::Debug for WizardStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WizardStep")
+            .field("key", &self.key)
+            .field("title", &self.title)
+            .field("description", &self.description)
+            .field("content", &self.content.is_some())
+            .field("guard", &self.guard)
+            .field("condition", &self.condition)
+            .field("optional", &self.optional)
+            .field("review", &self.review)
+            .field("fields", &self.fields)
+            .finish()
+    }
+}
+
+/// Keys of the steps that apply to `data`, in order
+pub fn active_steps(steps: &[WizardStep], data: &WizardData) -> Vec<String> {
+    steps
+        .iter()
+        .filter(|step| step.is_active(data))
+        .map(|step| step.key.clone())
+        .collect()
+}
+
+#[derive(Clone, Props, Default)]
+pub struct WizardProps {
+    #[props(default)]
+    pub steps: Vec<WizardStep>,
+
+    /// Values the steps start with (ignored when progress is restored)
+    #[props(default)]
+    pub initial_data: WizardData,
+
+    /// Remembers the progress in local storage under this key until the
+    /// wizard is finished
+    #[props(default)]
+    pub persist_key: Option<String>,
+
+    /// Called with the new progress after every change
+    #[props(default)]
+    pub on_change: Option<Callback<WizardProgress, ()>>,
+
+    /// Called with the collected data when "Finish" passes the last guard
+    #[props(default)]
+    pub on_finish: Option<Callback<WizardData, ()>>,
+
+    #[props(default)]
+    pub direction: StepsDirection,
+
+    #[props(default)]
+    pub class: String,
+}
+
+impl PartialEq for WizardProps {
+    fn eq(&self, other: &Self) -> bool {
+        self.steps == other.steps
+            && self.initial_data == other.initial_data
+            && self.persist_key == other.persist_key
+            && self.on_change == other.on_change
+            && self.on_finish == other.on_finish
+            && self.direction == other.direction
+            && self.class == other.class
+    }
+}
+
+impl fmt::Debug for WizardProps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WizardProps")
+            .field("steps", &self.steps)
+            .field("initial_data", &self.initial_data)
+            .field("persist_key", &self.persist_key)
+            .field("on_change", &self.on_change.is_some())
+            .field("on_finish", &self.on_finish.is_some())
+            .field("direction", &self.direction)
+            .field("class", &self.class)
+            .finish()
+    }
+}
+
+fn load_progress(persist_key: Option<&str>) -> Option<WizardProgress> {
+    persist_key
+        .and_then(|key| platform::local_storage_get(&format!("{STORAGE_PREFIX}{key}")))
+        .and_then(|json| WizardProgress::from_json(&json).ok())
+}
+
+fn save_progress(persist_key: Option<&str>, progress: &WizardProgress) {
+    if let Some(key) = persist_key
+        && let Ok(json) = progress.to_json()
+    {
+        platform::local_storage_set(&format!("{STORAGE_PREFIX}{key}"), &json);
+    }
+}
+
+fn clear_progress(persist_key: Option<&str>) {
+    if let Some(key) = persist_key {
+        platform::local_storage_remove(&format!("{STORAGE_PREFIX}{key}"));
+    }
+}
+
+/// Access to a wizard's data and navigation, passed to step content
+#[derive(Clone)]
+pub struct WizardHandle {
+    steps: Rc<Vec<WizardStep>>,
+    progress: Signal<WizardProgress>,
+    errors: Signal<StepErrors>,
+    /// Bumped whenever a pending guard answer becomes stale
+    generation: Signal<u64>,
+    validating: Signal<bool>,
+    persist_key: Option<String>,
+    on_change: Option<Callback<WizardProgress, ()>>,
+    on_finish: Option<Callback<WizardData, ()>>,
+}
+
+impl PartialEq for WizardHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.steps, &other.steps) && self.progress == other.progress
+    }
+}
+
+impl fmt::Debug for WizardHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WizardHandle")
+            .field("progress", &self.progress.get())
+            .finish()
+    }
+}
+
+#[allow(unused_braces)]
+impl WizardHandle {
+    pub fn data(&self) -> WizardData {
+        self.progress.get().data
+    }
+
+    pub fn get(&self, field: &str) -> Option<String> {
+        self.progress.get().get(field).map(str::to_string)
+    }
+
+    /// Stores a value, clearing the error of that field
+    ///
+    /// Steps whose condition changes with the value are added or removed
+    /// right away.
+    pub fn set(&self, field: &str, value: impl Into<String>) {
+        let mut errors = self.errors.get();
+        if errors.fields.remove(field).is_some() {
+            self.errors.set(errors);
+        }
+        self.cancel_validation();
+        self.update(|progress, steps| {
+            progress.set(field, value);
+            progress.sync(&active_steps(steps, &progress.data));
+        });
+    }
+
+    pub fn field_error(&self, field: &str) -> Option<String> {
+        self.errors.get().fields.get(field).cloned()
+    }
+
+    /// Status for the `FormField` showing `field`
+    pub fn field_status(&self, field: &str) -> FormFieldStatus {
+        if self.errors.get().fields.contains_key(field) {
+            FormFieldStatus::Error
+        } else {
+            FormFieldStatus::Default
+        }
+    }
+
+    pub fn errors(&self) -> StepErrors {
+        self.errors.get()
+    }
+
+    pub fn progress(&self) -> WizardProgress {
+        self.progress.get()
+    }
+
+    /// Keys of the steps that currently apply, in order
+    pub fn active_steps(&self) -> Vec<String> {
+        active_steps(&self.steps, &self.progress.get().data)
+    }
+
+    pub fn current_step(&self) -> Option<WizardStep> {
+        let current = self.progress.get().current?;
+        self.steps.iter().find(|step| step.key == current).cloned()
+    }
+
+    /// Whether the current step's guard has not answered yet
+    pub fn is_validating(&self) -> bool {
+        self.validating.get()
+    }
+
+    /// Runs the current step's guard and advances if it passes
+    ///
+    /// On the last step, a passing guard finishes the wizard.
+    pub fn next(&self) {
+        if self.validating.get() {
+            return;
+        }
+        let Some(step) = self.current_step() else {
+            return;
+        };
+        let Some(guard) = step.guard.clone() else {
+            self.advance();
+            return;
+        };
+
+        let generation = self.cancel_validation();
+        self.validating.set(true);
+        let wizard = self.clone();
+        guard.check(
+            &self.data(),
+            GuardSink::new(move |result| {
+                if wizard.generation.get() != generation {
+                    return;
+                }
+                wizard.validating.set(false);
+                match result {
+                    Ok(()) => wizard.advance(),
+                    Err(errors) => wizard.errors.set(errors),
+                }
+            }),
+        );
+    }
+
+    /// Advances past an optional step without running its guard
+    pub fn skip(&self) {
+        if self.current_step().is_some_and(|step| step.optional) {
+            self.cancel_validation();
+            self.advance();
+        }
+    }
+
+    pub fn back(&self) {
+        self.cancel_validation();
+        self.errors.set(StepErrors::default());
+        self.update(|progress, steps| {
+            progress.back(&active_steps(steps, &progress.data));
+        });
+    }
+
+    /// Opens a step that has been reached before
+    pub fn goto(&self, key: &str) {
+        if !self.can_goto(key) {
+            return;
+        }
+        self.cancel_validation();
+        self.errors.set(StepErrors::default());
+        self.update(|progress, steps| {
+            progress.jump(&active_steps(steps, &progress.data), key);
+        });
+    }
+
+    pub fn can_goto(&self, key: &str) -> bool {
+        self.progress.get().can_jump(&self.active_steps(), key)
+    }
+
+    /// Discards all data and returns to the first step
+    pub fn reset(&self) {
+        self.cancel_validation();
+        self.errors.set(StepErrors::default());
+        self.update(|progress, steps| {
+            *progress = WizardProgress::new(&active_steps(steps, &WizardData::new()));
+        });
+    }
+
+    fn advance(&self) {
+        self.errors.set(StepErrors::default());
+        let mut finished = false;
+        self.update(|progress, steps| {
+            finished = !progress.advance(&active_steps(steps, &progress.data));
+        });
+        if finished {
+            clear_progress(self.persist_key.as_deref());
+            if let Some(handler) = self.on_finish.as_ref() {
+                handler.call(self.data());
+            }
+        }
+    }
+
+    /// Invalidates a pending guard answer and returns the new generation
+    fn cancel_validation(&self) -> u64 {
+        let generation = self.generation.get().wrapping_add(1);
+        self.generation.set(generation);
+        self.validating.set(false);
+        generation
+    }
+
+    /// Applies a change, then persists and reports it
+    fn update(&self, change: impl FnOnce(&mut WizardProgress, &[WizardStep])) {
+        let before = self.progress.get();
+        let mut next = before.clone();
+        change(&mut next, &self.steps);
+        if next == before {
+            return;
+        }
+        self.progress.set(next.clone());
+        save_progress(self.persist_key.as_deref(), &next);
+        if let Some(handler) = self.on_change.as_ref() {
+            handler.call(next);
+        }
+    }
+
+    /// Labelled values of the steps before `review_key`, with edit buttons
+    fn summary(&self, review_key: &str) -> Element {
+        let progress = self.progress.get();
+        let active = self.active_steps();
+        let sections: Vec<Element> = self
+            .steps
+            .iter()
+            .take_while(|step| step.key != review_key)
+            .filter(|step| active.contains(&step.key) && !step.fields.is_empty())
+            .map(|step| {
+                let rows: Vec<Element> = step
+                    .fields
+                    .iter()
+                    .map(|(field, label)| {
+                        let value = progress.get(field).unwrap_or("—").to_string();
+                        rsx! {
+                            div { class: WizardClass::SummaryRow.as_class(),
+                                dt { class: WizardClass::SummaryLabel.as_class(), "{label}" }
+                                dd { class: WizardClass::SummaryValue.as_class(), "{value}" }
+                            }
+                        }
+                    })
+                    .collect();
+                let wizard = self.clone();
+                let key = step.key.clone();
+                let title = step.title.clone();
+                rsx! {
+                    section { class: WizardClass::SummarySection.as_class(),
+                        div { class: WizardClass::SummaryHeader.as_class(),
+                            h4 { class: WizardClass::SummaryTitle.as_class(), "{title}" }
+                            Button {
+                                variant: ButtonVariant::Ghost,
+                                glow: false,
+                                onclick: Some(EventHandler::new(move |_: MouseEvent| wizard.goto(&key))),
+                                "Edit"
+                            }
+                        }
+                        dl { ..rows }
+                    }
+                }
+            })
+            .collect();
+
+        rsx! {
+            div { class: WizardClass::Summary.as_class(), ..sections }
+        }
+    }
+
+    fn footer(&self, step: &WizardStep, is_first: bool, is_last: bool) -> Element {
+        let validating = self.validating.get();
+        let back = {
+            let wizard = self.clone();
+            move |_: MouseEvent| wizard.back()
+        };
+        let skip = {
+            let wizard = self.clone();
+            move |_: MouseEvent| wizard.skip()
+        };
+        let next = {
+            let wizard = self.clone();
+            move |_: MouseEvent| wizard.next()
+        };
+        let next_label = if is_last { "Finish" } else { "Next" };
+
+        rsx! {
+            div { class: WizardClass::Footer.as_class(),
+                if !is_first {
+                    Button {
+                        variant: ButtonVariant::Secondary,
+                        glow: false,
+                        onclick: Some(EventHandler::new(back)),
+                        "Back"
+                    }
+                }
+                span { class: WizardClass::FooterSpacer.as_class() }
+                if step.optional && !is_last {
+                    Button {
+                        variant: ButtonVariant::Ghost,
+                        glow: false,
+                        disabled: validating,
+                        onclick: Some(EventHandler::new(skip)),
+                        "Skip"
+                    }
+                }
+                Button {
+                    variant: ButtonVariant::Primary,
+                    loading: validating,
+                    disabled: validating,
+                    onclick: Some(EventHandler::new(next)),
+                    "{next_label}"
+                }
+            }
+        }
+    }
+}
+
+/// Multi-step flow with validated, branching steps
+#[component]
+pub fn Wizard(props: WizardProps) -> Element {
+    let steps = Rc::new(props.steps.clone());
+
+    let initial = {
+        let steps = steps.clone();
+        let persist_key = props.persist_key.clone();
+        let data = props.initial_data.clone();
+        move || {
+            let mut progress = load_progress(persist_key.as_deref()).unwrap_or_else(|| {
+                let mut progress = WizardProgress::new(&active_steps(&steps, &data));
+                progress.data = data;
+                progress
+            });
+            progress.sync(&active_steps(&steps, &progress.data));
+            progress
+        }
+    };
+    let progress = use_signal(initial);
+    let errors = use_signal(StepErrors::default);
+    let generation = use_signal(|| 0u64);
+    let validating = use_signal(|| false);
+
+    let wizard = WizardHandle {
+        steps: steps.clone(),
+        progress: progress.inner().clone(),
+        errors: errors.inner().clone(),
+        generation: generation.inner().clone(),
+        validating: validating.inner().clone(),
+        persist_key: props.persist_key.clone(),
+        on_change: props.on_change.clone(),
+        on_finish: props.on_finish.clone(),
+    };
+
+    let current = progress.get();
+    let active = wizard.active_steps();
+    let position = current.position(&active).unwrap_or(0);
+    let step_errors = errors.get();
+
+    let header_steps: Vec<StepData> = active
+        .iter()
+        .filter_map(|key| steps.iter().find(|step| &step.key == key))
+        .enumerate()
+        .map(|(index, step)| StepData {
+            title: step.title.clone(),
+            description: step.description.clone(),
+            status: if index == position && !step_errors.is_empty() {
+                StepStatus::Error
+            } else {
+                StepStatus::default()
+            },
+            ..Default::default()
+        })
+        .collect();
+
+    let on_step = {
+        let wizard = wizard.clone();
+        let active = active.clone();
+        Callback::new(move |index: usize| {
+            if let Some(key) = active.get(index) {
+                wizard.goto(key);
+            }
+        })
+    };
+
+    let Some(step) = wizard.current_step() else {
+        return VNode::empty();
+    };
+
+    let content = match step.content.as_ref() {
+        Some(render) => render(&wizard),
+        None => VNode::empty(),
+    };
+    let summary = if step.review {
+        wizard.summary(&step.key)
+    } else {
+        VNode::empty()
+    };
+    let message = step_errors.message.clone();
+    let footer = wizard.footer(&step, position == 0, position + 1 == active.len());
+
+    let classes = ClassesBuilder::new()
+        .add(WizardClass::Wizard)
+        .add_raw(&props.class)
+        .build();
+
+    rsx! {
+        div { class: classes,
+            div { class: WizardClass::Header.as_class(),
+                Steps {
+                    current: position,
+                    direction: props.direction,
+                    steps: header_steps,
+                    on_change: Some(on_step),
+                }
+            }
+            div {
+                class: WizardClass::Body.as_class(),
+                "aria-busy": validating.get().to_string(),
+                if let Some(message) = message {
+                    div { class: WizardClass::Error.as_class(), role: "alert", "{message}" }
+                }
+                div {
+                    class: WizardClass::Panel.as_class(),
+                    role: "group",
+                    "aria-label": step.title.clone(),
+                    { summary },
+                    { content },
+                }
+            }
+            { footer },
+        }
+    }
+}
+
+pub struct WizardComponent;
+
+impl crate::styled::StyledComponent for WizardComponent {
+    fn styles() -> &'static str {
+        r#"
+.hk-wizard {
+  display: flex;
+  flex-direction: column;
+  gap: 24px;
+  width: 100%;
+}
+
+.hk-wizard-body {
+  display: flex;
+  flex-direction: column;
+  gap: 16px;
+  min-height: 0;
+}
+
+.hk-wizard-error {
+  padding: 8px 12px;
+  border-radius: 6px;
+  color: var(--hi-color-danger, #dc2626);
+  background: color-mix(in srgb, var(--hi-color-danger, #dc2626) 10%, transparent);
+}
+
+.hk-wizard-panel {
+  display: flex;
+  flex-direction: column;
+  gap: 16px;
+}
+
+.hk-wizard-footer {
+  display: flex;
+  align-items: center;
+  gap: 8px;
+}
+
+.hk-wizard-footer-spacer {
+  flex: 1 1 auto;
+}
+
+.hk-wizard-summary {
+  display: flex;
+  flex-direction: column;
+  gap: 16px;
+}
+
+.hk-wizard-summary-section {
+  padding: 12px 16px;
+  border: 1px solid var(--hi-border, #e5e7eb);
+  border-radius: 8px;
+}
+
+.hk-wizard-summary-header {
+  display: flex;
+  align-items: center;
+  justify-content: space-between;
+  margin-bottom: 8px;
+}
+
+.hk-wizard-summary-title {
+  margin: 0;
+  font-size: 0.95rem;
+  font-weight: 600;
+}
+
+.hk-wizard-summary-row {
+  display: grid;
+  grid-template-columns: minmax(120px, 1fr) 2fr;
+  gap: 12px;
+  padding: 4px 0;
+}
+
+.hk-wizard-summary-label {
+  margin: 0;
+  color: var(--hi-text-secondary, #6b7280);
+}
+
+.hk-wizard-summary-value {
+  margin: 0;
+  overflow-wrap: anywhere;
+}
+"#
+    }
+
+    fn name() -> &'static str {
+        "wizard"
+    }
+}
@@ -102,6 +102,54 @@ pub fn get_element_rect_by_id(_id: &str) -> Option<tairitsu_vdom::platform::DomR
     None
 }
 
+pub fn get_input_caret_by_id(_id: &str) -> Option<usize> {
+    None
+}
+
+pub fn set_input_value_by_id(_id: &str, _value: &str, _caret: usize) {}
+
+pub fn get_caret_rect_by_id(_id: &str) -> Option<tairitsu_vdom::platform::DomRect> {
+    None
+}
+
+pub fn fit_textarea_rows_by_id(_id: &str, _fit: impl FnOnce(u32) -> u32) -> bool {
+    false
+}
+
+pub fn focus_items(_container_id: &str, _selector: &str) -> Vec<crate::utils::FocusItem> {
+    Vec::new()
+}
+
+pub fn focus_item(_container_id: &str, _selector: &str, _index: usize) {}
+
+pub fn click_item(_container_id: &str, _selector: &str, _index: usize, _target: Option<&str>) {}
+
+pub fn is_rtl(_id: &str) -> bool {
+    false
+}
+
+pub fn overflow_items(
+    _container_id: &str,
+    _selector: &str,
+) -> Vec<crate::navigation::OverflowItem> {
+    Vec::new()
+}
+
+/// `(scroll_left, client_width, scroll_width)` of an element.
+pub fn get_scroll_extent_by_id(_id: &str) -> Option<(f64, f64, f64)> {
+    None
+}
+
+pub fn get_viewport_by_id(_id: &str) -> Option<(f64, f64, f64)> {
+    None
+}
+
+pub fn set_scroll_top_by_id(_id: &str, _top: f64) {}
+
+pub fn set_style_by_id(_id: &str, _style: &str) {}
+
+pub fn scroll_left_by_id(_id: &str, _left: f64) {}
+
 pub fn get_scroll_top_by_selector(_selector: &str) -> f64 {
     0.0
 }
@@ -114,6 +162,71 @@ pub fn request_animation_frame_with_timestamp(_callback: impl FnOnce(f64) + 'sta
 
 pub fn on_scroll(_callback: impl FnMut() + 'static) {}
 
+pub fn on_popstate(_callback: impl FnMut() + 'static) {}
+
+pub fn on_color_scheme_change(_callback: impl FnMut(bool) + 'static) -> u64 {
+    0
+}
+
+pub fn on_contrast_change(_callback: impl FnMut() + 'static) -> u64 {
+    0
+}
+
+pub fn remove_media_listener(_listener_id: u64) {}
+
+pub fn supports_view_transitions() -> bool {
+    false
+}
+
+pub fn start_circular_reveal(
+    _origin: Option<(f64, f64)>,
+    _duration_ms: f64,
+    _progress: &[f64],
+    update: impl FnOnce() + 'static,
+) {
+    update();
+}
+
+pub fn on_keydown(_callback: impl FnMut(&crate::utils::KeyPress) -> bool + 'static) {}
+
+pub fn is_mac() -> bool {
+    false
+}
+
+pub fn focused_shortcut_scopes() -> Vec<String> {
+    Vec::new()
+}
+
+pub fn theme_scope_is_nested(_scope: &str) -> bool {
+    false
+}
+
+pub fn click_by_selector(_selector: &str) {}
+
+pub fn track_mouse_drag(
+    _on_move: impl FnMut(f64, f64) + 'static,
+    _on_end: impl FnOnce() + 'static,
+) {
+}
+
+pub fn track_page_drop(_on_drop: impl FnOnce() + 'static) {}
+
+pub fn local_storage_get(_key: &str) -> Option<String> {
+    None
+}
+
+pub fn local_storage_set(_key: &str, _value: &str) {}
+
+pub fn local_storage_remove(_key: &str) {}
+
+pub fn location_path() -> String {
+    "/".to_string()
+}
+
+pub fn history_push(_path: &str) {}
+
+pub fn history_replace(_path: &str) {}
+
 pub fn draw_qrcode_on_canvas_by_id(
     _canvas_id: &str,
     _matrix: &[Vec<bool>],
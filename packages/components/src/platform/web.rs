@@ -142,6 +142,402 @@ pub fn on_resize(callback: impl FnMut() + 'static) {
     closure.forget();
 }
 
+/// Listen for key presses on the whole window. The callback returns `true`
+/// when it handled the key, which suppresses the browser default.
+pub fn on_keydown(mut callback: impl FnMut(&crate::utils::KeyPress) -> bool + 'static) {
+    use wasm_bindgen::closure::Closure;
+
+    let window = match web_sys::window() {
+        Some(w) => w,
+        None => return,
+    };
+
+    let closure = Closure::wrap(Box::new(move |event: web_sys::KeyboardEvent| {
+        let editable = event
+            .target()
+            .and_then(|target| target.dyn_into::<HtmlElement>().ok())
+            .is_some_and(|el| {
+                el.is_content_editable()
+                    || matches!(el.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT")
+            });
+        let press = crate::utils::KeyPress {
+            key: event.key(),
+            ctrl: event.ctrl_key(),
+            alt: event.alt_key(),
+            shift: event.shift_key(),
+            meta: event.meta_key(),
+            editable,
+        };
+        if callback(&press) {
+            event.prevent_default();
+        }
+    }) as Box<dyn FnMut(web_sys::KeyboardEvent)>);
+
+    window
+        .add_event_listener_with_callback("keydown", closure.as_ref().unchecked_ref())
+        .expect("failed to add keydown listener");
+
+    closure.forget();
+}
+
+/// Follow the mouse until the button is released. `on_move` receives client
+/// coordinates; both listeners are removed on mouseup.
+pub fn track_mouse_drag(
+    mut on_move: impl FnMut(f64, f64) + 'static,
+    on_end: impl FnOnce() + 'static,
+) {
+    use wasm_bindgen::closure::Closure;
+
+    let document = match web_sys::window().and_then(|w| w.document()) {
+        Some(d) => d,
+        None => return,
+    };
+
+    let move_closure = Closure::wrap(Box::new(move |event: web_sys::MouseEvent| {
+        event.prevent_default();
+        on_move(event.client_x() as f64, event.client_y() as f64);
+    }) as Box<dyn FnMut(web_sys::MouseEvent)>);
+    let move_fn: js_sys::Function = move_closure
+        .as_ref()
+        .unchecked_ref::<js_sys::Function>()
+        .clone();
+    let _ = document.add_event_listener_with_callback("mousemove", &move_fn);
+
+    let doc = document.clone();
+    let up = Closure::once_into_js(move || {
+        let _ = doc.remove_event_listener_with_callback("mousemove", &move_fn);
+        drop(move_closure);
+        on_end();
+    });
+    let options = web_sys::AddEventListenerOptions::new();
+    options.set_once(true);
+    let _ = document.add_event_listener_with_callback_and_add_event_listener_options(
+        "mouseup",
+        up.unchecked_ref(),
+        &options,
+    );
+}
+
+/// Calls `on_drop` if the HTML drag in progress ends in a drop that no
+/// element stopped from propagating.
+///
+/// The whole page accepts the drop until the drag ends. A drag cancelled
+/// with Escape, or dropped outside the window, never calls `on_drop`.
+pub fn track_page_drop(on_drop: impl FnOnce() + 'static) {
+    use std::cell::Cell;
+
+    use wasm_bindgen::closure::Closure;
+
+    let document = match web_sys::window().and_then(|w| w.document()) {
+        Some(d) => d,
+        None => return,
+    };
+    let dropped = Rc::new(Cell::new(false));
+
+    let over_closure =
+        Closure::wrap(Box::new(|event: web_sys::Event| event.prevent_default())
+            as Box<dyn FnMut(web_sys::Event)>);
+    let drop_closure = {
+        let dropped = dropped.clone();
+        Closure::wrap(Box::new(move |event: web_sys::Event| {
+            event.prevent_default();
+            dropped.set(true);
+        }) as Box<dyn FnMut(web_sys::Event)>)
+    };
+    let over_fn: js_sys::Function = over_closure
+        .as_ref()
+        .unchecked_ref::<js_sys::Function>()
+        .clone();
+    let drop_fn: js_sys::Function = drop_closure
+        .as_ref()
+        .unchecked_ref::<js_sys::Function>()
+        .clone();
+    let _ = document.add_event_listener_with_callback("dragover", &over_fn);
+    let _ = document.add_event_listener_with_callback("drop", &drop_fn);
+
+    let doc = document.clone();
+    let end = Closure::once_into_js(move || {
+        let _ = doc.remove_event_listener_with_callback("dragover", &over_fn);
+        let _ = doc.remove_event_listener_with_callback("drop", &drop_fn);
+        drop((over_closure, drop_closure));
+        if dropped.get() {
+            on_drop();
+        }
+    });
+    let options = web_sys::AddEventListenerOptions::new();
+    options.set_once(true);
+    let _ = document.add_event_listener_with_callback_and_add_event_listener_options(
+        "dragend",
+        end.unchecked_ref(),
+        &options,
+    );
+}
+
+/// Read a value from `localStorage`.
+pub fn local_storage_get(key: &str) -> Option<String> {
+    web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(key).ok().flatten())
+}
+
+/// Write a value to `localStorage`, ignoring quota and privacy-mode errors.
+pub fn local_storage_set(key: &str, value: &str) {
+    if let Some(storage) = web_sys::window().and_then(|w| w.local_storage().ok().flatten()) {
+        let _ = storage.set_item(key, value);
+    }
+}
+
+/// Remove a value from `localStorage`.
+pub fn local_storage_remove(key: &str) {
+    if let Some(storage) = web_sys::window().and_then(|w| w.local_storage().ok().flatten()) {
+        let _ = storage.remove_item(key);
+    }
+}
+
+/// Whether the browser runs on macOS / iOS, where "Mod" shortcuts use Cmd.
+pub fn is_mac() -> bool {
+    web_sys::window()
+        .and_then(|w| w.navigator().platform().ok())
+        .is_some_and(|platform| {
+            let platform = platform.to_lowercase();
+            platform.contains("mac") || platform.contains("iphone") || platform.contains("ipad")
+        })
+}
+
+/// `data-shortcut-scope` values of the focused element and its ancestors,
+/// innermost first.
+pub fn focused_shortcut_scopes() -> Vec<String> {
+    let mut scopes = Vec::new();
+    let mut current = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.active_element());
+    while let Some(el) = current {
+        if let Some(scope) = el.get_attribute(crate::utils::SCOPE_ATTRIBUTE) {
+            scopes.push(scope);
+        }
+        current = el.parent_element();
+    }
+    scopes
+}
+
+/// `data-theme-scope` of the nearest theme scope around `element`.
+fn enclosing_theme_scope(element: &Element) -> Option<String> {
+    let selector = format!("[{}]", crate::theme::THEME_SCOPE_ATTRIBUTE);
+    element_closest(element, &selector)
+        .and_then(|scope| scope.get_attribute(crate::theme::THEME_SCOPE_ATTRIBUTE))
+}
+
+/// Whether the provider root owning `scope` sits inside another scope.
+pub fn theme_scope_is_nested(scope: &str) -> bool {
+    let selector = format!(
+        "[{}=\"{}\"]:not([data-theme-portal])",
+        crate::theme::THEME_SCOPE_ATTRIBUTE,
+        scope
+    );
+    query_selector(&selector)
+        .and_then(|root| root.parent_element())
+        .and_then(|parent| enclosing_theme_scope(&parent))
+        .is_some()
+}
+
+/// Click the first element matching `selector`, even if it is hidden.
+pub fn click_by_selector(selector: &str) {
+    if let Some(el) = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.query_selector(selector).ok().flatten())
+        .and_then(|el| el.dyn_into::<HtmlElement>().ok())
+    {
+        el.click();
+    }
+}
+
+pub fn on_popstate(callback: impl FnMut() + 'static) {
+    use wasm_bindgen::closure::Closure;
+
+    let window = match web_sys::window() {
+        Some(w) => w,
+        None => return,
+    };
+
+    let closure = Closure::wrap(Box::new(callback) as Box<dyn FnMut()>);
+
+    window
+        .add_event_listener_with_callback("popstate", closure.as_ref().unchecked_ref())
+        .expect("failed to add popstate listener");
+
+    closure.forget();
+}
+
+/// Calls `callback` with the new `prefers-color-scheme: dark` match whenever
+/// the OS switches between light and dark.
+///
+/// Returns an id for [`remove_media_listener`].
+pub fn on_color_scheme_change(mut callback: impl FnMut(bool) + 'static) -> u64 {
+    let Some(query) = web_sys::window()
+        .and_then(|w| w.match_media("(prefers-color-scheme: dark)").ok())
+        .flatten()
+    else {
+        return 0;
+    };
+    let query_for_closure = query.clone();
+    add_media_listeners(
+        vec![query],
+        Rc::new(RefCell::new(move || callback(query_for_closure.matches()))),
+    )
+}
+
+/// Calls `callback` when `prefers-contrast` or `forced-colors` changes.
+pub fn on_contrast_change(callback: impl FnMut() + 'static) -> u64 {
+    let Some(window) = web_sys::window() else {
+        return 0;
+    };
+    let queries = ["(prefers-contrast: more)", "(forced-colors: active)"]
+        .into_iter()
+        .filter_map(|query| window.match_media(query).ok().flatten())
+        .collect();
+    add_media_listeners(queries, Rc::new(RefCell::new(callback)))
+}
+
+/// Removes the listeners added by [`on_color_scheme_change`] or
+/// [`on_contrast_change`].
+pub fn remove_media_listener(listener_id: u64) {
+    MEDIA_LISTENERS.with(|map| {
+        for (query, closure) in map.borrow_mut().remove(&listener_id).unwrap_or_default() {
+            let _ = query
+                .remove_event_listener_with_callback("change", closure.as_ref().unchecked_ref());
+        }
+    });
+}
+
+fn add_media_listeners(
+    queries: Vec<web_sys::MediaQueryList>,
+    callback: Rc<RefCell<dyn FnMut()>>,
+) -> u64 {
+    use wasm_bindgen::closure::Closure;
+
+    let listeners = queries
+        .into_iter()
+        .map(|query| {
+            let callback = callback.clone();
+            let closure =
+                Closure::wrap(Box::new(move || (callback.borrow_mut())()) as Box<dyn FnMut()>);
+            let _ =
+                query.add_event_listener_with_callback("change", closure.as_ref().unchecked_ref());
+            (query, closure)
+        })
+        .collect();
+
+    let id = OBSERVER_ID_COUNTER.fetch_add(1, Ordering::SeqCst);
+    MEDIA_LISTENERS.with(|map| map.borrow_mut().insert(id, listeners));
+    id
+}
+
+/// Whether the browser implements `document.startViewTransition`.
+pub fn supports_view_transitions() -> bool {
+    web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| js_sys::Reflect::get(&d, &"startViewTransition".into()).ok())
+        .is_some_and(|start| start.is_function())
+}
+
+/// Runs `update` inside a view transition that reveals the new page in a
+/// circle growing from `origin` (the viewport centre when `None`).
+///
+/// `progress` holds the circle's share of its final radius at evenly spaced
+/// keyframes. `update` runs at once when view transitions are unsupported.
+pub fn start_circular_reveal(
+    origin: Option<(f64, f64)>,
+    duration_ms: f64,
+    progress: &[f64],
+    update: impl FnOnce() + 'static,
+) {
+    use js_sys::{Array, Function, Object, Promise, Reflect};
+    use wasm_bindgen::JsValue;
+    use wasm_bindgen::closure::Closure;
+
+    let Some((window, document)) = web_sys::window().and_then(|w| w.document().map(|d| (w, d)))
+    else {
+        return update();
+    };
+    let Some(start) = Reflect::get(&document, &"startViewTransition".into())
+        .ok()
+        .and_then(|start| start.dyn_into::<Function>().ok())
+    else {
+        return update();
+    };
+
+    let size = |value: Result<JsValue, JsValue>| value.ok().and_then(|v| v.as_f64()).unwrap_or(0.0);
+    let (width, height) = (size(window.inner_width()), size(window.inner_height()));
+    let (x, y) = origin.unwrap_or((width / 2.0, height / 2.0));
+    let radius = x.max(width - x).hypot(y.max(height - y));
+
+    let keyframes = Array::new();
+    for share in progress {
+        let frame = Object::new();
+        let circle = format!("circle({}px at {x}px {y}px)", radius * share);
+        let _ = Reflect::set(&frame, &"clipPath".into(), &circle.into());
+        keyframes.push(&frame);
+    }
+    let options = Object::new();
+    let _ = Reflect::set(&options, &"duration".into(), &duration_ms.into());
+    let _ = Reflect::set(&options, &"easing".into(), &"linear".into());
+    let _ = Reflect::set(
+        &options,
+        &"pseudoElement".into(),
+        &"::view-transition-new(root)".into(),
+    );
+
+    let Ok(transition) = start.call1(&document, &Closure::once_into_js(update)) else {
+        return;
+    };
+    let Some(ready) = Reflect::get(&transition, &"ready".into())
+        .ok()
+        .and_then(|ready| ready.dyn_into::<Promise>().ok())
+    else {
+        return;
+    };
+    let animate = Closure::once(move |_: JsValue| {
+        let Some(root) = document.document_element() else {
+            return;
+        };
+        if let Some(animate) = Reflect::get(&root, &"animate".into())
+            .ok()
+            .and_then(|animate| animate.dyn_into::<Function>().ok())
+        {
+            let _ = animate.call2(&root, &keyframes, &options);
+        }
+    });
+    let _ = ready.then(&animate);
+    animate.forget();
+}
+
+/// Path, query and hash of the current location.
+pub fn location_path() -> String {
+    web_sys::window()
+        .map(|w| w.location())
+        .map(|location| {
+            format!(
+                "{}{}{}",
+                location.pathname().unwrap_or_default(),
+                location.search().unwrap_or_default(),
+                location.hash().unwrap_or_default()
+            )
+        })
+        .unwrap_or_else(|| "/".to_string())
+}
+
+pub fn history_push(path: &str) {
+    if let Some(history) = web_sys::window().and_then(|w| w.history().ok()) {
+        let _ = history.push_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(path));
+    }
+}
+
+pub fn history_replace(path: &str) {
+    if let Some(history) = web_sys::window().and_then(|w| w.history().ok()) {
+        let _ = history.replace_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(path));
+    }
+}
+
 use std::sync::atomic::{AtomicU64, Ordering};
 static OBSERVER_ID_COUNTER: AtomicU64 = AtomicU64::new(1);
 
@@ -177,12 +573,19 @@ pub fn disconnect_resize(observer_id: u64) {
 
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::rc::Rc;
 
 thread_local! {
     static RESIZE_OBSERVERS: RefCell<HashMap<u64, web_sys::ResizeObserver>> = RefCell::new(HashMap::new());
     static MUTATION_OBSERVERS: RefCell<HashMap<u64, web_sys::MutationObserver>> = RefCell::new(HashMap::new());
+    static MEDIA_LISTENERS: RefCell<HashMap<u64, MediaListeners>> = RefCell::new(HashMap::new());
 }
 
+type MediaListeners = Vec<(
+    web_sys::MediaQueryList,
+    wasm_bindgen::closure::Closure<dyn FnMut()>,
+)>;
+
 pub fn create_mutation_observer(callback: impl FnMut() + 'static) -> u64 {
     use wasm_bindgen::closure::Closure;
     use web_sys::MutationObserver;
@@ -387,6 +790,367 @@ pub fn get_element_rect_by_id(id: &str) -> Option<DomRect> {
     get_element_by_id(id).and_then(|el| get_bounding_client_rect(&el))
 }
 
+enum TextControl {
+    Input(web_sys::HtmlInputElement),
+    TextArea(web_sys::HtmlTextAreaElement),
+}
+
+impl TextControl {
+    fn by_id(id: &str) -> Option<Self> {
+        let element = get_element_by_id(id)?;
+        match element.dyn_into::<web_sys::HtmlInputElement>() {
+            Ok(input) => Some(Self::Input(input)),
+            Err(element) => element
+                .dyn_into::<web_sys::HtmlTextAreaElement>()
+                .ok()
+                .map(Self::TextArea),
+        }
+    }
+
+    fn value(&self) -> String {
+        match self {
+            Self::Input(input) => input.value(),
+            Self::TextArea(textarea) => textarea.value(),
+        }
+    }
+
+    fn selection_start(&self) -> Option<u32> {
+        match self {
+            Self::Input(input) => input.selection_start().ok().flatten(),
+            Self::TextArea(textarea) => textarea.selection_start().ok().flatten(),
+        }
+    }
+
+    fn set_value(&self, value: &str, utf16_caret: u32) {
+        match self {
+            Self::Input(input) => {
+                input.set_value(value);
+                let _ = input.set_selection_range(utf16_caret, utf16_caret);
+            }
+            Self::TextArea(textarea) => {
+                textarea.set_value(value);
+                let _ = textarea.set_selection_range(utf16_caret, utf16_caret);
+            }
+        }
+    }
+}
+
+/// Caret position of the `<input>` or `<textarea>` with `id`, in characters.
+pub fn get_input_caret_by_id(id: &str) -> Option<usize> {
+    let control = TextControl::by_id(id)?;
+    let utf16_offset = control.selection_start()? as usize;
+    let mut units = 0;
+    Some(
+        control
+            .value()
+            .chars()
+            .take_while(|c| {
+                units += c.len_utf16();
+                units <= utf16_offset
+            })
+            .count(),
+    )
+}
+
+/// Replace the value of the `<input>` or `<textarea>` with `id` and put the
+/// caret at character offset `caret`.
+pub fn set_input_value_by_id(id: &str, value: &str, caret: usize) {
+    let Some(control) = TextControl::by_id(id) else {
+        return;
+    };
+    let utf16_offset: usize = value.chars().take(caret).map(char::len_utf16).sum();
+    control.set_value(value, utf16_offset as u32);
+}
+
+/// Viewport rect of the caret in the `<textarea>` with `id` (zero width).
+///
+/// Measured with an off-screen mirror element that copies the textarea's
+/// text metrics and wraps the text up to the caret.
+pub fn get_caret_rect_by_id(id: &str) -> Option<DomRect> {
+    const MIRRORED: &[&str] = &[
+        "box-sizing",
+        "width",
+        "font-family",
+        "font-size",
+        "font-weight",
+        "font-style",
+        "line-height",
+        "letter-spacing",
+        "word-spacing",
+        "text-transform",
+        "text-indent",
+        "tab-size",
+        "padding-top",
+        "padding-right",
+        "padding-bottom",
+        "padding-left",
+        "border-top-width",
+        "border-right-width",
+        "border-bottom-width",
+        "border-left-width",
+        "border-style",
+    ];
+
+    let window = web_sys::window()?;
+    let document = window.document()?;
+    let element = get_element_by_id(id)?;
+    let textarea = element.dyn_ref::<web_sys::HtmlTextAreaElement>()?;
+    let computed = window.get_computed_style(&element).ok()??;
+
+    let mirror = document.create_element("div").ok()?;
+    let mirror_html = mirror.dyn_ref::<HtmlElement>()?;
+    let style = mirror_html.style();
+    for property in MIRRORED {
+        let value = computed.get_property_value(property).unwrap_or_default();
+        let _ = style.set_property(property, &value);
+    }
+    let _ = style.set_property("position", "absolute");
+    let _ = style.set_property("visibility", "hidden");
+    let _ = style.set_property("top", "0");
+    let _ = style.set_property("left", "-9999px");
+    let _ = style.set_property("white-space", "pre-wrap");
+    let _ = style.set_property("overflow-wrap", "break-word");
+
+    let value = textarea.value();
+    let caret = textarea.selection_start().ok().flatten().unwrap_or(0) as usize;
+    let before: Vec<u16> = value.encode_utf16().take(caret).collect();
+    mirror.set_text_content(Some(&String::from_utf16_lossy(&before)));
+
+    let marker = document.create_element("span").ok()?;
+    marker.set_text_content(Some("\u{200b}"));
+    let _ = mirror.append_child(&marker);
+    let _ = document.body()?.append_child(&mirror);
+
+    let marker_rect = marker.get_bounding_client_rect();
+    let mirror_rect = mirror.get_bounding_client_rect();
+    let rect = element.get_bounding_client_rect();
+    mirror.remove();
+
+    Some(DomRect {
+        x: rect.x() + marker_rect.x() - mirror_rect.x() - element.scroll_left() as f64,
+        y: rect.y() + marker_rect.y() - mirror_rect.y() - element.scroll_top() as f64,
+        width: 0.0,
+        height: marker_rect.height(),
+    })
+}
+
+/// Set the `rows` of the `<textarea>` with `id` to `fit(lines)`, where
+/// `lines` is the height of its content (soft-wrapped lines included) in
+/// lines. Returns `false` if the textarea could not be measured.
+pub fn fit_textarea_rows_by_id(id: &str, fit: impl FnOnce(u32) -> u32) -> bool {
+    let Some(element) = get_element_by_id(id) else {
+        return false;
+    };
+    let Some(textarea) = element.dyn_ref::<web_sys::HtmlTextAreaElement>() else {
+        return false;
+    };
+    let Some(computed) = web_sys::window().and_then(|w| w.get_computed_style(&element).ok()?)
+    else {
+        return false;
+    };
+    let px = |property: &str| {
+        computed
+            .get_property_value(property)
+            .ok()
+            .and_then(|value| value.trim_end_matches("px").parse::<f64>().ok())
+    };
+    // `line-height: normal` has no pixel value; browsers use about 1.2em
+    let Some(line_height) = px("line-height")
+        .or_else(|| px("font-size").map(|size| size * 1.2))
+        .filter(|height| *height > 0.0)
+    else {
+        return false;
+    };
+    let padding = px("padding-top").unwrap_or(0.0) + px("padding-bottom").unwrap_or(0.0);
+
+    // Collapse to one row so scrollHeight reports the content, not the box
+    textarea.set_rows(1);
+    let lines = ((element.scroll_height() as f64 - padding) / line_height).round();
+    textarea.set_rows(fit(lines.max(1.0) as u32));
+    true
+}
+
+/// Visible elements matching `selector` inside the element with `container_id`.
+fn focus_elements(container_id: &str, selector: &str) -> Vec<HtmlElement> {
+    let Some(container) = get_element_by_id(container_id) else {
+        return Vec::new();
+    };
+    let Ok(nodes) = container.query_selector_all(selector) else {
+        return Vec::new();
+    };
+
+    (0..nodes.length())
+        .filter_map(|i| nodes.item(i))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .filter(|el| el.offset_parent().is_some())
+        .collect()
+}
+
+/// Snapshot of the roving-focus items of a composite widget.
+pub fn focus_items(container_id: &str, selector: &str) -> Vec<crate::utils::FocusItem> {
+    let active = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.active_element());
+
+    focus_elements(container_id, selector)
+        .into_iter()
+        .map(|el| {
+            let attr = |name: &str| el.get_attribute(name);
+            let level = attr("aria-level")
+                .and_then(|level| level.parse::<usize>().ok())
+                .map(|level| level.saturating_sub(1))
+                .or_else(|| attr("data-level").and_then(|level| level.parse().ok()))
+                .unwrap_or(0);
+
+            crate::utils::FocusItem {
+                label: el.text_content().unwrap_or_default().trim().to_string(),
+                disabled: attr("aria-disabled").as_deref() == Some("true")
+                    || el.has_attribute("disabled"),
+                focused: active
+                    .as_ref()
+                    .is_some_and(|active| el.contains(Some(active.unchecked_ref()))),
+                tab_stop: attr("tabindex").as_deref() == Some("0"),
+                level,
+                expanded: attr("aria-expanded").map(|expanded| expanded == "true"),
+            }
+        })
+        .collect()
+}
+
+/// Make item `index` the only tab stop of the widget and focus it.
+pub fn focus_item(container_id: &str, selector: &str, index: usize) {
+    let elements = focus_elements(container_id, selector);
+    let Some(target) = elements.get(index) else {
+        return;
+    };
+    for el in &elements {
+        let _ = el.set_attribute("tabindex", "-1");
+    }
+    let _ = target.set_attribute("tabindex", "0");
+    // Once an item holds the roving tabindex the container leaves the tab order.
+    if let Some(container) = get_element_by_id(container_id)
+        && container.get_attribute("tabindex").as_deref() == Some("0")
+    {
+        let _ = container.set_attribute("tabindex", "-1");
+    }
+    let _ = target.focus();
+}
+
+/// Click item `index`, or its first descendant matching `target`.
+pub fn click_item(container_id: &str, selector: &str, index: usize, target: Option<&str>) {
+    let Some(item) = focus_elements(container_id, selector)
+        .into_iter()
+        .nth(index)
+    else {
+        return;
+    };
+    let el = match target {
+        Some(target) => item
+            .query_selector(target)
+            .ok()
+            .flatten()
+            .and_then(|el| el.dyn_into::<HtmlElement>().ok()),
+        None => Some(item),
+    };
+    if let Some(el) = el {
+        el.click();
+    }
+}
+
+/// Whether the element's computed `direction` is `rtl`.
+pub fn is_rtl(id: &str) -> bool {
+    let Some(el) = get_element_by_id(id) else {
+        return false;
+    };
+    web_sys::window()
+        .and_then(|w| w.get_computed_style(&el).ok().flatten())
+        .and_then(|style| style.get_property_value("direction").ok())
+        .is_some_and(|direction| direction == "rtl")
+}
+
+/// Measure the elements matching `selector` inside `container_id`. The key is
+/// the closest `data-key`; the width includes the gap to the next item.
+pub fn overflow_items(container_id: &str, selector: &str) -> Vec<crate::navigation::OverflowItem> {
+    let Some(container) = get_element_by_id(container_id) else {
+        return Vec::new();
+    };
+    let Ok(nodes) = container.query_selector_all(selector) else {
+        return Vec::new();
+    };
+    let elements: Vec<Element> = (0..nodes.length())
+        .filter_map(|i| nodes.item(i))
+        .filter_map(|node| node.dyn_into::<Element>().ok())
+        .collect();
+
+    elements
+        .iter()
+        .enumerate()
+        .map(|(i, el)| {
+            let rect = el.get_bounding_client_rect();
+            let width = match elements.get(i + 1) {
+                Some(next) => {
+                    let gap = next.get_bounding_client_rect().left() - rect.right();
+                    rect.width() + gap.max(0.0)
+                }
+                None => rect.width(),
+            };
+            crate::navigation::OverflowItem {
+                key: el
+                    .closest("[data-key]")
+                    .ok()
+                    .flatten()
+                    .and_then(|keyed| keyed.get_attribute("data-key"))
+                    .unwrap_or_default(),
+                label: el.text_content().unwrap_or_default().trim().to_string(),
+                width,
+            }
+        })
+        .collect()
+}
+
+/// `(scroll_left, client_width, scroll_width)` of an element.
+pub fn get_scroll_extent_by_id(id: &str) -> Option<(f64, f64, f64)> {
+    let el = get_element_by_id(id)?;
+    Some((
+        el.scroll_left() as f64,
+        el.client_width() as f64,
+        el.scroll_width() as f64,
+    ))
+}
+
+/// `(scroll_top, client_width, client_height)` of a scroll container.
+pub fn get_viewport_by_id(id: &str) -> Option<(f64, f64, f64)> {
+    let el = get_element_by_id(id)?;
+    Some((
+        el.scroll_top() as f64,
+        el.client_width() as f64,
+        el.client_height() as f64,
+    ))
+}
+
+/// Jumps (without smooth scrolling) to `top`.
+pub fn set_scroll_top_by_id(id: &str, top: f64) {
+    if let Some(el) = get_element_by_id(id) {
+        el.set_scroll_top(top.round() as i32);
+    }
+}
+
+/// Replaces the inline style of the element `id`, bypassing a re-render
+pub fn set_style_by_id(id: &str, style: &str) {
+    if let Some(el) = get_element_by_id(id) {
+        let _ = el.set_attribute("style", style);
+    }
+}
+
+pub fn scroll_left_by_id(id: &str, left: f64) {
+    if let Some(el) = get_element_by_id(id) {
+        let options = web_sys::ScrollToOptions::new();
+        options.set_left(left);
+        options.set_behavior(web_sys::ScrollBehavior::Smooth);
+        el.scroll_to_with_scroll_to_options(&options);
+    }
+}
+
 pub fn get_scroll_top_by_selector(selector: &str) -> f64 {
     web_sys::window()
         .and_then(|w| w.document())
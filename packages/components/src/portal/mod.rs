@@ -105,6 +105,21 @@
 //! }
 //! ```
 //!
+//! #### Virtual Anchors
+//!
+//! Overlays can also be positioned against a [`VirtualAnchor`], a viewport rect
+//! with no DOM node behind it (text selections, canvas points). Its `as_rect()`
+//! tuple is passed as the entry's `trigger_rect`.
+//!
+//! ```rust
+//! Popover {
+//!     trigger_mode: PopoverTriggerMode::Manual,
+//!     anchor: Some(VirtualAnchor::from_point(x, y)),
+//!     open: true,
+//!     // ...
+//! }
+//! ```
+//!
 //! ## Placement Options
 //!
 //! 12 placement directions are supported:
@@ -183,8 +198,8 @@ pub mod render;
 pub mod types;
 
 pub use positioning::calculate_position;
-pub use provider::{PortalContext, PortalProvider, generate_portal_id, use_portal};
+pub use provider::{PortalContext, PortalProvider, generate_portal_id, try_use_portal, use_portal};
 pub use types::{
     ModalAnimationState, PORTAL_ID_COUNTER, PortalEntry, PortalMaskMode, PortalPositionStrategy,
-    ToastPosition, TriggerPlacement,
+    ToastPosition, TriggerPlacement, VirtualAnchor,
 };
@@ -6,6 +6,7 @@ use std::sync::atomic::Ordering;
 use super::render::{PortalRender, PortalRenderProps};
 use crate::portal::types::{ModalAnimationState, PORTAL_ID_COUNTER, PortalEntry};
 use crate::prelude::*;
+use crate::theme::try_use_theme;
 
 #[derive(Clone)]
 pub struct PortalContext {
@@ -14,6 +15,9 @@ pub struct PortalContext {
     pub remove_entry: Callback<String>,
     pub clear_all: Callback<()>,
     pub start_close_animation: Callback<String>,
+    /// Theme scope around the component that took the context, for the
+    /// entries it opens to carry
+    pub theme_scope: Option<String>,
 }
 
 #[component]
@@ -29,13 +33,7 @@ pub fn PortalProvider(children: Element) -> Element {
     let mut entries_for_remove = entries.clone();
     let remove_entry = Callback::new(move |id: String| {
         let mut e = entries_for_remove.write();
-        e.retain(|entry| match entry {
-            PortalEntry::Modal { id: entry_id, .. } => entry_id != &id,
-            PortalEntry::Dropdown { id: entry_id, .. } => entry_id != &id,
-            PortalEntry::Toast { id: entry_id, .. } => entry_id != &id,
-            PortalEntry::Popover { id: entry_id, .. } => entry_id != &id,
-            PortalEntry::Tooltip { id: entry_id, .. } => entry_id != &id,
-        });
+        e.retain(|entry| entry.id() != id);
     });
 
     let mut entries_for_clear = entries.clone();
@@ -70,6 +68,7 @@ pub fn PortalProvider(children: Element) -> Element {
         remove_entry,
         clear_all,
         start_close_animation,
+        theme_scope: None,
     });
 
     rsx! {
@@ -79,8 +78,17 @@ pub fn PortalProvider(children: Element) -> Element {
 }
 
 pub fn use_portal() -> PortalContext {
-    let ctx = use_context::<PortalContext>().expect("PortalContext not found");
-    ctx.get().clone()
+    try_use_portal().expect("PortalContext not found")
+}
+
+/// [`use_portal`] that returns `None` outside a `PortalProvider`
+///
+/// Entries opened through the returned context should carry its
+/// `theme_scope`, the scope of the provider around the caller.
+pub fn try_use_portal() -> Option<PortalContext> {
+    let mut portal = use_context::<PortalContext>()?.get().clone();
+    portal.theme_scope = try_use_theme().map(|theme| theme.scope);
+    Some(portal)
 }
 
 pub fn generate_portal_id() -> String {
@@ -19,6 +19,8 @@ use crate::portal::types::{
     TriggerPlacement,
 };
 use crate::prelude::*;
+use crate::theme::theme_scope_wrapper;
+use crate::utils::use_modal_scope;
 
 fn use_animated_portal_entry(
     id: String,
@@ -123,7 +125,7 @@ pub fn PortalRender(#[props(default)] entries: Option<Signal<Vec<PortalEntry>>>)
         .enumerate()
         .map(|(index, entry)| {
             let z_index = 10000 + index;
-            match entry {
+            let render = || match entry {
                 PortalEntry::Modal {
                     id,
                     title,
@@ -134,6 +136,7 @@ pub fn PortalRender(#[props(default)] entries: Option<Signal<Vec<PortalEntry>>>)
                     size,
                     children,
                     animation_state,
+                    ..
                 } => rsx! {
                     ModalPortalEntry {
                         z_index,
@@ -155,6 +158,7 @@ pub fn PortalRender(#[props(default)] entries: Option<Signal<Vec<PortalEntry>>>)
                     children,
                     trigger_rect,
                     close_on_select,
+                    ..
                 } => rsx! {
                     DropdownPortalEntry {
                         z_index,
@@ -170,6 +174,7 @@ pub fn PortalRender(#[props(default)] entries: Option<Signal<Vec<PortalEntry>>>)
                     id,
                     position,
                     children,
+                    ..
                 } => rsx! {
                     ToastPortalEntry {
                         z_index,
@@ -189,7 +194,9 @@ pub fn PortalRender(#[props(default)] entries: Option<Signal<Vec<PortalEntry>>>)
                     close_on_select,
                     on_close,
                     close_requested,
+                    on_hover,
                     children,
+                    ..
                 } => rsx! {
                     PopoverPortalEntry {
                         z_index,
@@ -203,6 +210,7 @@ pub fn PortalRender(#[props(default)] entries: Option<Signal<Vec<PortalEntry>>>)
                         close_on_select: *close_on_select,
                         on_close: on_close.clone(),
                         close_requested: Some(close_requested.clone()),
+                        on_hover: on_hover.clone(),
                         children: children.clone()
                     }
                 },
@@ -211,7 +219,11 @@ pub fn PortalRender(#[props(default)] entries: Option<Signal<Vec<PortalEntry>>>)
                     trigger_rect,
                     placement,
                     content,
+                    rich_content,
                     arrow,
+                    interactive,
+                    on_hover,
+                    ..
                 } => rsx! {
                     TooltipPortalEntry {
                         z_index,
@@ -219,9 +231,24 @@ pub fn PortalRender(#[props(default)] entries: Option<Signal<Vec<PortalEntry>>>)
                         trigger_rect: *trigger_rect,
                         placement: *placement,
                         content: content.clone(),
-                        arrow: *arrow
+                        rich_content: rich_content.clone(),
+                        arrow: *arrow,
+                        interactive: *interactive,
+                        on_hover: on_hover.clone()
                     }
                 },
+                PortalEntry::Floating { id, children, .. } => rsx! {
+                    FloatingPortalEntry {
+                        z_index,
+                        id: id.clone(),
+                        children: children.clone()
+                    }
+                },
+            };
+            // Entries render in the theme scope they were opened from
+            match entry.theme_scope() {
+                Some(scope) => theme_scope_wrapper(scope, render),
+                None => render(),
             }
         })
         .collect();
@@ -251,6 +278,8 @@ fn ModalPortalEntry(
     let _internal_animation_state = use_signal(|| animation_state);
     let (_, button_close, computed_opacity_scale) =
         use_animated_portal_entry(id.clone(), animation_state, "Modal");
+    // While open, the modal's scope suppresses page-level shortcuts.
+    use_modal_scope(&id);
 
     // Clone button_close for the overlay click handler
     let button_close_for_overlay = button_close.clone();
@@ -550,6 +579,21 @@ fn ToastPortalEntry(
     }
 }
 
+#[component]
+fn FloatingPortalEntry(
+    #[props(default)] z_index: usize,
+    #[props(default)] id: String,
+    #[props(default)] children: Element,
+) -> Element {
+    rsx! {
+        div {
+            "data-portal-id": id,
+            style: "position: relative; z-index: {z_index}; pointer-events: auto;",
+            {children}
+        }
+    }
+}
+
 #[component]
 fn PopoverPortalEntry(
     #[props(default)] z_index: usize,
@@ -563,6 +607,7 @@ fn PopoverPortalEntry(
     #[props(default)] close_on_select: bool,
     #[props(default)] on_close: Option<Callback<()>>,
     #[props(default)] close_requested: Option<Signal<bool>>,
+    #[props(default)] on_hover: Option<Callback<bool>>,
     #[props(default)] children: Element,
 ) -> Element {
     let (mut animation_state, close_popover, computed_opacity_scale) =
@@ -791,11 +836,26 @@ fn PopoverPortalEntry(
     let close_popover_for_content = close_popover.clone();
     let on_close_for_content = on_close.clone();
 
+    // Hover-triggered popovers report pointer presence so the owner's hover
+    // intent can keep them open while the content is being interacted with.
+    let on_hover_enter = on_hover.clone();
+    let on_hover_leave = on_hover;
+
     let popover_content = rsx! {
         div {
             class: popover_classes,
             style: popover_style,
             "data-open": "true",
+            onmouseenter: move |_| {
+                if let Some(handler) = on_hover_enter.as_ref() {
+                    handler.call(true);
+                }
+            },
+            onmouseleave: move |_| {
+                if let Some(handler) = on_hover_leave.as_ref() {
+                    handler.call(false);
+                }
+            },
 
             {title_el}
 
@@ -840,7 +900,10 @@ fn TooltipPortalEntry(
     #[props(default)] trigger_rect: Option<(f64, f64, f64, f64)>,
     #[props(default)] placement: TriggerPlacement,
     #[props(default)] content: String,
+    #[props(default)] rich_content: Option<Element>,
     #[props(default)] arrow: bool,
+    #[props(default)] interactive: bool,
+    #[props(default)] on_hover: Option<Callback<bool>>,
 ) -> Element {
     let viewport_width = use_signal(|| inner_width() as f64);
     let viewport_height = use_signal(|| inner_height() as f64);
@@ -900,14 +963,25 @@ fn TooltipPortalEntry(
         .add(TooltipClass::Tooltip)
         .add(placement_class)
         .add(TooltipClass::TooltipVisible)
+        .add_if(TooltipClass::TooltipInteractive, || interactive)
+        .add_if(TooltipClass::TooltipRich, || rich_content.is_some())
         .build();
 
     let tooltip_style = format!(
-        "{} z-index: {}; pointer-events: none;",
+        "{} z-index: {}; pointer-events: {};",
         position_style.read(),
-        z_index
+        z_index,
+        if interactive { "auto" } else { "none" }
     );
 
+    let content_el = match rich_content {
+        Some(rich) => rich,
+        None => VNode::Text(VText::new(&content)),
+    };
+
+    let on_hover_enter = on_hover.clone();
+    let on_hover_leave = on_hover;
+
     // Build arrow element outside rsx!
     let arrow_el = if arrow {
         rsx! {
@@ -918,9 +992,22 @@ fn TooltipPortalEntry(
     };
 
     rsx! {
-        div { class: tooltip_classes, style: tooltip_style,
+        div {
+            class: tooltip_classes,
+            style: tooltip_style,
+            role: "tooltip",
+            onmouseenter: move |_| {
+                if let Some(handler) = on_hover_enter.as_ref() {
+                    handler.call(true);
+                }
+            },
+            onmouseleave: move |_| {
+                if let Some(handler) = on_hover_leave.as_ref() {
+                    handler.call(false);
+                }
+            },
 
-            div { class: TooltipClass::TooltipContent.as_class(), "{content}" }
+            div { class: TooltipClass::TooltipContent.as_class(), {content_el} }
 
             {arrow_el}
         }
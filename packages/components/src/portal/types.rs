@@ -40,6 +40,56 @@ pub enum TriggerPlacement {
     Center,
 }
 
+/// A viewport-space rectangle that overlays can anchor to without a DOM node.
+///
+/// Used for text selections, caret positions and points on a canvas (e.g. the
+/// node-graph viewport), where there is no trigger element to measure.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct VirtualAnchor {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl VirtualAnchor {
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Zero-sized anchor at a single point (cursor, canvas coordinate).
+    pub fn from_point(x: f64, y: f64) -> Self {
+        Self::new(x, y, 0.0, 0.0)
+    }
+
+    /// Shift the anchor, e.g. to convert canvas-local coordinates to viewport ones.
+    pub fn translate(self, dx: f64, dy: f64) -> Self {
+        Self::new(self.x + dx, self.y + dy, self.width, self.height)
+    }
+
+    /// The `(x, y, width, height)` tuple used by portal entries as `trigger_rect`.
+    pub fn as_rect(&self) -> (f64, f64, f64, f64) {
+        (self.x, self.y, self.width, self.height)
+    }
+}
+
+impl From<(f64, f64, f64, f64)> for VirtualAnchor {
+    fn from((x, y, width, height): (f64, f64, f64, f64)) -> Self {
+        Self::new(x, y, width, height)
+    }
+}
+
+impl From<tairitsu_vdom::platform::DomRect> for VirtualAnchor {
+    fn from(rect: tairitsu_vdom::platform::DomRect) -> Self {
+        Self::new(rect.x, rect.y, rect.width, rect.height)
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Debug, Default)]
 pub enum ModalAnimationState {
     #[default]
@@ -52,6 +102,7 @@ pub enum ModalAnimationState {
 pub enum PortalEntry {
     Modal {
         id: String,
+        theme_scope: Option<String>,
         title: Option<String>,
         position: ModalPosition,
         mask_mode: MaskMode,
@@ -63,6 +114,7 @@ pub enum PortalEntry {
     },
     Dropdown {
         id: String,
+        theme_scope: Option<String>,
         strategy: PortalPositionStrategy,
         mask_mode: PortalMaskMode,
         children: Element,
@@ -71,11 +123,13 @@ pub enum PortalEntry {
     },
     Toast {
         id: String,
+        theme_scope: Option<String>,
         position: ToastPosition,
         children: Element,
     },
     Popover {
         id: String,
+        theme_scope: Option<String>,
         trigger_rect: Option<(f64, f64, f64, f64)>,
         preferred_placements: Vec<PopoverPlacement>,
         offset: f64,
@@ -85,17 +139,64 @@ pub enum PortalEntry {
         close_on_select: bool,
         on_close: Option<Callback<()>>,
         close_requested: Signal<bool>,
+        on_hover: Option<Callback<bool>>,
         children: Element,
     },
     Tooltip {
         id: String,
+        theme_scope: Option<String>,
         trigger_rect: Option<(f64, f64, f64, f64)>,
         placement: TriggerPlacement,
         content: String,
+        rich_content: Option<Element>,
         arrow: bool,
+        interactive: bool,
+        on_hover: Option<Callback<bool>>,
+    },
+    /// Free-positioned content such as an in-app window; `children` place
+    /// themselves (e.g. with `position: fixed`)
+    Floating {
+        id: String,
+        theme_scope: Option<String>,
+        children: Element,
     },
 }
 
+impl PortalEntry {
+    pub fn id(&self) -> &str {
+        match self {
+            PortalEntry::Modal { id, .. }
+            | PortalEntry::Dropdown { id, .. }
+            | PortalEntry::Toast { id, .. }
+            | PortalEntry::Popover { id, .. }
+            | PortalEntry::Tooltip { id, .. }
+            | PortalEntry::Floating { id, .. } => id,
+        }
+    }
+
+    /// Theme scope the entry renders in, when opened inside a provider
+    pub fn theme_scope(&self) -> Option<&str> {
+        match self {
+            PortalEntry::Modal { theme_scope, .. }
+            | PortalEntry::Dropdown { theme_scope, .. }
+            | PortalEntry::Toast { theme_scope, .. }
+            | PortalEntry::Popover { theme_scope, .. }
+            | PortalEntry::Tooltip { theme_scope, .. }
+            | PortalEntry::Floating { theme_scope, .. } => theme_scope.as_deref(),
+        }
+    }
+
+    /// Rect of the element the entry was opened from, when known
+    pub fn trigger_rect(&self) -> Option<(f64, f64, f64, f64)> {
+        match self {
+            PortalEntry::Dropdown { trigger_rect, .. }
+            | PortalEntry::Popover { trigger_rect, .. }
+            | PortalEntry::Tooltip { trigger_rect, .. } => *trigger_rect,
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Debug, Default)]
 pub enum PortalMaskMode {
     #[default]
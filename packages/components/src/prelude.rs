@@ -89,12 +89,13 @@ pub use crate::data::{
 // Display component Props
 pub use crate::display::{
     CalendarProps, CarouselProps, CommentProps, DragLayerProps, EmptyProps, QRCodeProps,
-    SkeletonCardProps, SkeletonProps, SkeletonTableProps, TagProps, TimelineItemProps,
-    TimelineProps, UserGuideProps, ZoomControlsProps,
+    ShortcutHintProps, SkeletonCardProps, SkeletonProps, SkeletonTableProps, TagProps,
+    TimelineItemProps, TimelineProps, UserGuideProps, ZoomControlsProps,
 };
 // Entry component Props
 pub use crate::entry::{
-    AutoCompleteProps, CascaderProps, NumberInputProps, SearchProps, TransferProps,
+    AutoCompleteProps, CascaderProps, CommandPaletteProps, NumberInputProps, SearchProps,
+    TagInputProps, TransferProps,
 };
 // Feedback component Props
 pub use crate::feedback::{
@@ -103,13 +104,14 @@ pub use crate::feedback::{
 };
 // Layout component Props
 pub use crate::layout::{
-    ContainerProps, DividerProps as LayoutDividerProps, FlexBoxProps, FooterProps, SpaceProps,
+    ContainerProps, DividerProps as LayoutDividerProps, DockProps, FlexBoxProps, FooterProps,
+    HideProps, MasonryProps, ShowProps, SpaceProps, SplitPaneProps, VirtualGridProps,
 };
 // Navigation component Props
 pub use crate::navigation::{
     BreadcrumbItemProps, BreadcrumbProps, MenuItemProps, MenuProps, SidebarItemProps,
     SidebarLeafProps, SidebarProps, SidebarSectionProps, StepsProps, SubMenuProps, TabPaneProps,
-    TabsProps,
+    TabsProps, WizardProps,
 };
 // Production component Props
 pub use crate::production::{
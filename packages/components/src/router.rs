@@ -0,0 +1,467 @@
+//! Router abstraction for navigation components
+//!
+//! `Menu`, `Sidebar`, `Breadcrumb` and `Tabs` work on plain string keys. When a
+//! [`RouterProvider`] is mounted, items with a `to` path derive their active
+//! state from the current route and navigate on click.
+//!
+//! Two implementations ship with the crate:
+//!
+//! - [`HistoryRouter`] - browser History API (`pushState` / `popstate`)
+//! - [`MemoryRouter`] - in-memory stack for tests and SSR
+//!
+//! Any other router can be plugged in by implementing [`Router`].
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! rsx! {
+//!     RouterProvider { router: Some(RouterHandle::history()),
+//!         Menu {
+//!             MenuItem { item_key: "home", to: Some("/".to_string()), "Home" }
+//!             MenuItem { item_key: "docs", to: Some("/docs".to_string()), "Docs" }
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Deref;
+use std::rc::Rc;
+
+use tairitsu_vdom::runtime;
+
+use crate::platform;
+use crate::prelude::*;
+
+pub type RouteListener = dyn Fn(&str);
+
+pub type SubscriptionId = u64;
+
+/// Source of the current path that navigation components follow.
+pub trait Router {
+    fn current_path(&self) -> String;
+
+    /// Navigate to `path`, adding a history entry.
+    fn navigate(&self, path: &str);
+
+    /// Navigate to `path`, replacing the current history entry.
+    fn replace(&self, path: &str);
+
+    /// Call `listener` with the new path after every route change.
+    fn subscribe(&self, listener: Rc<RouteListener>) -> SubscriptionId;
+
+    fn unsubscribe(&self, id: SubscriptionId);
+}
+
+#[derive(Default)]
+struct Listeners {
+    next_id: SubscriptionId,
+    entries: Vec<(SubscriptionId, Rc<RouteListener>)>,
+}
+
+impl Listeners {
+    fn add(&mut self, listener: Rc<RouteListener>) -> SubscriptionId {
+        self.next_id += 1;
+        self.entries.push((self.next_id, listener));
+        self.next_id
+    }
+
+    fn remove(&mut self, id: SubscriptionId) {
+        self.entries.retain(|(entry_id, _)| *entry_id != id);
+    }
+}
+
+/// Notify listeners without holding the borrow, so they may (un)subscribe.
+fn notify(listeners: &RefCell<Listeners>, path: &str) {
+    let snapshot: Vec<Rc<RouteListener>> = listeners
+        .borrow()
+        .entries
+        .iter()
+        .map(|(_, listener)| listener.clone())
+        .collect();
+    for listener in snapshot {
+        listener(path);
+    }
+}
+
+struct MemoryHistory {
+    entries: Vec<String>,
+    index: usize,
+}
+
+/// In-memory router with back/forward support, for tests and SSR.
+#[derive(Clone)]
+pub struct MemoryRouter {
+    history: Rc<RefCell<MemoryHistory>>,
+    listeners: Rc<RefCell<Listeners>>,
+}
+
+impl MemoryRouter {
+    pub fn new(initial_path: impl Into<String>) -> Self {
+        Self {
+            history: Rc::new(RefCell::new(MemoryHistory {
+                entries: vec![initial_path.into()],
+                index: 0,
+            })),
+            listeners: Rc::default(),
+        }
+    }
+
+    /// All history entries, oldest first.
+    pub fn entries(&self) -> Vec<String> {
+        self.history.borrow().entries.clone()
+    }
+
+    pub fn can_go_back(&self) -> bool {
+        self.history.borrow().index > 0
+    }
+
+    pub fn can_go_forward(&self) -> bool {
+        let history = self.history.borrow();
+        history.index + 1 < history.entries.len()
+    }
+
+    /// Step back one entry. Returns `false` at the start of the history.
+    pub fn back(&self) -> bool {
+        self.go(-1)
+    }
+
+    /// Step forward one entry. Returns `false` at the end of the history.
+    pub fn forward(&self) -> bool {
+        self.go(1)
+    }
+
+    fn go(&self, delta: isize) -> bool {
+        let path = {
+            let mut history = self.history.borrow_mut();
+            let target = history.index as isize + delta;
+            if target < 0 || target as usize >= history.entries.len() {
+                return false;
+            }
+            history.index = target as usize;
+            history.entries[history.index].clone()
+        };
+        notify(&self.listeners, &path);
+        true
+    }
+}
+
+impl Default for MemoryRouter {
+    fn default() -> Self {
+        Self::new("/")
+    }
+}
+
+impl Router for MemoryRouter {
+    fn current_path(&self) -> String {
+        let history = self.history.borrow();
+        history.entries[history.index].clone()
+    }
+
+    fn navigate(&self, path: &str) {
+        {
+            let mut history = self.history.borrow_mut();
+            let next = history.index + 1;
+            history.entries.truncate(next);
+            history.entries.push(path.to_string());
+            history.index = next;
+        }
+        notify(&self.listeners, path);
+    }
+
+    fn replace(&self, path: &str) {
+        {
+            let mut history = self.history.borrow_mut();
+            let index = history.index;
+            history.entries[index] = path.to_string();
+        }
+        notify(&self.listeners, path);
+    }
+
+    fn subscribe(&self, listener: Rc<RouteListener>) -> SubscriptionId {
+        self.listeners.borrow_mut().add(listener)
+    }
+
+    fn unsubscribe(&self, id: SubscriptionId) {
+        self.listeners.borrow_mut().remove(id);
+    }
+}
+
+/// Router backed by the browser History API.
+///
+/// Each instance registers its own `popstate` listener, so create one per
+/// app; [`RouterHandle::history`] hands out a shared instance. On non-browser
+/// targets the location is always `/` and navigation only notifies
+/// subscribers.
+#[derive(Clone)]
+pub struct HistoryRouter {
+    listeners: Rc<RefCell<Listeners>>,
+}
+
+impl HistoryRouter {
+    pub fn new() -> Self {
+        let listeners: Rc<RefCell<Listeners>> = Rc::default();
+        let listeners_for_popstate = listeners.clone();
+        platform::on_popstate(move || {
+            notify(&listeners_for_popstate, &platform::location_path());
+        });
+        Self { listeners }
+    }
+}
+
+impl Default for HistoryRouter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Router for HistoryRouter {
+    fn current_path(&self) -> String {
+        platform::location_path()
+    }
+
+    fn navigate(&self, path: &str) {
+        platform::history_push(path);
+        notify(&self.listeners, path);
+    }
+
+    fn replace(&self, path: &str) {
+        platform::history_replace(path);
+        notify(&self.listeners, path);
+    }
+
+    fn subscribe(&self, listener: Rc<RouteListener>) -> SubscriptionId {
+        self.listeners.borrow_mut().add(listener)
+    }
+
+    fn unsubscribe(&self, id: SubscriptionId) {
+        self.listeners.borrow_mut().remove(id);
+    }
+}
+
+/// Shared, cloneable handle to a [`Router`], usable as a prop.
+#[derive(Clone)]
+pub struct RouterHandle(Rc<dyn Router>);
+
+impl RouterHandle {
+    pub fn new(router: impl Router + 'static) -> Self {
+        Self(Rc::new(router))
+    }
+
+    /// Shared [`HistoryRouter`] for the current thread.
+    pub fn history() -> Self {
+        thread_local! {
+            static HISTORY: RouterHandle = RouterHandle::new(HistoryRouter::new());
+        }
+        HISTORY.with(Clone::clone)
+    }
+
+    pub fn memory(initial_path: impl Into<String>) -> Self {
+        Self::new(MemoryRouter::new(initial_path))
+    }
+}
+
+impl Deref for RouterHandle {
+    type Target = dyn Router;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_ref()
+    }
+}
+
+impl PartialEq for RouterHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for RouterHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RouterHandle(..)")
+    }
+}
+
+/// Context provided by [`RouterProvider`].
+#[derive(Clone)]
+pub struct RouterContext {
+    pub router: RouterHandle,
+    /// Current path, updated on every route change
+    pub path: Signal<String>,
+}
+
+impl RouterContext {
+    pub fn navigate(&self, path: &str) {
+        self.router.navigate(path);
+    }
+
+    pub fn is_active(&self, target: &str, exact: bool) -> bool {
+        is_route_active(target, &self.path.get(), exact)
+    }
+}
+
+#[define_props]
+pub struct RouterProviderProps {
+    /// Router to follow; defaults to a [`HistoryRouter`]
+    pub router: Option<RouterHandle>,
+
+    pub children: Element,
+}
+
+/// Route listener of a [`RouterProvider`], unsubscribed when dropped.
+struct RouteSubscription {
+    context: RouterContext,
+    id: SubscriptionId,
+}
+
+impl RouteSubscription {
+    fn new(router: RouterHandle) -> Self {
+        let path = Signal::new(router.current_path());
+        let path_for_listener = path.clone();
+        let id = router.subscribe(Rc::new(move |next: &str| {
+            path_for_listener.set(next.to_string())
+        }));
+        Self {
+            context: RouterContext { router, path },
+            id,
+        }
+    }
+}
+
+impl Drop for RouteSubscription {
+    fn drop(&mut self) {
+        self.context.router.unsubscribe(self.id);
+    }
+}
+
+#[component]
+pub fn RouterProvider(props: RouterProviderProps) -> Element {
+    let router = props.router.unwrap_or_else(RouterHandle::history);
+
+    // Nested providers for the same router share the outer subscription.
+    let context = match use_router().filter(|ctx| ctx.router == router) {
+        Some(context) => context,
+        None => {
+            let create = || Rc::new(RefCell::new(None::<RouteSubscription>));
+            // Hook slots are cleared on unmount, dropping the subscription
+            // with them.
+            let slot = match runtime::active_component_id() {
+                Some(component) => runtime::hook_slot(component, "hk-router", create),
+                None => create(),
+            };
+            let mut current = slot.borrow_mut();
+            if current
+                .as_ref()
+                .is_some_and(|subscription| subscription.context.router != router)
+            {
+                *current = None;
+            }
+            current
+                .get_or_insert_with(|| RouteSubscription::new(router))
+                .context
+                .clone()
+        }
+    };
+
+    use_context_provider(context);
+
+    props.children
+}
+
+pub fn use_router() -> Option<RouterContext> {
+    use_context::<RouterContext>().map(|ctx| ctx.get().clone())
+}
+
+/// Current path without query string or fragment.
+fn strip_path(path: &str) -> &str {
+    let end = path.find(['?', '#']).unwrap_or(path.len());
+    &path[..end]
+}
+
+fn segments(path: &str) -> Vec<&str> {
+    strip_path(path)
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect()
+}
+
+/// Whether a navigation target is active for `current`.
+///
+/// Non-exact matches also accept nested paths (`/docs` is active on
+/// `/docs/intro`); the root path `/` only matches exactly.
+pub fn is_route_active(target: &str, current: &str, exact: bool) -> bool {
+    let target = segments(target);
+    let current = segments(current);
+    if exact || target.is_empty() {
+        target == current
+    } else {
+        current.starts_with(&target)
+    }
+}
+
+/// Match `path` against a route pattern such as `/users/:id`.
+///
+/// Returns the captured parameters on success.
+pub fn match_route(pattern: &str, path: &str) -> Option<HashMap<String, String>> {
+    let pattern = segments(pattern);
+    let path = segments(path);
+    if pattern.len() != path.len() {
+        return None;
+    }
+
+    let mut params = HashMap::new();
+    for (expected, actual) in pattern.iter().zip(&path) {
+        if let Some(name) = expected.strip_prefix(':') {
+            params.insert(name.to_string(), actual.to_string());
+        } else if expected != actual {
+            return None;
+        }
+    }
+    Some(params)
+}
+
+/// Entry of a route table used to generate breadcrumbs.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Route {
+    /// Pattern, e.g. `/users/:id`
+    pub path: String,
+    /// Title; `:param` placeholders are filled from the matched path
+    pub title: String,
+}
+
+impl Route {
+    pub fn new(path: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            title: title.into(),
+        }
+    }
+}
+
+/// Resolved breadcrumb for one level of the current path.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Crumb {
+    pub path: String,
+    pub title: String,
+}
+
+/// Breadcrumbs for every prefix of `path` that matches a route, root first.
+pub fn breadcrumb_trail(routes: &[Route], path: &str) -> Vec<Crumb> {
+    let parts = segments(path);
+    (0..=parts.len())
+        .filter_map(|depth| {
+            let prefix = format!("/{}", parts[..depth].join("/"));
+            routes.iter().find_map(|route| {
+                let params = match_route(&route.path, &prefix)?;
+                let title = params.iter().fold(route.title.clone(), |title, (k, v)| {
+                    title.replace(&format!(":{k}"), v)
+                });
+                Some(Crumb {
+                    path: prefix.clone(),
+                    title,
+                })
+            })
+        })
+        .collect()
+}
@@ -137,12 +137,14 @@ impl StyleRegistry {
     pub fn register_navigation_components(&mut self) {
         use crate::navigation::{
             BreadcrumbComponent, MenuComponent, SidebarComponent, StepsComponent, TabsComponent,
+            WizardComponent,
         };
         MenuComponent::register(self);
         TabsComponent::register(self);
         BreadcrumbComponent::register(self);
         SidebarComponent::register(self);
         StepsComponent::register(self);
+        WizardComponent::register(self);
     }
 
     #[cfg(not(feature = "navigation"))]
@@ -154,13 +156,15 @@ impl StyleRegistry {
     #[cfg(feature = "display")]
     pub fn register_display_components(&mut self) {
         use crate::display::{
-            CommentComponent, EmptyComponent, QRCodeComponent, SkeletonComponent, TagComponent,
+            CommentComponent, EmptyComponent, QRCodeComponent, ShortcutHintComponent,
+            SkeletonComponent, TagComponent,
         };
         TagComponent::register(self);
         EmptyComponent::register(self);
         SkeletonComponent::register(self);
         CommentComponent::register(self);
         QRCodeComponent::register(self);
+        ShortcutHintComponent::register(self);
     }
 
     #[cfg(not(feature = "display"))]
@@ -172,14 +176,16 @@ impl StyleRegistry {
     #[cfg(feature = "entry")]
     pub fn register_entry_components(&mut self) {
         use crate::entry::{
-            AutoCompleteComponent, CascaderComponent, NumberInputComponent, SearchComponent,
-            TransferComponent,
+            AutoCompleteComponent, CascaderComponent, CommandPaletteComponent,
+            NumberInputComponent, SearchComponent, TagInputComponent, TransferComponent,
         };
         NumberInputComponent::register(self);
         SearchComponent::register(self);
         AutoCompleteComponent::register(self);
         CascaderComponent::register(self);
         TransferComponent::register(self);
+        TagInputComponent::register(self);
+        CommandPaletteComponent::register(self);
     }
 
     #[cfg(not(feature = "entry"))]
@@ -187,6 +193,21 @@ impl StyleRegistry {
         // No-op
     }
 
+    /// Registers the layout components whose CSS is not in the bundled
+    /// stylesheets
+    #[cfg(feature = "layout")]
+    pub fn register_layout_components(&mut self) {
+        use crate::layout::{DockComponent, MasonryComponent, SplitPaneComponent};
+        SplitPaneComponent::register(self);
+        DockComponent::register(self);
+        MasonryComponent::register(self);
+    }
+
+    #[cfg(not(feature = "layout"))]
+    pub fn register_layout_components(&mut self) {
+        // No-op
+    }
+
     /// Registers all available components based on feature flags
     pub fn register_available(&mut self) {
         #[cfg(feature = "basic")]
@@ -206,6 +227,9 @@ impl StyleRegistry {
 
         #[cfg(feature = "entry")]
         self.register_entry_components();
+
+        #[cfg(feature = "layout")]
+        self.register_layout_components();
     }
 
     /// Registers all components regardless of feature flags
@@ -216,6 +240,7 @@ impl StyleRegistry {
         self.register_navigation_components();
         self.register_display_components();
         self.register_entry_components();
+        self.register_layout_components();
     }
 }
 
@@ -68,6 +68,20 @@ impl ComponentPalette {
         }
     }
 
+    /// Component colors as CSS system colors, see [`ThemePalette::system_colors`]
+    pub fn system_colors() -> Self {
+        Self {
+            selection_icon_color: "HighlightText".to_string(),
+            selection_background: "Highlight".to_string(),
+            selection_border: "CanvasText".to_string(),
+            selection_surface: "Canvas".to_string(),
+            selection_glow: "transparent".to_string(),
+            input_border: "CanvasText".to_string(),
+            input_focus_border: "Highlight".to_string(),
+            input_background: "Canvas".to_string(),
+        }
+    }
+
     fn compute_defaults(palette: &Palette) -> Self {
         let selection_background = match palette.mode {
             ThemeMode::Light => format!(
@@ -326,6 +340,75 @@ impl ThemePalette {
         }
     }
 
+    /// Palette of CSS system colors, for `forced-colors: active`
+    ///
+    /// Backgrounds use `Canvas`, text and borders `CanvasText`, accents
+    /// `Highlight` and glows are dropped, so nothing the browser does not
+    /// recolor stays in theme colors.
+    pub fn system_colors() -> Self {
+        let system = |keyword: &str| keyword.to_string();
+        ThemePalette {
+            primary: system("Highlight"),
+            secondary: system("Highlight"),
+            accent: system("Highlight"),
+            success: system("Highlight"),
+            warning: system("Highlight"),
+            danger: system("Highlight"),
+            background: system("Canvas"),
+            surface: system("Canvas"),
+            border: system("CanvasText"),
+            text_primary: system("CanvasText"),
+            text_secondary: system("CanvasText"),
+            aside_bg: system("Canvas"),
+            header_bg: system("Canvas"),
+            menu_hover_glow: system("transparent"),
+            button_primary: system("Highlight"),
+            button_primary_dark: system("Highlight"),
+            button_primary_light: system("Highlight"),
+            button_secondary: system("Highlight"),
+            button_secondary_dark: system("Highlight"),
+            button_secondary_light: system("Highlight"),
+            button_danger: system("Highlight"),
+            button_danger_dark: system("Highlight"),
+            button_danger_light: system("Highlight"),
+            button_success: system("Highlight"),
+            button_success_dark: system("Highlight"),
+            button_success_light: system("Highlight"),
+            button_primary_hover_start: system("Highlight"),
+            button_primary_hover_end: system("Highlight"),
+            button_secondary_hover_start: system("Highlight"),
+            button_secondary_hover_end: system("Highlight"),
+            button_danger_hover_start: system("Highlight"),
+            button_danger_hover_end: system("Highlight"),
+            button_success_hover_start: system("Highlight"),
+            button_success_hover_end: system("Highlight"),
+            button_icon_on_dark: system("HighlightText"),
+            button_icon_on_light: system("LinkText"),
+            text_color_on_primary: system("HighlightText"),
+            text_color_on_secondary: system("HighlightText"),
+            text_color_on_danger: system("HighlightText"),
+            text_color_on_success: system("HighlightText"),
+            text_color_on_ghost: system("LinkText"),
+            border_light: system("CanvasText"),
+            border_ghost: system("CanvasText"),
+            glow_button_primary: system("transparent"),
+            glow_button_secondary: system("transparent"),
+            glow_button_success: system("transparent"),
+            glow_button_danger: system("transparent"),
+            glow_button_warning: system("transparent"),
+            glow_button_info: system("transparent"),
+            ghost_text: system("CanvasText"),
+            ghost_border: system("CanvasText"),
+            ghost_glow: system("transparent"),
+            focus_brightness_primary: system("1"),
+            focus_brightness_secondary: system("1"),
+            focus_brightness_success: system("1"),
+            focus_brightness_danger: system("1"),
+            focus_brightness_warning: system("1"),
+            focus_brightness_info: system("1"),
+        }
+    }
+
     pub(crate) fn with_overrides(mut self, overrides: PaletteOverrides) -> Self {
         if let Some(color) = overrides.primary {
             self.primary = color;
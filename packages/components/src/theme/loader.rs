@@ -0,0 +1,286 @@
+//! Runtime theme loading
+//!
+//! Theme definitions use the layout of hikari-palette's `data/themes.toml`:
+//! one `[palettes.<name>]` table per theme holding its `mode` (`"light"` or
+//! `"dark"`) and all eleven color roles as hex strings. Sub-tables, such as
+//! the `scales` written by [`GeneratedPalette::to_toml`], are ignored. JSON
+//! definitions nest the same keys:
+//!
+//! ```json
+//! { "palettes": { "ocean": { "mode": "dark", "primary": "#00b4d8", ... } } }
+//! ```
+//!
+//! Every theme is validated and then audited with [`Palette::audit`].
+//! [`load_themes`] registers the themes only when their text roles pass;
+//! brand and status colors are kept as given, and their failing checks are
+//! logged as warnings. [`load_themes_strict`] also rejects those. Use
+//! [`parse_themes`] and [`register_theme`] to accept any contrast.
+//!
+//! [`GeneratedPalette::to_toml`]: hikari_palette::GeneratedPalette::to_toml
+//!
+//! ```rust,ignore
+//! use hikari_components::theme::{ThemeFormat, load_themes};
+//!
+//! let themes = load_themes(ThemeFormat::Toml, &std::fs::read_to_string("brand.toml")?)?;
+//! rsx! { ThemeProvider { palette: themes[0].name.clone() } { App {} } }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use hikari_palette::{Color, ContrastAudit, Palette, ThemeMode};
+
+use crate::platform;
+use crate::theme::registry::register_theme;
+
+const ROLES: [&str; 11] = [
+    "primary",
+    "secondary",
+    "accent",
+    "success",
+    "warning",
+    "danger",
+    "background",
+    "surface",
+    "border",
+    "text_primary",
+    "text_secondary",
+];
+
+/// Source format of a theme definition
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ThemeFormat {
+    Toml,
+    Json,
+}
+
+impl ThemeFormat {
+    /// Format matching the extension of `path`
+    pub fn from_path(path: &str) -> Option<Self> {
+        let (_, extension) = path.rsplit_once('.')?;
+        match extension.to_ascii_lowercase().as_str() {
+            "toml" => Some(ThemeFormat::Toml),
+            "json" => Some(ThemeFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+/// A validated theme with its contrast audit
+#[derive(Debug, Clone)]
+pub struct ThemeDefinition {
+    pub name: String,
+    pub palette: Palette,
+    pub audit: ContrastAudit,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ThemeLoadError {
+    /// The source is not well-formed
+    Parse(String),
+    /// A theme has a missing, unknown or malformed entry
+    Invalid { theme: String, message: String },
+    /// A theme fails its contrast audit
+    Contrast { theme: String, audit: ContrastAudit },
+}
+
+impl fmt::Display for ThemeLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeLoadError::Parse(message) => write!(f, "invalid theme definition: {message}"),
+            ThemeLoadError::Invalid { theme, message } => write!(f, "theme `{theme}`: {message}"),
+            ThemeLoadError::Contrast { theme, audit } => write!(f, "theme `{theme}`: {audit}"),
+        }
+    }
+}
+
+impl std::error::Error for ThemeLoadError {}
+
+/// Parses and validates every theme of `source`, without registering them
+pub fn parse_themes(
+    format: ThemeFormat,
+    source: &str,
+) -> Result<Vec<ThemeDefinition>, ThemeLoadError> {
+    let tables = match format {
+        ThemeFormat::Toml => toml_tables(source)?,
+        ThemeFormat::Json => json_tables(source)?,
+    };
+    if tables.is_empty() {
+        return Err(ThemeLoadError::Parse("no palettes defined".to_string()));
+    }
+    tables
+        .into_iter()
+        .map(|(name, fields)| {
+            let palette = build_palette(&name, fields)?;
+            let audit = palette.audit();
+            Ok(ThemeDefinition {
+                name,
+                palette,
+                audit,
+            })
+        })
+        .collect()
+}
+
+/// Parses `source` and registers its themes if their text roles pass the
+/// contrast audit
+///
+/// Nothing is registered when any theme fails. Failing brand and status
+/// colors are logged and left in each definition's audit.
+pub fn load_themes(
+    format: ThemeFormat,
+    source: &str,
+) -> Result<Vec<ThemeDefinition>, ThemeLoadError> {
+    load(format, source, false)
+}
+
+/// [`load_themes`], also rejecting themes whose brand or status colors fail
+/// the contrast audit
+pub fn load_themes_strict(
+    format: ThemeFormat,
+    source: &str,
+) -> Result<Vec<ThemeDefinition>, ThemeLoadError> {
+    load(format, source, true)
+}
+
+fn load(
+    format: ThemeFormat,
+    source: &str,
+    strict: bool,
+) -> Result<Vec<ThemeDefinition>, ThemeLoadError> {
+    let themes = parse_themes(format, source)?;
+    let rejects = |theme: &ThemeDefinition| {
+        theme
+            .audit
+            .failures()
+            .any(|check| strict || check.is_text())
+    };
+    if let Some(failing) = themes.iter().find(|theme| rejects(theme)) {
+        return Err(ThemeLoadError::Contrast {
+            theme: failing.name.clone(),
+            audit: failing.audit.clone(),
+        });
+    }
+    for theme in &themes {
+        for check in theme.audit.failures() {
+            platform::log_warn(&format!("theme `{}`: {check}", theme.name));
+        }
+        register_theme(&theme.name, theme.palette.clone());
+    }
+    Ok(themes)
+}
+
+type Fields = Vec<(String, String)>;
+
+/// `[palettes.<name>]` tables; other tables are ignored
+fn toml_tables(source: &str) -> Result<Vec<(String, Fields)>, ThemeLoadError> {
+    let root: toml::Table =
+        toml::from_str(source).map_err(|err| ThemeLoadError::Parse(err.to_string()))?;
+    let Some(palettes) = root.get("palettes") else {
+        return Ok(Vec::new());
+    };
+    let palettes = palettes
+        .as_table()
+        .ok_or_else(|| ThemeLoadError::Parse("expected a `palettes` table".to_string()))?;
+
+    palettes
+        .iter()
+        .map(|(name, table)| {
+            let table = table.as_table().ok_or_else(|| ThemeLoadError::Invalid {
+                theme: name.clone(),
+                message: "expected a table".to_string(),
+            })?;
+            let fields = table
+                .iter()
+                .filter(|(_, value)| !value.is_table())
+                .map(|(key, value)| match value.as_str() {
+                    Some(value) => Ok((key.clone(), value.to_string())),
+                    None => Err(ThemeLoadError::Invalid {
+                        theme: name.clone(),
+                        message: format!("`{key}` must be a string"),
+                    }),
+                })
+                .collect::<Result<Fields, _>>()?;
+            Ok((name.clone(), fields))
+        })
+        .collect()
+}
+
+fn json_tables(source: &str) -> Result<Vec<(String, Fields)>, ThemeLoadError> {
+    let root: serde_json::Value =
+        serde_json::from_str(source).map_err(|err| ThemeLoadError::Parse(err.to_string()))?;
+    let palettes = root
+        .get("palettes")
+        .and_then(|palettes| palettes.as_object())
+        .ok_or_else(|| ThemeLoadError::Parse("expected a `palettes` object".to_string()))?;
+
+    palettes
+        .iter()
+        .map(|(name, table)| {
+            let table = table.as_object().ok_or_else(|| ThemeLoadError::Invalid {
+                theme: name.clone(),
+                message: "expected an object".to_string(),
+            })?;
+            let fields = table
+                .iter()
+                .filter(|(_, value)| !value.is_object())
+                .map(|(key, value)| match value.as_str() {
+                    Some(value) => Ok((key.clone(), value.to_string())),
+                    None => Err(ThemeLoadError::Invalid {
+                        theme: name.clone(),
+                        message: format!("`{key}` must be a string"),
+                    }),
+                })
+                .collect::<Result<Fields, _>>()?;
+            Ok((name.clone(), fields))
+        })
+        .collect()
+}
+
+fn build_palette(name: &str, fields: Fields) -> Result<Palette, ThemeLoadError> {
+    let invalid = |message: String| ThemeLoadError::Invalid {
+        theme: name.to_string(),
+        message,
+    };
+
+    let mut mode = None;
+    let mut colors: HashMap<&str, Color> = HashMap::new();
+    for (key, value) in fields {
+        if key == "mode" {
+            mode = Some(match value.as_str() {
+                "light" => ThemeMode::Light,
+                "dark" => ThemeMode::Dark,
+                _ => return Err(invalid(format!("unknown mode `{value}`"))),
+            });
+            continue;
+        }
+        let Some(role) = ROLES.iter().find(|role| **role == key) else {
+            return Err(invalid(format!("unknown key `{key}`")));
+        };
+        let color = Color::from_hex(&value)
+            .ok_or_else(|| invalid(format!("invalid color `{value}` for `{key}`")))?;
+        colors.insert(role, color);
+    }
+
+    let mode = mode.ok_or_else(|| invalid("missing `mode`".to_string()))?;
+    let role = |role: &str| {
+        colors
+            .get(role)
+            .copied()
+            .ok_or_else(|| invalid(format!("missing `{role}`")))
+    };
+    Ok(Palette {
+        mode,
+        primary: role("primary")?,
+        secondary: role("secondary")?,
+        accent: role("accent")?,
+        success: role("success")?,
+        warning: role("warning")?,
+        danger: role("danger")?,
+        background: role("background")?,
+        surface: role("surface")?,
+        border: role("border")?,
+        text_primary: role("text_primary")?,
+        text_secondary: role("text_secondary")?,
+    })
+}
@@ -4,14 +4,29 @@
 //! hierarchical theme management across the application.
 
 mod css;
+mod loader;
 mod provider;
 mod registry;
+mod scope;
+mod storage;
 mod traits;
+mod transition;
+mod vision;
 
 pub use css::{ComponentOverrides, ComponentPalette, PaletteOverrides, ThemePalette};
+pub use loader::{
+    ThemeDefinition, ThemeFormat, ThemeLoadError, load_themes, load_themes_strict, parse_themes,
+};
 pub use provider::{
-    LayoutDirection, ThemeContext, ThemeProvider, ThemeProviderProps, use_layout_direction,
-    use_theme,
+    ColorScheme, ContrastMode, LayoutDirection, ThemeContext, ThemeProvider, ThemeProviderProps,
+    try_use_theme, use_layout_direction, use_theme,
+};
+pub use registry::{
+    forced_colors_active, get_default_theme, get_registered_theme, prefers_dark_mode,
+    prefers_more_contrast, register_theme,
 };
-pub use registry::{get_default_theme, get_registered_theme, prefers_dark_mode, register_theme};
+pub(crate) use scope::theme_scope_wrapper;
+pub use scope::{THEME_SCOPE_ATTRIBUTE, theme_scope};
+pub use storage::{LocalThemeStorage, MemoryThemeStorage, ThemeStorage, ThemeStore};
 pub use traits::IntoThemeName;
+pub use transition::{ThemeTransition, TransitionEffect, TransitionGroup, blend_css_variables};
@@ -12,8 +12,11 @@
 //!
 //! The ThemeProvider supports nested/hierarchical theme configuration:
 //! - Child providers can override parent theme settings
-//! - Components automatically use the nearest provider's theme
-//! - CSS variables cascade naturally through the DOM
+//! - Each provider sets its CSS variables on its own root element, so they
+//!   cascade through its subtree only
+//! - [`use_theme`] resolves the provider in effect: inside a portal, the
+//!   one it was opened from; elsewhere, the outermost
+//! - Portals render with the theme of the scope they were opened from
 //!
 //! # Usage
 //!
@@ -50,6 +53,63 @@
 //! }
 //! ```
 //!
+//! ## Light, Dark and System Schemes
+//!
+//! `palette` is shown in the light scheme and `dark_palette` in the dark one.
+//! `ColorScheme::System` follows the OS preference live; with a
+//! `persist_key`, the scheme picked through `ThemeContext::set_scheme` is
+//! saved (localStorage by default) and restored on the next visit.
+//!
+//! ```rust,no_run
+//! use hikari_components::{ColorScheme, ThemeProvider};
+//!
+//! rsx! {
+//!     ThemeProvider {
+//!         scheme: ColorScheme::System,
+//!         persist_key: Some("app".to_string()),
+//!     } {
+//!         // Switch with `use_theme().set_scheme.call(ColorScheme::Dark)`
+//!     }
+//! }
+//! ```
+//!
+//! ## Animated Switching
+//!
+//! With a `transition`, switches cross-fade or reveal the new theme instead
+//! of swapping it at once (see [`ThemeTransition`](super::ThemeTransition)).
+//!
+//! ```rust,no_run
+//! use hikari_components::ThemeProvider;
+//! use hikari_components::theme::ThemeTransition;
+//!
+//! rsx! {
+//!     ThemeProvider { transition: Some(ThemeTransition::cross_fade(300)) } {
+//!         // use_theme().set_theme_from("tairitsu".to_string(), (x, y))
+//!     }
+//! }
+//! ```
+//!
+//! ## Contrast and Color Vision
+//!
+//! `contrast` defaults to `ContrastMode::System`: the provider switches to
+//! [`Palette::high_contrast`] under `prefers-contrast: more` and to CSS
+//! system colors under `forced-colors: active`, live. `simulate` renders the
+//! app as seen with a color vision deficiency, for checking dashboards.
+//!
+//! ```rust,no_run
+//! use hikari_components::{ContrastMode, ThemeProvider};
+//! use hikari_palette::ColorVisionDeficiency;
+//!
+//! rsx! {
+//!     ThemeProvider {
+//!         contrast: ContrastMode::More,
+//!         simulate: Some(ColorVisionDeficiency::Deuteranopia),
+//!     } {
+//!         // Dashboard under test
+//!     }
+//! }
+//! ```
+//!
 //! ## Custom Color Overrides
 //!
 //! ```rust,no_run
@@ -67,13 +127,26 @@
 
 #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 use hikari_animation::global_manager::init_global_animation_manager;
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use hikari_palette::*;
+use tairitsu_hooks::ReactiveSignal;
+use tairitsu_vdom::runtime;
 
+use crate::layout::responsive::{Breakpoints, scoped_responsive_styles};
 use crate::prelude::*;
 #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 use crate::scripts::scrollbar_container::init_all as init_scrollbars;
 use crate::theme::css::{ComponentOverrides, ComponentPalette, PaletteOverrides, ThemePalette};
-use crate::theme::registry::{get_default_theme, get_registered_theme};
+use crate::theme::registry::{
+    forced_colors_active, get_default_theme, get_registered_theme, prefers_dark_mode,
+    prefers_more_contrast,
+};
+use crate::theme::scope::{register_theme_scope, use_theme_scope_id};
+use crate::theme::storage::ThemeStore;
+use crate::theme::transition::{REVEAL_CSS, ThemeAnimator, ThemeTransition, TransitionEffect};
+use crate::theme::vision::{simulation_filter_id, simulation_svg};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum LayoutDirection {
@@ -95,19 +168,142 @@ impl LayoutDirection {
     }
 }
 
+/// Whether a provider shows its light or dark palette
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorScheme {
+    #[default]
+    Light,
+    Dark,
+    /// Follows the OS `prefers-color-scheme`, live
+    System,
+}
+
+impl ColorScheme {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ColorScheme::Light => "light",
+            ColorScheme::Dark => "dark",
+            ColorScheme::System => "system",
+        }
+    }
+
+    /// Whether the dark palette applies, given the OS preference
+    pub fn is_dark(&self, system_dark: bool) -> bool {
+        match self {
+            ColorScheme::Light => false,
+            ColorScheme::Dark => true,
+            ColorScheme::System => system_dark,
+        }
+    }
+}
+
+impl std::str::FromStr for ColorScheme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "light" => Ok(ColorScheme::Light),
+            "dark" => Ok(ColorScheme::Dark),
+            "system" => Ok(ColorScheme::System),
+            _ => Err(format!("Unknown color scheme `{s}`")),
+        }
+    }
+}
+
+/// How much contrast a provider's colors have
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ContrastMode {
+    /// The palette as defined
+    Standard,
+    /// The palette's [`high_contrast`](Palette::high_contrast) variant
+    More,
+    /// CSS system colors, as the browser's forced-colors mode expects
+    Forced,
+    /// Follows `forced-colors` and `prefers-contrast`, live
+    #[default]
+    System,
+}
+
+impl ContrastMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContrastMode::Standard => "standard",
+            ContrastMode::More => "more",
+            ContrastMode::Forced => "forced",
+            ContrastMode::System => "system",
+        }
+    }
+
+    /// The mode that applies now; `System` resolves to one of the others
+    pub fn resolve(&self) -> ContrastMode {
+        match self {
+            ContrastMode::System if forced_colors_active() => ContrastMode::Forced,
+            ContrastMode::System if prefers_more_contrast() => ContrastMode::More,
+            ContrastMode::System => ContrastMode::Standard,
+            mode => *mode,
+        }
+    }
+}
+
 #[derive(Clone, PartialEq)]
 pub struct ThemeContext {
     pub palette: Signal<String>,
     pub theme_name: Signal<String>,
     pub direction: Signal<LayoutDirection>,
+    pub breakpoints: Breakpoints,
+    /// Replaces the palette of the light or dark slot, whichever is showing
     pub set_theme: Callback<String>,
+    pub scheme: Signal<ColorScheme>,
+    /// Whether the OS currently prefers dark; updated live
+    pub system_dark: Signal<bool>,
+    /// Switches the scheme, saving it when the provider has a `persist_key`
+    pub set_scheme: Callback<ColorScheme>,
+    /// Id of the provider's scope, on its root as `data-theme-scope`
+    pub scope: String,
+    /// Inline CSS variables of the provider's root element
+    pub css_variables: Signal<String>,
+    /// Viewport point the next circular reveal grows from
+    pub transition_origin: Signal<Option<(f64, f64)>>,
+    /// Contrast in effect, never `ContrastMode::System`
+    pub contrast: Signal<ContrastMode>,
+}
+
+impl ThemeContext {
+    /// [`set_theme`](Self::set_theme), revealing the theme from `origin`
+    pub fn set_theme_from(&self, theme: String, origin: (f64, f64)) {
+        self.transition_origin.set(Some(origin));
+        self.set_theme.call(theme);
+    }
+
+    /// [`set_scheme`](Self::set_scheme), revealing the scheme from `origin`
+    pub fn set_scheme_from(&self, scheme: ColorScheme, origin: (f64, f64)) {
+        self.transition_origin.set(Some(origin));
+        self.set_scheme.call(scheme);
+    }
 }
 
 #[define_props]
 pub struct ThemeProviderProps {
+    /// Palette shown in the light scheme
     #[default("hikari".to_string())]
     pub palette: String,
 
+    /// Palette shown in the dark scheme
+    #[default("tairitsu".to_string())]
+    pub dark_palette: String,
+
+    /// Initial scheme; a scheme saved under `persist_key` wins
+    #[default]
+    pub scheme: ColorScheme,
+
+    /// Storage key that keeps the user's scheme across visits
+    #[default]
+    pub persist_key: Option<String>,
+
+    /// Where the scheme is persisted; localStorage by default
+    #[default]
+    pub storage: ThemeStore,
+
     #[default("ltr".to_string())]
     pub direction: String,
 
@@ -147,6 +343,22 @@ pub struct ThemeProviderProps {
     #[default]
     pub component_overrides: ComponentOverrides,
 
+    /// Breakpoint widths used by responsive layout props
+    #[default]
+    pub breakpoints: Breakpoints,
+
+    /// Animation of theme switches; `None` swaps at once
+    #[default]
+    pub transition: Option<ThemeTransition>,
+
+    /// Contrast of the colors; follows the OS by default
+    #[default]
+    pub contrast: ContrastMode,
+
+    /// Debug overlay showing the app as seen with a color vision deficiency
+    #[default]
+    pub simulate: Option<ColorVisionDeficiency>,
+
     pub children: Element,
 }
 
@@ -158,39 +370,45 @@ pub struct ThemeProviderProps {
 #[allow(non_snake_case)]
 #[allow(unused_braces)]
 pub fn ThemeProvider(props: ThemeProviderProps) -> Element {
-    let current_palette = use_signal(|| props.palette.clone());
-    let current_theme_name = use_signal(|| props.palette.clone());
+    let initial_scheme = props
+        .persist_key
+        .as_deref()
+        .and_then(|key| props.storage.load_scheme(key))
+        .unwrap_or(props.scheme);
+    let system_prefers_dark = prefers_dark_mode();
+    let initial_palette = if initial_scheme.is_dark(system_prefers_dark) {
+        props.dark_palette.clone()
+    } else {
+        props.palette.clone()
+    };
+
+    let slots = SchemeSlots {
+        light: use_signal(|| props.palette.clone()),
+        dark: use_signal(|| props.dark_palette.clone()),
+        scheme: use_signal(|| initial_scheme),
+        system_dark: use_signal(|| system_prefers_dark),
+        palette: use_signal(|| initial_palette.clone()),
+        theme_name: use_signal(|| initial_palette),
+    };
+    let current_theme_name = slots.theme_name.clone();
     let current_direction = use_signal(|| match props.direction.as_str() {
         "rtl" => LayoutDirection::Rtl,
         _ => LayoutDirection::Ltr,
     });
 
-    let palette_for_callback = current_palette.clone();
-    let theme_name_for_callback = current_theme_name.clone();
-    let set_theme = Callback::new(move |new_theme: String| {
-        palette_for_callback.set(new_theme.clone());
-        theme_name_for_callback.set(new_theme);
-    });
-
-    let palette_for_context = current_palette.clone();
-    let theme_name_for_context = current_theme_name.clone();
-    let direction_for_context = current_direction.clone();
-    use_context_provider(move || ThemeContext {
-        palette: palette_for_context.inner().clone(),
-        theme_name: theme_name_for_context.inner().clone(),
-        direction: direction_for_context.inner().clone(),
-        set_theme,
-    });
-
+    // Global setup is shared by nested providers, so it runs once
     #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
     use_effect(|| {
-        crate::platform::set_timeout(
-            || {
-                init_global_animation_manager();
-                init_scrollbars();
-            },
-            50,
-        );
+        static GLOBAL_INIT: std::sync::Once = std::sync::Once::new();
+        GLOBAL_INIT.call_once(|| {
+            crate::platform::set_timeout(
+                || {
+                    init_global_animation_manager();
+                    init_scrollbars();
+                },
+                50,
+            );
+        });
     });
 
     let primary_override = props.primary.clone();
@@ -206,9 +424,21 @@ pub fn ThemeProvider(props: ThemeProviderProps) -> Element {
     let text_secondary_override = props.text_secondary.clone();
     let component_overrides = props.component_overrides.clone();
 
+    let contrast = use_signal(|| props.contrast.resolve());
+
     let theme_name_for_memo = current_theme_name.clone();
+    let contrast_for_memo = contrast.clone();
     let css_vars = use_memo(move || {
         let theme_name = theme_name_for_memo.read();
+        let contrast = contrast_for_memo.read();
+
+        if contrast == ContrastMode::Forced {
+            return format!(
+                "{} {}",
+                ThemePalette::system_colors().css_variables(),
+                ComponentPalette::system_colors().css_variables()
+            );
+        }
 
         let base_palette = match get_registered_theme(&theme_name) {
             Some(palette) => palette,
@@ -217,6 +447,10 @@ pub fn ThemeProvider(props: ThemeProviderProps) -> Element {
                 get_registered_theme(default_name).unwrap_or_else(Hikari::palette)
             }
         };
+        let base_palette = match contrast {
+            ContrastMode::More => base_palette.high_contrast(),
+            _ => base_palette,
+        };
 
         let overrides = PaletteOverrides {
             primary: primary_override.clone(),
@@ -245,30 +479,235 @@ pub fn ThemeProvider(props: ThemeProviderProps) -> Element {
         )
     });
 
+    // Providers rendered by one component differ in what they show
+    let registration = use_theme_scope_id(&format!(
+        "{}:{}:{}",
+        props.palette,
+        props.dark_palette,
+        props.persist_key.as_deref().unwrap_or_default()
+    ));
+    let scope = registration.id().to_string();
+
+    let switch = ThemeSwitch {
+        target: css_vars.signal().clone(),
+        displayed: use_signal(|| css_vars.signal().get()),
+        origin: use_signal(|| None),
+        transition: props.transition.clone(),
+        animator: ThemeAnimator::use_animator(&scope),
+        scope: scope.clone(),
+    };
+
+    let slots_for_theme = slots.clone();
+    let switch_for_theme = switch.clone();
+    let set_theme = Callback::new(move |new_theme: String| {
+        if slots_for_theme.is_dark() {
+            slots_for_theme.dark.set(new_theme);
+        } else {
+            slots_for_theme.light.set(new_theme);
+        }
+        switch_for_theme.run(|| slots_for_theme.sync());
+    });
+
+    let slots_for_scheme = slots.clone();
+    let switch_for_scheme = switch.clone();
+    let storage = props.storage.clone();
+    let persist_key = props.persist_key.clone();
+    let set_scheme = Callback::new(move |scheme: ColorScheme| {
+        if let Some(key) = persist_key.as_deref() {
+            storage.save_scheme(key, scheme);
+        }
+        switch_for_scheme.run(|| {
+            slots_for_scheme.scheme.set(scheme);
+            slots_for_scheme.sync();
+        });
+    });
+
+    let slots_for_system = slots.clone();
+    let switch_for_system = switch.clone();
+    use_media_listener(
+        &format!("hk-theme-scheme:{scope}"),
+        move |dark| {
+            switch_for_system.run(|| {
+                slots_for_system.system_dark.set(dark);
+                slots_for_system.sync();
+            })
+        },
+        crate::platform::on_color_scheme_change,
+    );
+
+    if props.contrast == ContrastMode::System {
+        let contrast = contrast.clone();
+        let switch_for_contrast = switch.clone();
+        use_media_listener(
+            &format!("hk-theme-contrast:{scope}"),
+            move |()| switch_for_contrast.run(|| contrast.set(ContrastMode::System.resolve())),
+            |mut changed| crate::platform::on_contrast_change(move || changed(())),
+        );
+    }
+
+    let context = ThemeContext {
+        palette: slots.palette.inner().clone(),
+        theme_name: slots.theme_name.inner().clone(),
+        direction: current_direction.inner().clone(),
+        breakpoints: props.breakpoints,
+        set_theme,
+        scheme: slots.scheme.inner().clone(),
+        system_dark: slots.system_dark.inner().clone(),
+        set_scheme,
+        scope: scope.clone(),
+        css_variables: css_vars.signal().clone(),
+        transition_origin: switch.origin.inner().clone(),
+        contrast: contrast.inner().clone(),
+    };
+    register_theme_scope(context.clone());
+    use_context_provider(context);
+
     let theme_name = current_theme_name.read();
     let dir = current_direction.read().as_str();
+    // Rules are scoped to this provider, so nested ones keep their own
+    let responsive_css = scoped_responsive_styles(&props.breakpoints, &scope);
+    let mut style = match props.transition {
+        Some(_) => switch.displayed.read(),
+        None => css_vars.signal().get(),
+    };
+    let simulation_svg = match props.simulate {
+        Some(deficiency) => {
+            let filter = simulation_filter_id(&scope);
+            style.push_str(&format!(" filter: url(#{filter});"));
+            simulation_svg(&filter, deficiency)
+        }
+        None => String::new(),
+    };
+    let reveal_css = match &props.transition {
+        Some(transition) if transition.effect == TransitionEffect::CircularReveal => REVEAL_CSS,
+        _ => "",
+    };
 
     rsx! {
         div {
             class: "hk-theme-provider",
             "data-theme": theme_name,
+            "data-theme-scope": scope,
+            "data-contrast": contrast.read().as_str(),
             dir: dir,
-            style: css_vars,
+            style: style,
+            style { dangerous_inner_html: responsive_css }
+            style { dangerous_inner_html: reveal_css }
+            div { class: "hk-theme-simulation", dangerous_inner_html: simulation_svg }
             {props.children}
         }
     }
 }
 
+/// Removes a provider's media query listener when it unmounts.
+struct MediaListener(u64);
+
+impl Drop for MediaListener {
+    fn drop(&mut self) {
+        crate::platform::remove_media_listener(self.0);
+    }
+}
+
+type MediaHandler<A> = Rc<RefCell<Box<dyn FnMut(A)>>>;
+
+/// Adds a media query listener with `listen` once per mounted provider
+///
+/// The listener calls the `handler` of the latest render. Outside a
+/// component nothing would remove it, so none is added.
+fn use_media_listener<A: 'static>(
+    key: &str,
+    handler: impl FnMut(A) + 'static,
+    listen: impl FnOnce(Box<dyn FnMut(A)>) -> u64,
+) {
+    let Some(component) = runtime::active_component_id() else {
+        return;
+    };
+    // Hook slots are cleared on unmount, dropping the listener with them
+    let (current, _) = runtime::hook_slot(component, key, move || {
+        let current: MediaHandler<A> = Rc::new(RefCell::new(Box::new(|_| {})));
+        let forward = current.clone();
+        let id = listen(Box::new(move |value| (forward.borrow_mut())(value)));
+        (current, Rc::new(MediaListener(id)))
+    });
+    *current.borrow_mut() = Box::new(handler);
+}
+
+/// Signals behind a provider's light/dark switching
+#[derive(Clone)]
+struct SchemeSlots {
+    light: ReactiveSignal<String>,
+    dark: ReactiveSignal<String>,
+    scheme: ReactiveSignal<ColorScheme>,
+    system_dark: ReactiveSignal<bool>,
+    palette: ReactiveSignal<String>,
+    theme_name: ReactiveSignal<String>,
+}
+
+impl SchemeSlots {
+    fn is_dark(&self) -> bool {
+        self.scheme.get().is_dark(self.system_dark.get())
+    }
+
+    /// Points the palette and theme name at the slot that now applies
+    fn sync(&self) {
+        let name = if self.is_dark() {
+            self.dark.get()
+        } else {
+            self.light.get()
+        };
+        if self.theme_name.get() != name {
+            self.palette.set(name.clone());
+            self.theme_name.set(name);
+        }
+    }
+}
+
+/// Applies switches to the displayed variables, animated if configured
+#[derive(Clone)]
+struct ThemeSwitch {
+    target: Signal<String>,
+    displayed: ReactiveSignal<String>,
+    origin: ReactiveSignal<Option<(f64, f64)>>,
+    transition: Option<ThemeTransition>,
+    animator: ThemeAnimator,
+    scope: String,
+}
+
+impl ThemeSwitch {
+    /// Runs `change`, then moves to the variables it leads to
+    fn run(&self, change: impl FnOnce()) {
+        change();
+        let origin = self.origin.get();
+        self.origin.set(None);
+        if let Some(transition) = &self.transition {
+            self.animator.animate(
+                transition,
+                self.displayed.inner().clone(),
+                self.target.get(),
+                origin,
+                !crate::platform::theme_scope_is_nested(&self.scope),
+            );
+        }
+    }
+}
+
 /// Hook to access the current theme context
+///
+/// Resolves the provider in effect: inside a portal, the one it was opened
+/// from; elsewhere, the outermost.
 pub fn use_theme() -> ThemeContext {
-    let ctx = use_context::<ThemeContext>().expect("ThemeContext not found");
-    ctx.get().clone()
+    try_use_theme().expect("ThemeContext not found")
+}
+
+/// [`use_theme`] that returns `None` outside any provider
+pub fn try_use_theme() -> Option<ThemeContext> {
+    try_consume_context::<ThemeContext>().map(|ctx| ctx.get().clone())
 }
 
 /// Hook to access the current layout direction
 pub fn use_layout_direction() -> LayoutDirection {
-    try_consume_context::<ThemeContext>()
-        .map(|ctx| ctx.get().direction.get())
+    try_use_theme()
+        .map(|ctx| ctx.direction.get())
         .unwrap_or_default()
 }
 
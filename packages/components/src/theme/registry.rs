@@ -66,3 +66,30 @@ pub fn prefers_dark_mode() -> bool {
         false
     }
 }
+
+/// Returns true if the system asks for more contrast (`prefers-contrast: more`)
+pub fn prefers_more_contrast() -> bool {
+    media_matches("(prefers-contrast: more)")
+}
+
+/// Returns true while the system forces its own colors (`forced-colors: active`)
+pub fn forced_colors_active() -> bool {
+    media_matches("(forced-colors: active)")
+}
+
+fn media_matches(query: &str) -> bool {
+    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+    {
+        web_sys::window()
+            .and_then(|w| w.match_media(query).ok())
+            .flatten()
+            .map(|mql| mql.matches())
+            .unwrap_or(false)
+    }
+
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    {
+        let _ = query;
+        false
+    }
+}
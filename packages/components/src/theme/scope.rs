@@ -0,0 +1,109 @@
+//! Nested theme scopes
+//!
+//! Every [`ThemeProvider`](super::ThemeProvider) owns a scope: an id written
+//! to its root element as `data-theme-scope`, with the theme's CSS variables
+//! set inline on that element so they only cascade to its subtree. A dark
+//! sidebar inside a light app, or several themes previewed side by side, are
+//! providers nested in or placed next to each other.
+//!
+//! - Each provider provides its context like any other; rsx children are
+//!   built before their parent, so the outermost provider, rendered last,
+//!   is the one [`use_theme`](super::use_theme) resolves afterwards
+//! - Portals carry the scope of the component that opened them and render
+//!   inside a wrapper carrying that scope's variables, with that scope's
+//!   context provided while they render
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use tairitsu_vdom::runtime;
+
+use crate::prelude::*;
+use crate::theme::provider::ThemeContext;
+
+/// Attribute holding the scope id on a provider's root element
+pub const THEME_SCOPE_ATTRIBUTE: &str = "data-theme-scope";
+
+static SCOPE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    static SCOPES: RefCell<HashMap<String, ThemeContext>> = RefCell::new(HashMap::new());
+}
+
+/// Forgets a provider's scope once the last handle to it drops.
+pub(crate) struct ScopeRegistration(String);
+
+impl ScopeRegistration {
+    pub(crate) fn id(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Drop for ScopeRegistration {
+    fn drop(&mut self) {
+        // Hook slots may outlive the registry when the thread exits
+        let _ = SCOPES.try_with(|scopes| scopes.borrow_mut().remove(&self.0));
+    }
+}
+
+/// Scope of the calling provider, stable while it is mounted
+///
+/// Providers rendered by one component are told apart by `key`. The caller
+/// holds the registration while it renders. Inside a component the hook
+/// slot keeps it until unmount; outside one, the scope is forgotten as soon
+/// as the caller drops it.
+pub(crate) fn use_theme_scope_id(key: &str) -> Rc<ScopeRegistration> {
+    let create = || {
+        let id = format!("hk-theme-{}", SCOPE_COUNTER.fetch_add(1, Ordering::SeqCst));
+        Rc::new(ScopeRegistration(id))
+    };
+    match runtime::active_component_id() {
+        // Hook slots are cleared on unmount, dropping the registration
+        Some(component) => runtime::hook_slot(component, &format!("hk-theme-scope:{key}"), create),
+        None => create(),
+    }
+}
+
+/// Records a provider's context under its scope
+pub(crate) fn register_theme_scope(context: ThemeContext) {
+    SCOPES.with(|scopes| scopes.borrow_mut().insert(context.scope.clone(), context));
+}
+
+/// Context of the provider owning `scope`, while it is mounted
+pub fn theme_scope(scope: &str) -> Option<ThemeContext> {
+    SCOPES.with(|scopes| scopes.borrow().get(scope).cloned())
+}
+
+/// `render` wrapped in an element carrying the variables of `scope`
+///
+/// The scope's context is provided while `render` runs, so `use_theme()`
+/// calls in it resolve to that provider. The wrapper uses
+/// `display: contents`, so it does not affect layout.
+#[allow(unused_braces)]
+pub(crate) fn theme_scope_wrapper(scope: &str, render: impl FnOnce() -> Element) -> Element {
+    let Some(context) = theme_scope(scope) else {
+        return render();
+    };
+    let style = format!("display: contents; {}", context.css_variables.get());
+    let theme_name = context.theme_name.get();
+    let dir = context.direction.get().as_str();
+    let enclosing = try_consume_context::<ThemeContext>();
+    use_context_provider(context);
+    let content = render();
+    if let Some(enclosing) = enclosing {
+        use_context_provider(enclosing.get().clone());
+    }
+    rsx! {
+        div {
+            class: "hk-theme-scope",
+            "data-theme": theme_name,
+            "data-theme-scope": scope,
+            "data-theme-portal": "true",
+            dir: dir,
+            style: style,
+            {content}
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! Persistence of the user's color scheme
+//!
+//! [`ThemeProvider`](super::ThemeProvider) saves the scheme the user picks
+//! under its `persist_key` through a [`ThemeStorage`]: browser localStorage
+//! by default, or [`MemoryThemeStorage`] in tests and on the server.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::platform;
+use crate::theme::provider::ColorScheme;
+
+const STORAGE_PREFIX: &str = "hk-theme:";
+
+/// Key-value storage for theme preferences
+pub trait ThemeStorage {
+    fn load(&self, key: &str) -> Option<String>;
+    fn save(&self, key: &str, value: &str);
+    fn remove(&self, key: &str);
+}
+
+/// Browser localStorage; nothing is kept on other targets
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LocalThemeStorage;
+
+impl ThemeStorage for LocalThemeStorage {
+    fn load(&self, key: &str) -> Option<String> {
+        platform::local_storage_get(key)
+    }
+
+    fn save(&self, key: &str, value: &str) {
+        platform::local_storage_set(key, value);
+    }
+
+    fn remove(&self, key: &str) {
+        platform::local_storage_remove(key);
+    }
+}
+
+/// Storage kept in memory; clones share their entries
+#[derive(Debug, Clone, Default)]
+pub struct MemoryThemeStorage {
+    entries: Rc<RefCell<HashMap<String, String>>>,
+}
+
+impl MemoryThemeStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ThemeStorage for MemoryThemeStorage {
+    fn load(&self, key: &str) -> Option<String> {
+        self.entries.borrow().get(key).cloned()
+    }
+
+    fn save(&self, key: &str, value: &str) {
+        self.entries
+            .borrow_mut()
+            .insert(key.to_string(), value.to_string());
+    }
+
+    fn remove(&self, key: &str) {
+        self.entries.borrow_mut().remove(key);
+    }
+}
+
+/// Shared handle to a [`ThemeStorage`], as passed to `ThemeProvider`
+///
+/// Handles compare equal only when they share the same storage.
+#[derive(Clone)]
+pub struct ThemeStore(Rc<dyn ThemeStorage>);
+
+impl ThemeStore {
+    pub fn new(storage: impl ThemeStorage + 'static) -> Self {
+        Self(Rc::new(storage))
+    }
+
+    /// Browser localStorage
+    pub fn local() -> Self {
+        Self::new(LocalThemeStorage)
+    }
+
+    /// Scheme saved under `persist_key`, if any and valid
+    pub fn load_scheme(&self, persist_key: &str) -> Option<ColorScheme> {
+        self.0
+            .load(&format!("{STORAGE_PREFIX}{persist_key}"))
+            .and_then(|value| value.parse().ok())
+    }
+
+    pub fn save_scheme(&self, persist_key: &str, scheme: ColorScheme) {
+        self.0
+            .save(&format!("{STORAGE_PREFIX}{persist_key}"), scheme.as_str());
+    }
+
+    pub fn clear_scheme(&self, persist_key: &str) {
+        self.0.remove(&format!("{STORAGE_PREFIX}{persist_key}"));
+    }
+}
+
+impl Default for ThemeStore {
+    fn default() -> Self {
+        Self::local()
+    }
+}
+
+impl PartialEq for ThemeStore {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for ThemeStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ThemeStore(..)")
+    }
+}
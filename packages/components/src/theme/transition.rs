@@ -0,0 +1,300 @@
+//! Animated theme switching
+//!
+//! By default a [`ThemeProvider`](super::ThemeProvider) swaps its CSS
+//! variables at once. Given a [`ThemeTransition`], it instead either
+//!
+//! - cross-fades every color variable from the old theme to the new one,
+//!   tweened with `hikari-animation` and blended in OKLab, or
+//! - reveals the new theme in a growing circle from the toggle that switched
+//!   it, using the browser's View Transitions API. The API captures the whole
+//!   page, so only a root provider reveals; nested providers and browsers
+//!   without the API get the cross-fade
+//!
+//! Switches are instant while the user prefers reduced motion. Restricting
+//! [`ThemeTransition::groups`] animates only those token groups; the other
+//! variables switch at once.
+//!
+//! ```rust,ignore
+//! rsx! {
+//!     ThemeProvider {
+//!         transition: Some(ThemeTransition::circular_reveal(400)),
+//!     } {
+//!         // onclick: move |e| theme.set_scheme_from(ColorScheme::Dark, (e.client_x, e.client_y))
+//!     }
+//! }
+//! ```
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use hikari_animation::{AnimationEngine, EasingFunction, TweenBuilder, TweenId};
+use hikari_palette::{Color, ColorSpace, blend_colors_in};
+use tairitsu_vdom::runtime;
+
+use crate::platform;
+use crate::prelude::*;
+
+/// Keyframes sampled along the easing curve of a circular reveal
+const REVEAL_KEYFRAMES: usize = 24;
+
+/// Keeps the old theme still under the revealed new one
+pub(crate) const REVEAL_CSS: &str = "::view-transition-old(root), ::view-transition-new(root) { animation: none; mix-blend-mode: normal; }";
+
+/// How a provider moves from one theme to the next
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TransitionEffect {
+    /// Blends each variable from its old value to its new one
+    #[default]
+    CrossFade,
+    /// Grows the new theme in a circle from the switch origin
+    CircularReveal,
+}
+
+/// Token groups a transition can be limited to
+///
+/// Each CSS variable belongs to one group, see [`TransitionGroup::of`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransitionGroup {
+    /// Backgrounds, surfaces and borders
+    Surface,
+    /// Text colors
+    Text,
+    /// Success, warning, danger and info colors
+    Status,
+    /// `--hi-component-*` variables
+    Component,
+    /// Brand colors, glows and everything else
+    Accent,
+}
+
+impl TransitionGroup {
+    /// Group of the CSS variable `name`
+    pub fn of(name: &str) -> Self {
+        let name = name.trim_start_matches('-');
+        if name.starts_with("hi-component-") {
+            TransitionGroup::Component
+        } else if name.contains("text") {
+            TransitionGroup::Text
+        } else if ["background", "surface", "border", "-bg"]
+            .iter()
+            .any(|part| name.contains(part))
+        {
+            TransitionGroup::Surface
+        } else if ["success", "warning", "danger", "info"]
+            .iter()
+            .any(|part| name.contains(part))
+        {
+            TransitionGroup::Status
+        } else {
+            TransitionGroup::Accent
+        }
+    }
+}
+
+/// Animation used when a provider switches themes
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeTransition {
+    pub effect: TransitionEffect,
+    pub duration_ms: u64,
+    pub easing: EasingFunction,
+    /// Groups that animate; the others switch at once. Empty animates all.
+    pub groups: Vec<TransitionGroup>,
+}
+
+impl Default for ThemeTransition {
+    fn default() -> Self {
+        Self::cross_fade(300)
+    }
+}
+
+impl ThemeTransition {
+    pub fn cross_fade(duration_ms: u64) -> Self {
+        Self {
+            effect: TransitionEffect::CrossFade,
+            duration_ms,
+            easing: EasingFunction::EaseInOutCubic,
+            groups: Vec::new(),
+        }
+    }
+
+    pub fn circular_reveal(duration_ms: u64) -> Self {
+        Self {
+            effect: TransitionEffect::CircularReveal,
+            easing: EasingFunction::EaseOutCubic,
+            ..Self::cross_fade(duration_ms)
+        }
+    }
+
+    pub fn with_easing(mut self, easing: EasingFunction) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Animates only `groups`
+    pub fn with_groups(mut self, groups: impl IntoIterator<Item = TransitionGroup>) -> Self {
+        self.groups = groups.into_iter().collect();
+        self
+    }
+
+    /// Whether the variable `name` animates
+    pub fn animates(&self, name: &str) -> bool {
+        self.groups.is_empty() || self.groups.contains(&TransitionGroup::of(name))
+    }
+}
+
+/// `--name: value;` declarations of a provider style, in order
+fn declarations(css: &str) -> Vec<(&str, &str)> {
+    css.split(';')
+        .filter_map(|declaration| {
+            let (name, value) = declaration.split_once(':')?;
+            let name = name.trim();
+            name.starts_with("--").then(|| (name, value.trim()))
+        })
+        .collect()
+}
+
+/// CSS variables `t` of the way from `from` to `to`
+///
+/// Hex colors of the animated groups are blended in OKLab; other values of
+/// those groups (gradients, `rgba()`) flip halfway. Variables outside the
+/// groups, or missing from `from`, take their `to` value.
+pub fn blend_css_variables(from: &str, to: &str, t: f64, transition: &ThemeTransition) -> String {
+    let t = t.clamp(0.0, 1.0);
+    let old = declarations(from);
+    declarations(to)
+        .into_iter()
+        .map(|(name, target)| {
+            let start = old
+                .iter()
+                .find(|(old_name, _)| *old_name == name)
+                .map(|(_, value)| *value)
+                .filter(|_| transition.animates(name));
+            let value = match start {
+                Some(start) => match (Color::from_hex(start), Color::from_hex(target)) {
+                    (Some(a), Some(b)) => blend_colors_in(a, b, t, ColorSpace::Oklab).hex(),
+                    _ if t < 0.5 => start.to_string(),
+                    _ => target.to_string(),
+                },
+                None => target.to_string(),
+            };
+            format!("{name}: {value};")
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Runs a provider's theme transitions, one at a time
+#[derive(Clone, Default)]
+pub(crate) struct ThemeAnimator {
+    engine: AnimationEngine,
+    running: Rc<Cell<Option<TweenId>>>,
+}
+
+impl ThemeAnimator {
+    /// Animator of the provider owning `scope`, kept while it is mounted
+    pub(crate) fn use_animator(scope: &str) -> Self {
+        match runtime::active_component_id() {
+            Some(component) => runtime::hook_slot(
+                component,
+                &format!("hk-theme-animator:{scope}"),
+                Self::default,
+            ),
+            None => Self::default(),
+        }
+    }
+
+    /// Moves `displayed` from its current variables to `to`
+    ///
+    /// `origin` is the viewport point a circular reveal grows from; the
+    /// centre of the viewport when `None`. Only a provider at the `root`
+    /// covers the page a view transition captures, so others cross-fade.
+    pub(crate) fn animate(
+        &self,
+        transition: &ThemeTransition,
+        displayed: Signal<String>,
+        to: String,
+        origin: Option<(f64, f64)>,
+        root: bool,
+    ) {
+        if let Some(id) = self.running.take() {
+            self.engine.kill(id);
+        }
+        let from = displayed.get();
+        if from == to {
+            return;
+        }
+        if transition.duration_ms == 0 || hikari_animation::prefers_reduced_motion() {
+            displayed.set(to);
+            return;
+        }
+
+        if transition.effect == TransitionEffect::CircularReveal
+            && root
+            && platform::supports_view_transitions()
+        {
+            let progress: Vec<f64> = (0..=REVEAL_KEYFRAMES)
+                .map(|frame| {
+                    transition
+                        .easing
+                        .apply(frame as f64 / REVEAL_KEYFRAMES as f64)
+                })
+                .collect();
+            platform::start_circular_reveal(
+                origin,
+                transition.duration_ms as f64,
+                &progress,
+                move || {
+                    displayed.set(to);
+                    // The new theme must be in the DOM before the callback
+                    // returns, not on the next animation frame
+                    runtime::flush_render();
+                },
+            );
+            return;
+        }
+
+        let step = transition.clone();
+        let on_update = {
+            let (displayed, to) = (displayed.clone(), to.clone());
+            move |t: f64| {
+                displayed.set(blend_css_variables(&from, &to, step.easing.apply(t), &step))
+            }
+        };
+        let running = self.running.clone();
+        let id = TweenBuilder::new()
+            .duration_ms(transition.duration_ms)
+            .on_update(Box::new(on_update))
+            .on_complete(Box::new(move || {
+                running.set(None);
+                displayed.set(to.clone());
+            }))
+            .with_engine(self.engine.clone())
+            .play();
+        self.running.set(Some(id));
+
+        #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+        drive(self.engine.clone(), None);
+        // No animation frames off the browser: finish at once
+        #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+        self.engine
+            .tick(Duration::from_millis(transition.duration_ms));
+    }
+}
+
+/// Ticks `engine` every frame until its tweens are done
+#[cfg_attr(
+    not(all(target_arch = "wasm32", target_os = "unknown")),
+    allow(dead_code)
+)]
+fn drive(engine: AnimationEngine, last_frame: Option<f64>) {
+    platform::request_animation_frame_with_timestamp(move |now| {
+        let elapsed = last_frame.map_or(0.0, |last| (now - last).max(0.0));
+        engine.tick(Duration::from_secs_f64(elapsed / 1000.0));
+        if engine.get_all_active().is_empty() {
+            engine.kill_all();
+        } else {
+            drive(engine, Some(now));
+        }
+    });
+}
@@ -0,0 +1,35 @@
+//! Color-vision-deficiency simulation overlay
+//!
+//! A debug aid: with `simulate` set, a [`ThemeProvider`](super::ThemeProvider)
+//! renders its subtree through an SVG `feColorMatrix` filter built from
+//! [`ColorVisionDeficiency::matrix`], so the whole app (images included)
+//! looks as it would to someone with that deficiency.
+//!
+//! The filter makes the provider root the containing block of fixed
+//! descendants, and portals outside the root are not filtered; use it for
+//! inspection only.
+
+use hikari_palette::ColorVisionDeficiency;
+
+/// Id of the simulation filter of the provider owning `scope`
+pub(crate) fn simulation_filter_id(scope: &str) -> String {
+    format!("{scope}-cvd")
+}
+
+/// Hidden SVG defining the filter `id` for `deficiency`
+///
+/// The matrix works on linear-light channels, as SVG filters do by default.
+pub(crate) fn simulation_svg(id: &str, deficiency: ColorVisionDeficiency) -> String {
+    let rows: Vec<String> = deficiency
+        .matrix()
+        .iter()
+        .map(|[r, g, b]| format!("{r} {g} {b} 0 0"))
+        .collect();
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"0\" height=\"0\" aria-hidden=\"true\" \
+         style=\"position: absolute\"><filter id=\"{id}\" \
+         color-interpolation-filters=\"linearRGB\"><feColorMatrix type=\"matrix\" \
+         values=\"{} 0 0 0 1 0\"/></filter></svg>",
+        rows.join(" ")
+    )
+}
@@ -0,0 +1,46 @@
+// hi-components/src/utils/keys.rs
+// Key presses observed by document-level listeners
+
+/// A key press seen by [`crate::platform::on_keydown`], i.e. outside of any
+/// component's own `onkeydown` handler.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct KeyPress {
+    /// `KeyboardEvent.key`, e.g. `"k"`, `"Escape"`, `"ArrowDown"`
+    pub key: String,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+    pub meta: bool,
+    /// Focus is in a text field (input, textarea, select or contenteditable)
+    pub editable: bool,
+}
+
+impl KeyPress {
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+
+    pub fn with_shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    pub fn with_meta(mut self) -> Self {
+        self.meta = true;
+        self
+    }
+
+    /// `key` pressed together with the platform command modifier: Ctrl, or
+    /// Cmd on macOS. Letters are compared case-insensitively.
+    pub fn is_mod(&self, key: &str) -> bool {
+        (self.ctrl || self.meta) && !self.alt && self.key.eq_ignore_ascii_case(key)
+    }
+}
@@ -2,7 +2,13 @@
 // Utility modules for components
 
 pub mod icon_helper;
+pub mod keys;
 pub mod positioning;
+pub mod roving_focus;
+pub mod shortcuts;
 
 pub use icon_helper::*;
+pub use keys::*;
 pub use positioning::*;
+pub use roving_focus::*;
+pub use shortcuts::*;
@@ -0,0 +1,485 @@
+// hi-components/src/utils/roving_focus.rs
+// Roving tabindex and keyboard navigation for composite widgets
+//
+// Key handling is pure and works on a snapshot of the widget's items, so it
+// can be tested without a DOM. The platform layer collects the items (in
+// document order, visible ones only) and moves focus.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use tairitsu_vdom::{Key, KeyboardEvent, runtime};
+
+use crate::platform;
+use crate::theme::LayoutDirection;
+
+static ROVING_ID_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Owns a roving-focus container id and drops its typeahead buffer with it.
+struct RovingId(String);
+
+impl Drop for RovingId {
+    fn drop(&mut self) {
+        let _ = TYPEAHEAD.try_with(|buffers| buffers.borrow_mut().remove(&self.0));
+    }
+}
+
+/// Stable element id for a roving-focus container, e.g. `hi-menu-3`.
+pub fn use_roving_id(prefix: &str) -> String {
+    let create = || {
+        Rc::new(RovingId(format!(
+            "{prefix}-{}",
+            ROVING_ID_COUNTER.fetch_add(1, Ordering::Relaxed)
+        )))
+    };
+    // Hook slots are cleared on unmount, dropping the id and its typeahead
+    // buffer with them.
+    let id = match runtime::active_component_id() {
+        Some(component) => runtime::hook_slot(component, &format!("hk-roving:{prefix}"), create),
+        None => create(),
+    };
+    id.0.clone()
+}
+
+/// `[data-key="…"]` selector matching the item `key`, escaped as a CSS
+/// string so any key is a valid selector.
+pub fn data_key_selector(key: &str) -> String {
+    let mut escaped = String::with_capacity(key.len());
+    for ch in key.chars() {
+        match ch {
+            '"' | '\\' => {
+                escaped.push('\\');
+                escaped.push(ch);
+            }
+            // Control characters can only appear as hex escapes
+            ch if ch.is_control() => escaped.push_str(&format!("\\{:x} ", ch as u32)),
+            ch => escaped.push(ch),
+        }
+    }
+    format!("[data-key=\"{escaped}\"]")
+}
+
+/// Axis along which the arrow keys move focus.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Orientation {
+    Horizontal,
+    #[default]
+    Vertical,
+    /// Both arrow pairs move focus (e.g. a wrapping radio group)
+    Both,
+}
+
+impl Orientation {
+    /// Value for `aria-orientation`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Orientation::Horizontal => "horizontal",
+            Orientation::Vertical | Orientation::Both => "vertical",
+        }
+    }
+}
+
+/// Snapshot of one focusable item of a composite widget.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct FocusItem {
+    /// Text used for typeahead
+    pub label: String,
+    pub disabled: bool,
+    /// Whether the item (or something inside it) has focus
+    pub focused: bool,
+    /// Whether the item currently has `tabindex="0"`
+    pub tab_stop: bool,
+    /// Nesting depth for trees and nested menus, starting at 0
+    pub level: usize,
+    /// `Some` for items that can be expanded
+    pub expanded: Option<bool>,
+}
+
+impl FocusItem {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FocusMove {
+    Next,
+    Previous,
+    First,
+    Last,
+}
+
+/// What a key press should do to the widget.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FocusAction {
+    /// Move focus to the item at this index
+    Focus(usize),
+    /// Expand or collapse the item at this index
+    Toggle(usize),
+    /// Activate the item at this index (`Enter` / `Space`)
+    Activate(usize),
+}
+
+impl FocusAction {
+    pub fn index(&self) -> usize {
+        match *self {
+            FocusAction::Focus(index)
+            | FocusAction::Toggle(index)
+            | FocusAction::Activate(index) => index,
+        }
+    }
+}
+
+/// Roving-tabindex keyboard model following the WAI-ARIA Authoring Practices.
+///
+/// Only one item is in the tab sequence; arrow keys (mirrored in RTL for
+/// horizontal widgets), `Home` and `End` move focus between enabled items,
+/// and printable characters jump to the next item whose label starts with
+/// the typed text. Hierarchical widgets additionally map the cross axis to
+/// expand/collapse and parent/child moves.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RovingFocus {
+    pub orientation: Orientation,
+    pub direction: LayoutDirection,
+    /// Wrap from the last item to the first and back
+    pub wrap: bool,
+    /// Tree-style navigation over `FocusItem::level` / `expanded`
+    pub hierarchical: bool,
+    /// Report `Enter` and `Space` as [`FocusAction::Activate`]
+    pub activate: bool,
+    /// Descendant of the item that receives the click for toggle and
+    /// activate actions; the item itself when `None`
+    pub click_target: Option<&'static str>,
+}
+
+impl Default for RovingFocus {
+    fn default() -> Self {
+        Self::new(Orientation::Vertical)
+    }
+}
+
+impl RovingFocus {
+    pub fn new(orientation: Orientation) -> Self {
+        Self {
+            orientation,
+            direction: LayoutDirection::Ltr,
+            wrap: true,
+            hierarchical: false,
+            activate: true,
+            click_target: None,
+        }
+    }
+
+    pub fn horizontal() -> Self {
+        Self::new(Orientation::Horizontal)
+    }
+
+    pub fn vertical() -> Self {
+        Self::new(Orientation::Vertical)
+    }
+
+    /// Tree view: vertical, no wrapping, hierarchical.
+    pub fn tree() -> Self {
+        Self {
+            wrap: false,
+            hierarchical: true,
+            ..Self::vertical()
+        }
+    }
+
+    pub fn rtl(mut self) -> Self {
+        self.direction = LayoutDirection::Rtl;
+        self
+    }
+
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    pub fn hierarchical(mut self, hierarchical: bool) -> Self {
+        self.hierarchical = hierarchical;
+        self
+    }
+
+    /// Leave `Enter` and `Space` to the items (e.g. native inputs).
+    pub fn without_activation(mut self) -> Self {
+        self.activate = false;
+        self
+    }
+
+    /// Click `selector` inside the item instead of the item itself.
+    pub fn click_target(mut self, selector: &'static str) -> Self {
+        self.click_target = Some(selector);
+        self
+    }
+
+    /// Linear move for `key`, if any.
+    pub fn key_move(&self, key: &Key) -> Option<FocusMove> {
+        let rtl = self.direction == LayoutDirection::Rtl;
+        let horizontal = matches!(
+            self.orientation,
+            Orientation::Horizontal | Orientation::Both
+        );
+        let vertical = matches!(self.orientation, Orientation::Vertical | Orientation::Both);
+
+        match key {
+            Key::ArrowDown if vertical => Some(FocusMove::Next),
+            Key::ArrowUp if vertical => Some(FocusMove::Previous),
+            Key::ArrowRight if horizontal => Some(if rtl {
+                FocusMove::Previous
+            } else {
+                FocusMove::Next
+            }),
+            Key::ArrowLeft if horizontal => Some(if rtl {
+                FocusMove::Next
+            } else {
+                FocusMove::Previous
+            }),
+            Key::Other(name) if name == "Home" => Some(FocusMove::First),
+            Key::Other(name) if name == "End" => Some(FocusMove::Last),
+            _ => None,
+        }
+    }
+
+    /// Index reached from `current` by `movement`, skipping disabled items.
+    pub fn resolve(
+        &self,
+        movement: FocusMove,
+        current: Option<usize>,
+        items: &[FocusItem],
+    ) -> Option<usize> {
+        let enabled = |i: &usize| !items[*i].disabled;
+        let len = items.len();
+        match (movement, current) {
+            (FocusMove::First, _) | (FocusMove::Next, None) => (0..len).find(enabled),
+            (FocusMove::Last, _) | (FocusMove::Previous, None) => (0..len).rev().find(enabled),
+            (FocusMove::Next, Some(current)) => {
+                let after = (current + 1..len).find(enabled);
+                match after {
+                    None if self.wrap => (0..current).find(enabled),
+                    other => other,
+                }
+            }
+            (FocusMove::Previous, Some(current)) => {
+                let before = (0..current).rev().find(enabled);
+                match before {
+                    None if self.wrap => (current + 1..len).rev().find(enabled),
+                    other => other,
+                }
+            }
+        }
+    }
+
+    /// Action for `key` given the widget's items.
+    ///
+    /// `typed` is the accumulated typeahead query, if the key was printable.
+    pub fn action(
+        &self,
+        key: &Key,
+        items: &[FocusItem],
+        typed: Option<&str>,
+    ) -> Option<FocusAction> {
+        let current = items.iter().position(|item| item.focused);
+
+        if self.hierarchical
+            && let Some(index) = current
+            && let Some(action) = self.hierarchy_action(key, index, items)
+        {
+            return Some(action);
+        }
+
+        if let Some(movement) = self.key_move(key) {
+            return self
+                .resolve(movement, current, items)
+                .map(FocusAction::Focus);
+        }
+
+        match key {
+            Key::Enter | Key::Space if self.activate => current
+                .filter(|&index| !items[index].disabled)
+                .map(FocusAction::Activate),
+            _ => typed
+                .and_then(|query| typeahead_match(items, current, query))
+                .map(FocusAction::Focus),
+        }
+    }
+
+    /// Cross-axis keys of trees and menu bars.
+    fn hierarchy_action(
+        &self,
+        key: &Key,
+        index: usize,
+        items: &[FocusItem],
+    ) -> Option<FocusAction> {
+        let item = &items[index];
+        let rtl = self.direction == LayoutDirection::Rtl;
+
+        // Submenus and subtrees open sideways whatever the container's axis
+        let (open_key, close_key) = match self.orientation {
+            Orientation::Horizontal if item.level == 0 => (Key::ArrowDown, Key::ArrowUp),
+            _ if rtl => (Key::ArrowLeft, Key::ArrowRight),
+            _ => (Key::ArrowRight, Key::ArrowLeft),
+        };
+
+        if *key == open_key {
+            return match item.expanded {
+                Some(false) if !item.disabled => Some(FocusAction::Toggle(index)),
+                Some(true) => items
+                    .get(index + 1)
+                    .filter(|child| child.level > item.level && !child.disabled)
+                    .map(|_| FocusAction::Focus(index + 1)),
+                _ => None,
+            };
+        }
+
+        if *key == close_key {
+            if item.expanded == Some(true) {
+                return Some(FocusAction::Toggle(index));
+            }
+            if item.level == 0 {
+                return None;
+            }
+            return (0..index)
+                .rev()
+                .find(|&i| items[i].level < item.level)
+                .map(FocusAction::Focus);
+        }
+
+        None
+    }
+
+    /// Handle a keydown on the widget whose container has `container_id` and
+    /// whose items match `selector`.
+    ///
+    /// Moves focus (keeping `tabindex="0"` on the focused item only), clicks
+    /// the item for [`FocusAction::Toggle`] / [`FocusAction::Activate`], and
+    /// returns the action so the widget can follow focus (e.g. automatic tab
+    /// activation). Focus is restored to the same item after the re-render
+    /// such a click triggers.
+    pub fn handle_keydown(
+        &self,
+        e: &KeyboardEvent,
+        container_id: &str,
+        selector: &str,
+    ) -> Option<FocusAction> {
+        if e.ctrl_key || e.alt_key || e.meta_key {
+            return None;
+        }
+
+        let roving = if platform::is_rtl(container_id) {
+            self.rtl()
+        } else {
+            *self
+        };
+        let key = e.get_key();
+        let printable = e.key.chars().count() == 1 && !e.key.trim().is_empty();
+        let typed =
+            printable.then(|| typeahead_push(container_id, &e.key, platform::now_timestamp()));
+
+        let items = platform::focus_items(container_id, selector);
+        let action = roving.action(&key, &items, typed.as_deref())?;
+        e.prevent_default();
+
+        match action {
+            FocusAction::Focus(index) => platform::focus_item(container_id, selector, index),
+            FocusAction::Toggle(index) | FocusAction::Activate(index) => {
+                platform::click_item(container_id, selector, index, self.click_target);
+            }
+        }
+
+        let container_id = container_id.to_string();
+        let selector = selector.to_string();
+        let index = action.index();
+        platform::request_animation_frame(move || {
+            platform::focus_item(&container_id, &selector, index)
+        });
+
+        Some(action)
+    }
+}
+
+impl RovingFocus {
+    /// Forward focus from the container to its tab stop (or the first
+    /// enabled item). For containers that are only focusable until an item
+    /// holds the roving tabindex.
+    pub fn enter(&self, container_id: &str, selector: &str) {
+        let items = platform::focus_items(container_id, selector);
+        if items.iter().any(|item| item.focused) {
+            return;
+        }
+        let target = items
+            .iter()
+            .position(|item| item.tab_stop && !item.disabled)
+            .or_else(|| self.resolve(FocusMove::First, None, &items));
+        if let Some(index) = target {
+            platform::focus_item(container_id, selector, index);
+        }
+    }
+}
+
+/// Typeahead queries reset after this much idle time.
+pub const TYPEAHEAD_TIMEOUT_MS: f64 = 500.0;
+
+/// Accumulates typed characters into a query until the user pauses.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Typeahead {
+    query: String,
+    last_input: f64,
+}
+
+impl Typeahead {
+    /// Append `text` typed at `now` (milliseconds) and return the query.
+    pub fn push(&mut self, text: &str, now: f64) -> &str {
+        if now - self.last_input > TYPEAHEAD_TIMEOUT_MS {
+            self.query.clear();
+        }
+        self.last_input = now;
+        self.query.push_str(&text.to_lowercase());
+        &self.query
+    }
+}
+
+thread_local! {
+    static TYPEAHEAD: RefCell<HashMap<String, Typeahead>> = RefCell::new(HashMap::new());
+}
+
+fn typeahead_push(container_id: &str, text: &str, now: f64) -> String {
+    TYPEAHEAD.with(|buffers| {
+        buffers
+            .borrow_mut()
+            .entry(container_id.to_string())
+            .or_default()
+            .push(text, now)
+            .to_string()
+    })
+}
+
+/// Next enabled item whose label starts with `query` (case-insensitive).
+///
+/// The search starts after `current` and wraps. Repeating a single character
+/// (`"aaa"`) cycles through the items starting with it; longer queries keep
+/// the current item if it still matches.
+pub fn typeahead_match(items: &[FocusItem], current: Option<usize>, query: &str) -> Option<usize> {
+    let query = query.to_lowercase();
+    let mut chars = query.chars();
+    let first = chars.next()?;
+    let repeated = chars.all(|c| c == first);
+    let query = if repeated { first.to_string() } else { query };
+
+    let len = items.len();
+    let start = match current {
+        Some(current) if repeated => current + 1,
+        Some(current) => current,
+        None => 0,
+    };
+
+    (0..len)
+        .map(|offset| (start + offset) % len)
+        .find(|&i| !items[i].disabled && items[i].label.trim().to_lowercase().starts_with(&query))
+}
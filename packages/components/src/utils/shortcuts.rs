@@ -0,0 +1,703 @@
+// hi-components/src/utils/shortcuts.rs
+// Global keyboard shortcut manager
+//
+// Shortcuts are parsed from strings such as "Ctrl+Shift+P", "Mod+K" (Ctrl, or
+// Cmd on macOS) or sequences like "g then i". Bindings live in scopes: the
+// focused element's `data-shortcut-scope` ancestors come first, then the
+// topmost modal scope, or the global scope when no modal is open. An open
+// modal therefore suppresses page shortcuts.
+
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::rc::Rc;
+use std::str::FromStr;
+
+use tairitsu_vdom::runtime;
+
+use crate::platform;
+use crate::utils::KeyPress;
+
+/// Scope of bindings that apply while no modal is open.
+pub const GLOBAL_SCOPE: &str = "global";
+
+/// Attribute marking an element (and its descendants) as a focus scope.
+pub const SCOPE_ATTRIBUTE: &str = "data-shortcut-scope";
+
+/// Maximum pause between the chords of a sequence.
+pub const SEQUENCE_TIMEOUT_MS: f64 = 1000.0;
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum ShortcutParseError {
+    Empty,
+    UnknownModifier(String),
+    MissingKey(String),
+}
+
+impl fmt::Display for ShortcutParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShortcutParseError::Empty => f.write_str("empty shortcut"),
+            ShortcutParseError::UnknownModifier(modifier) => {
+                write!(f, "unknown modifier `{modifier}`")
+            }
+            ShortcutParseError::MissingKey(chord) => write!(f, "no key in `{chord}`"),
+        }
+    }
+}
+
+impl std::error::Error for ShortcutParseError {}
+
+/// Canonical name of a key: letters are lowercase, named keys use their
+/// `KeyboardEvent.key` spelling and a few common aliases are accepted.
+pub fn normalize_key(key: &str) -> String {
+    if key == " " {
+        return "Space".to_string();
+    }
+    if key.chars().count() == 1 {
+        return key.to_lowercase();
+    }
+    let canonical = match key.to_lowercase().as_str() {
+        "esc" | "escape" => "Escape",
+        "enter" | "return" => "Enter",
+        "tab" => "Tab",
+        "space" | "spacebar" => "Space",
+        "backspace" => "Backspace",
+        "del" | "delete" => "Delete",
+        "up" | "arrowup" => "ArrowUp",
+        "down" | "arrowdown" => "ArrowDown",
+        "left" | "arrowleft" => "ArrowLeft",
+        "right" | "arrowright" => "ArrowRight",
+        "home" => "Home",
+        "end" => "End",
+        "pgup" | "pageup" => "PageUp",
+        "pgdn" | "pagedown" => "PageDown",
+        "plus" => "+",
+        _ => return key.to_string(),
+    };
+    canonical.to_string()
+}
+
+fn is_modifier_key(key: &str) -> bool {
+    matches!(
+        key,
+        "Control" | "Shift" | "Alt" | "Meta" | "AltGraph" | "OS"
+    )
+}
+
+/// One key together with its modifiers.
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct KeyChord {
+    pub key: String,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+    pub meta: bool,
+    /// The platform command modifier: Ctrl, or Cmd on macOS
+    pub primary: bool,
+}
+
+impl KeyChord {
+    /// The chord with `primary` replaced by Ctrl or Meta.
+    pub fn resolve(&self, mac: bool) -> KeyChord {
+        KeyChord {
+            key: self.key.clone(),
+            ctrl: self.ctrl || (self.primary && !mac),
+            alt: self.alt,
+            shift: self.shift,
+            meta: self.meta || (self.primary && mac),
+            primary: false,
+        }
+    }
+
+    pub fn matches(&self, press: &KeyPress, mac: bool) -> bool {
+        let chord = self.resolve(mac);
+        chord.ctrl == press.ctrl
+            && chord.alt == press.alt
+            && chord.shift == press.shift
+            && chord.meta == press.meta
+            && chord.key.eq_ignore_ascii_case(&normalize_key(&press.key))
+    }
+
+    fn has_command_modifier(&self) -> bool {
+        self.ctrl || self.alt || self.meta || self.primary
+    }
+
+    /// Key caps to display, e.g. `["Ctrl", "Shift", "P"]` or `["⇧", "⌘", "P"]`.
+    pub fn caps(&self, mac: bool) -> Vec<String> {
+        let chord = self.resolve(mac);
+        let mut caps: Vec<&str> = Vec::new();
+        if mac {
+            if chord.ctrl {
+                caps.push("⌃");
+            }
+            if chord.alt {
+                caps.push("⌥");
+            }
+            if chord.shift {
+                caps.push("⇧");
+            }
+            if chord.meta {
+                caps.push("⌘");
+            }
+        } else {
+            if chord.ctrl {
+                caps.push("Ctrl");
+            }
+            if chord.alt {
+                caps.push("Alt");
+            }
+            if chord.shift {
+                caps.push("Shift");
+            }
+            if chord.meta {
+                caps.push("Win");
+            }
+        }
+        let key = match (chord.key.as_str(), mac) {
+            ("ArrowUp", _) => "↑".to_string(),
+            ("ArrowDown", _) => "↓".to_string(),
+            ("ArrowLeft", _) => "←".to_string(),
+            ("ArrowRight", _) => "→".to_string(),
+            ("Escape", _) => "Esc".to_string(),
+            ("Enter", true) => "↩".to_string(),
+            ("Backspace", true) => "⌫".to_string(),
+            ("Delete", true) => "⌦".to_string(),
+            (key, _) if key.chars().count() == 1 => key.to_uppercase(),
+            (key, _) => key.to_string(),
+        };
+        caps.into_iter()
+            .map(str::to_string)
+            .chain(std::iter::once(key))
+            .collect()
+    }
+}
+
+impl FromStr for KeyChord {
+    type Err = ShortcutParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ShortcutParseError::Empty);
+        }
+        // "Ctrl++" binds the plus key itself.
+        let (modifiers, key) = if s == "+" {
+            ("", "+")
+        } else if let Some(rest) = s.strip_suffix("++") {
+            (rest, "+")
+        } else {
+            s.rsplit_once('+').unwrap_or(("", s))
+        };
+        if key.is_empty() {
+            return Err(ShortcutParseError::MissingKey(s.to_string()));
+        }
+
+        let mut chord = KeyChord {
+            key: normalize_key(key),
+            ..Default::default()
+        };
+        for modifier in modifiers.split('+').filter(|m| !m.is_empty()) {
+            match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" | "⌃" => chord.ctrl = true,
+                "alt" | "option" | "opt" | "⌥" => chord.alt = true,
+                "shift" | "⇧" => chord.shift = true,
+                "meta" | "cmd" | "command" | "super" | "win" | "⌘" => chord.meta = true,
+                "mod" | "primary" => chord.primary = true,
+                _ => return Err(ShortcutParseError::UnknownModifier(modifier.to_string())),
+            }
+        }
+        Ok(chord)
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (on, name) in [
+            (self.primary, "Mod"),
+            (self.ctrl, "Ctrl"),
+            (self.alt, "Alt"),
+            (self.shift, "Shift"),
+            (self.meta, "Meta"),
+        ] {
+            if on {
+                write!(f, "{name}+")?;
+            }
+        }
+        if self.key.chars().count() == 1 {
+            f.write_str(&self.key.to_uppercase())
+        } else {
+            f.write_str(&self.key)
+        }
+    }
+}
+
+/// A chord, or a sequence of chords pressed one after another.
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Shortcut {
+    pub chords: Vec<KeyChord>,
+}
+
+impl Shortcut {
+    /// Parses `"Ctrl+Shift+P"`, `"Mod+K"` or sequences like `"g then i"` /
+    /// `"g i"`.
+    pub fn parse(s: &str) -> Result<Self, ShortcutParseError> {
+        s.parse()
+    }
+
+    /// Key caps of each chord.
+    pub fn caps(&self, mac: bool) -> Vec<Vec<String>> {
+        self.chords.iter().map(|chord| chord.caps(mac)).collect()
+    }
+
+    /// Human-readable label: `"Ctrl+Shift+P"` elsewhere, `"⇧⌘P"` on macOS.
+    pub fn label(&self, mac: bool) -> String {
+        let separator = if mac { "" } else { "+" };
+        self.caps(mac)
+            .iter()
+            .map(|caps| caps.join(separator))
+            .collect::<Vec<_>>()
+            .join(" then ")
+    }
+
+    /// Whether `self` begins with the chords of `other` on the given platform.
+    pub fn starts_with(&self, other: &Shortcut, mac: bool) -> bool {
+        other.chords.len() <= self.chords.len()
+            && other
+                .chords
+                .iter()
+                .zip(&self.chords)
+                .all(|(a, b)| a.resolve(mac) == b.resolve(mac))
+    }
+
+    fn matches_prefix(&self, presses: &[KeyPress], mac: bool) -> bool {
+        presses.len() <= self.chords.len()
+            && presses
+                .iter()
+                .zip(&self.chords)
+                .all(|(press, chord)| chord.matches(press, mac))
+    }
+}
+
+impl FromStr for Shortcut {
+    type Err = ShortcutParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chords = s
+            .split_whitespace()
+            .filter(|part| !part.eq_ignore_ascii_case("then"))
+            .map(KeyChord::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+        if chords.is_empty() {
+            return Err(ShortcutParseError::Empty);
+        }
+        Ok(Self { chords })
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, chord) in self.chords.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" then ")?;
+            }
+            write!(f, "{chord}")?;
+        }
+        Ok(())
+    }
+}
+
+pub type ShortcutFn = dyn Fn();
+
+#[derive(Clone)]
+pub struct ShortcutHandler(Rc<ShortcutFn>);
+
+impl ShortcutHandler {
+    pub fn new(f: impl Fn() + 'static) -> Self {
+        Self(Rc::new(f))
+    }
+
+    pub fn call(&self) {
+        (self.0)()
+    }
+}
+
+impl PartialEq for ShortcutHandler {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for ShortcutHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ShortcutHandler(..)")
+    }
+}
+
+/// A shortcut bound to a handler within a scope.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ShortcutBinding {
+    pub id: String,
+    pub shortcut: Shortcut,
+    pub scope: String,
+    pub description: String,
+    /// Fire while typing in a text field even without Ctrl/Alt/Cmd
+    pub in_inputs: bool,
+    pub handler: ShortcutHandler,
+}
+
+impl ShortcutBinding {
+    pub fn new(id: impl Into<String>, shortcut: Shortcut, f: impl Fn() + 'static) -> Self {
+        Self {
+            id: id.into(),
+            shortcut,
+            scope: GLOBAL_SCOPE.to_string(),
+            description: String::new(),
+            in_inputs: false,
+            handler: ShortcutHandler::new(f),
+        }
+    }
+
+    pub fn scope(mut self, scope: impl Into<String>) -> Self {
+        self.scope = scope.into();
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    pub fn in_inputs(mut self) -> Self {
+        self.in_inputs = true;
+        self
+    }
+
+    // Plain keys such as "g then i" must not fire while the user is typing.
+    fn fires_while_editing(&self) -> bool {
+        self.in_inputs
+            || self
+                .shortcut
+                .chords
+                .first()
+                .is_some_and(KeyChord::has_command_modifier)
+    }
+}
+
+/// Two bindings of the same scope where one shortcut equals or starts with
+/// the other, so the longer one can never fire as expected.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ShortcutConflict {
+    pub scope: String,
+    pub first: String,
+    pub second: String,
+}
+
+/// Outcome of a key press.
+#[derive(Clone, PartialEq, Debug)]
+pub enum ShortcutMatch {
+    Fired(ShortcutBinding),
+    /// The press started or continued a sequence
+    Pending,
+    None,
+}
+
+/// Bindings, modal scopes and the pending sequence of the shortcut manager.
+#[derive(Clone, Debug, Default)]
+pub struct ShortcutRegistry {
+    bindings: Vec<ShortcutBinding>,
+    modal_scopes: Vec<String>,
+    pending: Vec<KeyPress>,
+    pending_since: f64,
+}
+
+impl ShortcutRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn bindings(&self) -> &[ShortcutBinding] {
+        &self.bindings
+    }
+
+    /// Adds `binding`, replacing one with the same id, and returns the
+    /// conflicts it introduces.
+    pub fn register(&mut self, binding: ShortcutBinding, mac: bool) -> Vec<ShortcutConflict> {
+        let id = binding.id.clone();
+        match self.bindings.iter_mut().find(|b| b.id == binding.id) {
+            Some(existing) => *existing = binding,
+            None => self.bindings.push(binding),
+        }
+        self.conflicts(mac)
+            .into_iter()
+            .filter(|conflict| conflict.first == id || conflict.second == id)
+            .collect()
+    }
+
+    pub fn unregister(&mut self, id: &str) {
+        self.bindings.retain(|b| b.id != id);
+    }
+
+    pub fn conflicts(&self, mac: bool) -> Vec<ShortcutConflict> {
+        let mut conflicts = Vec::new();
+        for (index, a) in self.bindings.iter().enumerate() {
+            for b in &self.bindings[index + 1..] {
+                if a.scope == b.scope
+                    && (a.shortcut.starts_with(&b.shortcut, mac)
+                        || b.shortcut.starts_with(&a.shortcut, mac))
+                {
+                    conflicts.push(ShortcutConflict {
+                        scope: a.scope.clone(),
+                        first: a.id.clone(),
+                        second: b.id.clone(),
+                    });
+                }
+            }
+        }
+        conflicts
+    }
+
+    pub fn push_modal(&mut self, scope: impl Into<String>) {
+        self.modal_scopes.push(scope.into());
+        self.pending.clear();
+    }
+
+    pub fn pop_modal(&mut self, scope: &str) {
+        if let Some(index) = self.modal_scopes.iter().rposition(|s| s == scope) {
+            self.modal_scopes.remove(index);
+        }
+        self.pending.clear();
+    }
+
+    /// Scopes searched for a key press, highest priority first.
+    ///
+    /// `focused` are the focus scopes around the focused element, innermost
+    /// first.
+    pub fn active_scopes(&self, focused: &[String]) -> Vec<String> {
+        let mut scopes = focused.to_vec();
+        scopes.push(
+            self.modal_scopes
+                .last()
+                .cloned()
+                .unwrap_or_else(|| GLOBAL_SCOPE.to_string()),
+        );
+        scopes
+    }
+
+    pub fn is_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Feeds a key press at `now` (milliseconds).
+    ///
+    /// `editing` is whether focus is in a text field.
+    pub fn dispatch(
+        &mut self,
+        press: &KeyPress,
+        now: f64,
+        mac: bool,
+        focused: &[String],
+        editing: bool,
+    ) -> ShortcutMatch {
+        if is_modifier_key(&press.key) {
+            return ShortcutMatch::None;
+        }
+        if !self.pending.is_empty() && now - self.pending_since > SEQUENCE_TIMEOUT_MS {
+            self.pending.clear();
+        }
+
+        let mut sequence = self.pending.clone();
+        sequence.push(press.clone());
+        let result = self.find(&sequence, mac, focused, editing);
+        match result {
+            ShortcutMatch::None if !self.pending.is_empty() => {
+                // A broken sequence: the press may still start a new one.
+                self.pending.clear();
+                self.dispatch(press, now, mac, focused, editing)
+            }
+            ShortcutMatch::Pending => {
+                self.pending = sequence;
+                self.pending_since = now;
+                ShortcutMatch::Pending
+            }
+            other => {
+                self.pending.clear();
+                other
+            }
+        }
+    }
+
+    fn find(
+        &self,
+        sequence: &[KeyPress],
+        mac: bool,
+        focused: &[String],
+        editing: bool,
+    ) -> ShortcutMatch {
+        for scope in self.active_scopes(focused) {
+            let candidates = self.bindings.iter().filter(|b| {
+                b.scope == scope
+                    && (!editing || b.fires_while_editing())
+                    && b.shortcut.matches_prefix(sequence, mac)
+            });
+            let mut longer = false;
+            for binding in candidates {
+                if binding.shortcut.chords.len() == sequence.len() {
+                    return ShortcutMatch::Fired(binding.clone());
+                }
+                longer = true;
+            }
+            if longer {
+                return ShortcutMatch::Pending;
+            }
+        }
+        ShortcutMatch::None
+    }
+}
+
+thread_local! {
+    static SHORTCUTS: RefCell<ShortcutRegistry> = RefCell::new(ShortcutRegistry::new());
+    static LISTENING: Cell<bool> = const { Cell::new(false) };
+}
+
+fn listen() {
+    if LISTENING.with(|listening| listening.replace(true)) {
+        return;
+    }
+    platform::on_keydown(|press| {
+        let result = SHORTCUTS.with(|shortcuts| {
+            shortcuts.borrow_mut().dispatch(
+                press,
+                platform::now_timestamp(),
+                platform::is_mac(),
+                &platform::focused_shortcut_scopes(),
+                press.editable,
+            )
+        });
+        match result {
+            ShortcutMatch::Fired(binding) => {
+                binding.handler.call();
+                true
+            }
+            ShortcutMatch::Pending => true,
+            ShortcutMatch::None => false,
+        }
+    });
+}
+
+/// Adds `binding` to the global manager; conflicts are logged as warnings.
+pub fn register_shortcut(binding: ShortcutBinding) -> Vec<ShortcutConflict> {
+    listen();
+    let conflicts =
+        SHORTCUTS.with(|shortcuts| shortcuts.borrow_mut().register(binding, platform::is_mac()));
+    for conflict in &conflicts {
+        platform::log_warn(&format!(
+            "shortcut `{}` conflicts with `{}` in scope `{}`",
+            conflict.first, conflict.second, conflict.scope
+        ));
+    }
+    conflicts
+}
+
+pub fn unregister_shortcut(id: &str) {
+    SHORTCUTS.with(|shortcuts| shortcuts.borrow_mut().unregister(id));
+}
+
+/// Bindings of the global manager, e.g. for a shortcuts help dialog.
+pub fn registered_shortcuts() -> Vec<ShortcutBinding> {
+    SHORTCUTS.with(|shortcuts| shortcuts.borrow().bindings().to_vec())
+}
+
+pub fn shortcut_conflicts() -> Vec<ShortcutConflict> {
+    SHORTCUTS.with(|shortcuts| shortcuts.borrow().conflicts(platform::is_mac()))
+}
+
+pub fn push_modal_scope(scope: &str) {
+    SHORTCUTS.with(|shortcuts| shortcuts.borrow_mut().push_modal(scope));
+}
+
+pub fn pop_modal_scope(scope: &str) {
+    SHORTCUTS.with(|shortcuts| shortcuts.borrow_mut().pop_modal(scope));
+}
+
+/// Label of `shortcut` for the current platform, or `shortcut` itself when
+/// it does not parse.
+pub fn shortcut_label(shortcut: &str) -> String {
+    Shortcut::parse(shortcut)
+        .map(|parsed| parsed.label(platform::is_mac()))
+        .unwrap_or_else(|_| shortcut.to_string())
+}
+
+/// Unregisters a [`use_shortcut`] binding when its component unmounts.
+struct ShortcutRegistration(String);
+
+impl Drop for ShortcutRegistration {
+    fn drop(&mut self) {
+        unregister_shortcut(&self.0);
+    }
+}
+
+/// Pops a [`use_modal_scope`] scope when its component unmounts.
+struct ModalScopeGuard(String);
+
+impl Drop for ModalScopeGuard {
+    fn drop(&mut self) {
+        pop_modal_scope(&self.0);
+    }
+}
+
+/// Register `binding` while the calling component is mounted.
+///
+/// Later renders swap in their handler, and re-register the binding when
+/// its shortcut, scope or flags changed or it was unregistered meanwhile,
+/// e.g. by a menu item that was disabled and enabled again.
+pub fn use_shortcut(binding: ShortcutBinding) {
+    let Some(component) = runtime::active_component_id() else {
+        register_shortcut(binding);
+        return;
+    };
+    // Hook slots are cleared on unmount, dropping the guard with them.
+    let key = format!("hk-shortcut:{}", binding.id);
+    let (latest, forward, _) = runtime::hook_slot(component, &key, || {
+        let latest = Rc::new(RefCell::new(binding.handler.clone()));
+        let target = latest.clone();
+        let forward = ShortcutHandler::new(move || {
+            let handler = target.borrow().clone();
+            handler.call()
+        });
+        (
+            latest,
+            forward,
+            Rc::new(ShortcutRegistration(binding.id.clone())),
+        )
+    });
+    let registered = ShortcutBinding {
+        handler: forward,
+        ..binding.clone()
+    };
+    let current = SHORTCUTS.with(|shortcuts| {
+        shortcuts
+            .borrow()
+            .bindings()
+            .iter()
+            .find(|b| b.id == binding.id)
+            .cloned()
+    });
+    if current.as_ref() != Some(&registered) {
+        register_shortcut(registered);
+    }
+    *latest.borrow_mut() = binding.handler;
+}
+
+/// Make `scope` the modal scope while the calling component is mounted,
+/// suppressing the shortcuts of the page below.
+pub fn use_modal_scope(scope: &str) {
+    let Some(component) = runtime::active_component_id() else {
+        return;
+    };
+    let key = format!("hk-modal-scope:{scope}");
+    let scope = scope.to_string();
+    runtime::hook_slot(component, &key, move || {
+        push_modal_scope(&scope);
+        Rc::new(ModalScopeGuard(scope))
+    });
+}
@@ -10,6 +10,11 @@ mod tests {
     use hikari_components::basic::file_upload::{FileUpload, FileUploadProps};
     use hikari_components::basic::image::{Image, ImageProps};
     use hikari_components::basic::input::{Input, InputProps};
+    use hikari_components::basic::input_mask::{InputFormatter, InputMask, MaskSlot, MaskedValue};
+    use hikari_components::basic::mention::{
+        MentionEntity, MentionSource, MentionSpan, MentionValue, SlashCommand, auto_resize_rows,
+        clamp_rows, filter_commands, find_command, find_trigger,
+    };
     use hikari_components::basic::radio_group::{RadioGroup, RadioGroupProps};
     use hikari_components::basic::select::{Select, SelectProps};
     use hikari_components::basic::slider::{Slider, SliderProps};
@@ -128,6 +133,139 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_input_with_formatter_shows_formatted_value() {
+        let node = Input(InputProps {
+            value: Some("5551234567".to_string()),
+            formatter: Some(InputMask::phone().into()),
+            ..Default::default()
+        });
+        let inputs = find_elements_by_tag(&node, "input");
+        assert_eq!(
+            inputs[0].attributes.get("value").map(|s| s.as_str()),
+            Some("(555) 123-4567")
+        );
+    }
+
+    // ── InputMask ───────────────────────────────────────────────
+
+    #[test]
+    fn test_input_mask_pattern_slots() {
+        let mask = InputMask::new("\\9-a*?");
+        assert_eq!(
+            mask.slots(),
+            &[
+                MaskSlot::Literal('9'),
+                MaskSlot::Literal('-'),
+                MaskSlot::Letter,
+                MaskSlot::Alphanumeric,
+                MaskSlot::Any,
+            ]
+        );
+        assert_eq!(mask.capacity(), 3);
+    }
+
+    #[test]
+    fn test_input_mask_format_and_parse() {
+        let mask = InputMask::phone();
+        assert_eq!(mask.format(""), "");
+        assert_eq!(mask.format("555"), "(555) ");
+        assert_eq!(mask.format("5551234567"), "(555) 123-4567");
+        assert_eq!(mask.format("55512345678"), "(555) 123-4567");
+        assert_eq!(mask.parse("(555) 123-4567"), "5551234567");
+        assert_eq!(mask.parse("555-123-4567"), "5551234567");
+        assert_eq!(mask.parse("(55x5"), "555");
+    }
+
+    #[test]
+    fn test_input_mask_presets() {
+        assert_eq!(
+            InputMask::credit_card().format("4111111111111111"),
+            "4111 1111 1111 1111"
+        );
+        assert_eq!(
+            InputMask::iban().format("de89370400440532013000"),
+            "DE89 3704 0044 0532 0130 00"
+        );
+        assert_eq!(InputMask::time().format("2359"), "23:59");
+
+        let date = InputMask::date();
+        assert!(date.is_complete("31122024"));
+        assert!(!date.is_complete("3112"));
+    }
+
+    #[test]
+    fn test_input_mask_placeholder() {
+        let mask = InputMask::date().with_placeholder('_');
+        assert_eq!(mask.format(""), "");
+        assert_eq!(mask.format("12"), "12/__/____");
+        assert_eq!(mask.parse("12/3_/____"), "123");
+    }
+
+    #[test]
+    fn test_formatter_caret_skips_inserted_literals() {
+        let formatter = InputFormatter::from(InputMask::phone());
+        assert_eq!(
+            formatter.apply("(55", "(555", 4),
+            MaskedValue {
+                display: "(555) ".to_string(),
+                raw: "555".to_string(),
+                caret: 6,
+            }
+        );
+        // Typing in the middle keeps the caret after the new digit.
+        let result = formatter.apply("(555) 13", "(555) 123", 8);
+        assert_eq!(result.display, "(555) 123-");
+        assert_eq!(result.caret, 8);
+    }
+
+    #[test]
+    fn test_formatter_backspace_over_literal() {
+        let formatter = InputFormatter::from(InputMask::phone());
+        assert_eq!(
+            formatter.apply("(555) 123", "(555)123", 5),
+            MaskedValue {
+                display: "(551) 23".to_string(),
+                raw: "55123".to_string(),
+                caret: 3,
+            }
+        );
+
+        let result = formatter.apply("(555) 1", "(555) ", 6);
+        assert_eq!(result.raw, "555");
+        assert_eq!(result.caret, 4);
+    }
+
+    #[test]
+    fn test_formatter_caret_stops_at_placeholder() {
+        let formatter = InputFormatter::from(InputMask::date().with_placeholder('_'));
+        let result = formatter.apply("12/__/____", "12/3__/____", 4);
+        assert_eq!(result.display, "12/3_/____");
+        assert_eq!(result.raw, "123");
+        assert_eq!(result.caret, 4);
+    }
+
+    #[test]
+    fn test_formatter_ipv4() {
+        let formatter = InputFormatter::ipv4();
+        assert_eq!(formatter.format("1921680"), "192.168.0");
+        assert_eq!(formatter.format("10.0.0.1"), "10.0.0.1");
+        assert_eq!(formatter.format("1.2.3.4.5"), "1.2.3.45");
+
+        let result = formatter.apply("19", "192", 3);
+        assert_eq!(result.display, "192.");
+        assert_eq!(result.caret, 4);
+    }
+
+    #[test]
+    fn test_formatter_custom_hooks() {
+        let formatter = InputFormatter::new(|raw| raw.to_uppercase(), |text| text.to_lowercase());
+        let result = formatter.apply("ab", "abc", 3);
+        assert_eq!(result.display, "ABC");
+        assert_eq!(result.raw, "abc");
+        assert_eq!(result.caret, 3);
+    }
+
     #[test]
     fn test_checkbox_renders() {
         let _ = Checkbox(CheckboxProps::default());
@@ -198,6 +336,7 @@ mod tests {
             remove_entry: Callback::new(|_| {}),
             clear_all: Callback::new(|_| {}),
             start_close_animation: Callback::new(|_| {}),
+            theme_scope: None,
         });
         let _ = Select(SelectProps::default());
     }
@@ -212,6 +351,132 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_textarea_auto_resize_rows_attribute() {
+        let node = Textarea(TextareaProps {
+            value: "one\ntwo\nthree\nfour".to_string(),
+            rows: 2,
+            auto_resize: true,
+            max_rows: Some(3),
+            ..Default::default()
+        });
+        let textareas = find_elements_by_tag(&node, "textarea");
+        assert_eq!(
+            textareas[0].attributes.get("rows").map(|s| s.as_str()),
+            Some("3")
+        );
+    }
+
+    // ── Mentions ────────────────────────────────────────────────
+
+    fn mention(start: usize, end: usize, id: &str, label: &str) -> MentionSpan {
+        MentionSpan {
+            start,
+            end,
+            trigger: '@',
+            id: id.to_string(),
+            label: label.to_string(),
+        }
+    }
+
+    fn alice_and_bob() -> MentionValue {
+        MentionValue::new(
+            "hi @alice and @bob",
+            vec![mention(3, 9, "u1", "alice"), mention(14, 18, "u2", "bob")],
+        )
+    }
+
+    #[test]
+    fn test_find_trigger() {
+        let query = find_trigger("hi @al", 6, &['@', '#']).unwrap();
+        assert_eq!(query.trigger, '@');
+        assert_eq!((query.start, query.end), (3, 6));
+        assert_eq!(query.query, "al");
+
+        assert_eq!(find_trigger("#", 1, &['@', '#']).unwrap().query, "");
+        assert!(find_trigger("mail@host", 9, &['@']).is_none());
+        assert!(find_trigger("@al bob", 7, &['@']).is_none());
+    }
+
+    #[test]
+    fn test_find_command_only_at_line_start() {
+        assert_eq!(find_command("/he", 3).unwrap().query, "he");
+        assert_eq!(find_command("text\n/gi", 7).unwrap().start, 5);
+        assert!(find_command("a /he", 5).is_none());
+    }
+
+    #[test]
+    fn test_filter_commands() {
+        let commands = vec![
+            SlashCommand::new("giphy", "Search GIFs"),
+            SlashCommand::new("gist", "Share a gist"),
+            SlashCommand::new("help", "Show help"),
+        ];
+        let names: Vec<String> = filter_commands(&commands, "Gi")
+            .into_iter()
+            .map(|c| c.name)
+            .collect();
+        assert_eq!(names, vec!["giphy", "gist"]);
+    }
+
+    #[test]
+    fn test_mention_source_from_entities() {
+        let source = MentionSource::from_entities(vec![
+            MentionEntity::new("u1", "Alice"),
+            MentionEntity::new("u2", "Bob").with_description("Design"),
+        ]);
+        let found = source.search("ali");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, "u1");
+        assert_eq!(source.search("").len(), 2);
+    }
+
+    #[test]
+    fn test_auto_resize_rows() {
+        assert_eq!(auto_resize_rows("", 2, None), 2);
+        assert_eq!(auto_resize_rows("a\nb\nc", 2, Some(5)), 3);
+        assert_eq!(auto_resize_rows(&"x\n".repeat(10), 2, Some(5)), 5);
+        // Measured heights (wrapped lines) go through the same clamp
+        assert_eq!(clamp_rows(4, 2, None), 4);
+        assert_eq!(clamp_rows(9, 2, Some(5)), 5);
+        assert_eq!(clamp_rows(1, 3, Some(2)), 3);
+    }
+
+    #[test]
+    fn test_insert_mention() {
+        let value = MentionValue::plain("hi @al");
+        let query = find_trigger(&value.text, 6, &['@']).unwrap();
+        let (next, caret) = value.insert_mention(&query, &MentionEntity::new("u1", "alice"));
+        assert_eq!(next.text, "hi @alice ");
+        assert_eq!(next.mentions, vec![mention(3, 9, "u1", "alice")]);
+        assert_eq!(caret, 10);
+    }
+
+    #[test]
+    fn test_deleting_into_mention_removes_whole_token() {
+        let (next, caret) = alice_and_bob().apply_edit("hi @alic and @bob");
+        assert_eq!(next.text, "hi  and @bob");
+        assert_eq!(next.mentions, vec![mention(8, 12, "u2", "bob")]);
+        assert_eq!(caret, 3);
+    }
+
+    #[test]
+    fn test_typing_inside_mention_drops_token() {
+        let (next, _) = alice_and_bob().apply_edit("hi @axlice and @bob");
+        assert_eq!(next.text, "hi @axlice and @bob");
+        assert_eq!(next.mentions, vec![mention(15, 19, "u2", "bob")]);
+    }
+
+    #[test]
+    fn test_edit_before_mentions_shifts_spans() {
+        let (next, caret) = alice_and_bob().apply_edit("oh hi @alice and @bob");
+        assert_eq!(
+            next.mentions,
+            vec![mention(6, 12, "u1", "alice"), mention(17, 21, "u2", "bob")]
+        );
+        assert_eq!(caret, 3);
+    }
+
     #[test]
     fn test_date_picker_renders() {
         let _ = DatePicker(DatePickerProps::default());
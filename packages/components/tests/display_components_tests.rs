@@ -3,10 +3,10 @@ mod tests {
 
     use hikari_components::display::{
         Calendar, CalendarProps, Carousel, CarouselIndicatorPosition, CarouselIndicatorType,
-        CarouselProps, Comment, CommentProps, Empty, EmptyProps, QRCode, QRCodeProps, Skeleton,
-        SkeletonCard, SkeletonCardProps, SkeletonProps, SkeletonSize, SkeletonTableProps,
-        SkeletonVariant, Tag, TagProps, TagVariant, Timeline, TimelineItem, TimelineItemProps,
-        TimelinePosition, TimelineProps,
+        CarouselProps, Comment, CommentProps, Empty, EmptyProps, QRCode, QRCodeProps, ShortcutHint,
+        ShortcutHintProps, Skeleton, SkeletonCard, SkeletonCardProps, SkeletonProps, SkeletonSize,
+        SkeletonTableProps, SkeletonVariant, Tag, TagProps, TagVariant, Timeline, TimelineItem,
+        TimelineItemProps, TimelinePosition, TimelineProps,
     };
     use hikari_components::prelude::*;
 
@@ -351,4 +351,26 @@ mod tests {
         assert_eq!(props.avatar.as_deref().unwrap(), "avatar.jpg");
         assert_eq!(props.content, "Great post!");
     }
+
+    // ── ShortcutHint ────────────────────────────────────────────
+
+    #[test]
+    fn test_shortcut_hint_renders() {
+        let _ = ShortcutHint(ShortcutHintProps {
+            shortcut: "Mod+Shift+P".to_string(),
+            class: String::new(),
+        });
+        let _ = ShortcutHint(ShortcutHintProps {
+            shortcut: "g then i".to_string(),
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn test_shortcut_hint_unparseable_renders_verbatim() {
+        let _ = ShortcutHint(ShortcutHintProps {
+            shortcut: "Hyper+X".to_string(),
+            ..Default::default()
+        });
+    }
 }
@@ -1,11 +1,20 @@
 #[cfg(test)]
 mod tests {
 
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
     use hikari_components::entry::{
-        AutoCompleteProps, Cascader, CascaderOption, CascaderProps, CascaderSize, NumberInput,
-        NumberInputProps, NumberInputSize, SearchProps, SelectChangeEvent, Transfer, TransferItem,
-        TransferProps,
+        AutoCompleteProps, Cascader, CascaderOption, CascaderProps, CascaderSize, Command,
+        CommandPaletteProps, CommandProvider, CommandSink, MAX_RECENT, NumberInput,
+        NumberInputProps, NumberInputSize, ProviderResults, RECENT_GROUP, SearchProps,
+        SelectChangeEvent, SuggestionProvider, TagInputProps, TagValidator, Transfer, TransferItem,
+        TransferProps, command_score, filter_options, find_command_by_id, fuzzy_score,
+        group_commands, merge_tokens, move_token, palette_items, recent_command_ids, record_recent,
+        register_command, registered_commands, search_commands, split_tokens,
+        toggle_command_palette, unregister_command, use_command,
     };
+    use hikari_components::utils::KeyPress;
 
     // ── AutoComplete ────────────────────────────────────────────
 
@@ -42,6 +51,29 @@ mod tests {
         assert!(props.allow_clear);
     }
 
+    #[test]
+    fn test_filter_options_prefix_match() {
+        let options = vec![
+            "Apple".to_string(),
+            "apply".to_string(),
+            "banana".to_string(),
+        ];
+        assert_eq!(filter_options(&options, "ap"), vec!["Apple", "apply"]);
+        assert_eq!(filter_options(&options, "").len(), 3);
+        assert!(filter_options(&options, "z").is_empty());
+    }
+
+    #[test]
+    fn test_suggestion_provider() {
+        let provider = SuggestionProvider::new(|query| vec![format!("{query}!")]);
+        assert_eq!(provider.suggest("hi"), vec!["hi!"]);
+        assert_eq!(provider, provider.clone());
+
+        let static_provider = SuggestionProvider::from_options(vec!["rust".to_string()]);
+        assert_eq!(static_provider.suggest("ru"), vec!["rust"]);
+        assert_ne!(provider, static_provider);
+    }
+
     // ── Cascader ────────────────────────────────────────────────
 
     #[test]
@@ -266,4 +298,298 @@ mod tests {
         assert!(props.show_search);
         assert!(!props.one_way);
     }
+
+    // ── TagInput ────────────────────────────────────────────────
+
+    #[test]
+    fn test_tag_input_props_default() {
+        let props = TagInputProps::default();
+        assert!(props.value.is_empty());
+        assert_eq!(props.delimiters, vec![',', ';', '\n', '\t']);
+        assert!(props.validator.is_none());
+        assert!(props.max_count.is_none());
+        assert!(!props.allow_duplicates);
+        assert!(props.editable);
+        assert!(props.draggable);
+        assert!(!props.disabled);
+    }
+
+    #[test]
+    fn test_split_tokens_keeps_remainder() {
+        let (tokens, remainder) = split_tokens("a, b;; c,d", &[',', ';']);
+        assert_eq!(tokens, vec!["a", "b", "c"]);
+        assert_eq!(remainder, "d");
+
+        let (tokens, remainder) = split_tokens("one\ntwo\n", &['\n']);
+        assert_eq!(tokens, vec!["one", "two"]);
+        assert_eq!(remainder, "");
+    }
+
+    #[test]
+    fn test_merge_tokens_dedup_and_max() {
+        let existing = vec!["a".to_string()];
+        let incoming = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+
+        assert_eq!(
+            merge_tokens(&existing, incoming.clone(), None, false),
+            vec!["a", "b", "c"]
+        );
+        assert_eq!(
+            merge_tokens(&existing, incoming.clone(), Some(2), false),
+            vec!["a", "b"]
+        );
+        assert_eq!(
+            merge_tokens(&existing, incoming, None, true),
+            vec!["a", "a", "b", "c"]
+        );
+    }
+
+    #[test]
+    fn test_move_token() {
+        let tokens: Vec<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(move_token(&tokens, 0, 2), vec!["b", "c", "a"]);
+        assert_eq!(move_token(&tokens, 2, 0), vec!["c", "a", "b"]);
+        assert_eq!(move_token(&tokens, 5, 0), tokens);
+    }
+
+    #[test]
+    fn test_tag_validator() {
+        let validator = TagValidator::new(|token| {
+            if token.contains('@') {
+                Ok(())
+            } else {
+                Err("Not an email".to_string())
+            }
+        });
+        assert!(validator.validate("a@b.c").is_ok());
+        assert_eq!(validator.validate("abc"), Err("Not an email".to_string()));
+    }
+
+    // ── CommandPalette ──────────────────────────────────────────
+
+    fn sample_commands() -> Vec<Command> {
+        vec![
+            Command::new("file.open", "Open File")
+                .group("File")
+                .shortcut("Ctrl+O"),
+            Command::new("file.save", "Save").group("File"),
+            Command::new("view.theme", "Change Theme")
+                .keywords(["dark mode", "appearance"])
+                .group("View")
+                .children(vec![
+                    Command::new("theme.dark", "Dark"),
+                    Command::new("theme.light", "Light"),
+                ]),
+            Command::new("help.docs", "Documentation"),
+        ]
+    }
+
+    #[test]
+    fn test_command_palette_props_default() {
+        let props = CommandPaletteProps::default();
+        assert!(props.commands.is_empty());
+        assert!(props.providers.is_empty());
+        assert!(props.shortcut);
+        assert_eq!(props.empty_text, "No results");
+    }
+
+    #[test]
+    fn test_fuzzy_score_matching() {
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+        assert!(fuzzy_score("opf", "Open File").is_some());
+        assert!(fuzzy_score("OPEN file", "Open File").is_some());
+        assert_eq!(fuzzy_score("fo", "Open File"), None);
+        assert_eq!(fuzzy_score("xyz", "Open File"), None);
+    }
+
+    #[test]
+    fn test_fuzzy_score_prefers_prefix_and_consecutive() {
+        let prefix = fuzzy_score("sav", "Save").unwrap();
+        let scattered = fuzzy_score("sav", "Disable Avatar").unwrap();
+        assert!(prefix > scattered);
+
+        let word_start = fuzzy_score("f", "Open File").unwrap();
+        let mid_word = fuzzy_score("f", "Offline").unwrap();
+        assert!(word_start > mid_word);
+    }
+
+    #[test]
+    fn test_command_score_uses_keywords_and_group() {
+        let theme = &sample_commands()[2];
+        assert!(command_score("dark", theme).is_some());
+        assert!(command_score("view", theme).is_some());
+        assert_eq!(command_score("save", theme), None);
+
+        // The same text scores higher in the title than in a keyword
+        let titled = Command::new("a", "Dark");
+        let keyworded = Command::new("b", "Other").keywords(["dark"]);
+        assert!(command_score("dark", &titled) > command_score("dark", &keyworded));
+    }
+
+    #[test]
+    fn test_search_commands_ranks_best_first() {
+        let commands = sample_commands();
+        let all = search_commands(&commands, "  ");
+        assert_eq!(all.len(), commands.len());
+
+        let results = search_commands(&commands, "doc");
+        assert_eq!(results[0].id, "help.docs");
+
+        let ids: Vec<String> = search_commands(&commands, "theme")
+            .into_iter()
+            .map(|c| c.id)
+            .collect();
+        assert_eq!(ids, vec!["view.theme"]);
+    }
+
+    #[test]
+    fn test_palette_items_with_recent() {
+        let commands = sample_commands();
+        let recent = vec![commands[3].clone()];
+
+        let items = palette_items(&commands, "", &recent);
+        assert_eq!(items.len(), commands.len());
+        assert_eq!(items[0].id, "help.docs");
+        assert_eq!(items[0].group.as_deref(), Some(RECENT_GROUP));
+        assert_eq!(items.iter().filter(|c| c.id == "help.docs").count(), 1);
+
+        // Recent commands are not listed separately while searching
+        let searched = palette_items(&commands, "save", &recent);
+        assert_eq!(searched[0].id, "file.save");
+        assert_eq!(searched[0].group.as_deref(), Some("File"));
+    }
+
+    #[test]
+    fn test_group_commands_keeps_first_appearance_order() {
+        let groups = group_commands(&sample_commands());
+        let labels: Vec<Option<&str>> = groups.iter().map(|(g, _)| g.as_deref()).collect();
+        assert_eq!(labels, vec![Some("File"), Some("View"), None]);
+        assert_eq!(groups[0].1.len(), 2);
+    }
+
+    #[test]
+    fn test_find_command_by_id_searches_pages() {
+        let commands = sample_commands();
+        assert!(commands[2].is_page());
+        assert_eq!(
+            find_command_by_id(&commands, "theme.light").map(|c| c.title),
+            Some("Light".to_string())
+        );
+        assert!(find_command_by_id(&commands, "missing").is_none());
+    }
+
+    #[test]
+    fn test_command_registry() {
+        register_command(Command::new("reg.one", "One"));
+        register_command(Command::new("reg.two", "Two"));
+        register_command(Command::new("reg.one", "One again"));
+
+        let registered = registered_commands();
+        assert_eq!(registered.len(), 2);
+        assert_eq!(registered[0].title, "One again");
+
+        unregister_command("reg.one");
+        assert_eq!(registered_commands().len(), 1);
+    }
+
+    #[test]
+    fn test_use_command_outside_component_registers() {
+        use_command(Command::new("hook.cmd", "From hook"));
+        assert!(registered_commands().iter().any(|c| c.id == "hook.cmd"));
+    }
+
+    #[test]
+    fn test_recent_commands() {
+        record_recent("a");
+        record_recent("b");
+        record_recent("a");
+        assert_eq!(recent_command_ids(), vec!["a", "b"]);
+
+        for i in 0..10 {
+            record_recent(&format!("cmd-{i}"));
+        }
+        let recent = recent_command_ids();
+        assert_eq!(recent.len(), MAX_RECENT);
+        assert_eq!(recent[0], "cmd-9");
+    }
+
+    #[test]
+    fn test_command_action_runs() {
+        let ran = Rc::new(RefCell::new(0));
+        let ran_clone = ran.clone();
+        let command = Command::new("run", "Run").on_run(move || *ran_clone.borrow_mut() += 1);
+        command.action.as_ref().unwrap().run();
+        assert_eq!(*ran.borrow(), 1);
+    }
+
+    #[test]
+    fn test_command_provider_resolves_into_sink() {
+        let provider = CommandProvider::from_fn(|query| vec![Command::new(query, query)]);
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let received_clone = received.clone();
+        provider.query(
+            "remote",
+            CommandSink::new(move |results| received_clone.borrow_mut().extend(results)),
+        );
+        assert_eq!(received.borrow()[0].id, "remote");
+    }
+
+    #[test]
+    fn test_provider_results_drop_stale_answers() {
+        let mut results = ProviderResults::default();
+        let first = results.start(2);
+        assert!(results.is_loading());
+
+        let second = results.start(1);
+        assert!(!results.accept(first, vec![Command::new("old", "Old")]));
+        assert!(results.commands.is_empty());
+
+        assert!(results.accept(second, vec![Command::new("new", "New")]));
+        assert!(!results.is_loading());
+        assert_eq!(results.commands[0].id, "new");
+    }
+
+    #[test]
+    fn test_toggle_without_palette_mounted() {
+        assert!(!toggle_command_palette());
+    }
+
+    #[test]
+    fn test_toggle_after_palette_unmounts() {
+        use hikari_components::entry::CommandPalette;
+        use hikari_components::portal::PortalContext;
+        use hikari_components::prelude::*;
+        use tairitsu_vdom::runtime;
+
+        use_context_provider(PortalContext {
+            entries: Signal::new(Vec::new()),
+            add_entry: Callback::new(|_| {}),
+            remove_entry: Callback::new(|_| {}),
+            clear_all: Callback::new(|_| {}),
+            start_close_animation: Callback::new(|_| {}),
+            theme_scope: None,
+        });
+        let component = runtime::use_component(VNode::empty);
+        runtime::with_component(component, || {
+            CommandPalette(CommandPaletteProps {
+                shortcut: false,
+                ..Default::default()
+            })
+        });
+        assert!(toggle_command_palette());
+
+        runtime::cleanup_component(component);
+        assert!(!toggle_command_palette());
+    }
+
+    #[test]
+    fn test_key_press_is_mod() {
+        assert!(KeyPress::new("k").with_ctrl().is_mod("k"));
+        assert!(KeyPress::new("K").with_meta().is_mod("k"));
+        assert!(!KeyPress::new("k").is_mod("k"));
+
+        let mut alt = KeyPress::new("k").with_ctrl();
+        alt.alt = true;
+        assert!(!alt.is_mod("k"));
+    }
 }
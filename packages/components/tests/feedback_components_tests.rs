@@ -157,6 +157,58 @@ mod tests {
         assert_eq!(props.class, "custom-tooltip");
     }
 
+    #[test]
+    fn test_tooltip_props_hover_intent_defaults() {
+        let props = TooltipProps::default();
+        assert!(!props.interactive);
+        assert!(props.close_delay.is_none());
+        assert!(props.rich_content.is_none());
+    }
+
+    #[test]
+    fn test_tooltip_delay_group_warm_window() {
+        use hikari_components::feedback::DelayGroupState;
+
+        let mut group = DelayGroupState::new(500, 0, 300);
+        assert_eq!(group.open_delay(None, 0.0), 500);
+        assert_eq!(group.open_delay(Some(200), 0.0), 200);
+
+        group.mark_open();
+        assert_eq!(group.open_delay(None, 10.0), 0);
+
+        group.mark_closed(1000.0);
+        assert_eq!(group.open_delay(None, 1200.0), 0);
+        assert_eq!(group.open_delay(None, 1400.0), 500);
+    }
+
+    #[test]
+    fn test_hover_intent_immediate_transitions() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        use hikari_components::feedback::HoverIntent;
+
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let intent = HoverIntent::new();
+
+        let l = log.clone();
+        intent.leave(0, move || l.borrow_mut().push("close"));
+        assert!(log.borrow().is_empty());
+
+        let l = log.clone();
+        intent.enter(0, move || l.borrow_mut().push("open"));
+        assert!(intent.is_open());
+
+        // Re-entering (e.g. moving onto an interactive tooltip) does not reopen.
+        let l = log.clone();
+        intent.enter(0, move || l.borrow_mut().push("open"));
+
+        let l = log.clone();
+        intent.leave(0, move || l.borrow_mut().push("close"));
+        assert!(!intent.is_open());
+        assert_eq!(*log.borrow(), vec!["open", "close"]);
+    }
+
     #[test]
     fn test_modal_config_default() {
         let config = hikari_components::feedback::ModalConfig::default();
@@ -370,6 +422,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_progress_segment_layout_clamps_to_full() {
+        use hikari_components::feedback::{ProgressSegment, segment_layout};
+
+        let segments = vec![
+            ProgressSegment::new(30.0, "done"),
+            ProgressSegment::new(50.0, "running").with_color("red"),
+            ProgressSegment::new(40.0, "queued"),
+        ];
+        let layout = segment_layout(&segments, 100.0);
+        assert_eq!(layout[0], (0.0, 30.0));
+        assert_eq!(layout[1], (30.0, 50.0));
+        assert_eq!(layout[2], (80.0, 20.0));
+    }
+
+    #[test]
+    fn test_progress_steps_and_dashboard() {
+        use hikari_components::feedback::{dashboard_dash, filled_steps};
+
+        assert_eq!(filled_steps(0.0, 5), 0);
+        assert_eq!(filled_steps(60.0, 5), 3);
+        assert_eq!(filled_steps(150.0, 5), 5);
+
+        let (full_array, full_offset) = dashboard_dash(100.0);
+        let (_, empty_offset) = dashboard_dash(0.0);
+        assert!(full_array.starts_with(&empty_offset));
+        assert_eq!(full_offset, "0.000");
+    }
+
+    #[test]
+    fn test_progress_gradient_css() {
+        use hikari_components::feedback::gradient_css;
+        use hikari_palette::{Color, Gradient};
+
+        let gradient =
+            Gradient::from_colors(vec![Color::from_rgb(255, 0, 0), Color::from_rgb(0, 0, 255)]);
+        assert_eq!(
+            gradient_css(&gradient, 90.0),
+            "linear-gradient(90deg, #FF0000 0.0%, #0000FF 100.0%)"
+        );
+    }
+
+    #[test]
+    fn test_progress_rate_estimator_eta() {
+        use hikari_components::feedback::{ProgressRateEstimator, format_duration};
+
+        let mut estimator = ProgressRateEstimator::new(0.5);
+        assert!(estimator.eta_ms(100.0).is_none());
+
+        estimator.update(0.0, 0.0);
+        estimator.update(10.0, 1000.0);
+        assert_eq!(estimator.rate_per_second(), Some(10.0));
+        assert_eq!(estimator.eta_ms(100.0), Some(9000.0));
+        assert_eq!(estimator.eta_label(100.0).as_deref(), Some("9s"));
+
+        // A stalled update only halves the rate instead of dropping it to zero.
+        estimator.update(10.0, 2000.0);
+        assert_eq!(estimator.rate_per_second(), Some(5.0));
+
+        assert_eq!(format_duration(125_000.0), "2m 05s");
+        assert_eq!(format_duration(3_720_000.0), "1h 02m");
+    }
+
     #[test]
     fn test_progress_status_active() {
         let props = ProgressProps {
@@ -626,4 +741,69 @@ mod tests {
         };
         assert_eq!(props.class, "custom-popover");
     }
+
+    #[test]
+    fn test_popover_trigger_mode_and_anchor() {
+        use hikari_components::feedback::PopoverTriggerMode;
+        use hikari_components::portal::VirtualAnchor;
+
+        let props = PopoverProps::default();
+        assert_eq!(props.trigger_mode, PopoverTriggerMode::Click);
+        assert!(props.anchor.is_none());
+
+        let anchor = VirtualAnchor::from_point(40.0, 60.0).translate(10.0, -20.0);
+        let props = PopoverProps {
+            trigger_mode: PopoverTriggerMode::Manual,
+            anchor: Some(anchor),
+            open: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            props.anchor.map(|a| a.as_rect()),
+            Some((50.0, 40.0, 0.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn test_anchored_popover_follows_anchor() {
+        use hikari_components::feedback::{Popover, PopoverTriggerMode};
+        use hikari_components::portal::{PortalContext, PortalEntry, VirtualAnchor};
+        use tairitsu_vdom::runtime;
+
+        let entries = Signal::new(Vec::new());
+        let sink = entries.clone();
+        use_context_provider(PortalContext {
+            entries: entries.clone(),
+            add_entry: Callback::new(move |entry| sink.update(|e: &mut Vec<_>| e.push(entry))),
+            remove_entry: Callback::new(|_| {}),
+            clear_all: Callback::new(|_| {}),
+            start_close_animation: Callback::new(|_| {}),
+            theme_scope: None,
+        });
+
+        let component = runtime::use_component(VNode::empty);
+        let render = |x: f64| {
+            runtime::with_component(component, || {
+                Popover(PopoverProps {
+                    trigger_mode: PopoverTriggerMode::Manual,
+                    anchor: Some(VirtualAnchor::from_point(x, 10.0)),
+                    open: true,
+                    ..Default::default()
+                })
+            })
+        };
+        render(20.0);
+        render(80.0);
+
+        let rects: Vec<_> = entries
+            .get()
+            .iter()
+            .map(|entry| match entry {
+                PortalEntry::Popover { trigger_rect, .. } => *trigger_rect,
+                _ => panic!("expected a popover entry"),
+            })
+            .collect();
+        assert_eq!(rects, vec![Some((80.0, 10.0, 0.0, 0.0))]);
+        runtime::cleanup_component(component);
+    }
 }
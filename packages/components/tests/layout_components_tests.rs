@@ -8,7 +8,17 @@ mod tests {
         Row, RowProps, ScrollbarContainer, ScrollbarContainerProps, Section, SectionProps, Space,
         SpaceDirection, SpaceProps, Spacer, SpacerProps, Wrap,
     };
+    use hikari_components::layout::{
+        Breakpoints, Hide, HideProps, Responsive, ResponsiveProperty, ResponsiveStyle, Screen,
+        Show, ShowProps, SplitDirection, SplitPane, SplitPaneProps, SplitPaneState,
+        responsive_styles, scoped_responsive_styles,
+    };
+    use hikari_components::layout::{Dock, DockPanelRenderer};
+    use hikari_components::layout::{
+        GridItemRenderer, HeightCache, Masonry, MasonryLayout, VirtualGrid,
+    };
     use hikari_components::prelude::*;
+    use hikari_extra_components::extra::{DockLayout, DockPanel, DockRect, DockTarget, DockZone};
 
     // ── Divider ────────────────────────────────────────────────
 
@@ -254,6 +264,7 @@ mod tests {
             children: Some(VNode::empty()),
             columns: 6,
             gap: "lg".to_string(),
+            gap_at: Responsive::default(),
             class: "my-grid".to_string(),
         };
         assert_eq!(props.columns, 6);
@@ -298,6 +309,65 @@ mod tests {
         assert_eq!(props.offset, Some(2));
     }
 
+    #[test]
+    fn test_col_responsive_props() {
+        let props = ColProps {
+            children: Some(VNode::empty()),
+            span: Some(12),
+            span_md: Some(6),
+            span_xl: Some(4),
+            offset_at: Responsive::default().lg(2),
+            order: Responsive::new(2).md(1),
+            ..Default::default()
+        };
+        assert_eq!(props.order.resolve(Screen::Sm), Some(&2));
+        assert_eq!(props.order.resolve(Screen::Xxl), Some(&1));
+        let _ = Col(props);
+    }
+
+    fn style_of(node: &VNode) -> String {
+        match node {
+            VNode::Element(el) => el.style.static_styles.clone(),
+            _ => panic!("expected an element"),
+        }
+    }
+
+    #[test]
+    fn test_nested_responsive_values_do_not_inherit() {
+        // A full-width column inside a column that halves at md
+        let inner = Col(ColProps {
+            children: Some(VNode::empty()),
+            span: Some(12),
+            ..Default::default()
+        });
+        let inner_style = style_of(&inner);
+        let outer = Col(ColProps {
+            children: Some(inner),
+            span: Some(12),
+            span_md: Some(6),
+            ..Default::default()
+        });
+        assert!(style_of(&outer).contains("--hk-r-grid-column-md:auto / span 6;"));
+        assert!(inner_style.contains("--hk-r-grid-column-md:auto / span 12;"));
+
+        // A flexbox with a wider gap at lg inside one that turns into a row at md
+        let inner = FlexBox(FlexBoxProps {
+            direction: Direction::Column,
+            gap: FlexGap::Gap4,
+            gap_at: Responsive::default().lg(FlexGap::Gap6),
+            children: VNode::empty(),
+            ..Default::default()
+        });
+        let inner_style = style_of(&inner);
+        let _ = FlexBox(FlexBoxProps {
+            direction: Direction::Column,
+            direction_at: Responsive::default().md(Direction::Row),
+            children: inner,
+            ..Default::default()
+        });
+        assert!(inner_style.contains("--hk-r-gap-md:1rem;"));
+    }
+
     // ── Row ───────────────────────────────────────────────────
 
     #[test]
@@ -326,6 +396,7 @@ mod tests {
         let props = RowProps {
             children: Some(VNode::empty()),
             gap: "lg".to_string(),
+            gap_at: Responsive::default(),
             wrap: false,
             justify: "between".to_string(),
             align: "stretch".to_string(),
@@ -538,4 +609,368 @@ mod tests {
         assert_eq!(props.height, "400px");
         assert_eq!(props.width, "auto");
     }
+
+    // ── Responsive ────────────────────────────────────────────
+
+    #[test]
+    fn test_default_breakpoints() {
+        let bp = Breakpoints::default();
+        assert_eq!(bp.min_width(Screen::Xs), 0);
+        assert_eq!(bp.min_width(Screen::Md), 768);
+        assert_eq!(bp.max_width(Screen::Sm), Some(767));
+        assert_eq!(bp.max_width(Screen::Xxl), None);
+        assert_eq!(bp.screen_for_width(0), Screen::Xs);
+        assert_eq!(bp.screen_for_width(767), Screen::Sm);
+        assert_eq!(bp.screen_for_width(768), Screen::Md);
+        assert_eq!(bp.screen_for_width(4000), Screen::Xxl);
+        assert_eq!(bp.media_query(Screen::Xs), None);
+        assert_eq!(
+            bp.media_query(Screen::Lg).as_deref(),
+            Some("(min-width: 1024px)")
+        );
+    }
+
+    #[test]
+    fn test_screen_order() {
+        assert!(Screen::Xs < Screen::Sm);
+        assert!(Screen::Xl < Screen::Xxl);
+        assert_eq!(Screen::Md.next(), Some(Screen::Lg));
+        assert_eq!(Screen::Xxl.next(), None);
+        assert_eq!(Screen::Xxl.to_string(), "xxl");
+    }
+
+    #[test]
+    fn test_responsive_cascades_mobile_first() {
+        let value = Responsive::default().sm(1).lg(3);
+        assert_eq!(value.resolve(Screen::Xs), None);
+        assert_eq!(value.resolve(Screen::Sm), Some(&1));
+        assert_eq!(value.resolve(Screen::Md), Some(&1));
+        assert_eq!(value.resolve(Screen::Xxl), Some(&3));
+        assert_eq!(value.get(Screen::Md), None);
+
+        let from: Responsive<u8> = 4.into();
+        assert_eq!(from.resolve(Screen::Xl), Some(&4));
+        assert!(Responsive::<u8>::default().is_empty());
+        assert_eq!(value.map(|v| v * 2).resolve(Screen::Lg), Some(&6));
+    }
+
+    #[test]
+    fn test_responsive_style_variables() {
+        let style = ResponsiveStyle::new()
+            .set(
+                ResponsiveProperty::Order,
+                &Responsive::new("2".to_string()).md("1".to_string()),
+            )
+            .set(ResponsiveProperty::Gap, &Responsive::default());
+        assert_eq!(style.classes(), "hk-r-order");
+        assert_eq!(
+            style.style(),
+            "--hk-r-order-xs: 2; --hk-r-order-sm: 2; --hk-r-order-md: 1; \
+             --hk-r-order-lg: 1; --hk-r-order-xl: 1; --hk-r-order-xxl: 1;"
+        );
+
+        // Breakpoints below the first value are reset, not inherited
+        let style = ResponsiveStyle::new().set(
+            ResponsiveProperty::Gap,
+            &Responsive::default().lg("1rem".to_string()),
+        );
+        assert!(
+            style
+                .style()
+                .starts_with("--hk-r-gap-xs: initial; --hk-r-gap-sm: initial;")
+        );
+    }
+
+    #[test]
+    fn test_responsive_styles_use_media_queries() {
+        let css = responsive_styles(&Breakpoints {
+            md: 900,
+            ..Default::default()
+        });
+        assert!(css.contains(".hk-r-order.hk-r-order { order: var(--hk-r-order-xs); }"));
+        assert!(css.contains("@media (min-width: 900px)"));
+        assert!(
+            css.contains(
+                "order: var(--hk-r-order-md, var(--hk-r-order-sm, var(--hk-r-order-xs)));"
+            )
+        );
+        assert!(!css.contains("768px"));
+    }
+
+    #[test]
+    fn test_scoped_responsive_styles() {
+        let css = scoped_responsive_styles(&Breakpoints::default(), "hk-theme-3");
+        assert!(css.contains(
+            "[data-theme-scope=\"hk-theme-3\"] .hk-r-order.hk-r-order { order: var(--hk-r-order-xs); }"
+        ));
+        assert!(
+            css.lines()
+                .filter(|line| line.contains('{') && !line.starts_with("@media"))
+                .all(|line| line.starts_with("[data-theme-scope=\"hk-theme-3\"] ")),
+            "{css}"
+        );
+    }
+
+    #[test]
+    fn test_show_hide_render() {
+        let _ = Show(ShowProps {
+            above: Some(Screen::Md),
+            children: VNode::empty(),
+            ..Default::default()
+        });
+        let _ = Hide(HideProps {
+            below: Some(Screen::Lg),
+            children: VNode::empty(),
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn test_flexbox_responsive_props() {
+        assert_eq!(Direction::Row.css_value(false), "row");
+        assert_eq!(Direction::Row.css_value(true), "row-reverse");
+        assert_eq!(FlexGap::Gap4.css_value(), "1rem");
+
+        let props = FlexBoxProps {
+            direction: Direction::Column,
+            direction_at: Responsive::default().md(Direction::Row),
+            gap_at: Responsive::default().lg(FlexGap::Gap6),
+            children: VNode::empty(),
+            ..Default::default()
+        };
+        let _ = FlexBox(props);
+    }
+
+    // ── SplitPane ─────────────────────────────────────────────
+
+    #[test]
+    fn test_split_pane_props_default() {
+        let props = SplitPaneProps::default();
+        assert_eq!(props.direction, SplitDirection::Horizontal);
+        assert_eq!(props.default_size, 50.0);
+        assert_eq!(props.min_size, 0.0);
+        assert_eq!(props.max_size, 100.0);
+        assert!(props.collapsible);
+        assert!(props.persist_key.is_none());
+    }
+
+    #[test]
+    fn test_split_pane_renders_nested() {
+        let inner = SplitPane(SplitPaneProps {
+            direction: SplitDirection::Vertical,
+            first: VNode::empty(),
+            second: VNode::empty(),
+            ..Default::default()
+        });
+        let _ = SplitPane(SplitPaneProps {
+            default_size: 70.0,
+            persist_key: Some("editor".to_string()),
+            first: inner,
+            second: VNode::empty(),
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn test_split_state_clamps_to_limits() {
+        let mut state = SplitPaneState::new(90.0, 20.0, 80.0);
+        assert_eq!(state.size, 80.0);
+        state.resize_to(5.0);
+        assert_eq!(state.size, 20.0);
+        state.resize_by(15.0);
+        assert_eq!(state.size, 35.0);
+        assert_eq!(state.constraints().min_x, Some(20.0));
+    }
+
+    #[test]
+    fn test_split_state_drag_uses_extent() {
+        let mut state = SplitPaneState::new(50.0, 0.0, 100.0);
+        state.drag(50.0, 100.0, 1000.0);
+        assert_eq!(state.size, 60.0);
+        state.drag(50.0, -2000.0, 1000.0);
+        assert_eq!(state.size, 0.0);
+        // A zero-sized container leaves the size alone
+        state.drag(50.0, 100.0, 0.0);
+        assert_eq!(state.size, 0.0);
+    }
+
+    #[test]
+    fn test_split_state_collapse_to_nearest_edge() {
+        let mut state = SplitPaneState::new(30.0, 10.0, 90.0);
+        state.toggle_collapse();
+        assert!(state.is_collapsed());
+        assert_eq!(state.size, 0.0);
+        state.toggle_collapse();
+        assert!(!state.is_collapsed());
+        assert_eq!(state.size, 30.0);
+
+        let mut state = SplitPaneState::new(75.0, 10.0, 90.0);
+        state.toggle_collapse();
+        assert_eq!(state.size, 100.0);
+        // Keyboard resizing expands from the remembered size
+        state.resize_by(-5.0);
+        assert!(!state.is_collapsed());
+        assert_eq!(state.size, 70.0);
+    }
+
+    #[test]
+    fn test_split_state_storage_round_trip() {
+        let mut state = SplitPaneState::new(42.5, 0.0, 100.0);
+        assert_eq!(state.to_storage(), "42.5");
+        state.toggle_collapse();
+        assert_eq!(state.to_storage(), "0;42.5");
+
+        let mut restored = SplitPaneState::new(50.0, 0.0, 100.0);
+        assert!(restored.load("0;42.5"));
+        assert_eq!(restored, state);
+        assert!(restored.load("120"));
+        assert_eq!(restored.size, 100.0);
+        assert!(!restored.load("wide"));
+        assert!(!restored.load("30;40"));
+    }
+
+    // ── Dock ──────────────────────────────────────────────────
+
+    fn dock_layout() -> DockLayout {
+        DockLayout::new()
+            .with_panel(DockPanel::new("editor", "Editor"), DockZone::Center)
+            .with_panel(DockPanel::new("files", "Files"), DockZone::Left)
+            .with_panel(DockPanel::new("console", "Console"), DockZone::Bottom)
+    }
+
+    #[test]
+    fn test_dock_props_default() {
+        let props = DockProps::default();
+        assert!(props.layout.panels.is_empty());
+        assert!(props.on_change.is_none());
+        assert!(props.render_panel.is_none());
+        assert!(props.persist_key.is_none());
+    }
+
+    #[test]
+    fn test_dock_renders_empty() {
+        let render: DockPanelRenderer = std::rc::Rc::new(|id: &str| rsx! { div { "{id}" } });
+        let _ = Dock(DockProps {
+            render_panel: Some(render),
+            persist_key: Some("workspace".to_string()),
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn test_dock_layout_round_trips_through_props() {
+        let mut layout = dock_layout();
+        layout.move_panel("files", DockTarget::Float(DockRect::default()));
+        layout.toggle_side(DockZone::Bottom);
+        let json = layout.to_json().unwrap();
+        let props = DockProps {
+            layout: DockLayout::from_json(&json).unwrap(),
+            ..Default::default()
+        };
+        assert_eq!(props.layout, layout);
+        assert_eq!(props.layout.windows.len(), 1);
+        assert!(!props.layout.bottom.state.expanded);
+    }
+
+    #[test]
+    fn test_dock_props_compare_renderer_by_identity() {
+        let render: DockPanelRenderer = std::rc::Rc::new(|_: &str| VNode::empty());
+        let props = DockProps {
+            layout: dock_layout(),
+            render_panel: Some(render.clone()),
+            ..Default::default()
+        };
+        assert_eq!(props.clone(), props);
+        let other = DockProps {
+            render_panel: Some(std::rc::Rc::new(|_: &str| VNode::empty())),
+            ..props.clone()
+        };
+        assert_ne!(props, other);
+    }
+
+    // ── Masonry / VirtualGrid ─────────────────────────────────
+
+    #[test]
+    fn test_masonry_columns_for_width() {
+        assert_eq!(MasonryLayout::columns_for_width(1000.0, 240.0, 16.0), 3);
+        assert_eq!(MasonryLayout::columns_for_width(1040.0, 240.0, 16.0), 4);
+        assert_eq!(MasonryLayout::columns_for_width(100.0, 240.0, 16.0), 1);
+        assert_eq!(MasonryLayout::columns_for_width(0.0, 240.0, 16.0), 1);
+    }
+
+    #[test]
+    fn test_masonry_places_in_shortest_column() {
+        let layout = MasonryLayout::masonry([100.0, 50.0, 30.0, 40.0], 210.0, 2, 10.0);
+        assert_eq!(layout.column_width, 100.0);
+        assert_eq!(layout.column_items, vec![vec![0], vec![1, 2, 3]]);
+        assert_eq!(layout.items[2].y, 60.0);
+        assert_eq!(layout.items[2].x, 110.0);
+        assert_eq!(layout.items[3].y, 100.0);
+        assert_eq!(layout.height, 140.0);
+    }
+
+    #[test]
+    fn test_rows_use_tallest_item() {
+        let layout = MasonryLayout::rows([100.0, 50.0, 30.0], 210.0, 2, 10.0);
+        assert_eq!(layout.items[1].height, 100.0);
+        assert_eq!(layout.items[2].y, 110.0);
+        assert_eq!(layout.items[2].column, 0);
+        assert_eq!(layout.height, 140.0);
+    }
+
+    #[test]
+    fn test_masonry_visible_range() {
+        let layout = MasonryLayout::rows(std::iter::repeat_n(100.0, 20_000), 1000.0, 4, 0.0);
+        assert_eq!(layout.height, 500_000.0);
+        assert_eq!(layout.visible(250.0, 350.0), (8..16).collect::<Vec<_>>());
+        assert!(layout.visible(600_000.0, 700_000.0).is_empty());
+    }
+
+    #[test]
+    fn test_masonry_anchor_keeps_item_in_place() {
+        let mut heights = HeightCache::new(100.0);
+        let before = MasonryLayout::masonry(heights.heights(6), 200.0, 1, 0.0);
+        let anchor = before.anchor(250.0).unwrap();
+        assert_eq!(anchor, (2, -50.0));
+
+        // An image above the viewport finished loading and grew by 80px
+        assert!(heights.set(0, 180.0));
+        assert!(!heights.set(0, 180.2));
+        let after = MasonryLayout::masonry(heights.heights(6), 200.0, 1, 0.0);
+        assert_eq!(after.restore_anchor(anchor), Some(330.0));
+    }
+
+    #[test]
+    fn test_height_cache() {
+        let mut heights = HeightCache::new(120.0);
+        assert_eq!(heights.get(5), 120.0);
+        assert!(!heights.is_measured(5));
+        heights.set(5, 90.0);
+        assert!(heights.is_measured(5));
+        assert_eq!(heights.heights(7).collect::<Vec<_>>()[5], 90.0);
+        heights.clear();
+        assert_eq!(heights.get(5), 120.0);
+    }
+
+    #[test]
+    fn test_masonry_renders() {
+        let _ = Masonry(MasonryProps {
+            items: vec![rsx! { div { "a" } }, rsx! { div { "b" } }],
+            columns: Some(2),
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn test_virtual_grid_renders_window() {
+        let render: GridItemRenderer = std::rc::Rc::new(|index| rsx! { div { "{index}" } });
+        let props = VirtualGridProps {
+            count: 20_000,
+            render_item: Some(render),
+            ..Default::default()
+        };
+        assert_eq!(props.gap, 16.0);
+        assert_eq!(props.height, "600px");
+        let _ = VirtualGrid(props);
+    }
 }
@@ -10,3 +10,4 @@ mod layout_components_tests;
 mod navigation_components_tests;
 mod navigation_extra_tests;
 mod production_components_tests;
+mod theme_tests;
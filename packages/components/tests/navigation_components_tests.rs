@@ -5,7 +5,7 @@ mod tests {
         self, AnchorItem, BreadcrumbItemProps, BreadcrumbProps, MenuItemHeight, MenuItemProps,
         MenuMode, MenuProps, SidebarLeafProps, SidebarProps, SidebarSectionProps, StepData,
         StepperDirection, StepperProps, StepsDirection, StepsProps, SubMenuProps, TabPaneProps,
-        TabPosition, TabsProps,
+        TabPosition, TabsOverflow, TabsProps,
     };
     use hikari_components::prelude::*;
 
@@ -108,6 +108,8 @@ mod tests {
             height: MenuItemHeight::Default,
             onclick: None,
             glow: false,
+            to: None,
+            shortcut: None,
         };
     }
 
@@ -202,6 +204,7 @@ mod tests {
             secondary_label: Some("badge".to_string()),
             class: String::new(),
             children: VNode::empty(),
+            to: None,
         };
     }
 
@@ -217,11 +220,19 @@ mod tests {
     fn test_tabs_renders() {
         let _ = TabsProps {
             default_active: "1".to_string(),
+            active: None,
             tab_position: TabPosition::Top,
             animated: true,
             class: String::new(),
             children: VNode::empty(),
             on_change: None,
+            overflow: TabsOverflow::More,
+            editable: false,
+            on_close: None,
+            on_add: None,
+            draggable: false,
+            on_reorder: None,
+            on_drag: None,
         };
     }
 
@@ -296,6 +307,8 @@ mod tests {
             icon: None,
             children: VNode::empty(),
             class: String::new(),
+            to: None,
+            closable: true,
         };
     }
 
@@ -334,6 +347,7 @@ mod tests {
             separator: "/".to_string(),
             class: "test-breadcrumb".to_string(),
             children: VNode::empty(),
+            routes: Vec::new(),
         };
     }
 
@@ -352,6 +366,7 @@ mod tests {
             children: VNode::empty(),
             class: String::new(),
             onclick: None,
+            to: None,
         };
     }
 
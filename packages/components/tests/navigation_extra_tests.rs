@@ -2,10 +2,27 @@
 mod tests {
 
     use hikari_components::navigation::{
-        AnchorItem, SidebarItemProps, SidebarLeafProps, SidebarProps, SidebarSectionProps,
+        AnchorItem, GuardSink, OverflowItem, ScrollState, SidebarItemProps, SidebarLeafProps,
+        SidebarProps, SidebarSectionProps, StepErrors, StepGuard, TabPaneProps, TabReorder,
+        TabsOverflow, TabsProps, Wizard, WizardData, WizardStep, active_steps, compute_overflow,
+        scroll_offset,
     };
     use hikari_components::prelude::*;
+    use hikari_components::router::{
+        MemoryRouter, Route, RouteListener, Router, RouterHandle, RouterProvider,
+        RouterProviderProps, SubscriptionId, breadcrumb_trail, is_route_active, match_route,
+    };
+    use hikari_components::utils::{
+        FocusAction, FocusItem, FocusMove, GLOBAL_SCOPE, KeyChord, KeyPress, Orientation,
+        RovingFocus, SEQUENCE_TIMEOUT_MS, Shortcut, ShortcutBinding, ShortcutMatch,
+        ShortcutParseError, ShortcutRegistry, Typeahead, data_key_selector, normalize_key,
+        register_shortcut, registered_shortcuts, shortcut_label, typeahead_match,
+        unregister_shortcut, use_roving_id, use_shortcut,
+    };
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
     use tairitsu_macros::rsx;
+    use tairitsu_vdom::runtime;
 
     // ── Anchor ─────────────────────────────────────────────────
 
@@ -134,8 +151,840 @@ mod tests {
             secondary_label: Some("v1.0".to_string()),
             class: "leaf-item".to_string(),
             children: rsx! { a { "Link" } },
+            to: None,
         };
         assert_eq!(props.id, "leaf-1");
         assert_eq!(props.secondary_label.as_deref().unwrap(), "v1.0");
     }
+
+    // ── Router ─────────────────────────────────────────────────
+
+    #[test]
+    fn test_memory_router_navigate_back_forward() {
+        let router = MemoryRouter::default();
+        router.navigate("/docs");
+        router.navigate("/docs/intro");
+        assert_eq!(router.current_path(), "/docs/intro");
+
+        assert!(router.back());
+        assert_eq!(router.current_path(), "/docs");
+        assert!(router.can_go_forward());
+        assert!(router.forward());
+        assert_eq!(router.current_path(), "/docs/intro");
+        assert!(!router.forward());
+
+        router.back();
+        router.navigate("/about");
+        assert_eq!(router.entries(), vec!["/", "/docs", "/about"]);
+        assert!(!router.can_go_forward());
+    }
+
+    #[test]
+    fn test_memory_router_replace() {
+        let router = MemoryRouter::new("/a");
+        router.replace("/b");
+        assert_eq!(router.entries(), vec!["/b"]);
+        assert!(!router.can_go_back());
+    }
+
+    #[test]
+    fn test_memory_router_subscribe() {
+        let router = MemoryRouter::default();
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let sink = seen.clone();
+        let id = router.subscribe(Rc::new(move |path: &str| {
+            sink.borrow_mut().push(path.to_string())
+        }));
+
+        router.navigate("/x");
+        router.back();
+        router.unsubscribe(id);
+        router.navigate("/y");
+
+        assert_eq!(*seen.borrow(), vec!["/x", "/"]);
+    }
+
+    /// Memory router that counts its live subscriptions
+    struct CountingRouter {
+        inner: MemoryRouter,
+        live: Rc<Cell<usize>>,
+    }
+
+    impl Router for CountingRouter {
+        fn current_path(&self) -> String {
+            self.inner.current_path()
+        }
+
+        fn navigate(&self, path: &str) {
+            self.inner.navigate(path);
+        }
+
+        fn replace(&self, path: &str) {
+            self.inner.replace(path);
+        }
+
+        fn subscribe(&self, listener: Rc<RouteListener>) -> SubscriptionId {
+            self.live.set(self.live.get() + 1);
+            self.inner.subscribe(listener)
+        }
+
+        fn unsubscribe(&self, id: SubscriptionId) {
+            self.live.set(self.live.get() - 1);
+            self.inner.unsubscribe(id);
+        }
+    }
+
+    #[test]
+    fn test_router_provider_unsubscribes_on_unmount() {
+        let live = Rc::new(Cell::new(0));
+        let router = RouterHandle::new(CountingRouter {
+            inner: MemoryRouter::default(),
+            live: live.clone(),
+        });
+        let provider = || {
+            RouterProvider(RouterProviderProps {
+                router: Some(router.clone()),
+                children: VNode::empty(),
+            })
+        };
+
+        let component = runtime::use_component(VNode::empty);
+        runtime::with_component(component, provider);
+        // Re-rendering keeps the one subscription
+        runtime::with_component(component, provider);
+        assert_eq!(live.get(), 1);
+
+        runtime::cleanup_component(component);
+        assert_eq!(live.get(), 0);
+    }
+
+    #[test]
+    fn test_router_handle_equality() {
+        let handle = RouterHandle::memory("/");
+        assert_eq!(handle.clone(), handle);
+        assert_ne!(handle, RouterHandle::memory("/"));
+    }
+
+    #[test]
+    fn test_is_route_active() {
+        assert!(is_route_active("/docs", "/docs/intro", false));
+        assert!(!is_route_active("/docs", "/docs/intro", true));
+        assert!(is_route_active("/docs", "/docs?tab=1", true));
+        assert!(!is_route_active("/doc", "/docs", false));
+        assert!(!is_route_active("/", "/docs", false));
+        assert!(is_route_active("/", "/", false));
+    }
+
+    #[test]
+    fn test_match_route_params() {
+        let params = match_route("/users/:id/posts/:post", "/users/42/posts/7").unwrap();
+        assert_eq!(params["id"], "42");
+        assert_eq!(params["post"], "7");
+        assert!(match_route("/users/:id", "/users").is_none());
+        assert!(match_route("/users/:id", "/teams/1").is_none());
+    }
+
+    #[test]
+    fn test_breadcrumb_trail() {
+        let routes = vec![
+            Route::new("/", "Home"),
+            Route::new("/users", "Users"),
+            Route::new("/users/:id", "User :id"),
+        ];
+        let trail = breadcrumb_trail(&routes, "/users/42");
+        let titles: Vec<_> = trail.iter().map(|crumb| crumb.title.as_str()).collect();
+        let paths: Vec<_> = trail.iter().map(|crumb| crumb.path.as_str()).collect();
+        assert_eq!(titles, vec!["Home", "Users", "User 42"]);
+        assert_eq!(paths, vec!["/", "/users", "/users/42"]);
+    }
+
+    #[test]
+    fn test_breadcrumb_trail_skips_unmatched_levels() {
+        let routes = vec![Route::new("/", "Home"), Route::new("/a/b", "B")];
+        let trail = breadcrumb_trail(&routes, "/a/b");
+        assert_eq!(trail.len(), 2);
+        assert_eq!(trail[1].title, "B");
+    }
+
+    #[test]
+    fn test_sidebar_leaf_to_default() {
+        assert!(SidebarLeafProps::default().to.is_none());
+    }
+
+    // ── Roving focus ───────────────────────────────────────────
+
+    fn focus_items(labels: &[&str], focused: usize) -> Vec<FocusItem> {
+        labels
+            .iter()
+            .enumerate()
+            .map(|(i, label)| FocusItem {
+                focused: i == focused,
+                ..FocusItem::new(*label)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_roving_arrows_follow_orientation() {
+        let items = focus_items(&["a", "b", "c"], 1);
+        let horizontal = RovingFocus::horizontal();
+        assert_eq!(
+            horizontal.action(&Key::ArrowRight, &items, None),
+            Some(FocusAction::Focus(2))
+        );
+        assert_eq!(horizontal.action(&Key::ArrowDown, &items, None), None);
+
+        let vertical = RovingFocus::vertical();
+        assert_eq!(
+            vertical.action(&Key::ArrowUp, &items, None),
+            Some(FocusAction::Focus(0))
+        );
+        assert_eq!(vertical.action(&Key::ArrowLeft, &items, None), None);
+    }
+
+    #[test]
+    fn test_roving_rtl_mirrors_horizontal_arrows() {
+        let items = focus_items(&["a", "b", "c"], 1);
+        let roving = RovingFocus::horizontal().rtl();
+        assert_eq!(
+            roving.action(&Key::ArrowRight, &items, None),
+            Some(FocusAction::Focus(0))
+        );
+        assert_eq!(
+            roving.action(&Key::ArrowLeft, &items, None),
+            Some(FocusAction::Focus(2))
+        );
+    }
+
+    #[test]
+    fn test_roving_home_end_and_wrap() {
+        let items = focus_items(&["a", "b", "c"], 2);
+        let roving = RovingFocus::vertical();
+        let home = Key::Other("Home".to_string());
+        let end = Key::Other("End".to_string());
+        assert_eq!(
+            roving.action(&home, &items, None),
+            Some(FocusAction::Focus(0))
+        );
+        assert_eq!(
+            roving.action(&end, &items, None),
+            Some(FocusAction::Focus(2))
+        );
+        assert_eq!(
+            roving.action(&Key::ArrowDown, &items, None),
+            Some(FocusAction::Focus(0))
+        );
+        assert_eq!(
+            roving.wrap(false).action(&Key::ArrowDown, &items, None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_roving_skips_disabled_items() {
+        let mut items = focus_items(&["a", "b", "c", "d"], 0);
+        items[1].disabled = true;
+        items[2].disabled = true;
+        let roving = RovingFocus::vertical();
+        assert_eq!(roving.resolve(FocusMove::Next, Some(0), &items), Some(3));
+        assert_eq!(
+            roving.resolve(FocusMove::Previous, Some(3), &items),
+            Some(0)
+        );
+        items[0].disabled = true;
+        assert_eq!(roving.resolve(FocusMove::First, None, &items), Some(3));
+    }
+
+    #[test]
+    fn test_roving_activation() {
+        let items = focus_items(&["a", "b"], 1);
+        assert_eq!(
+            RovingFocus::vertical().action(&Key::Enter, &items, None),
+            Some(FocusAction::Activate(1))
+        );
+        assert_eq!(
+            RovingFocus::vertical()
+                .without_activation()
+                .action(&Key::Space, &items, None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_roving_tree_navigation() {
+        // root (expanded) > child, then a collapsed sibling
+        let mut items = focus_items(&["root", "child", "other"], 0);
+        items[0].expanded = Some(true);
+        items[1].level = 1;
+        items[2].expanded = Some(false);
+        let tree = RovingFocus::tree();
+
+        assert_eq!(
+            tree.action(&Key::ArrowRight, &items, None),
+            Some(FocusAction::Focus(1))
+        );
+        assert_eq!(
+            tree.action(&Key::ArrowLeft, &items, None),
+            Some(FocusAction::Toggle(0))
+        );
+
+        items[0].focused = false;
+        items[1].focused = true;
+        assert_eq!(
+            tree.action(&Key::ArrowLeft, &items, None),
+            Some(FocusAction::Focus(0))
+        );
+
+        items[1].focused = false;
+        items[2].focused = true;
+        assert_eq!(
+            tree.action(&Key::ArrowRight, &items, None),
+            Some(FocusAction::Toggle(2))
+        );
+        assert_eq!(tree.action(&Key::ArrowDown, &items, None), None);
+    }
+
+    #[test]
+    fn test_roving_tree_rtl_swaps_expand_keys() {
+        let mut items = focus_items(&["root"], 0);
+        items[0].expanded = Some(false);
+        assert_eq!(
+            RovingFocus::tree()
+                .rtl()
+                .action(&Key::ArrowLeft, &items, None),
+            Some(FocusAction::Toggle(0))
+        );
+    }
+
+    #[test]
+    fn test_roving_menubar_opens_with_arrow_down() {
+        let mut items = focus_items(&["File", "Edit"], 0);
+        items[0].expanded = Some(false);
+        let menubar = RovingFocus::horizontal().hierarchical(true);
+        assert_eq!(
+            menubar.action(&Key::ArrowDown, &items, None),
+            Some(FocusAction::Toggle(0))
+        );
+        assert_eq!(
+            menubar.action(&Key::ArrowRight, &items, None),
+            Some(FocusAction::Focus(1))
+        );
+    }
+
+    #[test]
+    fn test_roving_menubar_submenus_open_sideways() {
+        // File (open) > Recent (collapsed submenu)
+        let mut items = focus_items(&["File", "Recent"], 1);
+        items[0].expanded = Some(true);
+        items[1].level = 1;
+        items[1].expanded = Some(false);
+        let menubar = RovingFocus::horizontal().hierarchical(true);
+
+        assert_eq!(
+            menubar.action(&Key::ArrowRight, &items, None),
+            Some(FocusAction::Toggle(1))
+        );
+        assert_eq!(
+            menubar.action(&Key::ArrowLeft, &items, None),
+            Some(FocusAction::Focus(0))
+        );
+        assert_eq!(
+            menubar.rtl().action(&Key::ArrowLeft, &items, None),
+            Some(FocusAction::Toggle(1))
+        );
+        assert_eq!(menubar.action(&Key::ArrowDown, &items, None), None);
+    }
+
+    #[test]
+    fn test_typeahead_match() {
+        let items = focus_items(&["Apple", "Banana", "Blueberry", "Cherry"], 0);
+        assert_eq!(typeahead_match(&items, Some(0), "b"), Some(1));
+        assert_eq!(typeahead_match(&items, Some(1), "bb"), Some(2));
+        assert_eq!(typeahead_match(&items, Some(2), "b"), Some(1));
+        assert_eq!(typeahead_match(&items, Some(1), "blu"), Some(2));
+        assert_eq!(typeahead_match(&items, Some(1), "ban"), Some(1));
+        assert_eq!(typeahead_match(&items, None, "z"), None);
+        assert_eq!(
+            RovingFocus::vertical().action(&Key::Character("c".into()), &items, Some("c")),
+            Some(FocusAction::Focus(3))
+        );
+    }
+
+    #[test]
+    fn test_typeahead_buffer_resets_after_pause() {
+        let mut typeahead = Typeahead::default();
+        assert_eq!(typeahead.push("B", 1000.0), "b");
+        assert_eq!(typeahead.push("l", 1200.0), "bl");
+        assert_eq!(typeahead.push("c", 2000.0), "c");
+    }
+
+    #[test]
+    fn test_roving_id_is_stable_while_mounted() {
+        let component = runtime::use_component(VNode::empty);
+        let first = runtime::with_component(component, || use_roving_id("hi-test"));
+        let again = runtime::with_component(component, || use_roving_id("hi-test"));
+        assert_eq!(first, again);
+        assert!(first.starts_with("hi-test-"));
+
+        runtime::cleanup_component(component);
+        let remounted = runtime::use_component(VNode::empty);
+        let fresh = runtime::with_component(remounted, || use_roving_id("hi-test"));
+        assert_ne!(first, fresh);
+        runtime::cleanup_component(remounted);
+    }
+
+    #[test]
+    fn test_orientation_aria_value() {
+        assert_eq!(Orientation::Horizontal.as_str(), "horizontal");
+        assert_eq!(Orientation::Vertical.as_str(), "vertical");
+    }
+
+    // ── Overflow ───────────────────────────────────────────────
+
+    fn bar(widths: &[f64]) -> Vec<OverflowItem> {
+        widths
+            .iter()
+            .enumerate()
+            .map(|(i, width)| OverflowItem::new(format!("k{i}"), format!("Item {i}"), *width))
+            .collect()
+    }
+
+    fn keys(items: &[OverflowItem]) -> Vec<&str> {
+        items.iter().map(|item| item.key.as_str()).collect()
+    }
+
+    #[test]
+    fn test_compute_overflow_fits() {
+        assert!(compute_overflow(&bar(&[100.0, 100.0]), 200.0, 40.0, None).is_empty());
+    }
+
+    #[test]
+    fn test_compute_overflow_reserves_more_width() {
+        let hidden = compute_overflow(&bar(&[100.0, 100.0, 100.0]), 250.0, 40.0, None);
+        assert_eq!(keys(&hidden), vec!["k2"]);
+        let hidden = compute_overflow(&bar(&[100.0, 100.0, 100.0]), 230.0, 40.0, None);
+        assert_eq!(keys(&hidden), vec!["k1", "k2"]);
+    }
+
+    #[test]
+    fn test_compute_overflow_keeps_active_item() {
+        let hidden = compute_overflow(&bar(&[100.0, 100.0, 100.0, 100.0]), 300.0, 40.0, Some("k3"));
+        assert_eq!(keys(&hidden), vec!["k1", "k2"]);
+    }
+
+    #[test]
+    fn test_scroll_state_and_offset() {
+        assert_eq!(
+            ScrollState::new(0.0, 200.0, 500.0),
+            ScrollState {
+                can_scroll_start: false,
+                can_scroll_end: true
+            }
+        );
+        assert!(!ScrollState::new(300.0, 200.0, 500.0).can_scroll_end);
+        assert_eq!(scroll_offset(0.0, 200.0, 500.0, true), 160.0);
+        assert_eq!(scroll_offset(250.0, 200.0, 500.0, true), 300.0);
+        assert_eq!(scroll_offset(100.0, 200.0, 500.0, false), 0.0);
+    }
+
+    #[test]
+    fn test_tab_reorder_apply() {
+        let tabs: Vec<String> = ["a", "b", "c", "d"].iter().map(|s| s.to_string()).collect();
+        let moved = TabReorder {
+            key: "a".to_string(),
+            target: "c".to_string(),
+        }
+        .apply(&tabs);
+        assert_eq!(moved, vec!["b", "c", "a", "d"]);
+        let moved = TabReorder {
+            key: "d".to_string(),
+            target: "b".to_string(),
+        }
+        .apply(&tabs);
+        assert_eq!(moved, vec!["a", "d", "b", "c"]);
+        let unknown = TabReorder {
+            key: "x".to_string(),
+            target: "a".to_string(),
+        };
+        assert_eq!(unknown.apply(&tabs), tabs);
+    }
+
+    #[test]
+    fn test_tabs_editable_defaults() {
+        let props = TabsProps::default();
+        assert_eq!(props.overflow, TabsOverflow::More);
+        assert!(!props.editable);
+        assert!(!props.draggable);
+        assert!(TabPaneProps::default().closable);
+    }
+
+    // ── Shortcuts ──────────────────────────────────────────────
+
+    fn binding(id: &str, shortcut: &str) -> ShortcutBinding {
+        ShortcutBinding::new(id, Shortcut::parse(shortcut).unwrap(), || {})
+    }
+
+    fn fired(result: ShortcutMatch) -> Option<String> {
+        match result {
+            ShortcutMatch::Fired(binding) => Some(binding.id),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_parse_chord() {
+        let chord: KeyChord = "Ctrl+Shift+P".parse().unwrap();
+        assert!(chord.ctrl && chord.shift && !chord.alt && !chord.meta);
+        assert_eq!(chord.key, "p");
+
+        let chord: KeyChord = "cmd+option+esc".parse().unwrap();
+        assert!(chord.meta && chord.alt);
+        assert_eq!(chord.key, "Escape");
+
+        let plus: KeyChord = "Ctrl++".parse().unwrap();
+        assert_eq!(plus.key, "+");
+        assert!(plus.ctrl);
+
+        assert_eq!(
+            "Hyper+K".parse::<KeyChord>(),
+            Err(ShortcutParseError::UnknownModifier("Hyper".to_string()))
+        );
+        assert_eq!(Shortcut::parse("  "), Err(ShortcutParseError::Empty));
+    }
+
+    #[test]
+    fn test_parse_sequence() {
+        let shortcut = Shortcut::parse("g then i").unwrap();
+        assert_eq!(shortcut.chords.len(), 2);
+        assert_eq!(Shortcut::parse("g i").unwrap(), shortcut);
+        assert_eq!(shortcut.to_string(), "G then I");
+        assert_eq!(
+            Shortcut::parse("Mod+Shift+P").unwrap().to_string(),
+            "Mod+Shift+P"
+        );
+    }
+
+    #[test]
+    fn test_normalize_key() {
+        assert_eq!(normalize_key("K"), "k");
+        assert_eq!(normalize_key(" "), "Space");
+        assert_eq!(normalize_key("up"), "ArrowUp");
+        assert_eq!(normalize_key("F5"), "F5");
+    }
+
+    #[test]
+    fn test_mod_key_is_platform_aware() {
+        let chord: KeyChord = "Mod+K".parse().unwrap();
+        assert!(chord.matches(&KeyPress::new("k").with_ctrl(), false));
+        assert!(!chord.matches(&KeyPress::new("k").with_meta(), false));
+        assert!(chord.matches(&KeyPress::new("k").with_meta(), true));
+        assert!(!chord.matches(&KeyPress::new("k").with_ctrl(), true));
+        // Extra modifiers do not match
+        assert!(!chord.matches(&KeyPress::new("k").with_ctrl().with_shift(), false));
+    }
+
+    #[test]
+    fn test_shortcut_labels() {
+        let shortcut = Shortcut::parse("Mod+Shift+P").unwrap();
+        assert_eq!(shortcut.label(false), "Ctrl+Shift+P");
+        assert_eq!(shortcut.label(true), "⇧⌘P");
+        assert_eq!(
+            Shortcut::parse("Alt+ArrowUp").unwrap().caps(false),
+            vec![vec!["Alt".to_string(), "↑".to_string()]]
+        );
+        assert_eq!(Shortcut::parse("g i").unwrap().label(false), "G then I");
+        assert_eq!(shortcut_label("Mod+S"), "Ctrl+S");
+        assert_eq!(shortcut_label("Hyper+S"), "Hyper+S");
+    }
+
+    #[test]
+    fn test_registry_fires_chord() {
+        let mut registry = ShortcutRegistry::new();
+        registry.register(binding("save", "Mod+S"), false);
+
+        let press = KeyPress::new("s").with_ctrl();
+        let result = registry.dispatch(&press, 0.0, false, &[], false);
+        assert_eq!(fired(result), Some("save".to_string()));
+        let plain = registry.dispatch(&KeyPress::new("s"), 0.0, false, &[], false);
+        assert_eq!(plain, ShortcutMatch::None);
+    }
+
+    #[test]
+    fn test_registry_sequences() {
+        let mut registry = ShortcutRegistry::new();
+        registry.register(binding("inbox", "g then i"), false);
+        registry.register(binding("drafts", "g then d"), false);
+
+        let g = KeyPress::new("g");
+        assert_eq!(
+            registry.dispatch(&g, 0.0, false, &[], false),
+            ShortcutMatch::Pending
+        );
+        assert!(registry.is_pending());
+        let result = registry.dispatch(&KeyPress::new("d"), 100.0, false, &[], false);
+        assert_eq!(fired(result), Some("drafts".to_string()));
+        assert!(!registry.is_pending());
+
+        // Modifier keys pressed between chords do not break a sequence
+        registry.dispatch(&g, 200.0, false, &[], false);
+        registry.dispatch(&KeyPress::new("Shift"), 250.0, false, &[], false);
+        assert!(registry.is_pending());
+
+        // Sequences time out
+        let late = 200.0 + SEQUENCE_TIMEOUT_MS + 1.0;
+        let result = registry.dispatch(&KeyPress::new("i"), late, false, &[], false);
+        assert_eq!(result, ShortcutMatch::None);
+    }
+
+    #[test]
+    fn test_registry_broken_sequence_restarts() {
+        let mut registry = ShortcutRegistry::new();
+        registry.register(binding("inbox", "g then i"), false);
+        registry.register(binding("help", "?"), false);
+
+        registry.dispatch(&KeyPress::new("g"), 0.0, false, &[], false);
+        let result = registry.dispatch(&KeyPress::new("?"), 10.0, false, &[], false);
+        assert_eq!(fired(result), Some("help".to_string()));
+    }
+
+    #[test]
+    fn test_registry_ignores_plain_keys_while_editing() {
+        let mut registry = ShortcutRegistry::new();
+        registry.register(binding("inbox", "g then i"), false);
+        registry.register(binding("save", "Mod+S"), false);
+        registry.register(binding("search", "/").in_inputs(), false);
+
+        let g = KeyPress::new("g");
+        assert_eq!(
+            registry.dispatch(&g, 0.0, false, &[], true),
+            ShortcutMatch::None
+        );
+        let save = registry.dispatch(&KeyPress::new("s").with_ctrl(), 0.0, false, &[], true);
+        assert_eq!(fired(save), Some("save".to_string()));
+        let search = registry.dispatch(&KeyPress::new("/"), 0.0, false, &[], true);
+        assert_eq!(fired(search), Some("search".to_string()));
+    }
+
+    #[test]
+    fn test_registry_modal_scope_suppresses_page() {
+        let mut registry = ShortcutRegistry::new();
+        registry.register(binding("page", "Mod+S"), false);
+        registry.register(binding("dialog", "Escape").scope("dialog"), false);
+
+        let save = KeyPress::new("s").with_ctrl();
+        let escape = KeyPress::new("Escape");
+        assert_eq!(
+            registry.dispatch(&escape, 0.0, false, &[], false),
+            ShortcutMatch::None
+        );
+
+        registry.push_modal("dialog");
+        assert_eq!(registry.active_scopes(&[]), vec!["dialog".to_string()]);
+        assert_eq!(
+            registry.dispatch(&save, 0.0, false, &[], false),
+            ShortcutMatch::None
+        );
+        let result = registry.dispatch(&escape, 0.0, false, &[], false);
+        assert_eq!(fired(result), Some("dialog".to_string()));
+
+        registry.pop_modal("dialog");
+        assert_eq!(registry.active_scopes(&[]), vec![GLOBAL_SCOPE.to_string()]);
+        let result = registry.dispatch(&save, 0.0, false, &[], false);
+        assert_eq!(fired(result), Some("page".to_string()));
+    }
+
+    #[test]
+    fn test_registry_focus_scope_takes_priority() {
+        let mut registry = ShortcutRegistry::new();
+        registry.register(binding("global-delete", "Delete"), false);
+        registry.register(binding("graph-delete", "Delete").scope("graph"), false);
+
+        let delete = KeyPress::new("Delete");
+        let focused = vec!["graph".to_string()];
+        let result = registry.dispatch(&delete, 0.0, false, &focused, false);
+        assert_eq!(fired(result), Some("graph-delete".to_string()));
+        let result = registry.dispatch(&delete, 0.0, false, &[], false);
+        assert_eq!(fired(result), Some("global-delete".to_string()));
+    }
+
+    #[test]
+    fn test_registry_conflicts() {
+        let mut registry = ShortcutRegistry::new();
+        assert!(registry.register(binding("a", "Mod+K"), false).is_empty());
+        // Same chord on this platform
+        let conflicts = registry.register(binding("b", "Ctrl+K"), false);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].first, "a");
+        assert_eq!(conflicts[0].second, "b");
+        // ...but not on macOS, where Mod is Cmd
+        assert!(registry.conflicts(true).is_empty());
+
+        // A sequence shadowed by its own prefix
+        registry.register(binding("g", "g"), false);
+        assert_eq!(registry.register(binding("gi", "g then i"), false).len(), 1);
+
+        // Other scopes never conflict
+        let conflicts = registry.register(binding("c", "Mod+K").scope("dialog"), false);
+        assert!(conflicts.is_empty());
+
+        // Re-registering the same id replaces the binding
+        registry.register(binding("a", "Mod+J"), false);
+        assert_eq!(registry.bindings().len(), 5);
+    }
+
+    #[test]
+    fn test_global_shortcut_registry() {
+        register_shortcut(binding("global.test", "Mod+Shift+T"));
+        assert!(registered_shortcuts().iter().any(|b| b.id == "global.test"));
+        unregister_shortcut("global.test");
+        assert!(!registered_shortcuts().iter().any(|b| b.id == "global.test"));
+    }
+
+    #[test]
+    fn test_data_key_selector_escapes_keys() {
+        assert_eq!(data_key_selector("save"), "[data-key=\"save\"]");
+        assert_eq!(data_key_selector("a\"b\\c"), "[data-key=\"a\\\"b\\\\c\"]");
+        assert_eq!(data_key_selector("a\nb"), "[data-key=\"a\\a b\"]");
+    }
+
+    fn registered(id: &str) -> Option<ShortcutBinding> {
+        registered_shortcuts().into_iter().find(|b| b.id == id)
+    }
+
+    #[test]
+    fn test_use_shortcut_reregisters_after_disable() {
+        let component = runtime::use_component(VNode::empty);
+        let render =
+            || runtime::with_component(component, || use_shortcut(binding("hook.toggle", "Mod+K")));
+        render();
+        assert!(registered("hook.toggle").is_some());
+
+        // A disabled item unregisters its binding; enabling it again must restore it
+        unregister_shortcut("hook.toggle");
+        render();
+        assert!(registered("hook.toggle").is_some());
+
+        runtime::cleanup_component(component);
+        assert!(registered("hook.toggle").is_none());
+    }
+
+    #[test]
+    fn test_use_shortcut_follows_changed_chord() {
+        let component = runtime::use_component(VNode::empty);
+        let render = |shortcut: &str| {
+            runtime::with_component(component, || use_shortcut(binding("hook.chord", shortcut)))
+        };
+        render("Mod+J");
+        render("Mod+Shift+J");
+        let bindings: Vec<_> = registered_shortcuts()
+            .into_iter()
+            .filter(|b| b.id == "hook.chord")
+            .collect();
+        assert_eq!(bindings.len(), 1);
+        assert_eq!(
+            bindings[0].shortcut,
+            Shortcut::parse("Mod+Shift+J").unwrap()
+        );
+        runtime::cleanup_component(component);
+    }
+
+    #[test]
+    fn test_menu_item_shortcut_prop() {
+        let props = MenuItemProps {
+            item_key: "save".to_string(),
+            shortcut: Some("Mod+S".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(props.shortcut.as_deref(), Some("Mod+S"));
+    }
+
+    // ── Wizard ─────────────────────────────────────────────────
+
+    fn wizard_steps() -> Vec<WizardStep> {
+        vec![
+            WizardStep::new("type", "Account type").with_field("type", "Type"),
+            WizardStep::new("company", "Company")
+                .when(|data| data.get("type").map(String::as_str) == Some("business")),
+            WizardStep::new("newsletter", "Newsletter").optional(),
+            WizardStep::new("review", "Review").review(),
+        ]
+    }
+
+    #[test]
+    fn test_wizard_active_steps_follow_conditions() {
+        let steps = wizard_steps();
+        let mut data = WizardData::new();
+        assert_eq!(
+            active_steps(&steps, &data),
+            vec!["type", "newsletter", "review"]
+        );
+        data.insert("type".to_string(), "business".to_string());
+        assert_eq!(
+            active_steps(&steps, &data),
+            vec!["type", "company", "newsletter", "review"]
+        );
+    }
+
+    #[test]
+    fn test_wizard_step_builder() {
+        let step = WizardStep::new("account", "Account")
+            .with_description("Sign-in details")
+            .with_field("email", "Email")
+            .with_content(|_| VNode::empty())
+            .optional();
+        assert_eq!(step.description.as_deref(), Some("Sign-in details"));
+        assert_eq!(
+            step.fields,
+            vec![("email".to_string(), "Email".to_string())]
+        );
+        assert!(step.optional && !step.review);
+        assert!(step.content.is_some());
+        assert_eq!(step.clone(), step);
+        assert!(step.is_active(&WizardData::new()));
+    }
+
+    #[test]
+    fn test_step_guard_answers_through_sink() {
+        let guard = StepGuard::from_fn(|data| match data.get("email") {
+            Some(email) if email.contains('@') => Ok(()),
+            _ => Err(StepErrors::new()
+                .with_message("Check the highlighted fields")
+                .with_field("email", "Enter a valid email")),
+        });
+        let answer = Rc::new(RefCell::new(None));
+        let sink = {
+            let answer = answer.clone();
+            GuardSink::new(move |result| *answer.borrow_mut() = Some(result))
+        };
+
+        guard.check(&WizardData::new(), sink.clone());
+        let errors = answer.borrow_mut().take().unwrap().unwrap_err();
+        assert!(!errors.is_empty());
+        assert_eq!(errors.fields["email"], "Enter a valid email");
+
+        let mut data = WizardData::new();
+        data.insert("email".to_string(), "ada@example.com".to_string());
+        guard.check(&data, sink);
+        assert_eq!(answer.borrow_mut().take(), Some(Ok(())));
+        assert!(StepErrors::new().is_empty());
+    }
+
+    #[test]
+    fn test_wizard_props_default() {
+        let props = WizardProps::default();
+        assert!(props.steps.is_empty());
+        assert!(props.persist_key.is_none());
+        assert!(props.on_finish.is_none());
+    }
+
+    #[test]
+    fn test_wizard_renders() {
+        let _ = rsx! {
+            Wizard {
+                steps: wizard_steps(),
+                persist_key: Some("signup".to_string()),
+            }
+        };
+        let _ = rsx! { Wizard {} };
+    }
 }
@@ -0,0 +1,531 @@
+#[cfg(test)]
+mod tests {
+
+    use hikari_components::portal::render::{PortalRender, PortalRenderProps};
+    use hikari_components::portal::{PortalContext, PortalEntry, try_use_portal};
+    use hikari_components::prelude::*;
+    use hikari_components::theme::{
+        ColorScheme, ContrastMode, MemoryThemeStorage, THEME_SCOPE_ATTRIBUTE, ThemeFormat,
+        ThemeLoadError, ThemeProvider, ThemeProviderProps, ThemeStorage, ThemeStore,
+        ThemeTransition, TransitionGroup, blend_css_variables, get_registered_theme, load_themes,
+        load_themes_strict, parse_themes, theme_scope, try_use_theme, use_theme,
+    };
+    use hikari_palette::{Color, ColorVisionDeficiency, PaletteGenerator, ThemeMode};
+    use tairitsu_vdom::runtime;
+
+    fn data_theme(node: &VNode) -> Option<String> {
+        match node {
+            VNode::Element(el) => el.attributes.get("data-theme").cloned(),
+            _ => None,
+        }
+    }
+
+    // ── Color scheme ───────────────────────────────────────────
+
+    #[test]
+    fn test_color_scheme_resolution() {
+        assert_eq!(ColorScheme::default(), ColorScheme::Light);
+        assert!(!ColorScheme::Light.is_dark(true));
+        assert!(ColorScheme::Dark.is_dark(false));
+        assert!(ColorScheme::System.is_dark(true));
+        assert!(!ColorScheme::System.is_dark(false));
+
+        for scheme in [ColorScheme::Light, ColorScheme::Dark, ColorScheme::System] {
+            assert_eq!(scheme.as_str().parse::<ColorScheme>(), Ok(scheme));
+        }
+        assert!("auto".parse::<ColorScheme>().is_err());
+    }
+
+    #[test]
+    fn test_theme_store_persists_scheme() {
+        let memory = MemoryThemeStorage::new();
+        let store = ThemeStore::new(memory.clone());
+        assert_eq!(store.load_scheme("app"), None);
+
+        store.save_scheme("app", ColorScheme::System);
+        assert_eq!(store.load_scheme("app"), Some(ColorScheme::System));
+        assert_eq!(memory.load("hk-theme:app").as_deref(), Some("system"));
+
+        memory.save("hk-theme:app", "sepia");
+        assert_eq!(store.load_scheme("app"), None);
+
+        store.clear_scheme("app");
+        assert_eq!(memory.load("hk-theme:app"), None);
+
+        assert_eq!(store.clone(), store);
+        assert_ne!(ThemeStore::new(memory), store);
+    }
+
+    #[test]
+    fn test_theme_provider_resolves_scheme() {
+        let provider = |scheme: ColorScheme, store: ThemeStore| {
+            ThemeProvider(ThemeProviderProps {
+                scheme,
+                persist_key: Some("app".to_string()),
+                storage: store,
+                children: VNode::empty(),
+                ..Default::default()
+            })
+        };
+
+        let store = ThemeStore::new(MemoryThemeStorage::new());
+        let node = provider(ColorScheme::Light, store.clone());
+        assert_eq!(data_theme(&node).as_deref(), Some("hikari"));
+        let node = provider(ColorScheme::Dark, store.clone());
+        assert_eq!(data_theme(&node).as_deref(), Some("tairitsu"));
+        // Off the browser the OS never prefers dark
+        let node = provider(ColorScheme::System, store.clone());
+        assert_eq!(data_theme(&node).as_deref(), Some("hikari"));
+
+        // A saved choice wins over the prop
+        store.save_scheme("app", ColorScheme::Dark);
+        let node = provider(ColorScheme::Light, store);
+        assert_eq!(data_theme(&node).as_deref(), Some("tairitsu"));
+    }
+
+    // ── Contrast and color vision ──────────────────────────────
+
+    fn contrast_provider(contrast: ContrastMode) -> VNode {
+        let (_, node) = mounted(|| {
+            ThemeProvider(ThemeProviderProps {
+                contrast,
+                children: VNode::empty(),
+                ..Default::default()
+            })
+        });
+        node
+    }
+
+    fn root_attribute(node: &VNode, name: &str) -> String {
+        match node {
+            VNode::Element(el) => el.attributes[name].clone(),
+            _ => panic!("provider should render an element"),
+        }
+    }
+
+    #[test]
+    fn test_theme_provider_contrast_modes() {
+        // Off the browser the OS never asks for more contrast
+        let node = contrast_provider(ContrastMode::System);
+        assert_eq!(root_attribute(&node, "data-contrast"), "standard");
+        assert_eq!(ContrastMode::System.resolve(), ContrastMode::Standard);
+
+        let node = contrast_provider(ContrastMode::More);
+        assert_eq!(root_attribute(&node, "data-contrast"), "more");
+        let vars = theme_scope(&scope_of(&node)).unwrap().css_variables.get();
+        assert!(vars.contains("--hi-background: #FFFFFF;"));
+        assert!(vars.contains("--hi-text-primary: #000000;"));
+
+        let node = contrast_provider(ContrastMode::Forced);
+        let vars = theme_scope(&scope_of(&node)).unwrap().css_variables.get();
+        assert!(vars.contains("--hi-background: Canvas;"));
+        assert!(vars.contains("--hi-text-primary: CanvasText;"));
+        assert!(vars.contains("--hi-component-input-bg: Canvas;"));
+        assert!(!vars.contains('#'));
+    }
+
+    #[test]
+    fn test_theme_provider_simulates_color_vision() {
+        let node = ThemeProvider(ThemeProviderProps {
+            simulate: Some(ColorVisionDeficiency::Protanopia),
+            children: VNode::empty(),
+            ..Default::default()
+        });
+        let filter = format!("{}-cvd", scope_of(&node));
+        let VNode::Element(root) = &node else {
+            panic!("provider should render an element");
+        };
+        assert!(
+            root.style
+                .to_string()
+                .contains(&format!("filter:url(#{filter})"))
+        );
+
+        let svg = root
+            .children
+            .iter()
+            .find_map(|child| match child {
+                VNode::Element(el) => el
+                    .inner_html
+                    .as_ref()
+                    .filter(|html| html.contains("feColorMatrix")),
+                _ => None,
+            })
+            .expect("simulation filter should be rendered");
+        assert!(svg.contains(&format!("id=\"{filter}\"")));
+        assert!(svg.contains("0.152286 1.052583 -0.204868 0 0"));
+    }
+
+    // ── Scopes ─────────────────────────────────────────────────
+
+    fn scope_of(node: &VNode) -> String {
+        match node {
+            VNode::Element(el) => el.attributes[THEME_SCOPE_ATTRIBUTE].clone(),
+            _ => panic!("provider should render an element"),
+        }
+    }
+
+    /// Renders `f` in a fresh component, as the runtime mounts one
+    fn mounted<T>(f: impl FnOnce() -> T) -> (runtime::ComponentId, T) {
+        let component = runtime::use_component(VNode::empty);
+        (component, runtime::with_component(component, f))
+    }
+
+    /// A light app around a dark sidebar, rendered in `component`; returns
+    /// both scopes
+    fn nested_providers(component: runtime::ComponentId) -> (String, String) {
+        runtime::with_component(component, || {
+            let sidebar = ThemeProvider(ThemeProviderProps {
+                palette: "tairitsu".to_string(),
+                ..Default::default()
+            });
+            let inner = scope_of(&sidebar);
+            let app = ThemeProvider(ThemeProviderProps {
+                children: VNode::Fragment(vec![sidebar]),
+                ..Default::default()
+            });
+            (scope_of(&app), inner)
+        })
+    }
+
+    #[test]
+    fn test_nested_providers_keep_own_scopes() {
+        let (component, _) = mounted(|| ());
+        let (outer, inner) = nested_providers(component);
+        assert_ne!(outer, inner);
+
+        // Each scope keeps its own variables
+        let outer_ctx = theme_scope(&outer).unwrap();
+        let inner_ctx = theme_scope(&inner).unwrap();
+        assert_eq!(outer_ctx.theme_name.get(), "hikari");
+        assert_eq!(inner_ctx.theme_name.get(), "tairitsu");
+        assert_ne!(outer_ctx.css_variables.get(), inner_ctx.css_variables.get());
+        assert!(theme_scope("hk-theme-missing").is_none());
+
+        // Re-rendering keeps both
+        assert_eq!(nested_providers(component), (outer, inner));
+    }
+
+    #[test]
+    fn test_use_theme_resolves_outermost_provider() {
+        assert!(try_use_theme().is_none());
+        let (component, _) = mounted(|| ());
+        let (outer, _) = nested_providers(component);
+
+        // Children are built before their provider, so the outermost one,
+        // rendered last, stays in effect
+        assert_eq!(use_theme().scope, outer);
+        nested_providers(component);
+        assert_eq!(use_theme().scope, outer);
+    }
+
+    #[test]
+    fn test_portal_takes_scope_of_caller() {
+        use_context_provider(PortalContext {
+            entries: Signal::new(Vec::new()),
+            add_entry: Callback::new(|_| {}),
+            remove_entry: Callback::new(|_| {}),
+            clear_all: Callback::new(|_| {}),
+            start_close_animation: Callback::new(|_| {}),
+            theme_scope: None,
+        });
+        assert_eq!(try_use_portal().unwrap().theme_scope, None);
+
+        let (component, _) = mounted(|| ());
+        let (outer, _) = nested_providers(component);
+        assert_eq!(try_use_portal().unwrap().theme_scope, Some(outer));
+    }
+
+    #[test]
+    fn test_scopes_forgotten_on_unmount() {
+        // Outside a component, the scope lasts as long as the render
+        let detached = scope_of(&ThemeProvider(ThemeProviderProps::default()));
+        assert!(theme_scope(&detached).is_none());
+
+        let (component, app) = mounted(|| ThemeProvider(ThemeProviderProps::default()));
+        let scope = scope_of(&app);
+        // Re-rendering keeps the scope
+        let again =
+            runtime::with_component(component, || ThemeProvider(ThemeProviderProps::default()));
+        assert_eq!(scope_of(&again), scope);
+        assert!(theme_scope(&scope).is_some());
+
+        runtime::cleanup_component(component);
+        assert!(theme_scope(&scope).is_none());
+    }
+
+    #[test]
+    fn test_portal_entries_inherit_scope() {
+        let (component, _) = mounted(|| ());
+        let (outer, inner) = nested_providers(component);
+        let entry = |id: &str, theme_scope: Option<String>| PortalEntry::Floating {
+            id: id.to_string(),
+            theme_scope,
+            children: VNode::empty(),
+        };
+        let entries =
+            use_signal(|| vec![entry("scoped", Some(inner.clone())), entry("plain", None)]);
+
+        let node = PortalRender(PortalRenderProps {
+            entries: Some(entries.inner().clone()),
+        });
+        // Rendering in the entry's scope gives the enclosing theme back
+        assert_eq!(use_theme().scope, outer);
+        let VNode::Element(root) = node else {
+            panic!("portal root should be an element");
+        };
+        let wrappers: Vec<_> = root
+            .children
+            .iter()
+            .filter_map(|child| match child {
+                VNode::Element(el) if el.attributes.contains_key(THEME_SCOPE_ATTRIBUTE) => Some(el),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(wrappers.len(), 1);
+        assert_eq!(wrappers[0].attributes[THEME_SCOPE_ATTRIBUTE], inner);
+        assert_eq!(wrappers[0].attributes["data-theme"], "tairitsu");
+    }
+
+    // ── Transitions ────────────────────────────────────────────
+
+    const LIGHT_VARS: &str = "--hi-background: #FFFFFF; --hi-primary: #000000; \
+        --hi-component-selection-bg: linear-gradient(red, blue);";
+    const DARK_VARS: &str = "--hi-background: #000000; --hi-primary: #FFFFFF; \
+        --hi-component-selection-bg: rgba(0, 0, 0, 0.5);";
+
+    fn variable<'a>(css: &'a str, name: &str) -> &'a str {
+        css.split(';')
+            .find_map(|declaration| {
+                let (key, value) = declaration.split_once(':')?;
+                (key.trim() == name).then(|| value.trim())
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_transition_groups() {
+        assert_eq!(
+            TransitionGroup::of("--hi-background"),
+            TransitionGroup::Surface
+        );
+        assert_eq!(
+            TransitionGroup::of("--hi-header-bg"),
+            TransitionGroup::Surface
+        );
+        assert_eq!(
+            TransitionGroup::of("--hi-color-text-on-primary"),
+            TransitionGroup::Text
+        );
+        assert_eq!(
+            TransitionGroup::of("--hi-button-danger"),
+            TransitionGroup::Status
+        );
+        assert_eq!(
+            TransitionGroup::of("--hi-component-input-border"),
+            TransitionGroup::Component
+        );
+        assert_eq!(TransitionGroup::of("--hi-primary"), TransitionGroup::Accent);
+    }
+
+    #[test]
+    fn test_blend_css_variables() {
+        let fade = ThemeTransition::cross_fade(300);
+        let start = blend_css_variables(LIGHT_VARS, DARK_VARS, 0.0, &fade);
+        assert_eq!(variable(&start, "--hi-background"), "#FFFFFF");
+        assert_eq!(variable(&start, "--hi-primary"), "#000000");
+        assert_eq!(
+            blend_css_variables(LIGHT_VARS, DARK_VARS, 1.0, &fade),
+            DARK_VARS.split_whitespace().collect::<Vec<_>>().join(" ")
+        );
+
+        let early = blend_css_variables(LIGHT_VARS, DARK_VARS, 0.25, &fade);
+        let late = blend_css_variables(LIGHT_VARS, DARK_VARS, 0.75, &fade);
+        let gray = variable(&early, "--hi-background");
+        assert!(gray.starts_with('#') && gray != "#FFFFFF" && gray != "#000000");
+        assert_ne!(gray, variable(&late, "--hi-background"));
+        // Values that cannot be blended flip halfway
+        assert_eq!(
+            variable(&early, "--hi-component-selection-bg"),
+            "linear-gradient(red, blue)"
+        );
+        assert_eq!(
+            variable(&late, "--hi-component-selection-bg"),
+            "rgba(0, 0, 0, 0.5)"
+        );
+
+        // Groups left out switch at once
+        let surfaces = fade.with_groups([TransitionGroup::Surface]);
+        let partial = blend_css_variables(LIGHT_VARS, DARK_VARS, 0.25, &surfaces);
+        assert_eq!(variable(&partial, "--hi-background"), gray);
+        assert_eq!(variable(&partial, "--hi-primary"), "#FFFFFF");
+    }
+
+    #[test]
+    fn test_theme_provider_transition_settles() {
+        let (_, node) = mounted(|| {
+            ThemeProvider(ThemeProviderProps {
+                transition: Some(ThemeTransition::circular_reveal(200)),
+                children: VNode::empty(),
+                ..Default::default()
+            })
+        });
+        let theme = theme_scope(&scope_of(&node)).unwrap();
+        let before = theme.css_variables.get();
+
+        theme.set_theme_from("tairitsu".to_string(), (10.0, 20.0));
+        assert_eq!(theme.theme_name.get(), "tairitsu");
+        assert_ne!(theme.css_variables.get(), before);
+        // The origin is used by a single switch
+        assert_eq!(theme.transition_origin.get(), None);
+    }
+
+    // ── Theme loading ──────────────────────────────────────────
+
+    const OCEAN_JSON: &str = r##"{
+        "palettes": {
+            "ocean-test": {
+                "mode": "dark",
+                "primary": "#00b4d8",
+                "secondary": "#ffd700",
+                "accent": "#ffd700",
+                "success": "#3fb950",
+                "warning": "#d29922",
+                "danger": "#f85149",
+                "background": "#0d1117",
+                "surface": "#161b22",
+                "border": "#30363d",
+                "text_primary": "#e6edf3",
+                "text_secondary": "#8b949e"
+            }
+        }
+    }"##;
+
+    #[test]
+    fn test_parse_themes_toml() {
+        let themes = parse_themes(
+            ThemeFormat::Toml,
+            include_str!("../../palette/data/themes.toml"),
+        )
+        .unwrap();
+        let names: Vec<&str> = themes.iter().map(|theme| theme.name.as_str()).collect();
+        assert_eq!(names, vec!["hikari", "tairitsu", "arknights"]);
+        assert_eq!(themes[0].palette.mode, ThemeMode::Light);
+        assert_eq!(themes[1].palette.background.hex(), "#161823");
+        assert_eq!(themes[1].audit.checks.len(), 16);
+
+        assert_eq!(
+            ThemeFormat::from_path("themes/brand.JSON"),
+            Some(ThemeFormat::Json)
+        );
+        assert_eq!(ThemeFormat::from_path("brand.yaml"), None);
+    }
+
+    #[test]
+    fn test_load_themes_registers_passing_themes() {
+        let themes = load_themes(ThemeFormat::Json, OCEAN_JSON).unwrap();
+        assert_eq!(themes.len(), 1);
+        assert!(themes[0].audit.passes());
+
+        let palette = get_registered_theme("ocean-test").unwrap();
+        assert_eq!(palette.mode, ThemeMode::Dark);
+        assert_eq!(palette.primary.hex(), "#00B4D8");
+    }
+
+    #[test]
+    fn test_load_themes_rejects_low_contrast() {
+        let source = OCEAN_JSON
+            .replace("ocean-test", "murky-test")
+            .replace("\"#8b949e\"", "\"#202530\"");
+
+        // Parsing alone keeps the theme and its failing audit
+        let themes = parse_themes(ThemeFormat::Json, &source).unwrap();
+        assert!(!themes[0].audit.passes());
+
+        match load_themes(ThemeFormat::Json, &source) {
+            Err(ThemeLoadError::Contrast { theme, audit }) => {
+                assert_eq!(theme, "murky-test");
+                assert!(
+                    audit
+                        .failures()
+                        .any(|check| check.foreground == "text_secondary")
+                );
+            }
+            other => panic!("expected a contrast error, got {other:?}"),
+        }
+        assert!(get_registered_theme("murky-test").is_none());
+    }
+
+    #[test]
+    fn test_load_generated_theme_toml() {
+        let generated = PaletteGenerator::new(Color::from_hex("#0a84ff").unwrap()).light();
+        let themes = load_themes(ThemeFormat::Toml, &generated.to_toml("brand-test")).unwrap();
+        assert_eq!(themes.len(), 1);
+        assert_eq!(themes[0].name, "brand-test");
+        assert_eq!(themes[0].palette.roles(), generated.palette.roles());
+        assert!(get_registered_theme("brand-test").is_some());
+    }
+
+    #[test]
+    fn test_load_bundled_themes_keeps_brand_colors() {
+        let source = include_str!("../../palette/data/themes.toml");
+        let themes = load_themes(ThemeFormat::Toml, source).unwrap();
+        let hikari = &themes[0];
+        assert_eq!(hikari.palette.primary.hex(), "#FFB3A7");
+        // The failing brand color is reported, not rejected
+        assert!(hikari.audit.failures().all(|check| !check.is_text()));
+        assert!(
+            hikari
+                .audit
+                .failures()
+                .any(|check| check.foreground == "primary")
+        );
+
+        assert!(matches!(
+            load_themes_strict(ThemeFormat::Toml, source),
+            Err(ThemeLoadError::Contrast { theme, .. }) if theme == "hikari"
+        ));
+    }
+
+    #[test]
+    fn test_load_themes_validation_errors() {
+        let invalid = |source: &str| match parse_themes(ThemeFormat::Toml, source) {
+            Err(ThemeLoadError::Invalid { message, .. }) => message,
+            other => panic!("expected a validation error, got {other:?}"),
+        };
+
+        assert_eq!(
+            invalid("[palettes.a]\nmode = \"dim\""),
+            "unknown mode `dim`"
+        );
+        assert_eq!(
+            invalid("[palettes.a]\nmode = \"light\""),
+            "missing `primary`"
+        );
+        assert_eq!(
+            invalid("[palettes.a]\nprimary = \"#12345\""),
+            "invalid color `#12345` for `primary`"
+        );
+        assert_eq!(
+            invalid("[palettes.a]\nglow = \"#123456\""),
+            "unknown key `glow`"
+        );
+
+        assert!(matches!(
+            parse_themes(ThemeFormat::Toml, "[collection]\nname = \"empty\""),
+            Err(ThemeLoadError::Parse(_))
+        ));
+        assert!(matches!(
+            parse_themes(ThemeFormat::Toml, "[palettes.a]\nmode = light"),
+            Err(ThemeLoadError::Parse(_))
+        ));
+        assert!(matches!(
+            parse_themes(ThemeFormat::Json, "{\"palettes\": 3}"),
+            Err(ThemeLoadError::Parse(_))
+        ));
+        assert!(matches!(
+            parse_themes(ThemeFormat::Json, "{\"palettes\": {\"a\": {\"mode\": 1}}}"),
+            Err(ThemeLoadError::Invalid { .. })
+        ));
+    }
+}
@@ -5,8 +5,10 @@
 //! Previously a component. Now provides a pure state model (migrated from legacy Dioxus)
 //! that can be used with any framework.
 
+use serde::{Deserialize, Serialize};
+
 /// Position of the collapsible panel
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
 pub enum CollapsiblePosition {
     #[default]
     Left,
@@ -27,7 +29,7 @@ pub enum CollapsiblePosition {
 /// // Toggle state
 /// state.expanded = !state.expanded;
 /// ```
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct CollapsibleState {
     /// Title displayed in the header
     pub title: String,
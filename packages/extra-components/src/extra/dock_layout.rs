@@ -0,0 +1,879 @@
+//! Dock layout - Framework Agnostic State Model
+//!
+//! Describes a docking window manager: panels live in tabbed groups that are
+//! docked to the center or to a side (left, right, bottom), or float as
+//! in-app windows. Any panel can be maximized. The whole layout serializes to
+//! JSON so applications can restore a user's workspace.
+//!
+//! Panels only carry metadata (id, title, flags); the application renders
+//! their content by id.
+
+use serde::de::Error as _;
+use serde::{Deserialize, Serialize};
+
+use super::collapsible::{CollapsiblePosition, CollapsibleState};
+
+/// Current version of the serialized layout format
+pub const DOCK_LAYOUT_VERSION: u32 = 1;
+
+/// Smallest size a floating window can be resized to
+pub const MIN_WINDOW_SIZE: (f64, f64) = (160.0, 100.0);
+
+/// Smallest width (or height, for the bottom zone) of a side zone
+pub const MIN_SIDE_SIZE: u32 = 80;
+
+/// Area of the dock a panel group is attached to
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DockZone {
+    Left,
+    Right,
+    Bottom,
+    #[default]
+    Center,
+}
+
+impl DockZone {
+    pub const ALL: [DockZone; 4] = [
+        DockZone::Left,
+        DockZone::Right,
+        DockZone::Bottom,
+        DockZone::Center,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DockZone::Left => "left",
+            DockZone::Right => "right",
+            DockZone::Bottom => "bottom",
+            DockZone::Center => "center",
+        }
+    }
+
+    /// Zone under a point, given relative to the dock's own rectangle
+    ///
+    /// The outer quarter on each side docks to that side; the rest (and the
+    /// top edge, which has no zone) docks to the center.
+    pub fn at_point(x: f64, y: f64, width: f64, height: f64) -> DockZone {
+        if width <= 0.0 || height <= 0.0 {
+            return DockZone::Center;
+        }
+        let (fx, fy) = (x / width, y / height);
+        if fx < 0.25 {
+            DockZone::Left
+        } else if fx > 0.75 {
+            DockZone::Right
+        } else if fy > 0.75 {
+            DockZone::Bottom
+        } else {
+            DockZone::Center
+        }
+    }
+}
+
+/// Metadata of a dockable panel
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct DockPanel {
+    pub id: String,
+    pub title: String,
+    /// Whether the user can close the panel
+    #[serde(default = "default_true")]
+    pub closable: bool,
+    /// Whether the panel can be torn out into a floating window
+    #[serde(default = "default_true")]
+    pub floatable: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl DockPanel {
+    pub fn new(id: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            closable: true,
+            floatable: true,
+        }
+    }
+
+    pub fn with_closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    pub fn with_floatable(mut self, floatable: bool) -> Self {
+        self.floatable = floatable;
+        self
+    }
+}
+
+/// A stack of panels shown as tabs
+#[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct DockGroup {
+    /// Panel ids in tab order
+    pub panels: Vec<String>,
+    /// The visible tab
+    #[serde(default)]
+    pub active: Option<String>,
+}
+
+impl DockGroup {
+    pub fn is_empty(&self) -> bool {
+        self.panels.is_empty()
+    }
+
+    pub fn contains(&self, panel_id: &str) -> bool {
+        self.panels.iter().any(|id| id == panel_id)
+    }
+
+    /// Appends a panel and makes it the active tab
+    pub fn push(&mut self, panel_id: &str) {
+        if !self.contains(panel_id) {
+            self.panels.push(panel_id.to_string());
+        }
+        self.active = Some(panel_id.to_string());
+    }
+
+    /// Removes a panel, activating its neighbour if it was active
+    pub fn remove(&mut self, panel_id: &str) -> bool {
+        let Some(index) = self.panels.iter().position(|id| id == panel_id) else {
+            return false;
+        };
+        self.panels.remove(index);
+        if self.active.as_deref() == Some(panel_id) {
+            self.active = self
+                .panels
+                .get(index.min(self.panels.len().saturating_sub(1)))
+                .cloned();
+        }
+        true
+    }
+
+    /// The active panel id, falling back to the first tab
+    pub fn active_panel(&self) -> Option<&str> {
+        self.active
+            .as_deref()
+            .filter(|id| self.contains(id))
+            .or_else(|| self.panels.first().map(String::as_str))
+    }
+}
+
+/// A side zone: collapsible, with a size stored in the collapsible state
+///
+/// `state.width` is the zone's width, or its height for the bottom zone.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct DockSide {
+    pub state: CollapsibleState,
+    pub group: DockGroup,
+}
+
+impl DockSide {
+    fn new(zone: DockZone, size: u32) -> Self {
+        let position = match zone {
+            DockZone::Right => CollapsiblePosition::Right,
+            _ => CollapsiblePosition::Left,
+        };
+        Self {
+            state: CollapsibleState::new(zone.as_str().to_string())
+                .with_expanded(true)
+                .with_position(position)
+                .with_width(size),
+            group: DockGroup::default(),
+        }
+    }
+}
+
+/// Position and size of a floating window, in px
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub struct DockRect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl DockRect {
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
+
+impl Default for DockRect {
+    fn default() -> Self {
+        Self::new(120.0, 120.0, 420.0, 300.0)
+    }
+}
+
+/// An in-app window holding a panel group
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct FloatingWindow {
+    pub id: String,
+    pub rect: DockRect,
+    pub group: DockGroup,
+}
+
+/// Where a panel currently lives
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum DockLocation {
+    Zone(DockZone),
+    Window(String),
+}
+
+/// Where to move a panel
+#[derive(Clone, PartialEq, Debug)]
+pub enum DockTarget {
+    Zone(DockZone),
+    /// An existing floating window
+    Window(String),
+    /// A new floating window
+    Float(DockRect),
+}
+
+/// State model for a docking layout
+///
+/// ## Example
+///
+/// ```rust
+/// use hikari_extra_components::extra::{DockLayout, DockPanel, DockRect, DockTarget, DockZone};
+///
+/// let mut layout = DockLayout::new()
+///     .with_panel(DockPanel::new("editor", "Editor"), DockZone::Center)
+///     .with_panel(DockPanel::new("outline", "Outline"), DockZone::Left);
+///
+/// layout.move_panel("outline", DockTarget::Float(DockRect::default()));
+/// let json = layout.to_json().unwrap();
+/// assert_eq!(DockLayout::from_json(&json).unwrap(), layout);
+/// ```
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct DockLayout {
+    #[serde(default = "default_version")]
+    pub version: u32,
+    pub panels: Vec<DockPanel>,
+    pub center: DockGroup,
+    pub left: DockSide,
+    pub right: DockSide,
+    pub bottom: DockSide,
+    /// Floating windows, back to front
+    #[serde(default)]
+    pub windows: Vec<FloatingWindow>,
+    /// Panel filling the whole dock
+    #[serde(default)]
+    pub maximized: Option<String>,
+    #[serde(default)]
+    next_window: u32,
+}
+
+fn default_version() -> u32 {
+    DOCK_LAYOUT_VERSION
+}
+
+impl Default for DockLayout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DockLayout {
+    /// An empty layout with expanded side zones
+    pub fn new() -> Self {
+        Self {
+            version: DOCK_LAYOUT_VERSION,
+            panels: Vec::new(),
+            center: DockGroup::default(),
+            left: DockSide::new(DockZone::Left, 260),
+            right: DockSide::new(DockZone::Right, 300),
+            bottom: DockSide::new(DockZone::Bottom, 220),
+            windows: Vec::new(),
+            maximized: None,
+            next_window: 0,
+        }
+    }
+
+    /// Builder form of [`add_panel`](Self::add_panel) for docked panels
+    pub fn with_panel(mut self, panel: DockPanel, zone: DockZone) -> Self {
+        self.add_panel(panel, DockTarget::Zone(zone));
+        self
+    }
+
+    /// Adds a panel, or moves it if it already exists
+    pub fn add_panel(&mut self, panel: DockPanel, target: DockTarget) -> bool {
+        let id = panel.id.clone();
+        match self.panels.iter_mut().find(|p| p.id == id) {
+            Some(existing) => *existing = panel,
+            None => self.panels.push(panel),
+        }
+        if self.locate(&id).is_none() {
+            // Not placed yet: put it somewhere so `move_panel` can take over
+            self.center.push(&id);
+        }
+        self.move_panel(&id, target)
+    }
+
+    pub fn panel(&self, panel_id: &str) -> Option<&DockPanel> {
+        self.panels.iter().find(|p| p.id == panel_id)
+    }
+
+    /// The side state of a zone (`None` for the center)
+    pub fn side(&self, zone: DockZone) -> Option<&DockSide> {
+        match zone {
+            DockZone::Left => Some(&self.left),
+            DockZone::Right => Some(&self.right),
+            DockZone::Bottom => Some(&self.bottom),
+            DockZone::Center => None,
+        }
+    }
+
+    pub fn side_mut(&mut self, zone: DockZone) -> Option<&mut DockSide> {
+        match zone {
+            DockZone::Left => Some(&mut self.left),
+            DockZone::Right => Some(&mut self.right),
+            DockZone::Bottom => Some(&mut self.bottom),
+            DockZone::Center => None,
+        }
+    }
+
+    pub fn zone_group(&self, zone: DockZone) -> &DockGroup {
+        match self.side(zone) {
+            Some(side) => &side.group,
+            None => &self.center,
+        }
+    }
+
+    fn zone_group_mut(&mut self, zone: DockZone) -> &mut DockGroup {
+        match zone {
+            DockZone::Left => &mut self.left.group,
+            DockZone::Right => &mut self.right.group,
+            DockZone::Bottom => &mut self.bottom.group,
+            DockZone::Center => &mut self.center,
+        }
+    }
+
+    pub fn window(&self, window_id: &str) -> Option<&FloatingWindow> {
+        self.windows.iter().find(|w| w.id == window_id)
+    }
+
+    fn window_mut(&mut self, window_id: &str) -> Option<&mut FloatingWindow> {
+        self.windows.iter_mut().find(|w| w.id == window_id)
+    }
+
+    /// Finds the group holding a panel
+    pub fn locate(&self, panel_id: &str) -> Option<DockLocation> {
+        DockZone::ALL
+            .into_iter()
+            .find(|zone| self.zone_group(*zone).contains(panel_id))
+            .map(DockLocation::Zone)
+            .or_else(|| {
+                self.windows
+                    .iter()
+                    .find(|w| w.group.contains(panel_id))
+                    .map(|w| DockLocation::Window(w.id.clone()))
+            })
+    }
+
+    fn group_mut(&mut self, location: &DockLocation) -> Option<&mut DockGroup> {
+        match location {
+            DockLocation::Zone(zone) => Some(self.zone_group_mut(*zone)),
+            DockLocation::Window(id) => self.window_mut(id).map(|w| &mut w.group),
+        }
+    }
+
+    /// Shows a panel: activates its tab, raises its window and expands its side
+    pub fn activate(&mut self, panel_id: &str) -> bool {
+        let Some(location) = self.locate(panel_id) else {
+            return false;
+        };
+        if let Some(group) = self.group_mut(&location) {
+            group.active = Some(panel_id.to_string());
+        }
+        match location {
+            DockLocation::Zone(zone) => {
+                if let Some(side) = self.side_mut(zone) {
+                    side.state.expanded = true;
+                }
+            }
+            DockLocation::Window(id) => self.raise_window(&id),
+        }
+        true
+    }
+
+    /// Moves a panel to another zone or window, or floats it
+    ///
+    /// Returns false (changing nothing) for unknown panels, unknown target
+    /// windows and non-floatable panels asked to float.
+    pub fn move_panel(&mut self, panel_id: &str, target: DockTarget) -> bool {
+        let Some(panel) = self.panel(panel_id) else {
+            return false;
+        };
+        let valid = match &target {
+            DockTarget::Zone(_) => true,
+            DockTarget::Window(id) => self.window(id).is_some(),
+            DockTarget::Float(_) => panel.floatable,
+        };
+        if !valid {
+            return false;
+        }
+
+        if let Some(location) = self.locate(panel_id)
+            && let Some(group) = self.group_mut(&location)
+        {
+            group.remove(panel_id);
+        }
+
+        match target {
+            DockTarget::Zone(zone) => {
+                self.zone_group_mut(zone).push(panel_id);
+                if let Some(side) = self.side_mut(zone) {
+                    side.state.expanded = true;
+                }
+            }
+            DockTarget::Window(id) => {
+                if let Some(window) = self.window_mut(&id) {
+                    window.group.push(panel_id);
+                }
+                self.raise_window(&id);
+            }
+            DockTarget::Float(rect) => {
+                self.next_window += 1;
+                let mut group = DockGroup::default();
+                group.push(panel_id);
+                self.windows.push(FloatingWindow {
+                    id: format!("window-{}", self.next_window),
+                    rect: clamp_window(rect),
+                    group,
+                });
+            }
+        }
+        self.prune_windows();
+        true
+    }
+
+    /// Docks every panel of a floating window into `zone`
+    pub fn dock_window(&mut self, window_id: &str, zone: DockZone) -> bool {
+        let Some(index) = self.windows.iter().position(|w| w.id == window_id) else {
+            return false;
+        };
+        let window = self.windows.remove(index);
+        let active = window.group.active_panel().map(str::to_string);
+        for panel_id in &window.group.panels {
+            self.zone_group_mut(zone).push(panel_id);
+        }
+        if let Some(active) = active {
+            self.zone_group_mut(zone).active = Some(active);
+        }
+        if let Some(side) = self.side_mut(zone) {
+            side.state.expanded = true;
+        }
+        true
+    }
+
+    /// Removes a closable panel from the layout
+    pub fn close_panel(&mut self, panel_id: &str) -> bool {
+        if !self.panel(panel_id).is_some_and(|p| p.closable) {
+            return false;
+        }
+        if let Some(location) = self.locate(panel_id)
+            && let Some(group) = self.group_mut(&location)
+        {
+            group.remove(panel_id);
+        }
+        self.panels.retain(|p| p.id != panel_id);
+        if self.maximized.as_deref() == Some(panel_id) {
+            self.maximized = None;
+        }
+        self.prune_windows();
+        true
+    }
+
+    /// Closes a floating window together with its panels
+    ///
+    /// Panels that cannot be closed are docked to the center instead.
+    pub fn close_window(&mut self, window_id: &str) -> bool {
+        let Some(window) = self.window(window_id).cloned() else {
+            return false;
+        };
+        for panel_id in &window.group.panels {
+            if !self.close_panel(panel_id) {
+                self.move_panel(panel_id, DockTarget::Zone(DockZone::Center));
+            }
+        }
+        self.windows.retain(|w| w.id != window_id);
+        true
+    }
+
+    pub fn move_window(&mut self, window_id: &str, x: f64, y: f64) {
+        if let Some(window) = self.window_mut(window_id) {
+            window.rect.x = x.max(0.0);
+            window.rect.y = y.max(0.0);
+        }
+    }
+
+    pub fn resize_window(&mut self, window_id: &str, width: f64, height: f64) {
+        if let Some(window) = self.window_mut(window_id) {
+            window.rect = clamp_window(DockRect {
+                width,
+                height,
+                ..window.rect
+            });
+        }
+    }
+
+    /// Brings a floating window to the front
+    pub fn raise_window(&mut self, window_id: &str) {
+        if let Some(index) = self.windows.iter().position(|w| w.id == window_id) {
+            let window = self.windows.remove(index);
+            self.windows.push(window);
+        }
+    }
+
+    /// Maximizes a panel, or restores it if it is already maximized
+    pub fn toggle_maximize(&mut self, panel_id: &str) {
+        self.maximized = if self.maximized.as_deref() == Some(panel_id) {
+            None
+        } else if self.panel(panel_id).is_some() {
+            Some(panel_id.to_string())
+        } else {
+            self.maximized.take()
+        };
+    }
+
+    /// Collapses or expands a side zone
+    pub fn toggle_side(&mut self, zone: DockZone) {
+        if let Some(side) = self.side_mut(zone) {
+            side.state.toggle();
+        }
+    }
+
+    /// Sets the width (or height, for the bottom zone) of a side zone
+    pub fn resize_side(&mut self, zone: DockZone, size: u32) {
+        if let Some(side) = self.side_mut(zone) {
+            side.state.width = size.max(MIN_SIDE_SIZE);
+        }
+    }
+
+    /// Drops references to unknown or duplicated panels and empty windows
+    ///
+    /// Called after deserializing, so hand-edited or stale layouts still load.
+    pub fn normalize(&mut self) {
+        let known: Vec<String> = self.panels.iter().map(|p| p.id.clone()).collect();
+        let mut seen: Vec<String> = Vec::new();
+        let mut clean = |group: &mut DockGroup| {
+            group.panels.retain(|id| {
+                let keep = known.contains(id) && !seen.contains(id);
+                if keep {
+                    seen.push(id.clone());
+                }
+                keep
+            });
+            if group.active.as_ref().is_some_and(|id| !group.contains(id)) {
+                group.active = group.panels.first().cloned();
+            }
+        };
+        clean(&mut self.center);
+        clean(&mut self.left.group);
+        clean(&mut self.right.group);
+        clean(&mut self.bottom.group);
+        for window in &mut self.windows {
+            clean(&mut window.group);
+        }
+
+        // Panels listed but not placed anywhere go to the center
+        let unplaced: Vec<String> = known
+            .iter()
+            .filter(|id| !seen.contains(id))
+            .cloned()
+            .collect();
+        for id in unplaced {
+            self.center.panels.push(id);
+        }
+        if self.center.active.is_none() {
+            self.center.active = self.center.panels.first().cloned();
+        }
+
+        if self
+            .maximized
+            .as_ref()
+            .is_some_and(|id| !known.contains(id))
+        {
+            self.maximized = None;
+        }
+        self.prune_windows();
+
+        // Stale or hand-edited counters must not hand out a taken id
+        self.next_window = self
+            .windows
+            .iter()
+            .filter_map(|w| w.id.strip_prefix("window-")?.parse().ok())
+            .fold(self.next_window, u32::max);
+    }
+
+    fn prune_windows(&mut self) {
+        self.windows.retain(|w| !w.group.is_empty());
+    }
+
+    /// Serializes the layout to pretty-printed JSON
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Restores a layout saved with [`to_json`](Self::to_json)
+    ///
+    /// Layouts of another format version are rejected.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        let mut layout: Self = serde_json::from_str(json)?;
+        if layout.version != DOCK_LAYOUT_VERSION {
+            return Err(serde_json::Error::custom(format!(
+                "unsupported dock layout version {}",
+                layout.version
+            )));
+        }
+        layout.normalize();
+        Ok(layout)
+    }
+}
+
+fn clamp_window(rect: DockRect) -> DockRect {
+    DockRect {
+        x: rect.x.max(0.0),
+        y: rect.y.max(0.0),
+        width: rect.width.max(MIN_WINDOW_SIZE.0),
+        height: rect.height.max(MIN_WINDOW_SIZE.1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> DockLayout {
+        DockLayout::new()
+            .with_panel(DockPanel::new("editor", "Editor"), DockZone::Center)
+            .with_panel(DockPanel::new("preview", "Preview"), DockZone::Center)
+            .with_panel(DockPanel::new("files", "Files"), DockZone::Left)
+            .with_panel(
+                DockPanel::new("console", "Console").with_closable(false),
+                DockZone::Bottom,
+            )
+    }
+
+    #[test]
+    fn test_panels_are_placed() {
+        let layout = sample();
+        assert_eq!(layout.center.panels, vec!["editor", "preview"]);
+        assert_eq!(layout.center.active_panel(), Some("preview"));
+        assert_eq!(
+            layout.locate("files"),
+            Some(DockLocation::Zone(DockZone::Left))
+        );
+        assert_eq!(layout.locate("missing"), None);
+    }
+
+    #[test]
+    fn test_move_between_zones() {
+        let mut layout = sample();
+        layout.left.state.expanded = false;
+        assert!(layout.move_panel("preview", DockTarget::Zone(DockZone::Left)));
+        assert_eq!(layout.center.panels, vec!["editor"]);
+        assert_eq!(layout.center.active_panel(), Some("editor"));
+        assert_eq!(layout.left.group.panels, vec!["files", "preview"]);
+        assert!(layout.left.state.expanded);
+    }
+
+    #[test]
+    fn test_float_and_dock_window() {
+        let mut layout = sample();
+        assert!(layout.move_panel("files", DockTarget::Float(DockRect::default())));
+        assert!(layout.left.group.is_empty());
+        assert_eq!(layout.windows.len(), 1);
+        let window_id = layout.windows[0].id.clone();
+        assert_eq!(
+            layout.locate("files"),
+            Some(DockLocation::Window(window_id.clone()))
+        );
+
+        assert!(layout.move_panel("preview", DockTarget::Window(window_id.clone())));
+        assert_eq!(layout.windows[0].group.panels, vec!["files", "preview"]);
+
+        assert!(layout.dock_window(&window_id, DockZone::Right));
+        assert!(layout.windows.is_empty());
+        assert_eq!(layout.right.group.panels, vec!["files", "preview"]);
+        assert_eq!(layout.right.group.active_panel(), Some("preview"));
+    }
+
+    #[test]
+    fn test_empty_windows_are_removed() {
+        let mut layout = sample();
+        layout.move_panel("files", DockTarget::Float(DockRect::default()));
+        layout.move_panel("files", DockTarget::Zone(DockZone::Center));
+        assert!(layout.windows.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_moves_change_nothing() {
+        let mut layout = sample();
+        let before = layout.clone();
+        assert!(!layout.move_panel("files", DockTarget::Window("nope".to_string())));
+        assert!(!layout.move_panel("nope", DockTarget::Zone(DockZone::Left)));
+        layout.add_panel(
+            DockPanel::new("pinned", "Pinned").with_floatable(false),
+            DockTarget::Zone(DockZone::Right),
+        );
+        assert!(!layout.move_panel("pinned", DockTarget::Float(DockRect::default())));
+        assert_eq!(layout.right.group.panels, vec!["pinned"]);
+        assert_eq!(layout.left, before.left);
+    }
+
+    #[test]
+    fn test_close_panel() {
+        let mut layout = sample();
+        layout.toggle_maximize("editor");
+        assert!(layout.close_panel("editor"));
+        assert!(layout.panel("editor").is_none());
+        assert_eq!(layout.maximized, None);
+        assert!(!layout.close_panel("console"));
+        assert!(layout.bottom.group.contains("console"));
+    }
+
+    #[test]
+    fn test_close_window_keeps_unclosable_panels() {
+        let mut layout = sample();
+        layout.move_panel("console", DockTarget::Float(DockRect::default()));
+        let window_id = layout.windows[0].id.clone();
+        layout.move_panel("files", DockTarget::Window(window_id.clone()));
+        assert!(layout.close_window(&window_id));
+        assert!(layout.windows.is_empty());
+        assert!(layout.panel("files").is_none());
+        assert!(layout.center.contains("console"));
+    }
+
+    #[test]
+    fn test_windows_raise_and_clamp() {
+        let mut layout = sample();
+        layout.move_panel("files", DockTarget::Float(DockRect::default()));
+        layout.move_panel(
+            "preview",
+            DockTarget::Float(DockRect::new(-5.0, 10.0, 10.0, 10.0)),
+        );
+        let (first, second) = (layout.windows[0].id.clone(), layout.windows[1].id.clone());
+        assert_eq!(
+            layout.windows[1].rect,
+            DockRect::new(0.0, 10.0, 160.0, 100.0)
+        );
+
+        layout.activate("files");
+        assert_eq!(
+            layout.windows.last().map(|w| w.id.clone()),
+            Some(first.clone())
+        );
+
+        layout.move_window(&second, 50.0, -20.0);
+        layout.resize_window(&second, 500.0, 20.0);
+        let rect = layout.window(&second).unwrap().rect;
+        assert_eq!(rect, DockRect::new(50.0, 0.0, 500.0, 100.0));
+    }
+
+    #[test]
+    fn test_sides_collapse_and_resize() {
+        let mut layout = sample();
+        layout.toggle_side(DockZone::Left);
+        assert!(!layout.left.state.expanded);
+        layout.activate("files");
+        assert!(layout.left.state.expanded);
+        layout.resize_side(DockZone::Bottom, 10);
+        assert_eq!(layout.bottom.state.width, MIN_SIDE_SIZE);
+        assert_eq!(layout.right.state.position, CollapsiblePosition::Right);
+    }
+
+    #[test]
+    fn test_toggle_maximize() {
+        let mut layout = sample();
+        layout.toggle_maximize("editor");
+        assert_eq!(layout.maximized.as_deref(), Some("editor"));
+        layout.toggle_maximize("editor");
+        assert_eq!(layout.maximized, None);
+        layout.toggle_maximize("missing");
+        assert_eq!(layout.maximized, None);
+    }
+
+    #[test]
+    fn test_zone_at_point() {
+        assert_eq!(
+            DockZone::at_point(10.0, 300.0, 1000.0, 600.0),
+            DockZone::Left
+        );
+        assert_eq!(
+            DockZone::at_point(900.0, 300.0, 1000.0, 600.0),
+            DockZone::Right
+        );
+        assert_eq!(
+            DockZone::at_point(500.0, 550.0, 1000.0, 600.0),
+            DockZone::Bottom
+        );
+        assert_eq!(
+            DockZone::at_point(500.0, 20.0, 1000.0, 600.0),
+            DockZone::Center
+        );
+        assert_eq!(DockZone::at_point(5.0, 5.0, 0.0, 0.0), DockZone::Center);
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let mut layout = sample();
+        layout.move_panel("files", DockTarget::Float(DockRect::default()));
+        layout.toggle_maximize("editor");
+        let json = layout.to_json().unwrap();
+        assert!(json.contains("\"bottom\""));
+        assert_eq!(DockLayout::from_json(&json).unwrap(), layout);
+    }
+
+    #[test]
+    fn test_from_json_normalizes() {
+        let json = r#"{
+            "panels": [
+                { "id": "a", "title": "A" },
+                { "id": "b", "title": "B" }
+            ],
+            "center": { "panels": ["a", "ghost", "a"], "active": "ghost" },
+            "left": { "state": { "title": "left", "expanded": true, "collapsible": true,
+                                 "position": "Left", "width": 200, "class": "" },
+                      "group": { "panels": [] } },
+            "right": { "state": { "title": "right", "expanded": true, "collapsible": true,
+                                  "position": "Right", "width": 200, "class": "" },
+                       "group": { "panels": [] } },
+            "bottom": { "state": { "title": "bottom", "expanded": true, "collapsible": true,
+                                   "position": "Left", "width": 200, "class": "" },
+                        "group": { "panels": [] } },
+            "maximized": "ghost"
+        }"#;
+        let layout = DockLayout::from_json(json).unwrap();
+        assert_eq!(layout.version, DOCK_LAYOUT_VERSION);
+        assert_eq!(layout.center.panels, vec!["a", "b"]);
+        assert_eq!(layout.center.active.as_deref(), Some("a"));
+        assert!(layout.panel("a").unwrap().closable);
+        assert_eq!(layout.maximized, None);
+
+        let future = json.replacen('{', r#"{ "version": 99,"#, 1);
+        assert!(DockLayout::from_json(&future).is_err());
+    }
+
+    #[test]
+    fn test_normalize_recomputes_window_counter() {
+        let mut layout = sample();
+        layout.move_panel("files", DockTarget::Float(DockRect::default()));
+        let json = layout
+            .to_json()
+            .unwrap()
+            .replace("\"next_window\": 1", "\"next_window\": 0");
+        assert!(json.contains("\"next_window\": 0"));
+        let mut restored = DockLayout::from_json(&json).unwrap();
+        restored.move_panel("editor", DockTarget::Float(DockRect::default()));
+        assert_eq!(restored.windows.len(), 2);
+        assert_ne!(restored.windows[0].id, restored.windows[1].id);
+    }
+}
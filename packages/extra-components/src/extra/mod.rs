@@ -8,21 +8,27 @@
 //! ## Modules
 //!
 //! - [`collapsible`] - Collapsible panel state
+//! - [`dock_layout`] - Dockable panel layout state
 //! - [`drag_layer`] - Drag and drop state management
 //! - [`zoom_controls`] - Zoom controls state
 //! - [`timeline`] - Timeline event model
 //! - [`user_guide`] - User onboarding guide state
+//! - [`wizard`] - Multi-step wizard progress
 
 pub mod collapsible;
+pub mod dock_layout;
 pub mod drag_layer;
 pub mod timeline;
 pub mod user_guide;
+pub mod wizard;
 pub mod zoom_controls;
 
 pub use collapsible::*;
+pub use dock_layout::*;
 pub use drag_layer::*;
 pub use timeline::*;
 pub use user_guide::*;
+pub use wizard::*;
 pub use zoom_controls::*;
 
 // NOTE: The following modules have been removed as they were heavily dependent on legacy Dioxus/wasm-bindgen:
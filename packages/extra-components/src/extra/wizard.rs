@@ -0,0 +1,247 @@
+//! Wizard - Framework Agnostic State Model
+//!
+//! Tracks progress through a multi-step flow: the current step, the steps
+//! already completed and the data collected so far. Steps are identified by
+//! key, and navigation works on the list of *active* step keys, so flows with
+//! conditional (branching) steps simply pass a different list when the data
+//! changes which steps apply.
+//!
+//! Progress serializes to JSON so a reload can resume where the user left.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Values collected by the steps, by field name
+pub type WizardData = BTreeMap<String, String>;
+
+/// State model for a wizard
+///
+/// ## Example
+///
+/// ```rust
+/// use hikari_extra_components::extra::WizardProgress;
+///
+/// let steps = ["account", "profile", "review"].map(String::from);
+/// let mut progress = WizardProgress::new(&steps);
+/// progress.set("email", "ada@example.com");
+/// assert!(progress.advance(&steps));
+/// assert_eq!(progress.current.as_deref(), Some("profile"));
+///
+/// let restored = WizardProgress::from_json(&progress.to_json().unwrap()).unwrap();
+/// assert_eq!(restored, progress);
+/// ```
+#[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct WizardProgress {
+    /// Key of the current step
+    pub current: Option<String>,
+
+    /// Keys of the steps passed with "next", in completion order
+    #[serde(default)]
+    pub completed: Vec<String>,
+
+    #[serde(default)]
+    pub data: WizardData,
+}
+
+impl WizardProgress {
+    /// Progress at the first of `steps`
+    pub fn new(steps: &[String]) -> Self {
+        Self {
+            current: steps.first().cloned(),
+            ..Default::default()
+        }
+    }
+
+    pub fn get(&self, field: &str) -> Option<&str> {
+        self.data.get(field).map(String::as_str)
+    }
+
+    pub fn set(&mut self, field: impl Into<String>, value: impl Into<String>) {
+        self.data.insert(field.into(), value.into());
+    }
+
+    pub fn remove(&mut self, field: &str) -> Option<String> {
+        self.data.remove(field)
+    }
+
+    pub fn is_completed(&self, step: &str) -> bool {
+        self.completed.iter().any(|key| key == step)
+    }
+
+    /// Position of the current step among `steps`
+    pub fn position(&self, steps: &[String]) -> Option<usize> {
+        let current = self.current.as_deref()?;
+        steps.iter().position(|key| key == current)
+    }
+
+    pub fn is_first(&self, steps: &[String]) -> bool {
+        self.position(steps) == Some(0)
+    }
+
+    pub fn is_last(&self, steps: &[String]) -> bool {
+        self.position(steps)
+            .is_some_and(|index| index + 1 == steps.len())
+    }
+
+    fn complete_current(&mut self) {
+        if let Some(current) = self.current.clone()
+            && !self.is_completed(&current)
+        {
+            self.completed.push(current);
+        }
+    }
+
+    /// Completes the current step and moves to the next one
+    ///
+    /// Returns false on the last step, which is still marked completed.
+    pub fn advance(&mut self, steps: &[String]) -> bool {
+        self.complete_current();
+        match self.position(steps) {
+            Some(index) if index + 1 < steps.len() => {
+                self.current = Some(steps[index + 1].clone());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Moves to the previous step; returns false on the first one
+    pub fn back(&mut self, steps: &[String]) -> bool {
+        match self.position(steps) {
+            Some(index) if index > 0 => {
+                self.current = Some(steps[index - 1].clone());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether `step` can be opened directly
+    ///
+    /// Any step up to the first one not yet completed is reachable, so users
+    /// can revisit earlier steps but not skip validation of later ones.
+    pub fn can_jump(&self, steps: &[String], step: &str) -> bool {
+        let Some(target) = steps.iter().position(|key| key == step) else {
+            return false;
+        };
+        let frontier = steps
+            .iter()
+            .position(|key| !self.is_completed(key))
+            .unwrap_or(steps.len().saturating_sub(1));
+        target <= frontier || self.position(steps).is_some_and(|index| target <= index)
+    }
+
+    /// Opens `step` if [`can_jump`](Self::can_jump) allows it
+    pub fn jump(&mut self, steps: &[String], step: &str) -> bool {
+        if !self.can_jump(steps, step) {
+            return false;
+        }
+        self.current = Some(step.to_string());
+        true
+    }
+
+    /// Re-targets the current step after the active steps changed
+    ///
+    /// If the current step no longer applies (its condition turned false),
+    /// moves to the first active step not completed yet. Completions of
+    /// inactive steps are kept, so they count again if the branch returns.
+    pub fn sync(&mut self, steps: &[String]) {
+        if self.position(steps).is_some() {
+            return;
+        }
+        self.current = steps
+            .iter()
+            .find(|key| !self.is_completed(key))
+            .or_else(|| steps.last())
+            .cloned();
+    }
+
+    /// Serializes the progress to pretty-printed JSON
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Restores progress saved with [`to_json`](Self::to_json)
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(keys: &[&str]) -> Vec<String> {
+        keys.iter().map(|key| key.to_string()).collect()
+    }
+
+    #[test]
+    fn test_advance_and_back() {
+        let steps = keys(&["a", "b", "c"]);
+        let mut progress = WizardProgress::new(&steps);
+        assert!(progress.is_first(&steps));
+        assert!(progress.advance(&steps));
+        assert!(progress.advance(&steps));
+        assert!(progress.is_last(&steps));
+        assert!(!progress.advance(&steps));
+        assert_eq!(progress.completed, keys(&["a", "b", "c"]));
+        assert!(progress.back(&steps));
+        assert_eq!(progress.current.as_deref(), Some("b"));
+
+        let mut first = WizardProgress::new(&steps);
+        assert!(!first.back(&steps));
+    }
+
+    #[test]
+    fn test_jump_stops_at_first_incomplete_step() {
+        let steps = keys(&["a", "b", "c", "d"]);
+        let mut progress = WizardProgress::new(&steps);
+        progress.advance(&steps);
+        assert!(progress.can_jump(&steps, "a"));
+        assert!(progress.can_jump(&steps, "b"));
+        assert!(!progress.can_jump(&steps, "c"));
+        assert!(!progress.jump(&steps, "d"));
+        assert!(!progress.jump(&steps, "missing"));
+
+        progress.advance(&steps);
+        assert!(progress.jump(&steps, "a"));
+        // Going back keeps later completed steps reachable
+        assert!(progress.jump(&steps, "c"));
+    }
+
+    #[test]
+    fn test_sync_after_branch_change() {
+        let all = keys(&["type", "company", "billing", "review"]);
+        let mut progress = WizardProgress::new(&all);
+        progress.advance(&all);
+        assert_eq!(progress.current.as_deref(), Some("company"));
+
+        // Choosing "personal" removes the company step
+        let personal = keys(&["type", "billing", "review"]);
+        progress.sync(&personal);
+        assert_eq!(progress.current.as_deref(), Some("billing"));
+
+        // A current step that is still active is left alone
+        progress.sync(&personal);
+        assert_eq!(progress.current.as_deref(), Some("billing"));
+    }
+
+    #[test]
+    fn test_data_and_json_round_trip() {
+        let steps = keys(&["a", "b"]);
+        let mut progress = WizardProgress::new(&steps);
+        progress.set("name", "Ada");
+        progress.set("plan", "pro");
+        assert_eq!(progress.remove("plan").as_deref(), Some("pro"));
+        progress.advance(&steps);
+
+        let json = progress.to_json().unwrap();
+        let restored = WizardProgress::from_json(&json).unwrap();
+        assert_eq!(restored, progress);
+        assert_eq!(restored.get("name"), Some("Ada"));
+
+        let partial = WizardProgress::from_json(r#"{ "current": "b" }"#).unwrap();
+        assert!(partial.data.is_empty());
+    }
+}
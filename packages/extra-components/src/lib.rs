@@ -18,10 +18,12 @@
 //!
 //! ### Extra Components
 //! - [`Collapsible`] - Collapsible state model
+//! - [`DockLayout`] - Dockable panel layout with floating windows
 //! - [`DragLayer`] - Drag and drop state management
 //! - [`ZoomControls`] - Zoom state and controls
 //! - [`Timeline`] - Timeline event model
 //! - [`UserGuide`] - User onboarding guide state
+//! - [`WizardProgress`] - Multi-step wizard progress with JSON persistence
 //!
 //! ### Node Graph
 //! - [`NodeGraphState`] - Node graph canvas state
@@ -11,6 +11,10 @@ const KNOWN_COLLECTIONS: &[(&str, &str)] = &[
     // ("material", "material.toml"),
 ];
 
+/// Optional alias tables: (collection, data file). Aliases make names
+/// searchable at runtime, e.g. by pinyin.
+const KNOWN_ALIASES: &[(&str, &str)] = &[("chinese", "pinyin.toml")];
+
 fn main() {
     let manifest_dir =
         env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR must be set by Cargo");
@@ -146,7 +150,13 @@ fn generate_collections(manifest_dir: &str, out_dir: &str) {
         };
         let colors = parse_color_toml(&content)
             .unwrap_or_else(|e| panic!("hk-palette: failed to parse {file}: {e}"));
-        emit_collection_module(name, &colors, &collections_dir.join(format!("{name}.rs")));
+        let aliases = read_aliases(manifest_dir, name, &colors);
+        emit_collection_module(
+            name,
+            &colors,
+            &aliases,
+            &collections_dir.join(format!("{name}.rs")),
+        );
         println!(
             "cargo:warning=collection '{name}' — {} colors",
             colors.len()
@@ -265,7 +275,78 @@ fn parse_color_toml(content: &str) -> Result<Vec<ColorEntry>, String> {
     Ok(entries)
 }
 
-fn emit_collection_module(name: &str, colors: &[ColorEntry], dest: &Path) {
+/// Alias of each color of collection `name`, from its alias table in
+/// `data/` (see `KNOWN_ALIASES`). Returns one entry per color, empty when
+/// the collection has no table.
+///
+/// The table has two sections: `[chars]` maps single characters to a
+/// reading, and a name's alias is its characters' readings joined by
+/// spaces; `[names]` overrides whole names. A name with an unmapped
+/// character gets no alias.
+fn read_aliases(manifest_dir: &str, name: &str, colors: &[ColorEntry]) -> Vec<Option<String>> {
+    let Some(&(_, file)) = KNOWN_ALIASES.iter().find(|(n, _)| *n == name) else {
+        return vec![None; colors.len()];
+    };
+    let path = Path::new(manifest_dir).join("data").join(file);
+    let Ok(content) = fs::read_to_string(&path) else {
+        return vec![None; colors.len()];
+    };
+
+    let mut chars: BTreeMap<char, String> = BTreeMap::new();
+    let mut names: BTreeMap<String, String> = BTreeMap::new();
+    let mut section = "";
+    for raw in content.lines() {
+        let line = raw.trim();
+        if line.starts_with('#') || line.is_empty() {
+            continue;
+        }
+        if line.starts_with('[') {
+            section = match line {
+                "[chars]" => "chars",
+                "[names]" => "names",
+                _ => "",
+            };
+            continue;
+        }
+        let Some((key, val)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim().trim_matches('"');
+        let val = val.trim().trim_matches('"').to_string();
+        match section {
+            "chars" => {
+                if let Some(ch) = key.chars().next() {
+                    chars.insert(ch, val);
+                }
+            }
+            "names" => {
+                names.insert(key.to_string(), val);
+            }
+            _ => {}
+        }
+    }
+
+    colors
+        .iter()
+        .map(|c| {
+            if let Some(alias) = names.get(&c.name) {
+                return Some(alias.clone());
+            }
+            c.name
+                .chars()
+                .map(|ch| chars.get(&ch).cloned())
+                .collect::<Option<Vec<_>>>()
+                .map(|readings| readings.join(" "))
+        })
+        .collect()
+}
+
+fn emit_collection_module(
+    name: &str,
+    colors: &[ColorEntry],
+    aliases: &[Option<String>],
+    dest: &Path,
+) {
     let mut f = fs::File::create(dest).expect("Failed to create collection module");
     let _ = writeln!(
         f,
@@ -295,6 +376,21 @@ fn emit_collection_module(name: &str, colors: &[ColorEntry], dest: &Path) {
         }
         let _ = writeln!(f);
     }
+
+    // Runtime index for `crate::catalog`, in data-file order.
+    let _ = writeln!(
+        f,
+        "/// Every color of this collection with its search aliases\n\
+         pub const CATALOG: &[(Color, &[&str])] = &["
+    );
+    for (c, alias) in colors.iter().zip(aliases) {
+        let alias = alias
+            .as_deref()
+            .map(|a| format!("\"{a}\""))
+            .unwrap_or_default();
+        let _ = writeln!(f, "    ({}, &[{alias}]),", c.name);
+    }
+    let _ = writeln!(f, "];");
 }
 
 /// Emit `OUT_DIR/color_macro.rs` — a `macro_rules! color` that resolves a
@@ -0,0 +1,179 @@
+//! Named-color catalog
+//!
+//! A runtime index over every color of the enabled [`collections`]:
+//! - [`named_colors`] — all colors, collection by collection
+//! - [`find_color`] — exact lookup by name or alias (pinyin for the
+//!   chinese collection), ignoring case, spaces, `-` and `_`
+//! - [`search_colors`] — fuzzy search over names and aliases
+//! - [`colors_in_category`] — filter by [`ColorCategory`]
+//! - [`nearest_colors`] — the named colors closest to any color by CIEDE2000
+//!
+//! With no collection enabled the catalog is empty.
+//!
+//! ```rust,ignore
+//! use hikari_palette::{Color, find_color, nearest_colors_in};
+//!
+//! assert_eq!(find_color("fen hong").unwrap().name(), "粉红");
+//!
+//! let brand = Color::from_rgb(0xee, 0xa0, 0xa0);
+//! let (nearest, distance) = nearest_colors_in("chinese", brand, 1)[0];
+//! println!("{} is {distance:.1} ΔE from {}", brand.hex(), nearest.name());
+//! ```
+//!
+//! [`collections`]: crate::collections
+
+use crate::{Color, ColorCategory};
+
+/// A color of an enabled collection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NamedColor {
+    pub collection: &'static str,
+    pub color: Color,
+    /// Other spellings of the name, e.g. pinyin
+    pub aliases: &'static [&'static str],
+}
+
+impl NamedColor {
+    pub fn name(&self) -> &'static str {
+        self.color.name().unwrap_or_default()
+    }
+
+    /// The name followed by the aliases
+    pub fn keys(&self) -> impl Iterator<Item = &'static str> + '_ {
+        std::iter::once(self.name()).chain(self.aliases.iter().copied())
+    }
+}
+
+/// Colors of one generated collection module, with their aliases
+type CatalogEntries = &'static [(Color, &'static [&'static str])];
+
+/// Every enabled collection, by name
+const COLLECTIONS: &[(&str, CatalogEntries)] = &[
+    #[cfg(hikari_collection_chinese)]
+    ("chinese", crate::collections::chinese::CATALOG),
+    #[cfg(hikari_collection_tailwind)]
+    ("tailwind", crate::collections::tailwind::CATALOG),
+];
+
+/// Names of the enabled collections
+pub fn collection_names() -> Vec<&'static str> {
+    COLLECTIONS.iter().map(|(name, _)| *name).collect()
+}
+
+/// Every color of the enabled collections
+pub fn named_colors() -> impl Iterator<Item = NamedColor> {
+    COLLECTIONS.iter().flat_map(|&(collection, entries)| {
+        entries.iter().map(move |(color, aliases)| NamedColor {
+            collection,
+            color: *color,
+            aliases,
+        })
+    })
+}
+
+/// Colors of one collection; empty when it is not enabled
+pub fn collection_colors(collection: &str) -> impl Iterator<Item = NamedColor> + '_ {
+    named_colors().filter(move |named| named.collection == collection)
+}
+
+/// Lowercase, without spaces, `-` and `_`, so `Red-500`, `red_500` and
+/// `red 500` compare equal
+fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|ch| !ch.is_whitespace() && *ch != '-' && *ch != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Color whose name or alias is `name`; the first enabled collection wins
+/// when several have it
+pub fn find_color(name: &str) -> Option<NamedColor> {
+    let query = normalize(name);
+    if query.is_empty() {
+        return None;
+    }
+    named_colors().find(|named| named.keys().any(|key| normalize(key) == query))
+}
+
+/// How well `query` matches `key`, both normalized; higher is better
+///
+/// Exact matches rank first, then prefixes, substrings and finally
+/// subsequences (the query's characters in order, with gaps). Within a kind,
+/// shorter keys and earlier or tighter matches rank higher.
+fn match_score(query: &[char], key: &[char]) -> Option<usize> {
+    const KIND: usize = 1000;
+    let slack = |extra: usize| KIND - 1 - extra.min(KIND - 1);
+    if key == query {
+        return Some(4 * KIND);
+    }
+    if key.starts_with(query) {
+        return Some(3 * KIND + slack(key.len() - query.len()));
+    }
+    if let Some(position) = key.windows(query.len()).position(|window| window == query) {
+        return Some(2 * KIND + slack(position + key.len() - query.len()));
+    }
+
+    let mut rest = key.iter().enumerate();
+    let mut first = None;
+    let mut last = 0;
+    for ch in query {
+        let (index, _) = rest.find(|(_, k)| *k == ch)?;
+        first.get_or_insert(index);
+        last = index;
+    }
+    let span = last + 1 - first.unwrap_or(0);
+    Some(KIND + slack(span - query.len() + key.len() - query.len()))
+}
+
+/// Colors whose name or an alias fuzzily matches `query`, best first
+pub fn search_colors(query: &str) -> Vec<NamedColor> {
+    let query: Vec<char> = normalize(query).chars().collect();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let mut matches: Vec<(usize, NamedColor)> = named_colors()
+        .filter_map(|named| {
+            named
+                .keys()
+                .filter_map(|key| {
+                    let key: Vec<char> = normalize(key).chars().collect();
+                    match_score(&query, &key)
+                })
+                .max()
+                .map(|score| (score, named))
+        })
+        .collect();
+    // Stable sort keeps collection order among equal scores
+    matches.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    matches.into_iter().map(|(_, named)| named).collect()
+}
+
+/// Colors of the enabled collections in `category`
+pub fn colors_in_category(category: ColorCategory) -> impl Iterator<Item = NamedColor> {
+    named_colors().filter(move |named| named.color.category == category)
+}
+
+/// The `k` named colors closest to `color`, nearest first, with their
+/// CIEDE2000 distance
+pub fn nearest_colors(color: Color, k: usize) -> Vec<(NamedColor, f64)> {
+    nearest_among(named_colors(), color, k)
+}
+
+/// [`nearest_colors`] within one collection
+pub fn nearest_colors_in(collection: &str, color: Color, k: usize) -> Vec<(NamedColor, f64)> {
+    nearest_among(collection_colors(collection), color, k)
+}
+
+fn nearest_among(
+    candidates: impl Iterator<Item = NamedColor>,
+    color: Color,
+    k: usize,
+) -> Vec<(NamedColor, f64)> {
+    let lab = color.to_lab();
+    let mut ranked: Vec<(NamedColor, f64)> = candidates
+        .map(|named| (named, lab.delta_e_2000(&named.color.to_lab())))
+        .collect();
+    ranked.sort_by(|a, b| a.1.total_cmp(&b.1));
+    ranked.truncate(k);
+    ranked
+}
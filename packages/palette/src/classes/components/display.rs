@@ -192,6 +192,25 @@ impl UtilityClass for TagClass {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ShortcutHintClass {
+    Hint,
+    Chord,
+    Key,
+    Then,
+}
+
+impl UtilityClass for ShortcutHintClass {
+    fn as_suffix(&self) -> &'static str {
+        match self {
+            ShortcutHintClass::Hint => "shortcut-hint",
+            ShortcutHintClass::Chord => "shortcut-hint-chord",
+            ShortcutHintClass::Key => "shortcut-hint-key",
+            ShortcutHintClass::Then => "shortcut-hint-then",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub enum DescriptionListClass {
@@ -65,6 +65,8 @@ pub enum TooltipClass {
     TooltipArrowBottom,
     TooltipArrowLeft,
     TooltipArrowRight,
+    TooltipInteractive,
+    TooltipRich,
 }
 
 impl UtilityClass for TooltipClass {
@@ -84,6 +86,8 @@ impl UtilityClass for TooltipClass {
             TooltipClass::TooltipArrowBottom => "tooltip-arrow-bottom",
             TooltipClass::TooltipArrowLeft => "tooltip-arrow-left",
             TooltipClass::TooltipArrowRight => "tooltip-arrow-right",
+            TooltipClass::TooltipInteractive => "tooltip-interactive",
+            TooltipClass::TooltipRich => "tooltip-rich",
         }
     }
 }
@@ -251,6 +251,82 @@ impl UtilityClass for AutoCompleteClass {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TagInputClass {
+    Wrapper,
+    Focused,
+    Disabled,
+    Token,
+    TokenInvalid,
+    TokenDragging,
+    TokenDragOver,
+    TokenEditor,
+    Input,
+    Error,
+    Count,
+}
+
+impl UtilityClass for TagInputClass {
+    fn as_suffix(&self) -> &'static str {
+        match self {
+            TagInputClass::Wrapper => "tag-input",
+            TagInputClass::Focused => "tag-input-focused",
+            TagInputClass::Disabled => "tag-input-disabled",
+            TagInputClass::Token => "tag-input-token",
+            TagInputClass::TokenInvalid => "tag-input-token-invalid",
+            TagInputClass::TokenDragging => "tag-input-token-dragging",
+            TagInputClass::TokenDragOver => "tag-input-token-drag-over",
+            TagInputClass::TokenEditor => "tag-input-token-editor",
+            TagInputClass::Input => "tag-input-field",
+            TagInputClass::Error => "tag-input-error",
+            TagInputClass::Count => "tag-input-count",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CommandPaletteClass {
+    Panel,
+    Header,
+    Back,
+    Breadcrumb,
+    Input,
+    List,
+    Group,
+    GroupLabel,
+    Item,
+    ItemActive,
+    ItemIcon,
+    ItemTitle,
+    ItemShortcut,
+    ItemChevron,
+    Empty,
+    Loading,
+}
+
+impl UtilityClass for CommandPaletteClass {
+    fn as_suffix(&self) -> &'static str {
+        match self {
+            CommandPaletteClass::Panel => "command-palette",
+            CommandPaletteClass::Header => "command-palette-header",
+            CommandPaletteClass::Back => "command-palette-back",
+            CommandPaletteClass::Breadcrumb => "command-palette-breadcrumb",
+            CommandPaletteClass::Input => "command-palette-input",
+            CommandPaletteClass::List => "command-palette-list",
+            CommandPaletteClass::Group => "command-palette-group",
+            CommandPaletteClass::GroupLabel => "command-palette-group-label",
+            CommandPaletteClass::Item => "command-palette-item",
+            CommandPaletteClass::ItemActive => "command-palette-item-active",
+            CommandPaletteClass::ItemIcon => "command-palette-item-icon",
+            CommandPaletteClass::ItemTitle => "command-palette-item-title",
+            CommandPaletteClass::ItemShortcut => "command-palette-item-shortcut",
+            CommandPaletteClass::ItemChevron => "command-palette-item-chevron",
+            CommandPaletteClass::Empty => "command-palette-empty",
+            CommandPaletteClass::Loading => "command-palette-loading",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CascaderClass {
     Wrapper,
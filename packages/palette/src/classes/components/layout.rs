@@ -221,3 +221,110 @@ impl UtilityClass for DividerClass {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SplitPaneClass {
+    SplitPane,
+    Horizontal,
+    Vertical,
+    Pane,
+    First,
+    Second,
+    Handle,
+    Dragging,
+    Collapsed,
+}
+
+impl UtilityClass for SplitPaneClass {
+    fn as_suffix(&self) -> &'static str {
+        match self {
+            SplitPaneClass::SplitPane => "split-pane",
+            SplitPaneClass::Horizontal => "split-pane-horizontal",
+            SplitPaneClass::Vertical => "split-pane-vertical",
+            SplitPaneClass::Pane => "split-pane-pane",
+            SplitPaneClass::First => "split-pane-first",
+            SplitPaneClass::Second => "split-pane-second",
+            SplitPaneClass::Handle => "split-pane-handle",
+            SplitPaneClass::Dragging => "split-pane-dragging",
+            SplitPaneClass::Collapsed => "split-pane-collapsed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DockClass {
+    Dock,
+    Main,
+    Center,
+    Side,
+    SideLeft,
+    SideRight,
+    SideBottom,
+    SideCollapsed,
+    SideStrip,
+    ResizeHandle,
+    Group,
+    GroupActions,
+    Action,
+    Dragging,
+    DropOverlay,
+    DropZone,
+    Window,
+    WindowTitle,
+    WindowBody,
+    WindowResize,
+    Maximized,
+    MaximizedHeader,
+    Empty,
+}
+
+impl UtilityClass for DockClass {
+    fn as_suffix(&self) -> &'static str {
+        match self {
+            DockClass::Dock => "dock",
+            DockClass::Main => "dock-main",
+            DockClass::Center => "dock-center",
+            DockClass::Side => "dock-side",
+            DockClass::SideLeft => "dock-side-left",
+            DockClass::SideRight => "dock-side-right",
+            DockClass::SideBottom => "dock-side-bottom",
+            DockClass::SideCollapsed => "dock-side-collapsed",
+            DockClass::SideStrip => "dock-side-strip",
+            DockClass::ResizeHandle => "dock-resize-handle",
+            DockClass::Group => "dock-group",
+            DockClass::GroupActions => "dock-group-actions",
+            DockClass::Action => "dock-action",
+            DockClass::Dragging => "dock-dragging",
+            DockClass::DropOverlay => "dock-drop-overlay",
+            DockClass::DropZone => "dock-drop-zone",
+            DockClass::Window => "dock-window",
+            DockClass::WindowTitle => "dock-window-title",
+            DockClass::WindowBody => "dock-window-body",
+            DockClass::WindowResize => "dock-window-resize",
+            DockClass::Maximized => "dock-maximized",
+            DockClass::MaximizedHeader => "dock-maximized-header",
+            DockClass::Empty => "dock-empty",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MasonryClass {
+    Masonry,
+    Column,
+    Item,
+    VirtualGrid,
+    Content,
+}
+
+impl UtilityClass for MasonryClass {
+    fn as_suffix(&self) -> &'static str {
+        match self {
+            MasonryClass::Masonry => "masonry",
+            MasonryClass::Column => "masonry-column",
+            MasonryClass::Item => "masonry-item",
+            MasonryClass::VirtualGrid => "virtual-grid",
+            MasonryClass::Content => "virtual-grid-content",
+        }
+    }
+}
@@ -347,3 +347,42 @@ impl UtilityClass for StepsClass {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WizardClass {
+    Wizard,
+    Header,
+    Body,
+    Panel,
+    Error,
+    Footer,
+    FooterSpacer,
+    Summary,
+    SummarySection,
+    SummaryHeader,
+    SummaryTitle,
+    SummaryRow,
+    SummaryLabel,
+    SummaryValue,
+}
+
+impl UtilityClass for WizardClass {
+    fn as_suffix(&self) -> &'static str {
+        match self {
+            WizardClass::Wizard => "wizard",
+            WizardClass::Header => "wizard-header",
+            WizardClass::Body => "wizard-body",
+            WizardClass::Panel => "wizard-panel",
+            WizardClass::Error => "wizard-error",
+            WizardClass::Footer => "wizard-footer",
+            WizardClass::FooterSpacer => "wizard-footer-spacer",
+            WizardClass::Summary => "wizard-summary",
+            WizardClass::SummarySection => "wizard-summary-section",
+            WizardClass::SummaryHeader => "wizard-summary-header",
+            WizardClass::SummaryTitle => "wizard-summary-title",
+            WizardClass::SummaryRow => "wizard-summary-row",
+            WizardClass::SummaryLabel => "wizard-summary-label",
+            WizardClass::SummaryValue => "wizard-summary-value",
+        }
+    }
+}
@@ -2,11 +2,27 @@
 //!
 //! Provides advanced color calculations and transformations including:
 //! - HSL/HSV color space conversions
-//! - Saturation/lightness adjustments
-//! - Color interpolation and blending
+//! - Perceptual color spaces: OKLab/OKLCH and CIELAB (D65)
+//! - Color differences: ΔE2000 and ΔEOK
+//! - Gamut mapping of out-of-range colors back into sRGB
+//! - Saturation/lightness adjustments, and perceptual `lighten`/`darken`/`mix`
+//! - Color interpolation and blending in a chosen [`ColorSpace`]
 //! - Gradient color stops
+//! - Color-vision-deficiency simulation of colors and palettes
+//!
+//! ```
+//! use hikari_palette::{Color, ColorSpace, blend_colors_in};
+//!
+//! let blue = Color::from_rgb(0, 0, 255);
+//! let lighter = blue.lighten(0.1);
+//! assert!(lighter.to_oklch().l > blue.to_oklch().l);
+//!
+//! // Mixing in OKLCH keeps the midpoint saturated instead of going grey
+//! let mid = blend_colors_in(blue, Color::from_rgb(255, 255, 0), 0.5, ColorSpace::Oklch);
+//! assert!(mid.to_oklch().c > 0.1);
+//! ```
 
-use crate::Color;
+use crate::{Color, Palette};
 
 /// HSL color representation
 ///
@@ -126,11 +142,22 @@ impl GradientStop {
 #[derive(Debug, Clone, PartialEq)]
 pub struct Gradient {
     pub stops: Vec<GradientStop>,
+    /// Space stops are interpolated in (sRGB by default)
+    pub space: ColorSpace,
 }
 
 impl Gradient {
     pub fn new(stops: Vec<GradientStop>) -> Self {
-        Self { stops }
+        Self {
+            stops,
+            space: ColorSpace::default(),
+        }
+    }
+
+    /// Interpolates between stops in `space`
+    pub fn with_space(mut self, space: ColorSpace) -> Self {
+        self.space = space;
+        self
     }
 
     pub fn from_colors(colors: Vec<Color>) -> Self {
@@ -216,10 +243,7 @@ impl Gradient {
     }
 
     fn interpolate(&self, color1: Color, color2: Color, t: f64) -> Color {
-        let r = (color1.rgb.0 as f64 * (1.0 - t) + color2.rgb.0 as f64 * t).round() as u8;
-        let g = (color1.rgb.1 as f64 * (1.0 - t) + color2.rgb.1 as f64 * t).round() as u8;
-        let b = (color1.rgb.2 as f64 * (1.0 - t) + color2.rgb.2 as f64 * t).round() as u8;
-        Color::from_rgb(r, g, b)
+        blend_colors_in(color1, color2, t, self.space)
     }
 }
 
@@ -243,9 +267,546 @@ pub fn average_colors(colors: &[(Color, f64)]) -> Color {
 
 /// Blend two colors with given ratio (0.0 = color1, 1.0 = color2)
 pub fn blend_colors(color1: Color, color2: Color, ratio: f64) -> Color {
+    blend_colors_in(color1, color2, ratio, ColorSpace::Srgb)
+}
+
+/// Blend two colors in `space` (0.0 = color1, 1.0 = color2)
+///
+/// Results outside sRGB (possible in the perceptual spaces) are gamut mapped.
+pub fn blend_colors_in(color1: Color, color2: Color, ratio: f64, space: ColorSpace) -> Color {
     let t = ratio.clamp(0.0, 1.0);
-    let r = (color1.rgb.0 as f64 * (1.0 - t) + color2.rgb.0 as f64 * t).round() as u8;
-    let g = (color1.rgb.1 as f64 * (1.0 - t) + color2.rgb.1 as f64 * t).round() as u8;
-    let b = (color1.rgb.2 as f64 * (1.0 - t) + color2.rgb.2 as f64 * t).round() as u8;
-    Color::from_rgb(r, g, b)
+    let lerp = |a: f64, b: f64| a * (1.0 - t) + b * t;
+    match space {
+        ColorSpace::Srgb => Color::from_rgb(
+            lerp(color1.rgb.0 as f64, color2.rgb.0 as f64).round() as u8,
+            lerp(color1.rgb.1 as f64, color2.rgb.1 as f64).round() as u8,
+            lerp(color1.rgb.2 as f64, color2.rgb.2 as f64).round() as u8,
+        ),
+        ColorSpace::LinearSrgb => {
+            let a = color1.to_linear_rgb();
+            let b = color2.to_linear_rgb();
+            Color::from_linear_rgb([lerp(a[0], b[0]), lerp(a[1], b[1]), lerp(a[2], b[2])])
+        }
+        ColorSpace::Oklab => {
+            let a = color1.to_oklab();
+            let b = color2.to_oklab();
+            Oklab::new(lerp(a.l, b.l), lerp(a.a, b.a), lerp(a.b, b.b)).to_color()
+        }
+        ColorSpace::Oklch => {
+            let a = color1.to_oklch();
+            let b = color2.to_oklch();
+            let (h1, h2) = match (a.is_achromatic(), b.is_achromatic()) {
+                (true, false) => (b.h, b.h),
+                (false, true) => (a.h, a.h),
+                _ => (a.h, b.h),
+            };
+            // Shorter arc around the hue circle
+            let mut delta = h2 - h1;
+            if delta > 180.0 {
+                delta -= 360.0;
+            } else if delta < -180.0 {
+                delta += 360.0;
+            }
+            Oklch::new(lerp(a.l, b.l), lerp(a.c, b.c), h1 + delta * t).to_color()
+        }
+        ColorSpace::Lab => {
+            let a = color1.to_lab();
+            let b = color2.to_lab();
+            Lab::new(lerp(a.l, b.l), lerp(a.a, b.a), lerp(a.b, b.b)).to_color()
+        }
+    }
+}
+
+/// Color space used for interpolation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorSpace {
+    /// Gamma-encoded sRGB channels, as CSS does by default
+    #[default]
+    Srgb,
+    /// Linear-light sRGB; physically correct light mixing
+    LinearSrgb,
+    /// OKLab; perceptually even, keeps midpoints from turning muddy
+    Oklab,
+    /// OKLCH; like OKLab, but travels around the hue circle
+    Oklch,
+    /// CIELAB (D65)
+    Lab,
+}
+
+/// Decodes a gamma-encoded sRGB channel in `[0.0, 1.0]` to linear light
+pub fn srgb_to_linear(channel: f64) -> f64 {
+    let abs = channel.abs();
+    let linear = if abs <= 0.04045 {
+        abs / 12.92
+    } else {
+        ((abs + 0.055) / 1.055).powf(2.4)
+    };
+    linear.copysign(channel)
+}
+
+/// Encodes a linear-light channel to gamma-encoded sRGB
+pub fn linear_to_srgb(channel: f64) -> f64 {
+    let abs = channel.abs();
+    let encoded = if abs <= 0.003_130_8 {
+        abs * 12.92
+    } else {
+        1.055 * abs.powf(1.0 / 2.4) - 0.055
+    };
+    encoded.copysign(channel)
+}
+
+/// Tolerance for sRGB channels to count as in gamut
+const GAMUT_EPSILON: f64 = 1e-6;
+
+fn srgb_in_gamut(rgb: [f64; 3]) -> bool {
+    rgb.iter()
+        .all(|channel| (-GAMUT_EPSILON..=1.0 + GAMUT_EPSILON).contains(channel))
+}
+
+/// OKLab color: perceptual lightness `l` (0–1) and the opponent axes `a`, `b`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Oklab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+impl Oklab {
+    pub fn new(l: f64, a: f64, b: f64) -> Self {
+        Self { l, a, b }
+    }
+
+    fn from_linear_rgb([r, g, b]: [f64; 3]) -> Self {
+        let l = (0.412_221_470_8 * r + 0.536_332_536_3 * g + 0.051_445_992_9 * b).cbrt();
+        let m = (0.211_903_498_2 * r + 0.680_699_545_1 * g + 0.107_396_956_6 * b).cbrt();
+        let s = (0.088_302_461_9 * r + 0.281_718_837_6 * g + 0.629_978_700_5 * b).cbrt();
+        Self {
+            l: 0.210_454_255_3 * l + 0.793_617_785_0 * m - 0.004_072_046_8 * s,
+            a: 1.977_998_495_1 * l - 2.428_592_205_0 * m + 0.450_593_709_9 * s,
+            b: 0.025_904_037_1 * l + 0.782_771_766_2 * m - 0.808_675_766_0 * s,
+        }
+    }
+
+    /// Linear-light sRGB channels, not clamped to the gamut
+    pub fn to_linear_rgb(&self) -> [f64; 3] {
+        let l = (self.l + 0.396_337_777_4 * self.a + 0.215_803_757_3 * self.b).powi(3);
+        let m = (self.l - 0.105_561_345_8 * self.a - 0.063_854_172_8 * self.b).powi(3);
+        let s = (self.l - 0.089_484_177_5 * self.a - 1.291_485_548_0 * self.b).powi(3);
+        [
+            4.076_741_662_1 * l - 3.307_711_591_3 * m + 0.230_969_929_2 * s,
+            -1.268_438_004_6 * l + 2.609_757_401_1 * m - 0.341_319_396_5 * s,
+            -0.004_196_086_3 * l - 0.703_418_614_7 * m + 1.707_614_701_0 * s,
+        ]
+    }
+
+    /// Gamma-encoded sRGB channels, not clamped to the gamut
+    pub fn to_srgb(&self) -> [f64; 3] {
+        self.to_linear_rgb().map(linear_to_srgb)
+    }
+
+    pub fn in_gamut(&self) -> bool {
+        srgb_in_gamut(self.to_srgb())
+    }
+
+    pub fn to_oklch(&self) -> Oklch {
+        let c = self.a.hypot(self.b);
+        let h = self.b.atan2(self.a).to_degrees().rem_euclid(360.0);
+        Oklch::new(self.l, c, h)
+    }
+
+    /// Nearest sRGB color, gamut mapped
+    pub fn to_color(&self) -> Color {
+        self.to_oklch().to_color()
+    }
+
+    /// ΔEOK: Euclidean distance in OKLab
+    ///
+    /// About 0.02 is the smallest difference most people notice.
+    pub fn delta_e(&self, other: &Oklab) -> f64 {
+        let dl = self.l - other.l;
+        let da = self.a - other.a;
+        let db = self.b - other.b;
+        (dl * dl + da * da + db * db).sqrt()
+    }
+}
+
+/// OKLCH color: OKLab in polar form, with chroma `c` and hue `h` in degrees
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Oklch {
+    pub l: f64,
+    pub c: f64,
+    pub h: f64,
+}
+
+impl Oklch {
+    /// Chroma below which the hue is meaningless
+    pub const ACHROMATIC_CHROMA: f64 = 1e-4;
+
+    pub fn new(l: f64, c: f64, h: f64) -> Self {
+        Self { l, c, h }
+    }
+
+    pub fn is_achromatic(&self) -> bool {
+        self.c < Self::ACHROMATIC_CHROMA
+    }
+
+    pub fn to_oklab(&self) -> Oklab {
+        let h = self.h.to_radians();
+        Oklab::new(self.l, self.c * h.cos(), self.c * h.sin())
+    }
+
+    pub fn in_gamut(&self) -> bool {
+        self.to_oklab().in_gamut()
+    }
+
+    /// Nearest sRGB color, gamut mapped
+    ///
+    /// Follows the CSS Color 4 gamut mapping: chroma is reduced at constant
+    /// lightness and hue until clipping the color changes it by less than a
+    /// just noticeable difference (ΔEOK 0.02).
+    pub fn to_color(&self) -> Color {
+        const JND: f64 = 0.02;
+        const EPSILON: f64 = 1e-4;
+
+        let clip = |color: &Oklch| {
+            let [r, g, b] = color.to_oklab().to_srgb();
+            Color::from_rgb_float(r, g, b)
+        };
+        let clip_lab = |color: &Oklch| {
+            let srgb = color.to_oklab().to_srgb();
+            Oklab::from_linear_rgb(srgb.map(|channel| srgb_to_linear(channel.clamp(0.0, 1.0))))
+        };
+
+        if self.l >= 1.0 {
+            return Color::from_rgb(255, 255, 255);
+        }
+        if self.l <= 0.0 {
+            return Color::from_rgb(0, 0, 0);
+        }
+        if self.in_gamut() {
+            return clip(self);
+        }
+
+        let mut current = Oklch::new(self.l, self.c.max(0.0), self.h);
+        if clip_lab(&current).delta_e(&current.to_oklab()) < JND {
+            return clip(&current);
+        }
+
+        let mut min = 0.0;
+        let mut max = current.c;
+        let mut min_in_gamut = true;
+        while max - min > EPSILON {
+            current.c = (min + max) / 2.0;
+            if min_in_gamut && current.in_gamut() {
+                min = current.c;
+                continue;
+            }
+            let error = clip_lab(&current).delta_e(&current.to_oklab());
+            if error < JND {
+                if JND - error < EPSILON {
+                    break;
+                }
+                min_in_gamut = false;
+                min = current.c;
+            } else {
+                max = current.c;
+            }
+        }
+        clip(&current)
+    }
+}
+
+/// CIE white point D65 (2°), as XYZ with Y = 1
+const D65: [f64; 3] = [0.950_47, 1.0, 1.088_83];
+
+/// CIELAB color under D65: lightness `l` (0–100) and the opponent axes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+impl Lab {
+    pub fn new(l: f64, a: f64, b: f64) -> Self {
+        Self { l, a, b }
+    }
+
+    fn from_linear_rgb([r, g, b]: [f64; 3]) -> Self {
+        const DELTA: f64 = 6.0 / 29.0;
+        let f = |t: f64| {
+            if t > DELTA.powi(3) {
+                t.cbrt()
+            } else {
+                t / (3.0 * DELTA * DELTA) + 4.0 / 29.0
+            }
+        };
+        let x = (0.412_456_4 * r + 0.357_576_1 * g + 0.180_437_5 * b) / D65[0];
+        let y = (0.212_672_9 * r + 0.715_152_2 * g + 0.072_175_0 * b) / D65[1];
+        let z = (0.019_333_9 * r + 0.119_192_0 * g + 0.950_304_1 * b) / D65[2];
+        let (fx, fy, fz) = (f(x), f(y), f(z));
+        Self {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+
+    /// Linear-light sRGB channels, not clamped to the gamut
+    pub fn to_linear_rgb(&self) -> [f64; 3] {
+        const DELTA: f64 = 6.0 / 29.0;
+        let f_inv = |t: f64| {
+            if t > DELTA {
+                t.powi(3)
+            } else {
+                3.0 * DELTA * DELTA * (t - 4.0 / 29.0)
+            }
+        };
+        let fy = (self.l + 16.0) / 116.0;
+        let x = f_inv(fy + self.a / 500.0) * D65[0];
+        let y = f_inv(fy) * D65[1];
+        let z = f_inv(fy - self.b / 200.0) * D65[2];
+        [
+            3.240_454_2 * x - 1.537_138_5 * y - 0.498_531_4 * z,
+            -0.969_266_0 * x + 1.876_010_8 * y + 0.041_556_0 * z,
+            0.055_643_4 * x - 0.204_025_9 * y + 1.057_225_2 * z,
+        ]
+    }
+
+    pub fn in_gamut(&self) -> bool {
+        srgb_in_gamut(self.to_linear_rgb().map(linear_to_srgb))
+    }
+
+    /// Nearest sRGB color, gamut mapped in OKLCH
+    pub fn to_color(&self) -> Color {
+        Oklab::from_linear_rgb(self.to_linear_rgb()).to_color()
+    }
+
+    /// CIEDE2000 color difference
+    ///
+    /// About 1.0 is the smallest difference most people notice; 2–3 is
+    /// noticeable side by side.
+    pub fn delta_e_2000(&self, other: &Lab) -> f64 {
+        let pow7 = |x: f64| x.powi(7);
+        let twenty_five_7 = pow7(25.0);
+
+        let c1 = self.a.hypot(self.b);
+        let c2 = other.a.hypot(other.b);
+        let c_bar = (c1 + c2) / 2.0;
+        let g = 0.5 * (1.0 - (pow7(c_bar) / (pow7(c_bar) + twenty_five_7)).sqrt());
+        let a1 = (1.0 + g) * self.a;
+        let a2 = (1.0 + g) * other.a;
+        let c1 = a1.hypot(self.b);
+        let c2 = a2.hypot(other.b);
+        let hue = |b: f64, a: f64| {
+            if a == 0.0 && b == 0.0 {
+                0.0
+            } else {
+                b.atan2(a).to_degrees().rem_euclid(360.0)
+            }
+        };
+        let h1 = hue(self.b, a1);
+        let h2 = hue(other.b, a2);
+
+        let delta_l = other.l - self.l;
+        let delta_c = c2 - c1;
+        let delta_h = if c1 * c2 == 0.0 {
+            0.0
+        } else {
+            let d = h2 - h1;
+            if d > 180.0 {
+                d - 360.0
+            } else if d < -180.0 {
+                d + 360.0
+            } else {
+                d
+            }
+        };
+        let delta_h = 2.0 * (c1 * c2).sqrt() * (delta_h.to_radians() / 2.0).sin();
+
+        let l_bar = (self.l + other.l) / 2.0;
+        let c_bar = (c1 + c2) / 2.0;
+        let h_bar = if c1 * c2 == 0.0 {
+            h1 + h2
+        } else if (h1 - h2).abs() <= 180.0 {
+            (h1 + h2) / 2.0
+        } else if h1 + h2 < 360.0 {
+            (h1 + h2 + 360.0) / 2.0
+        } else {
+            (h1 + h2 - 360.0) / 2.0
+        };
+
+        let t = 1.0 - 0.17 * (h_bar - 30.0).to_radians().cos()
+            + 0.24 * (2.0 * h_bar).to_radians().cos()
+            + 0.32 * (3.0 * h_bar + 6.0).to_radians().cos()
+            - 0.20 * (4.0 * h_bar - 63.0).to_radians().cos();
+        let delta_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+        let r_c = 2.0 * (pow7(c_bar) / (pow7(c_bar) + twenty_five_7)).sqrt();
+        let l_offset = (l_bar - 50.0).powi(2);
+        let s_l = 1.0 + 0.015 * l_offset / (20.0 + l_offset).sqrt();
+        let s_c = 1.0 + 0.045 * c_bar;
+        let s_h = 1.0 + 0.015 * c_bar * t;
+        let r_t = -(2.0 * delta_theta).to_radians().sin() * r_c;
+
+        let l = delta_l / s_l;
+        let c = delta_c / s_c;
+        let h = delta_h / s_h;
+        (l * l + c * c + h * h + r_t * c * h).sqrt()
+    }
+}
+
+impl Color {
+    /// Linear-light sRGB channels in `[0.0, 1.0]`
+    pub fn to_linear_rgb(&self) -> [f64; 3] {
+        [self.rgb.0, self.rgb.1, self.rgb.2].map(|channel| srgb_to_linear(channel as f64 / 255.0))
+    }
+
+    /// Construct from linear-light channels, clamped to `[0.0, 1.0]`
+    pub fn from_linear_rgb(rgb: [f64; 3]) -> Color {
+        let [r, g, b] = rgb.map(|channel| linear_to_srgb(channel.clamp(0.0, 1.0)));
+        Color::from_rgb_float(r, g, b)
+    }
+
+    pub fn to_oklab(&self) -> Oklab {
+        Oklab::from_linear_rgb(self.to_linear_rgb())
+    }
+
+    pub fn to_oklch(&self) -> Oklch {
+        self.to_oklab().to_oklch()
+    }
+
+    pub fn to_lab(&self) -> Lab {
+        Lab::from_linear_rgb(self.to_linear_rgb())
+    }
+
+    /// CIEDE2000 difference to `other`
+    pub fn delta_e_2000(&self, other: &Color) -> f64 {
+        self.to_lab().delta_e_2000(&other.to_lab())
+    }
+
+    /// ΔEOK difference to `other`
+    pub fn delta_e_ok(&self, other: &Color) -> f64 {
+        self.to_oklab().delta_e(&other.to_oklab())
+    }
+
+    /// Raises OKLCH lightness by `amount` (0–1), keeping chroma and hue
+    ///
+    /// Unlike [`adjust_lightness`](Self::adjust_lightness), equal amounts
+    /// look like equal steps whatever the hue.
+    pub fn lighten(&self, amount: f64) -> Color {
+        let mut lch = self.to_oklch();
+        lch.l = (lch.l + amount).clamp(0.0, 1.0);
+        lch.to_color()
+    }
+
+    /// Lowers OKLCH lightness by `amount` (0–1), keeping chroma and hue
+    pub fn darken(&self, amount: f64) -> Color {
+        self.lighten(-amount)
+    }
+
+    /// Mixes with `other` in OKLCH (0.0 = self, 1.0 = other)
+    pub fn mix(&self, other: Color, ratio: f64) -> Color {
+        blend_colors_in(*self, other, ratio, ColorSpace::Oklch)
+    }
+}
+
+/// Color vision deficiency, for simulating how colors are seen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorVisionDeficiency {
+    /// No working long-wavelength (red) cones
+    Protanopia,
+    /// No working medium-wavelength (green) cones
+    Deuteranopia,
+    /// No working short-wavelength (blue) cones
+    Tritanopia,
+    /// No color vision; only luminance is seen
+    Achromatopsia,
+}
+
+impl ColorVisionDeficiency {
+    pub const ALL: [ColorVisionDeficiency; 4] = [
+        ColorVisionDeficiency::Protanopia,
+        ColorVisionDeficiency::Deuteranopia,
+        ColorVisionDeficiency::Tritanopia,
+        ColorVisionDeficiency::Achromatopsia,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ColorVisionDeficiency::Protanopia => "protanopia",
+            ColorVisionDeficiency::Deuteranopia => "deuteranopia",
+            ColorVisionDeficiency::Tritanopia => "tritanopia",
+            ColorVisionDeficiency::Achromatopsia => "achromatopsia",
+        }
+    }
+
+    /// Row-major matrix applied to linear-light sRGB channels
+    ///
+    /// The dichromacies use the full-severity matrices of Machado, Oliveira
+    /// and Fernandes (2009); achromatopsia maps every channel to the
+    /// relative luminance.
+    pub fn matrix(&self) -> [[f64; 3]; 3] {
+        match self {
+            ColorVisionDeficiency::Protanopia => [
+                [0.152_286, 1.052_583, -0.204_868],
+                [0.114_503, 0.786_281, 0.099_216],
+                [-0.003_882, -0.048_116, 1.051_998],
+            ],
+            ColorVisionDeficiency::Deuteranopia => [
+                [0.367_322, 0.860_646, -0.227_968],
+                [0.280_085, 0.672_501, 0.047_413],
+                [-0.011_820, 0.042_940, 0.968_881],
+            ],
+            ColorVisionDeficiency::Tritanopia => [
+                [1.255_528, -0.076_749, -0.178_779],
+                [-0.078_411, 0.930_809, 0.147_602],
+                [0.004_733, 0.691_367, 0.303_900],
+            ],
+            ColorVisionDeficiency::Achromatopsia => [[0.2126, 0.7152, 0.0722]; 3],
+        }
+    }
+}
+
+impl std::str::FromStr for ColorVisionDeficiency {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ColorVisionDeficiency::ALL
+            .into_iter()
+            .find(|deficiency| deficiency.as_str() == s)
+            .ok_or_else(|| format!("Unknown color vision deficiency `{s}`"))
+    }
+}
+
+impl Color {
+    /// How this color looks to someone with `deficiency`
+    pub fn simulate(&self, deficiency: ColorVisionDeficiency) -> Color {
+        let rgb = self.to_linear_rgb();
+        Color::from_linear_rgb(
+            deficiency
+                .matrix()
+                .map(|row| row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2]),
+        )
+    }
+}
+
+impl Palette {
+    /// Every role as seen by someone with `deficiency`
+    pub fn simulate(&self, deficiency: ColorVisionDeficiency) -> Palette {
+        let see = |color: Color| color.simulate(deficiency);
+        Palette {
+            mode: self.mode,
+            primary: see(self.primary),
+            secondary: see(self.secondary),
+            accent: see(self.accent),
+            success: see(self.success),
+            warning: see(self.warning),
+            danger: see(self.danger),
+            background: see(self.background),
+            surface: see(self.surface),
+            border: see(self.border),
+            text_primary: see(self.text_primary),
+            text_secondary: see(self.text_secondary),
+        }
+    }
 }
@@ -50,6 +50,29 @@ impl Color {
         self.name
     }
 
+    /// Parse `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa` (the `#` is
+    /// optional). Alpha is ignored.
+    #[must_use]
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let digits = hex.trim().strip_prefix('#').unwrap_or(hex.trim());
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |range: std::ops::Range<usize>| u8::from_str_radix(&digits[range], 16).ok();
+        match digits.len() {
+            3 | 4 => {
+                let short = |index: usize| channel(index..index + 1).map(|v| v * 17);
+                Some(Self::from_rgb_hex(short(0)?, short(1)?, short(2)?))
+            }
+            6 | 8 => Some(Self::from_rgb_hex(
+                channel(0..2)?,
+                channel(2..4)?,
+                channel(4..6)?,
+            )),
+            _ => None,
+        }
+    }
+
     #[must_use]
     pub fn hex(&self) -> String {
         format!("#{:02X}{:02X}{:02X}", self.rgb.0, self.rgb.1, self.rgb.2)
@@ -109,3 +109,18 @@ fn test_with_name_round_trip() {
     // Without a name, defaults to None.
     assert_eq!(Color::from_rgb_hex(1, 2, 3).name(), None);
 }
+
+#[test]
+fn test_from_hex() {
+    let pink = Color::from_rgb_hex(0xff, 0xb3, 0xa7);
+    assert_eq!(Color::from_hex("#FFB3A7"), Some(pink));
+    assert_eq!(Color::from_hex("ffb3a7"), Some(pink));
+    assert_eq!(Color::from_hex("#ffb3a780"), Some(pink));
+    assert_eq!(
+        Color::from_hex("#f0c"),
+        Some(Color::from_rgb_hex(0xff, 0x00, 0xcc))
+    );
+    assert_eq!(Color::from_hex("#ffb3a"), None);
+    assert_eq!(Color::from_hex("#gggggg"), None);
+    assert_eq!(Color::from_hex(""), None);
+}
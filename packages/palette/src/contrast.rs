@@ -0,0 +1,386 @@
+//! Contrast checking
+//!
+//! Accessibility checks for text and UI colors:
+//! - WCAG 2.x relative luminance and contrast ratio, with AA/AAA thresholds
+//!   for normal and large text
+//! - APCA lightness contrast (Lc), the candidate method for WCAG 3
+//! - [`nearest_accessible`], which fixes a failing foreground by changing
+//!   its OKLCH lightness as little as possible
+//! - [`Palette::audit`], which checks every text and accent role against the
+//!   backgrounds it is shown on
+//! - [`Palette::high_contrast`], a variant of a palette for users who ask
+//!   for more contrast
+//!
+//! ```
+//! use hikari_palette::{Color, ContrastTarget, TextSize, WcagLevel, contrast_ratio, nearest_accessible};
+//!
+//! let grey = Color::from_rgb(150, 150, 150);
+//! let white = Color::from_rgb(255, 255, 255);
+//! assert!(contrast_ratio(grey, white) < 4.5);
+//!
+//! let target = ContrastTarget::wcag(WcagLevel::AA, TextSize::Normal);
+//! let fixed = nearest_accessible(grey, white, target).unwrap();
+//! assert!(contrast_ratio(fixed, white) >= 4.5);
+//! ```
+
+use std::fmt;
+
+use crate::color_math::Oklch;
+use crate::{Color, Palette, ThemeMode};
+
+/// Minimum contrast of graphical objects and UI components (WCAG 1.4.11)
+pub const NON_TEXT_CONTRAST: f64 = 3.0;
+
+/// WCAG conformance level
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WcagLevel {
+    #[default]
+    AA,
+    AAA,
+}
+
+impl WcagLevel {
+    /// Minimum contrast ratio for text of `size`
+    pub fn minimum_ratio(&self, size: TextSize) -> f64 {
+        match (self, size) {
+            (WcagLevel::AA, TextSize::Normal) => 4.5,
+            (WcagLevel::AA, TextSize::Large) => 3.0,
+            (WcagLevel::AAA, TextSize::Normal) => 7.0,
+            (WcagLevel::AAA, TextSize::Large) => 4.5,
+        }
+    }
+}
+
+/// Text size as WCAG defines it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TextSize {
+    #[default]
+    Normal,
+    /// At least 18pt (24px), or 14pt (18.66px) bold
+    Large,
+}
+
+impl TextSize {
+    /// Size category of text with the given CSS size and weight
+    pub fn from_css(font_size_px: f64, bold: bool) -> Self {
+        if font_size_px >= 24.0 || (bold && font_size_px >= 18.66) {
+            TextSize::Large
+        } else {
+            TextSize::Normal
+        }
+    }
+
+    /// APCA Lc recommended for body text of this size
+    ///
+    /// Lc 75 for normal body text and Lc 60 for large text, following the
+    /// APCA "bronze" simple mode.
+    pub fn minimum_apca(&self) -> f64 {
+        match self {
+            TextSize::Normal => 75.0,
+            TextSize::Large => 60.0,
+        }
+    }
+}
+
+/// WCAG 2.x relative luminance, 0.0 (black) to 1.0 (white)
+pub fn relative_luminance(color: Color) -> f64 {
+    let [r, g, b] = color.to_linear_rgb();
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+/// WCAG 2.x contrast ratio, from 1.0 to 21.0; the order does not matter
+pub fn contrast_ratio(a: Color, b: Color) -> f64 {
+    let la = relative_luminance(a);
+    let lb = relative_luminance(b);
+    (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+}
+
+/// Whether `foreground` text on `background` meets `level` for `size`
+pub fn passes_wcag(foreground: Color, background: Color, level: WcagLevel, size: TextSize) -> bool {
+    contrast_ratio(foreground, background) >= level.minimum_ratio(size)
+}
+
+/// APCA lightness contrast (Lc) of `text` on `background`
+///
+/// Roughly -108 to 106: positive for dark text on a light background,
+/// negative for light text on a dark one. Compare the absolute value with
+/// the thresholds. Implements APCA 0.0.98G-4g.
+pub fn apca_contrast(text: Color, background: Color) -> f64 {
+    const BLACK_THRESHOLD: f64 = 0.022;
+    const BLACK_CLAMP: f64 = 1.414;
+    const SCALE: f64 = 1.14;
+    const OFFSET: f64 = 0.027;
+    const LOW_CLIP: f64 = 0.1;
+    const DELTA_Y_MIN: f64 = 0.0005;
+
+    let screen_luminance = |color: Color| {
+        let channel = |value: u8| (value as f64 / 255.0).powf(2.4);
+        let y = 0.212_672_9 * channel(color.rgb.0)
+            + 0.715_152_2 * channel(color.rgb.1)
+            + 0.072_175_0 * channel(color.rgb.2);
+        if y < BLACK_THRESHOLD {
+            y + (BLACK_THRESHOLD - y).powf(BLACK_CLAMP)
+        } else {
+            y
+        }
+    };
+
+    let text_y = screen_luminance(text);
+    let background_y = screen_luminance(background);
+    if (background_y - text_y).abs() < DELTA_Y_MIN {
+        return 0.0;
+    }
+
+    let contrast = if background_y > text_y {
+        let sapc = (background_y.powf(0.56) - text_y.powf(0.57)) * SCALE;
+        if sapc < LOW_CLIP { 0.0 } else { sapc - OFFSET }
+    } else {
+        let sapc = (background_y.powf(0.65) - text_y.powf(0.62)) * SCALE;
+        if sapc > -LOW_CLIP { 0.0 } else { sapc + OFFSET }
+    };
+    contrast * 100.0
+}
+
+/// Contrast a foreground must reach against its background
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ContrastTarget {
+    /// Minimum WCAG 2.x contrast ratio
+    Ratio(f64),
+    /// Minimum absolute APCA Lc
+    Apca(f64),
+}
+
+impl ContrastTarget {
+    pub fn wcag(level: WcagLevel, size: TextSize) -> Self {
+        ContrastTarget::Ratio(level.minimum_ratio(size))
+    }
+
+    pub fn apca(size: TextSize) -> Self {
+        ContrastTarget::Apca(size.minimum_apca())
+    }
+
+    /// Contrast of `foreground` on `background`, in this target's measure
+    pub fn measure(&self, foreground: Color, background: Color) -> f64 {
+        match self {
+            ContrastTarget::Ratio(_) => contrast_ratio(foreground, background),
+            ContrastTarget::Apca(_) => apca_contrast(foreground, background).abs(),
+        }
+    }
+
+    pub fn minimum(&self) -> f64 {
+        match self {
+            ContrastTarget::Ratio(minimum) | ContrastTarget::Apca(minimum) => *minimum,
+        }
+    }
+
+    pub fn is_met(&self, foreground: Color, background: Color) -> bool {
+        self.measure(foreground, background) >= self.minimum()
+    }
+}
+
+/// Closest color to `foreground` that meets `target` on `background`
+///
+/// Only the OKLCH lightness changes (chroma shrinks if the gamut requires
+/// it), in whichever direction needs the smaller step. Returns `foreground`
+/// itself when it already passes, and `None` when no lightness does.
+pub fn nearest_accessible(
+    foreground: Color,
+    background: Color,
+    target: ContrastTarget,
+) -> Option<Color> {
+    if target.is_met(foreground, background) {
+        return Some(foreground);
+    }
+
+    let base = foreground.to_oklch();
+    let at = |lightness: f64| Oklch::new(lightness.clamp(0.0, 1.0), base.c, base.h).to_color();
+    let passes = |lightness: f64| target.is_met(at(lightness), background);
+
+    // Moving away from the background only increases contrast, so each
+    // direction has a single boundary to search for.
+    let search = |limit: f64| -> Option<f64> {
+        if !passes(limit) {
+            return None;
+        }
+        let (mut near, mut far) = (base.l, limit);
+        for _ in 0..32 {
+            let mid = (near + far) / 2.0;
+            if passes(mid) {
+                far = mid;
+            } else {
+                near = mid;
+            }
+        }
+        Some(far)
+    };
+
+    [search(1.0), search(0.0)]
+        .into_iter()
+        .flatten()
+        .map(at)
+        .min_by(|a, b| {
+            a.delta_e_ok(&foreground)
+                .total_cmp(&b.delta_e_ok(&foreground))
+        })
+}
+
+/// Check of one foreground role on one background role
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContrastCheck {
+    pub foreground: &'static str,
+    pub background: &'static str,
+    pub ratio: f64,
+    /// APCA Lc of the pair
+    pub apca: f64,
+    /// Minimum ratio for this pair
+    pub required: f64,
+    /// Closest passing foreground, when this check fails
+    pub suggestion: Option<Color>,
+}
+
+impl ContrastCheck {
+    pub fn passes(&self) -> bool {
+        self.ratio >= self.required
+    }
+
+    /// Whether this checks a text role rather than a brand or status color
+    pub fn is_text(&self) -> bool {
+        TEXT_ROLES.contains(&self.foreground)
+    }
+}
+
+impl fmt::Display for ContrastCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} on {}: {:.2}:1 (needs {:.1}:1, APCA Lc {:.0})",
+            self.foreground, self.background, self.ratio, self.required, self.apca
+        )?;
+        if let Some(suggestion) = self.suggestion {
+            write!(f, ", try {}", suggestion.hex())?;
+        }
+        Ok(())
+    }
+}
+
+/// Result of [`Palette::audit`]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ContrastAudit {
+    pub checks: Vec<ContrastCheck>,
+}
+
+impl ContrastAudit {
+    pub fn failures(&self) -> impl Iterator<Item = &ContrastCheck> {
+        self.checks.iter().filter(|check| !check.passes())
+    }
+
+    pub fn passes(&self) -> bool {
+        self.failures().next().is_none()
+    }
+}
+
+impl fmt::Display for ContrastAudit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let failures: Vec<_> = self.failures().collect();
+        if failures.is_empty() {
+            return write!(f, "All {} contrast checks pass", self.checks.len());
+        }
+        writeln!(
+            f,
+            "{} of {} contrast checks fail:",
+            failures.len(),
+            self.checks.len()
+        )?;
+        for check in failures {
+            writeln!(f, "  {check}")?;
+        }
+        Ok(())
+    }
+}
+
+const BACKGROUND_ROLES: [&str; 2] = ["background", "surface"];
+const TEXT_ROLES: [&str; 2] = ["text_primary", "text_secondary"];
+const ACCENT_ROLES: [&str; 6] = [
+    "primary",
+    "secondary",
+    "accent",
+    "success",
+    "warning",
+    "danger",
+];
+
+impl Palette {
+    /// Checks the palette at WCAG AA, see [`audit_at`](Self::audit_at)
+    pub fn audit(&self) -> ContrastAudit {
+        self.audit_at(WcagLevel::AA)
+    }
+
+    /// Checks text roles (at `level`, normal text) and accent roles (at the
+    /// 3:1 non-text minimum) on `background` and `surface`
+    pub fn audit_at(&self, level: WcagLevel) -> ContrastAudit {
+        let text = TEXT_ROLES
+            .iter()
+            .map(|role| (*role, level.minimum_ratio(TextSize::Normal)));
+        let accents = ACCENT_ROLES.iter().map(|role| (*role, NON_TEXT_CONTRAST));
+
+        let mut checks = Vec::new();
+        for (foreground, required) in text.chain(accents) {
+            for background in BACKGROUND_ROLES {
+                let (Some(fg), Some(bg)) = (self.role(foreground), self.role(background)) else {
+                    continue;
+                };
+                let ratio = contrast_ratio(fg, bg);
+                let suggestion = if ratio < required {
+                    nearest_accessible(fg, bg, ContrastTarget::Ratio(required))
+                } else {
+                    None
+                };
+                checks.push(ContrastCheck {
+                    foreground,
+                    background,
+                    ratio,
+                    apca: apca_contrast(fg, bg),
+                    required,
+                    suggestion,
+                });
+            }
+        }
+        ContrastAudit { checks }
+    }
+}
+
+/// Minimum contrast of accent roles and borders in [`Palette::high_contrast`]
+pub const HIGH_CONTRAST_NON_TEXT: f64 = 4.5;
+
+impl Palette {
+    /// Variant for `prefers-contrast: more`
+    ///
+    /// Backgrounds become pure white (light mode) or black (dark mode),
+    /// primary text their opposite, secondary text meets WCAG AAA and the
+    /// accent roles and border reach 4.5:1. Hues are kept wherever the
+    /// contrast allows it.
+    pub fn high_contrast(&self) -> Palette {
+        let (background, text) = match self.mode {
+            ThemeMode::Light => (Color::from_rgb(255, 255, 255), Color::from_rgb(0, 0, 0)),
+            ThemeMode::Dark => (Color::from_rgb(0, 0, 0), Color::from_rgb(255, 255, 255)),
+        };
+        let fix = |color: Color, ratio: f64| {
+            nearest_accessible(color, background, ContrastTarget::Ratio(ratio)).unwrap_or(text)
+        };
+        let text_ratio = WcagLevel::AAA.minimum_ratio(TextSize::Normal);
+        Palette {
+            mode: self.mode,
+            primary: fix(self.primary, HIGH_CONTRAST_NON_TEXT),
+            secondary: fix(self.secondary, HIGH_CONTRAST_NON_TEXT),
+            accent: fix(self.accent, HIGH_CONTRAST_NON_TEXT),
+            success: fix(self.success, HIGH_CONTRAST_NON_TEXT),
+            warning: fix(self.warning, HIGH_CONTRAST_NON_TEXT),
+            danger: fix(self.danger, HIGH_CONTRAST_NON_TEXT),
+            background,
+            surface: background,
+            border: fix(self.border, HIGH_CONTRAST_NON_TEXT),
+            text_primary: text,
+            text_secondary: fix(self.text_secondary, text_ratio),
+        }
+    }
+}
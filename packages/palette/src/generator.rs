@@ -0,0 +1,414 @@
+//! Palette generation
+//!
+//! Builds complete [`Palette`]s from one seed color (or several brand
+//! colors):
+//! - [`TonalScale`] — a 50–950 ramp of one hue in OKLCH, with the seed kept
+//!   at its nearest step
+//! - [`Harmony`] — secondary and accent hues derived from the seed
+//! - [`PaletteGenerator`] — light and dark palettes whose derived text and
+//!   accent roles are adjusted until they pass a [`Palette::audit`]; brand
+//!   colors are kept, and the audit reports them if they fail. The dark
+//!   primary is the seed's nearest tone that passes on the dark backgrounds
+//!
+//! ```
+//! use hikari_palette::{Color, Harmony, PaletteGenerator};
+//!
+//! let generated = PaletteGenerator::new(Color::from_rgb(0x14, 0x4a, 0x74))
+//!     .with_harmony(Harmony::Complementary)
+//!     .light();
+//! assert!(generated.audit.passes());
+//! let toml = generated.to_toml("brand");
+//! assert!(toml.starts_with("[palettes.\"brand\"]"));
+//! ```
+
+use std::fmt::Write;
+
+use crate::color_math::Oklch;
+use crate::contrast::{
+    ContrastAudit, ContrastTarget, NON_TEXT_CONTRAST, WcagLevel, contrast_ratio, nearest_accessible,
+};
+use crate::{Color, Palette, ThemeMode};
+
+/// Steps of a tonal scale, lightest first
+pub const TONE_STEPS: [u16; 11] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950];
+
+/// OKLCH lightness of each step
+const TONE_LIGHTNESS: [f64; 11] = [
+    0.97, 0.93, 0.87, 0.79, 0.71, 0.63, 0.55, 0.47, 0.39, 0.31, 0.25,
+];
+
+/// Share of the seed chroma kept at each step; very light and very dark
+/// tones cannot hold much chroma without leaving sRGB
+const TONE_CHROMA: [f64; 11] = [0.25, 0.4, 0.6, 0.8, 0.95, 1.0, 0.95, 0.85, 0.7, 0.55, 0.45];
+
+/// Chroma of the neutral scale, tinted with the seed hue
+const NEUTRAL_CHROMA: f64 = 0.012;
+
+/// A 50–950 ramp of one hue
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TonalScale {
+    pub tones: [Color; 11],
+}
+
+impl TonalScale {
+    /// Ramp through the seed's hue; the step closest in lightness to the
+    /// seed is the seed itself
+    pub fn from_seed(seed: Color) -> Self {
+        let lch = seed.to_oklch();
+        let mut scale = Self::from_oklch(lch.c, lch.h);
+        let nearest = TONE_LIGHTNESS
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| (*a - lch.l).abs().total_cmp(&(*b - lch.l).abs()))
+            .map(|(index, _)| index)
+            .unwrap_or(5);
+        scale.tones[nearest] = seed;
+        scale
+    }
+
+    /// Ramp with peak chroma `chroma` at hue `hue` (degrees)
+    pub fn from_oklch(chroma: f64, hue: f64) -> Self {
+        Self {
+            tones: std::array::from_fn(|index| {
+                Oklch::new(TONE_LIGHTNESS[index], chroma * TONE_CHROMA[index], hue).to_color()
+            }),
+        }
+    }
+
+    /// Tone at `step` (50, 100, …, 950)
+    pub fn get(&self, step: u16) -> Option<Color> {
+        TONE_STEPS
+            .iter()
+            .position(|s| *s == step)
+            .map(|index| self.tones[index])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (u16, Color)> + '_ {
+        TONE_STEPS.iter().copied().zip(self.tones.iter().copied())
+    }
+
+    /// Tone closest to `from` (a tone of this scale) with at least `ratio`
+    /// contrast on every one of `backgrounds`; the lighter one on a tie
+    pub fn nearest_passing(&self, from: Color, backgrounds: &[Color], ratio: f64) -> Option<Color> {
+        let start = self.tones.iter().position(|tone| *tone == from)?;
+        self.tones
+            .iter()
+            .enumerate()
+            .filter(|(_, tone)| {
+                backgrounds
+                    .iter()
+                    .all(|background| contrast_ratio(**tone, *background) >= ratio)
+            })
+            .min_by_key(|(index, _)| index.abs_diff(start))
+            .map(|(_, tone)| *tone)
+    }
+}
+
+/// How secondary and accent hues relate to the seed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Harmony {
+    /// Neighbours on the hue circle, 30° either side
+    #[default]
+    Analogous,
+    /// Secondary opposite the seed, accent next to it
+    Complementary,
+    /// Three hues 120° apart
+    Triadic,
+    /// The two neighbours of the complement, 150° and 210°
+    SplitComplementary,
+}
+
+impl Harmony {
+    /// Hue offsets of secondary and accent, in degrees
+    pub fn offsets(&self) -> (f64, f64) {
+        match self {
+            Harmony::Analogous => (30.0, -30.0),
+            Harmony::Complementary => (180.0, 30.0),
+            Harmony::Triadic => (120.0, 240.0),
+            Harmony::SplitComplementary => (150.0, 210.0),
+        }
+    }
+
+    /// Secondary and accent colors for `seed`, at its lightness and chroma
+    pub fn colors(&self, seed: Color) -> (Color, Color) {
+        let lch = seed.to_oklch();
+        let (secondary, accent) = self.offsets();
+        let rotate = |offset: f64| Oklch::new(lch.l, lch.c, (lch.h + offset).rem_euclid(360.0));
+        (rotate(secondary).to_color(), rotate(accent).to_color())
+    }
+}
+
+/// A generated palette with the ramps it was picked from
+#[derive(Debug, Clone)]
+pub struct GeneratedPalette {
+    pub palette: Palette,
+    /// Ramps by role: primary, secondary, accent, success, warning, danger
+    /// and neutral
+    pub scales: Vec<(&'static str, TonalScale)>,
+    /// Contrast audit of the final palette
+    pub audit: ContrastAudit,
+}
+
+impl GeneratedPalette {
+    pub fn scale(&self, role: &str) -> Option<&TonalScale> {
+        self.scales
+            .iter()
+            .find(|(name, _)| *name == role)
+            .map(|(_, scale)| scale)
+    }
+
+    /// The palette as a `[palettes."<name>"]` entry of `data/themes.toml`,
+    /// with the ramps in a `scales` sub-table
+    pub fn to_toml(&self, name: &str) -> String {
+        let hex = |color: Color| color.hex().to_lowercase();
+        // Quoted, so any name is a valid key
+        let key = format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""));
+        let mut out = String::new();
+        let _ = writeln!(out, "[palettes.{key}]");
+        let mode = match self.palette.mode {
+            ThemeMode::Light => "light",
+            ThemeMode::Dark => "dark",
+        };
+        let _ = writeln!(out, "mode = \"{mode}\"");
+        for (role, color) in self.palette.roles() {
+            let _ = writeln!(out, "{role} = \"{}\"", hex(color));
+        }
+        let _ = writeln!(out);
+        let _ = writeln!(out, "[palettes.{key}.scales]");
+        let _ = writeln!(
+            out,
+            "# {}",
+            TONE_STEPS.map(|step| step.to_string()).join(", ")
+        );
+        for (role, scale) in &self.scales {
+            let tones: Vec<String> = scale
+                .tones
+                .iter()
+                .map(|color| format!("\"{}\"", hex(*color)))
+                .collect();
+            let _ = writeln!(out, "{role} = [{}]", tones.join(", "));
+        }
+        out
+    }
+}
+
+/// Builds light and dark palettes from a seed color
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaletteGenerator {
+    pub seed: Color,
+    pub harmony: Harmony,
+    /// Brand secondary color, instead of the harmony's
+    pub secondary: Option<Color>,
+    /// Brand accent color, instead of the harmony's
+    pub accent: Option<Color>,
+    /// Level text roles are adjusted to
+    pub level: WcagLevel,
+}
+
+impl PaletteGenerator {
+    pub fn new(seed: Color) -> Self {
+        Self {
+            seed,
+            harmony: Harmony::default(),
+            secondary: None,
+            accent: None,
+            level: WcagLevel::AA,
+        }
+    }
+
+    /// Generator for several brand colors: the first is the seed, the next
+    /// ones become secondary and accent
+    pub fn from_brand_colors(colors: &[Color]) -> Option<Self> {
+        let (seed, rest) = colors.split_first()?;
+        let mut generator = Self::new(*seed);
+        generator.secondary = rest.first().copied();
+        generator.accent = rest.get(1).copied();
+        Some(generator)
+    }
+
+    pub fn with_harmony(mut self, harmony: Harmony) -> Self {
+        self.harmony = harmony;
+        self
+    }
+
+    pub fn with_secondary(mut self, color: Color) -> Self {
+        self.secondary = Some(color);
+        self
+    }
+
+    pub fn with_accent(mut self, color: Color) -> Self {
+        self.accent = Some(color);
+        self
+    }
+
+    pub fn with_level(mut self, level: WcagLevel) -> Self {
+        self.level = level;
+        self
+    }
+
+    pub fn light(&self) -> GeneratedPalette {
+        self.generate(ThemeMode::Light)
+    }
+
+    pub fn dark(&self) -> GeneratedPalette {
+        self.generate(ThemeMode::Dark)
+    }
+
+    /// Ramps by role, shared by the light and dark variants
+    pub fn scales(&self) -> Vec<(&'static str, TonalScale)> {
+        let seed = self.seed.to_oklch();
+        let (secondary, accent) = self.harmony.colors(self.seed);
+        // Semantic hues keep their meaning; chroma follows the seed so they
+        // sit well next to it, within a readable range
+        let semantic_chroma = seed.c.clamp(0.12, 0.2);
+        vec![
+            ("primary", TonalScale::from_seed(self.seed)),
+            (
+                "secondary",
+                TonalScale::from_seed(self.secondary.unwrap_or(secondary)),
+            ),
+            (
+                "accent",
+                TonalScale::from_seed(self.accent.unwrap_or(accent)),
+            ),
+            ("success", TonalScale::from_oklch(semantic_chroma, 145.0)),
+            ("warning", TonalScale::from_oklch(semantic_chroma, 75.0)),
+            ("danger", TonalScale::from_oklch(semantic_chroma, 27.0)),
+            ("neutral", TonalScale::from_oklch(NEUTRAL_CHROMA, seed.h)),
+        ]
+    }
+
+    pub fn generate(&self, mode: ThemeMode) -> GeneratedPalette {
+        let scales = self.scales();
+        let tone = |role: &str, step: u16| {
+            scales
+                .iter()
+                .find(|(name, _)| *name == role)
+                .and_then(|(_, scale)| scale.get(step))
+                .unwrap_or(self.seed)
+        };
+        // Brand colors are used as they are; semantic colors come from the
+        // tone that suits the mode
+        let brand = |role: &str, color: Option<Color>| color.unwrap_or_else(|| tone(role, 500));
+        let semantic_step = match mode {
+            ThemeMode::Light => 600,
+            ThemeMode::Dark => 400,
+        };
+
+        let mut palette = match mode {
+            ThemeMode::Light => Palette {
+                mode,
+                primary: self.seed,
+                secondary: brand("secondary", self.secondary),
+                accent: brand("accent", self.accent),
+                success: tone("success", semantic_step),
+                warning: tone("warning", semantic_step),
+                danger: tone("danger", semantic_step),
+                background: tone("neutral", 50),
+                surface: Color::from_rgb(255, 255, 255),
+                border: tone("neutral", 200),
+                text_primary: tone("neutral", 900),
+                text_secondary: tone("neutral", 600),
+            },
+            ThemeMode::Dark => Palette {
+                mode,
+                primary: self.seed,
+                secondary: brand("secondary", self.secondary),
+                accent: brand("accent", self.accent),
+                success: tone("success", semantic_step),
+                warning: tone("warning", semantic_step),
+                danger: tone("danger", semantic_step),
+                background: tone("neutral", 950),
+                surface: tone("neutral", 900),
+                border: tone("neutral", 700),
+                text_primary: tone("neutral", 50),
+                text_secondary: tone("neutral", 300),
+            },
+        };
+
+        // A dark seed would vanish on the dark backgrounds, so the dark
+        // variant takes its nearest passing tone
+        if mode == ThemeMode::Dark
+            && let Some(primary) =
+                scales
+                    .iter()
+                    .find(|(name, _)| *name == "primary")
+                    .and_then(|(_, scale)| {
+                        scale.nearest_passing(
+                            self.seed,
+                            &[palette.background, palette.surface],
+                            NON_TEXT_CONTRAST,
+                        )
+                    })
+        {
+            palette.primary = primary;
+        }
+
+        self.fix_contrast(&mut palette);
+        let audit = palette.audit_at(self.level);
+        GeneratedPalette {
+            palette,
+            scales,
+            audit,
+        }
+    }
+
+    /// Whether `role` is a color the caller chose rather than a derived one
+    fn is_brand(&self, role: &str) -> bool {
+        match role {
+            "primary" => true,
+            "secondary" => self.secondary.is_some(),
+            "accent" => self.accent.is_some(),
+            _ => false,
+        }
+    }
+
+    /// Moves failing derived foreground roles to the nearest passing
+    /// lightness; failing brand colors are left to the audit
+    fn fix_contrast(&self, palette: &mut Palette) {
+        // A few rounds settle roles that fail on both backgrounds
+        for _ in 0..3 {
+            let audit = palette.audit_at(self.level);
+            if audit
+                .failures()
+                .all(|check| self.is_brand(check.foreground))
+            {
+                return;
+            }
+            for check in audit.failures() {
+                if self.is_brand(check.foreground) {
+                    continue;
+                }
+                let (Some(foreground), Some(background)) = (
+                    palette.role(check.foreground),
+                    palette.role(check.background),
+                ) else {
+                    continue;
+                };
+                // Aim slightly above the minimum so rounding cannot undo it
+                let target = ContrastTarget::Ratio(check.required + 0.05);
+                if let Some(fixed) = nearest_accessible(foreground, background, target) {
+                    set_role(palette, check.foreground, fixed);
+                }
+            }
+        }
+    }
+}
+
+fn set_role(palette: &mut Palette, role: &str, color: Color) {
+    let slot = match role {
+        "primary" => &mut palette.primary,
+        "secondary" => &mut palette.secondary,
+        "accent" => &mut palette.accent,
+        "success" => &mut palette.success,
+        "warning" => &mut palette.warning,
+        "danger" => &mut palette.danger,
+        "background" => &mut palette.background,
+        "surface" => &mut palette.surface,
+        "border" => &mut palette.border,
+        "text_primary" => &mut palette.text_primary,
+        "text_secondary" => &mut palette.text_secondary,
+        _ => return,
+    };
+    *slot = color;
+}
@@ -60,21 +60,36 @@
 //! ## Modules
 //!
 //! - [`colors`] — the [`Color`] core and [`ColorCategory`].
+//! - [`color_math`] — HSL, OKLab/OKLCH and CIELAB conversions, ΔE, blending.
+//! - [`contrast`] — WCAG and APCA contrast checks and [`Palette::audit`].
+//! - [`generator`] — tonal scales and palettes generated from a seed color.
+//! - [`swatches`] — import and export of ASE, GPL, CSS and W3C design-token
+//!   swatch files.
 //! - [`themes`] — themed [`Palette`]s and a runtime [`ThemeRegistry`](themes::ThemeRegistry).
 //! - [`collections`] — opt-in named-color catalogs.
+//! - [`catalog`] — runtime lookup, search and nearest-color matching across
+//!   the enabled collections.
 //! - [`classes`] — auto-generated utility-class enums from SCSS.
 //!
 //! For the data file format and how to add your own collection, see the
 //! [data schema](https://github.com/celestia-island/hikari/blob/dev/packages/palette/data/SCHEMA.md).
 
+pub mod catalog;
 pub mod classes;
 pub mod color_math;
 pub mod colors;
+pub mod contrast;
+pub mod generator;
+pub mod swatches;
 pub mod themes;
 
+pub use catalog::*;
 pub use classes::*;
 pub use color_math::*;
 pub use colors::{Color, ColorCategory};
+pub use contrast::*;
+pub use generator::*;
+pub use swatches::*;
 pub use themes::*;
 
 // Compile-time color lookup across all enabled collections.
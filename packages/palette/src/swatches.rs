@@ -0,0 +1,730 @@
+//! Swatch import and export
+//!
+//! Converts lists of named colors between the collection format of
+//! `data/<name>.toml` (see the data schema) and the files design tools
+//! exchange:
+//! - Adobe Swatch Exchange (`.ase`)
+//! - GIMP palettes (`.gpl`)
+//! - CSS custom properties (`--name: #rrggbb;`)
+//! - W3C Design Tokens JSON (`.tokens.json`)
+//!
+//! ```
+//! use hikari_palette::SwatchCollection;
+//!
+//! let gpl = "GIMP Palette\nName: brand\n#\n255 179 167\tPeach Pink\n";
+//! let swatches = SwatchCollection::from_gpl(gpl).unwrap();
+//! let toml = swatches.to_toml();
+//! assert!(toml.contains("[colors]\nPeach_Pink = \"#ffb3a7\""));
+//! ```
+
+use std::fmt::Write;
+
+use serde_json::Value;
+
+use crate::Color;
+use crate::color_math::Lab;
+
+/// A named color
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Swatch {
+    pub name: String,
+    pub color: Color,
+}
+
+/// File formats [`SwatchCollection`] reads and writes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SwatchFormat {
+    /// Collection TOML of `data/<name>.toml`
+    Toml,
+    Ase,
+    Gpl,
+    Css,
+    DesignTokens,
+}
+
+impl SwatchFormat {
+    /// Format of a file, from its extension
+    pub fn from_path(path: &str) -> Option<Self> {
+        let extension = path.rsplit_once('.')?.1.to_ascii_lowercase();
+        match extension.as_str() {
+            "toml" => Some(SwatchFormat::Toml),
+            "ase" => Some(SwatchFormat::Ase),
+            "gpl" => Some(SwatchFormat::Gpl),
+            "css" => Some(SwatchFormat::Css),
+            "json" => Some(SwatchFormat::DesignTokens),
+            _ => None,
+        }
+    }
+}
+
+/// A named list of swatches, convertible between [`SwatchFormat`]s
+///
+/// Formats without a collection name (CSS, and ASE or token files without a
+/// group) leave `name` empty; set it to the data file stem before writing
+/// TOML.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SwatchCollection {
+    pub name: String,
+    pub description: Option<String>,
+    pub source: Option<String>,
+    pub swatches: Vec<Swatch>,
+}
+
+impl SwatchCollection {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    pub fn with_swatch(mut self, name: impl Into<String>, color: Color) -> Self {
+        self.push(name, color);
+        self
+    }
+
+    pub fn push(&mut self, name: impl Into<String>, color: Color) {
+        self.swatches.push(Swatch {
+            name: name.into(),
+            color,
+        });
+    }
+
+    pub fn get(&self, name: &str) -> Option<Color> {
+        self.swatches
+            .iter()
+            .find(|swatch| swatch.name == name)
+            .map(|swatch| swatch.color)
+    }
+
+    /// Colors of an enabled collection, see [`crate::catalog`]
+    pub fn from_catalog(collection: &str) -> Self {
+        let mut swatches = Self::new(collection);
+        for named in crate::catalog::collection_colors(collection) {
+            // The swatch carries the name, as for imported colors
+            let (r, g, b) = named.color.rgb;
+            swatches.push(named.name(), Color::new(r, g, b, named.color.category));
+        }
+        swatches
+    }
+
+    pub fn import(format: SwatchFormat, data: &[u8]) -> Result<Self, String> {
+        if format == SwatchFormat::Ase {
+            return Self::from_ase(data);
+        }
+        let text = std::str::from_utf8(data).map_err(|e| format!("Invalid UTF-8: {e}"))?;
+        match format {
+            SwatchFormat::Toml => Self::from_toml(text),
+            SwatchFormat::Gpl => Self::from_gpl(text),
+            SwatchFormat::Css => Self::from_css(text),
+            SwatchFormat::DesignTokens => Self::from_design_tokens(text),
+            SwatchFormat::Ase => unreachable!(),
+        }
+    }
+
+    pub fn export(&self, format: SwatchFormat) -> Vec<u8> {
+        match format {
+            SwatchFormat::Toml => self.to_toml().into_bytes(),
+            SwatchFormat::Ase => self.to_ase(),
+            SwatchFormat::Gpl => self.to_gpl().into_bytes(),
+            SwatchFormat::Css => self.to_css().into_bytes(),
+            SwatchFormat::DesignTokens => self.to_design_tokens().into_bytes(),
+        }
+    }
+
+    // ── TOML ────────────────────────────────────────────────────────────
+
+    /// Read a collection file in the `data/<name>.toml` format
+    pub fn from_toml(toml: &str) -> Result<Self, String> {
+        let mut swatches = Self::default();
+        let mut table = String::new();
+        for (index, raw) in toml.lines().enumerate() {
+            let line = raw.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if line.starts_with('[') {
+                table = line.trim_matches(['[', ']']).trim().to_string();
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(format!("Line {}: expected `key = value`", index + 1));
+            };
+            let key = key.trim().trim_matches('"');
+            let Some(value) = toml_string(value.trim()) else {
+                return Err(format!("Line {}: expected a string value", index + 1));
+            };
+            match (table.as_str(), key) {
+                ("collection", "name") => swatches.name = value,
+                ("collection", "description") => swatches.description = Some(value),
+                ("collection", "source") => swatches.source = Some(value),
+                ("colors", _) => {
+                    let color = Color::from_hex(&value).ok_or_else(|| {
+                        format!("Line {}: invalid color `{value}` for {key}", index + 1)
+                    })?;
+                    swatches.push(key, color);
+                }
+                _ => {}
+            }
+        }
+        Ok(swatches)
+    }
+
+    /// The collection as a `data/<name>.toml` file
+    ///
+    /// Names become ASCII Rust identifiers, as the build script turns each
+    /// one into a `pub const`; duplicates get a numeric suffix.
+    pub fn to_toml(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "# Schema: hikari-palette color collection v1");
+        let _ = writeln!(out);
+        let _ = writeln!(out, "[collection]");
+        let _ = writeln!(out, "name = {}", quote(&self.name));
+        if let Some(description) = &self.description {
+            let _ = writeln!(out, "description = {}", quote(description));
+        }
+        if let Some(source) = &self.source {
+            let _ = writeln!(out, "source = {}", quote(source));
+        }
+        let _ = writeln!(out);
+        let _ = writeln!(out, "[colors]");
+        let names = unique_names(&self.swatches, identifier, '_');
+        for (name, swatch) in names.iter().zip(&self.swatches) {
+            let _ = writeln!(out, "{name} = \"{}\"", lower_hex(swatch.color));
+        }
+        out
+    }
+
+    // ── Adobe Swatch Exchange ───────────────────────────────────────────
+
+    /// Read an `.ase` file
+    ///
+    /// RGB, CMYK, LAB and Gray entries are converted to sRGB; CMYK uses the
+    /// naive device conversion, without a color profile. The first group's
+    /// name becomes the collection name.
+    pub fn from_ase(data: &[u8]) -> Result<Self, String> {
+        let mut reader = AseReader { data, offset: 0 };
+        if reader.take(4)? != b"ASEF" {
+            return Err("Not an ASE file: missing ASEF signature".to_string());
+        }
+        let _version = (reader.u16()?, reader.u16()?);
+        let blocks = reader.u32()?;
+
+        let mut swatches = Self::default();
+        for _ in 0..blocks {
+            let kind = reader.u16()?;
+            let length = reader.u32()? as usize;
+            let mut block = AseReader {
+                data: reader.take(length)?,
+                offset: 0,
+            };
+            match kind {
+                ASE_GROUP_START => {
+                    let name = block.name()?;
+                    if swatches.name.is_empty() {
+                        swatches.name = name;
+                    }
+                }
+                ASE_COLOR => {
+                    let name = block.name()?;
+                    let model = block.take(4)?;
+                    let color = match model {
+                        b"RGB " => {
+                            let [r, g, b] = [block.f32()?, block.f32()?, block.f32()?];
+                            Color::from_rgb_float(r, g, b)
+                        }
+                        b"CMYK" => {
+                            let [c, m, y, k] =
+                                [block.f32()?, block.f32()?, block.f32()?, block.f32()?];
+                            let channel = |v: f64| (1.0 - v) * (1.0 - k);
+                            Color::from_rgb_float(channel(c), channel(m), channel(y))
+                        }
+                        b"LAB " => {
+                            // L is stored as a fraction of 100
+                            let [l, a, b] = [block.f32()?, block.f32()?, block.f32()?];
+                            Lab::new(l * 100.0, a, b).to_color()
+                        }
+                        b"Gray" => {
+                            let gray = block.f32()?;
+                            Color::from_rgb_float(gray, gray, gray)
+                        }
+                        other => {
+                            return Err(format!(
+                                "Unsupported ASE color model `{}` for {name}",
+                                String::from_utf8_lossy(other)
+                            ));
+                        }
+                    };
+                    swatches.push(name, color);
+                }
+                _ => {}
+            }
+        }
+        Ok(swatches)
+    }
+
+    /// The collection as an `.ase` file of RGB global colors, in a group
+    /// named after the collection
+    pub fn to_ase(&self) -> Vec<u8> {
+        let grouped = !self.name.is_empty();
+        let blocks = self.swatches.len() + if grouped { 2 } else { 0 };
+
+        let mut out = Vec::new();
+        out.extend_from_slice(b"ASEF");
+        out.extend_from_slice(&1u16.to_be_bytes());
+        out.extend_from_slice(&0u16.to_be_bytes());
+        out.extend_from_slice(&(blocks as u32).to_be_bytes());
+
+        let mut block = |kind: u16, body: Vec<u8>| {
+            out.extend_from_slice(&kind.to_be_bytes());
+            out.extend_from_slice(&(body.len() as u32).to_be_bytes());
+            out.extend_from_slice(&body);
+        };
+        if grouped {
+            block(ASE_GROUP_START, ase_name(&self.name));
+        }
+        for swatch in &self.swatches {
+            let mut body = ase_name(&swatch.name);
+            body.extend_from_slice(b"RGB ");
+            let (r, g, b) = swatch.color.rgb;
+            for channel in [r, g, b] {
+                body.extend_from_slice(&(channel as f32 / 255.0).to_be_bytes());
+            }
+            body.extend_from_slice(&ASE_GLOBAL.to_be_bytes());
+            block(ASE_COLOR, body);
+        }
+        if grouped {
+            block(ASE_GROUP_END, Vec::new());
+        }
+        out
+    }
+
+    // ── GIMP palette ────────────────────────────────────────────────────
+
+    /// Read a `.gpl` file; unnamed entries are called `color_<n>`
+    pub fn from_gpl(gpl: &str) -> Result<Self, String> {
+        let mut lines = gpl.lines().enumerate();
+        if lines.next().map(|(_, line)| line.trim()) != Some("GIMP Palette") {
+            return Err("Not a GIMP palette: missing `GIMP Palette` header".to_string());
+        }
+        let mut swatches = Self::default();
+        for (index, raw) in lines {
+            let line = raw.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix("Name:") {
+                swatches.name = name.trim().to_string();
+                continue;
+            }
+            if line.starts_with("Columns:") {
+                continue;
+            }
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            let channel = |position: usize| {
+                parts
+                    .get(position)
+                    .and_then(|part| part.parse::<u8>().ok())
+                    .ok_or_else(|| format!("Line {}: expected `r g b name`", index + 1))
+            };
+            let (r, g, b) = (channel(0)?, channel(1)?, channel(2)?);
+            let name = match parts.get(3..) {
+                Some(words) if !words.is_empty() => words.join(" "),
+                _ => format!("color_{}", swatches.swatches.len() + 1),
+            };
+            swatches.push(name, Color::from_rgb_hex(r, g, b));
+        }
+        Ok(swatches)
+    }
+
+    pub fn to_gpl(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "GIMP Palette");
+        let _ = writeln!(out, "Name: {}", self.name);
+        let _ = writeln!(out, "Columns: 8");
+        let _ = writeln!(out, "#");
+        for swatch in &self.swatches {
+            let (r, g, b) = swatch.color.rgb;
+            let _ = writeln!(out, "{r:3} {g:3} {b:3}\t{}", swatch.name);
+        }
+        out
+    }
+
+    // ── CSS custom properties ───────────────────────────────────────────
+
+    /// Read the color custom properties of a stylesheet
+    ///
+    /// Accepts hex and `rgb()`/`rgba()` values; other properties are
+    /// skipped. The name is the property without its leading `--`.
+    pub fn from_css(css: &str) -> Result<Self, String> {
+        let css = strip_css_comments(css);
+        let mut swatches = Self::default();
+        for declaration in css.split([';', '{', '}']) {
+            let Some((property, value)) = declaration.trim().split_once(':') else {
+                continue;
+            };
+            let Some(name) = property.trim().strip_prefix("--") else {
+                continue;
+            };
+            let value = value.trim().trim_end_matches("!important").trim();
+            if let Some(color) = parse_css_color(value) {
+                swatches.push(name, color);
+            }
+        }
+        if swatches.swatches.is_empty() {
+            return Err("No color custom properties found".to_string());
+        }
+        Ok(swatches)
+    }
+
+    /// The collection as custom properties on `:root`
+    pub fn to_css(&self) -> String {
+        let mut out = String::new();
+        if !self.name.is_empty() {
+            let _ = writeln!(out, "/* {} */", self.name);
+        }
+        let _ = writeln!(out, ":root {{");
+        let names = unique_names(&self.swatches, css_identifier, '-');
+        for (name, swatch) in names.iter().zip(&self.swatches) {
+            let _ = writeln!(out, "  --{name}: {};", lower_hex(swatch.color));
+        }
+        let _ = writeln!(out, "}}");
+        out
+    }
+
+    // ── W3C Design Tokens ───────────────────────────────────────────────
+
+    /// Read a W3C Design Tokens file
+    ///
+    /// Collects every token whose `$type` (own or inherited from a group)
+    /// is `color`. Names are the token paths joined with `-`; a document
+    /// with a single top-level group uses that group as the collection name
+    /// instead. Tokens come out sorted by name, as JSON objects are read
+    /// without their key order.
+    pub fn from_design_tokens(json: &str) -> Result<Self, String> {
+        let root: Value = serde_json::from_str(json).map_err(|e| format!("Invalid JSON: {e}"))?;
+        let Value::Object(object) = &root else {
+            return Err("Design tokens must be a JSON object".to_string());
+        };
+
+        let mut swatches = Self::default();
+        let groups: Vec<_> = object
+            .iter()
+            .filter(|(key, _)| !key.starts_with('$'))
+            .collect();
+        let mut inherited = root.get("$type").and_then(Value::as_str);
+        let mut path = Vec::new();
+        let mut start = &root;
+        if let [(name, group)] = groups.as_slice()
+            && group.get("$value").is_none()
+        {
+            swatches.name = name.to_string();
+            swatches.description = group
+                .get("$description")
+                .and_then(Value::as_str)
+                .map(str::to_string);
+            inherited = group.get("$type").and_then(Value::as_str).or(inherited);
+            start = group;
+        } else {
+            swatches.description = root
+                .get("$description")
+                .and_then(Value::as_str)
+                .map(str::to_string);
+        }
+        collect_tokens(start, inherited, &mut path, &mut swatches)?;
+        Ok(swatches)
+    }
+
+    /// The collection as a W3C Design Tokens file: one group named after the
+    /// collection, holding a `color` token per swatch
+    pub fn to_design_tokens(&self) -> String {
+        let json = |text: &str| Value::from(text).to_string();
+        let group = if self.name.is_empty() {
+            "colors"
+        } else {
+            &self.name
+        };
+        let mut out = String::new();
+        let _ = writeln!(out, "{{");
+        let _ = writeln!(out, "  {}: {{", json(group));
+        let _ = write!(out, "    \"$type\": \"color\"");
+        if let Some(description) = &self.description {
+            let _ = write!(out, ",\n    \"$description\": {}", json(description));
+        }
+        for swatch in &self.swatches {
+            let _ = write!(
+                out,
+                ",\n    {}: {{ \"$value\": \"{}\" }}",
+                json(&swatch.name),
+                lower_hex(swatch.color)
+            );
+        }
+        let _ = writeln!(out);
+        let _ = writeln!(out, "  }}");
+        let _ = writeln!(out, "}}");
+        out
+    }
+}
+
+const ASE_COLOR: u16 = 0x0001;
+const ASE_GROUP_START: u16 = 0xc001;
+const ASE_GROUP_END: u16 = 0xc002;
+const ASE_GLOBAL: u16 = 0;
+
+/// Big-endian reader over an ASE file or block
+struct AseReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> AseReader<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a [u8], String> {
+        let truncated = || format!("Truncated ASE data at byte {}", self.offset);
+        let end = self.offset.checked_add(length).ok_or_else(truncated)?;
+        let bytes = self.data.get(self.offset..end).ok_or_else(truncated)?;
+        self.offset = end;
+        Ok(bytes)
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn f32(&mut self) -> Result<f64, String> {
+        Ok(f32::from_bits(self.u32()?) as f64)
+    }
+
+    /// Length-prefixed, NUL-terminated UTF-16 name
+    fn name(&mut self) -> Result<String, String> {
+        let units = self.u16()? as usize;
+        let mut name = Vec::with_capacity(units);
+        for _ in 0..units {
+            name.push(self.u16()?);
+        }
+        if name.last() == Some(&0) {
+            name.pop();
+        }
+        String::from_utf16(&name).map_err(|e| format!("Invalid ASE name: {e}"))
+    }
+}
+
+fn ase_name(name: &str) -> Vec<u8> {
+    let units: Vec<u16> = name.encode_utf16().chain([0]).collect();
+    let mut out = (units.len() as u16).to_be_bytes().to_vec();
+    for unit in units {
+        out.extend_from_slice(&unit.to_be_bytes());
+    }
+    out
+}
+
+fn collect_tokens<'a>(
+    node: &'a Value,
+    inherited: Option<&'a str>,
+    path: &mut Vec<&'a str>,
+    swatches: &mut SwatchCollection,
+) -> Result<(), String> {
+    let Value::Object(object) = node else {
+        return Ok(());
+    };
+    let kind = object.get("$type").and_then(Value::as_str).or(inherited);
+    if let Some(value) = object.get("$value") {
+        if kind != Some("color") {
+            return Ok(());
+        }
+        let name = path.join("-");
+        let color = token_color(value).ok_or_else(|| format!("Invalid color value for {name}"))?;
+        swatches.push(name, color);
+        return Ok(());
+    }
+    for (key, child) in object {
+        if key.starts_with('$') {
+            continue;
+        }
+        path.push(key);
+        collect_tokens(child, kind, path, swatches)?;
+        path.pop();
+    }
+    Ok(())
+}
+
+/// A token `$value`: a CSS color string, or the object form with `hex` or
+/// sRGB `components`
+fn token_color(value: &Value) -> Option<Color> {
+    match value {
+        Value::String(text) => parse_css_color(text),
+        Value::Object(object) => {
+            if let Some(hex) = object.get("hex").and_then(Value::as_str) {
+                return Color::from_hex(hex);
+            }
+            let space = object.get("colorSpace").and_then(Value::as_str)?;
+            let components: Vec<f64> = object
+                .get("components")?
+                .as_array()?
+                .iter()
+                .map(Value::as_f64)
+                .collect::<Option<_>>()?;
+            match (space, components.as_slice()) {
+                ("srgb", [r, g, b]) => Some(Color::from_rgb_float(*r, *g, *b)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Hex, `rgb()` or `rgba()`; channels as 0–255 or percentages
+fn parse_css_color(value: &str) -> Option<Color> {
+    let value = value.trim();
+    if value.starts_with('#') {
+        return Color::from_hex(value);
+    }
+    let arguments = value
+        .strip_prefix("rgba(")
+        .or_else(|| value.strip_prefix("rgb("))?
+        .strip_suffix(')')?;
+    let channels: Vec<f64> = arguments
+        .split([',', ' ', '/'])
+        .filter(|part| !part.is_empty())
+        .take(3)
+        .map(|part| match part.strip_suffix('%') {
+            Some(percent) => percent.parse::<f64>().ok().map(|p| p / 100.0),
+            None => part.parse::<f64>().ok().map(|v| v / 255.0),
+        })
+        .collect::<Option<_>>()?;
+    match channels.as_slice() {
+        [r, g, b] => Some(Color::from_rgb_float(*r, *g, *b)),
+        _ => None,
+    }
+}
+
+fn strip_css_comments(css: &str) -> String {
+    let mut out = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(start) = rest.find("/*") {
+        out.push_str(&rest[..start]);
+        rest = match rest[start + 2..].find("*/") {
+            Some(end) => &rest[start + 2 + end + 2..],
+            None => "",
+        };
+    }
+    out.push_str(rest);
+    out
+}
+
+/// A TOML basic string, unescaped; anything after it (a comment) is ignored
+fn toml_string(value: &str) -> Option<String> {
+    let mut chars = value.strip_prefix('"')?.chars();
+    let mut out = String::new();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' => return Some(out),
+            '\\' => match chars.next()? {
+                'n' => out.push('\n'),
+                't' => out.push('\t'),
+                other => out.push(other),
+            },
+            _ => out.push(ch),
+        }
+    }
+    None
+}
+
+fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn lower_hex(color: Color) -> String {
+    color.hex().to_lowercase()
+}
+
+/// Words Rust reserves, which cannot name a `pub const`
+const RESERVED: &[&str] = &[
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// `name` as an ASCII Rust identifier: other letters and digits are escaped
+/// as `u<hex>`, anything else becomes `_`; a leading digit or a keyword gets
+/// an underscore
+fn identifier(name: &str) -> String {
+    let mut out = String::new();
+    for ch in name.chars() {
+        if ch.is_ascii_alphanumeric() {
+            out.push(ch);
+        } else if ch.is_alphanumeric() {
+            if !out.is_empty() && !out.ends_with('_') {
+                out.push('_');
+            }
+            let _ = write!(out, "u{:04x}_", ch as u32);
+        } else if !out.is_empty() && !out.ends_with('_') {
+            out.push('_');
+        }
+    }
+    let mut out = out.trim_end_matches('_').to_string();
+    if out.is_empty() {
+        out.push_str("color");
+    }
+    if out.starts_with(|ch: char| ch.is_ascii_digit()) {
+        out.insert(0, '_');
+    }
+    if RESERVED.contains(&out.as_str()) {
+        out.push('_');
+    }
+    out
+}
+
+/// `name` as a lowercase, dash-separated custom property name
+fn css_identifier(name: &str) -> String {
+    let mut out = String::new();
+    for ch in name.chars() {
+        if ch.is_alphanumeric() {
+            out.extend(ch.to_lowercase());
+        } else if !out.is_empty() && !out.ends_with('-') {
+            out.push('-');
+        }
+    }
+    let out = out.trim_end_matches('-').to_string();
+    if out.is_empty() {
+        "color".to_string()
+    } else {
+        out
+    }
+}
+
+/// Each swatch name converted by `convert`, with `<separator>2`,
+/// `<separator>3`… appended to repeats
+fn unique_names(swatches: &[Swatch], convert: fn(&str) -> String, separator: char) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    swatches
+        .iter()
+        .map(|swatch| {
+            let base = convert(&swatch.name);
+            let mut name = base.clone();
+            let mut count = 1;
+            while !seen.insert(name.clone()) {
+                count += 1;
+                name = format!("{base}{separator}{count}");
+            }
+            name
+        })
+        .collect()
+}
@@ -39,6 +39,31 @@ pub struct Palette {
 }
 
 impl Palette {
+    /// Every color role with its field name, in declaration order.
+    pub fn roles(&self) -> [(&'static str, Color); 11] {
+        [
+            ("primary", self.primary),
+            ("secondary", self.secondary),
+            ("accent", self.accent),
+            ("success", self.success),
+            ("warning", self.warning),
+            ("danger", self.danger),
+            ("background", self.background),
+            ("surface", self.surface),
+            ("border", self.border),
+            ("text_primary", self.text_primary),
+            ("text_secondary", self.text_secondary),
+        ]
+    }
+
+    /// Color of the role named like its field, e.g. `"text_secondary"`.
+    pub fn role(&self, name: &str) -> Option<Color> {
+        self.roles()
+            .into_iter()
+            .find(|(role, _)| *role == name)
+            .map(|(_, color)| color)
+    }
+
     /// Contrast color (black or white) for a button variant color, used for
     /// glow effects. Dynamic opacity is derived from the button's brightness.
     pub fn button_glow_color(&self, color: &Color) -> String {
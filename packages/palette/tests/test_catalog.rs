@@ -0,0 +1,99 @@
+// Runtime catalog over the enabled collections.
+#![cfg(all(hikari_collection_chinese, hikari_collection_tailwind))]
+
+use hikari_palette::collections::{chinese, tailwind};
+use hikari_palette::*;
+
+#[test]
+fn test_named_colors_cover_enabled_collections() {
+    assert_eq!(collection_names(), vec!["chinese", "tailwind"]);
+    assert_eq!(
+        named_colors().count(),
+        chinese::CATALOG.len() + tailwind::CATALOG.len()
+    );
+    assert_eq!(
+        collection_colors("tailwind").count(),
+        tailwind::CATALOG.len()
+    );
+    assert_eq!(collection_colors("missing").count(), 0);
+    assert!(named_colors().all(|named| !named.name().is_empty()));
+}
+
+#[test]
+fn test_find_color_by_name_and_alias() {
+    let pink = find_color("粉红").unwrap();
+    assert_eq!(pink.collection, "chinese");
+    assert_eq!(pink.color, chinese::粉红);
+    assert_eq!(pink.aliases, &["fen hong"]);
+
+    // Pinyin, with or without spaces and in any case
+    assert_eq!(find_color("fen hong").unwrap().color, chinese::粉红);
+    assert_eq!(find_color("FenHong").unwrap().color, chinese::粉红);
+    // Whole-name reading overrides the per-character one
+    assert_eq!(find_color("bolinlan").unwrap().color, chinese::柏林蓝);
+    assert_eq!(find_color("ku lv").unwrap().color, chinese::枯绿);
+
+    // Separators are ignored for ASCII names too
+    assert_eq!(find_color("red-500").unwrap().color, tailwind::red_500);
+    assert_eq!(find_color("Red 500").unwrap().color, tailwind::red_500);
+
+    assert_eq!(find_color("no such color"), None);
+    assert_eq!(find_color(" "), None);
+}
+
+#[test]
+fn test_search_colors_ranks_matches() {
+    let results = search_colors("fenhong");
+    assert_eq!(results[0].color, chinese::粉红);
+    // Longer names containing the query follow
+    assert!(results.iter().any(|named| named.color == chinese::牡丹粉红));
+
+    // CJK substring search
+    let results = search_colors("粉红");
+    assert_eq!(results[0].name(), "粉红");
+    assert!(results.iter().all(|named| named.name().contains('粉')));
+
+    // Subsequences still match, below prefixes
+    let results = search_colors("rd5");
+    assert!(results.iter().any(|named| named.color == tailwind::red_500));
+    let results = search_colors("red");
+    assert!(results[0].name().starts_with("red"));
+
+    assert!(search_colors("").is_empty());
+    assert!(search_colors("qqqqqq").is_empty());
+}
+
+#[test]
+fn test_colors_in_category() {
+    let reds: Vec<NamedColor> = colors_in_category(ColorCategory::Red).collect();
+    assert!(reds.iter().any(|named| named.color == chinese::粉红));
+    assert!(
+        reds.iter()
+            .all(|named| named.color.category == ColorCategory::Red)
+    );
+}
+
+#[test]
+fn test_nearest_colors() {
+    // A named color is its own nearest neighbour
+    let (nearest, distance) = nearest_colors_in("chinese", chinese::粉红, 1)[0];
+    assert_eq!(nearest.color.rgb, chinese::粉红.rgb);
+    assert!(distance < 1e-9);
+
+    // Snap a brand color to traditional names, nearest first
+    let brand = Color::from_rgb(0xee, 0xa0, 0xa0);
+    let matches = nearest_colors_in("chinese", brand, 5);
+    assert_eq!(matches.len(), 5);
+    assert!(
+        matches
+            .iter()
+            .all(|(named, _)| named.collection == "chinese")
+    );
+    assert!(matches.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+    assert!(matches[0].1 < 5.0, "nearest is {:?}", matches[0]);
+
+    // Across collections
+    let matches = nearest_colors(tailwind::blue_500, 3);
+    assert_eq!(matches[0].0.color, tailwind::blue_500);
+    assert_eq!(nearest_colors(brand, 0), Vec::new());
+}
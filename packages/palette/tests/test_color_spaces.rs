@@ -0,0 +1,222 @@
+// Perceptual color spaces, color differences and gamut mapping.
+
+use hikari_palette::Color;
+use hikari_palette::color_math::*;
+
+fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+    assert!(
+        (actual - expected).abs() <= tolerance,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn test_srgb_linear_roundtrip() {
+    for value in [0.0, 0.02, 0.04045, 0.2, 0.5, 0.9, 1.0] {
+        assert_close(linear_to_srgb(srgb_to_linear(value)), value, 1e-6);
+    }
+    // Out-of-range values keep their sign
+    assert!(srgb_to_linear(-0.5) < 0.0);
+}
+
+#[test]
+fn test_oklab_reference_values() {
+    let white = Color::from_rgb(255, 255, 255).to_oklab();
+    assert_close(white.l, 1.0, 1e-4);
+    assert_close(white.a, 0.0, 1e-4);
+    assert_close(white.b, 0.0, 1e-4);
+
+    // Reference values from the CSS Color 4 specification
+    let red = Color::from_rgb(255, 0, 0).to_oklch();
+    assert_close(red.l, 0.627_96, 1e-3);
+    assert_close(red.c, 0.257_68, 1e-3);
+    assert_close(red.h, 29.23, 0.1);
+
+    let black = Color::from_rgb(0, 0, 0).to_oklch();
+    assert!(black.is_achromatic());
+}
+
+#[test]
+fn test_lab_reference_values() {
+    let red = Color::from_rgb(255, 0, 0).to_lab();
+    assert_close(red.l, 53.24, 0.05);
+    assert_close(red.a, 80.09, 0.1);
+    assert_close(red.b, 67.20, 0.1);
+
+    let grey = Color::from_rgb(119, 119, 119).to_lab();
+    assert_close(grey.a, 0.0, 0.01);
+    assert_close(grey.b, 0.0, 0.01);
+}
+
+#[test]
+fn test_conversions_roundtrip() {
+    for (r, g, b) in [
+        (0, 0, 0),
+        (255, 255, 255),
+        (255, 0, 0),
+        (0, 128, 255),
+        (12, 200, 77),
+        (200, 100, 150),
+    ] {
+        let color = Color::from_rgb(r, g, b);
+        assert_eq!(color.to_oklab().to_color().rgb, (r, g, b));
+        assert_eq!(color.to_oklch().to_color().rgb, (r, g, b));
+        assert_eq!(color.to_lab().to_color().rgb, (r, g, b));
+        assert_eq!(Color::from_linear_rgb(color.to_linear_rgb()).rgb, (r, g, b));
+    }
+}
+
+#[test]
+fn test_delta_e_2000_reference_pairs() {
+    // Test data from Sharma, Wu & Dalal (2005)
+    let pairs = [
+        ((50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485), 2.0425),
+        ((50.0, 3.1571, -77.2803), (50.0, 0.0, -82.7485), 2.8615),
+        ((50.0, 2.5, 0.0), (50.0, 0.0, -2.5), 4.3065),
+        ((50.0, 2.5, 0.0), (73.0, 25.0, -18.0), 27.1492),
+        ((2.0776, 0.0795, -1.135), (0.9033, -0.0636, -0.5514), 0.9082),
+    ];
+    for ((l1, a1, b1), (l2, a2, b2), expected) in pairs {
+        let first = Lab::new(l1, a1, b1);
+        let second = Lab::new(l2, a2, b2);
+        assert_close(first.delta_e_2000(&second), expected, 1e-4);
+        assert_close(second.delta_e_2000(&first), expected, 1e-4);
+    }
+
+    let color = Color::from_rgb(10, 20, 30);
+    assert_eq!(color.delta_e_2000(&color), 0.0);
+}
+
+#[test]
+fn test_delta_e_ok() {
+    let black = Color::from_rgb(0, 0, 0);
+    let white = Color::from_rgb(255, 255, 255);
+    assert_close(black.delta_e_ok(&white), 1.0, 1e-3);
+    assert!(Color::from_rgb(100, 100, 100).delta_e_ok(&Color::from_rgb(101, 100, 100)) < 0.02);
+}
+
+#[test]
+fn test_gamut_mapping() {
+    // Very saturated green at this lightness lies outside sRGB
+    let outside = Oklch::new(0.7, 0.4, 145.0);
+    assert!(!outside.in_gamut());
+    let mapped = outside.to_color();
+    let lch = mapped.to_oklch();
+    // Lightness and hue are kept, chroma is reduced
+    assert_close(lch.l, 0.7, 0.02);
+    assert_close(lch.h, 145.0, 3.0);
+    assert!(lch.c < 0.4);
+
+    assert_eq!(Oklch::new(1.2, 0.1, 10.0).to_color().rgb, (255, 255, 255));
+    assert_eq!(Oklch::new(-0.1, 0.1, 10.0).to_color().rgb, (0, 0, 0));
+    assert!(!Lab::new(50.0, 150.0, 0.0).in_gamut());
+}
+
+#[test]
+fn test_blend_in_spaces() {
+    let blue = Color::from_rgb(0, 0, 255);
+    let yellow = Color::from_rgb(255, 255, 0);
+
+    // sRGB midpoint of complementary colors is grey
+    let srgb = blend_colors_in(blue, yellow, 0.5, ColorSpace::Srgb);
+    assert_eq!(srgb, blend_colors(blue, yellow, 0.5));
+    assert!(srgb.to_oklch().c < 0.01);
+
+    // OKLCH keeps the chroma
+    let oklch = blend_colors_in(blue, yellow, 0.5, ColorSpace::Oklch);
+    assert!(oklch.to_oklch().c > 0.1);
+
+    for space in [
+        ColorSpace::Srgb,
+        ColorSpace::LinearSrgb,
+        ColorSpace::Oklab,
+        ColorSpace::Oklch,
+        ColorSpace::Lab,
+    ] {
+        assert_eq!(blend_colors_in(blue, yellow, 0.0, space).rgb, blue.rgb);
+        assert_eq!(blend_colors_in(blue, yellow, 1.0, space).rgb, yellow.rgb);
+    }
+
+    // Achromatic ends take the hue of the other end
+    let white = Color::from_rgb(255, 255, 255);
+    let red = Color::from_rgb(255, 0, 0);
+    let pink = blend_colors_in(white, red, 0.5, ColorSpace::Oklch);
+    assert_close(pink.to_oklch().h, red.to_oklch().h, 2.0);
+}
+
+#[test]
+fn test_gradient_space() {
+    let colors = vec![Color::from_rgb(0, 0, 255), Color::from_rgb(255, 255, 0)];
+    let srgb = Gradient::from_colors(colors.clone());
+    assert_eq!(srgb.space, ColorSpace::Srgb);
+    let oklch = Gradient::from_colors(colors).with_space(ColorSpace::Oklch);
+    assert!(oklch.sample(0.5).to_oklch().c > srgb.sample(0.5).to_oklch().c);
+}
+
+#[test]
+fn test_lighten_darken_mix() {
+    for color in [
+        Color::from_rgb(0, 0, 255),
+        Color::from_rgb(255, 255, 0),
+        Color::from_rgb(30, 160, 90),
+    ] {
+        let base = color.to_oklch().l;
+        let lighter = color.lighten(0.1).to_oklch();
+        let darker = color.darken(0.1).to_oklch();
+        assert!(lighter.l > base && darker.l < base);
+        // Equal steps regardless of hue (unless the gamut forces a change)
+        assert_close(base - darker.l, 0.1, 0.02);
+    }
+
+    let white = Color::from_rgb(255, 255, 255);
+    assert_eq!(white.lighten(0.2), white);
+
+    let red = Color::from_rgb(255, 0, 0);
+    let blue = Color::from_rgb(0, 0, 255);
+    assert_eq!(red.mix(blue, 0.0).rgb, red.rgb);
+    assert_eq!(
+        red.mix(blue, 0.5),
+        blend_colors_in(red, blue, 0.5, ColorSpace::Oklch)
+    );
+}
+
+#[test]
+fn test_color_vision_deficiency_simulation() {
+    let white = Color::from_rgb(255, 255, 255);
+    let black = Color::from_rgb(0, 0, 0);
+    for deficiency in ColorVisionDeficiency::ALL {
+        // Neutral colors look the same to everyone
+        assert!(white.simulate(deficiency).delta_e_ok(&white) < 0.01);
+        assert_eq!(black.simulate(deficiency), black);
+        assert_eq!(deficiency.as_str().parse(), Ok(deficiency));
+    }
+
+    // Red and green become hard to tell apart for red-green deficiencies
+    let red = Color::from_rgb(220, 50, 50);
+    let green = Color::from_rgb(50, 160, 50);
+    // Distance in hue and chroma, ignoring lightness
+    let apart = |a: Color, b: Color| {
+        let (a, b) = (a.to_oklab(), b.to_oklab());
+        (a.a - b.a).hypot(a.b - b.b)
+    };
+    let normal = apart(red, green);
+    for deficiency in [
+        ColorVisionDeficiency::Protanopia,
+        ColorVisionDeficiency::Deuteranopia,
+    ] {
+        let seen = apart(red.simulate(deficiency), green.simulate(deficiency));
+        assert!(seen < normal / 3.0, "{deficiency:?}: {seen} vs {normal}");
+    }
+
+    let grey = red.simulate(ColorVisionDeficiency::Achromatopsia);
+    assert!(grey.rgb.0 == grey.rgb.1 && grey.rgb.1 == grey.rgb.2);
+    assert!("colorblind".parse::<ColorVisionDeficiency>().is_err());
+
+    let palette = hikari_palette::themes::Hikari::palette();
+    let simulated = palette.simulate(ColorVisionDeficiency::Tritanopia);
+    assert_eq!(simulated.mode, palette.mode);
+    assert_eq!(
+        simulated.primary,
+        palette.primary.simulate(ColorVisionDeficiency::Tritanopia)
+    );
+}
@@ -0,0 +1,164 @@
+// WCAG / APCA contrast checks and palette audits.
+
+use hikari_palette::themes::{Hikari, Tairitsu};
+use hikari_palette::*;
+
+fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+    assert!(
+        (actual - expected).abs() <= tolerance,
+        "expected {expected}, got {actual}"
+    );
+}
+
+const BLACK: Color = Color::from_rgb_hex(0, 0, 0);
+const WHITE: Color = Color::from_rgb_hex(255, 255, 255);
+
+#[test]
+fn test_relative_luminance() {
+    assert_close(relative_luminance(BLACK), 0.0, 1e-9);
+    assert_close(relative_luminance(WHITE), 1.0, 1e-9);
+    assert_close(relative_luminance(Color::from_rgb(255, 0, 0)), 0.2126, 1e-4);
+    assert_close(
+        relative_luminance(Color::from_rgb(0x77, 0x77, 0x77)),
+        0.1845,
+        1e-3,
+    );
+}
+
+#[test]
+fn test_contrast_ratio() {
+    assert_close(contrast_ratio(BLACK, WHITE), 21.0, 1e-9);
+    assert_close(contrast_ratio(WHITE, BLACK), 21.0, 1e-9);
+    assert_close(contrast_ratio(WHITE, WHITE), 1.0, 1e-9);
+    // #777 on white is the classic "just fails AA" grey
+    let grey = Color::from_rgb(0x77, 0x77, 0x77);
+    assert_close(contrast_ratio(grey, WHITE), 4.48, 0.01);
+    assert!(!passes_wcag(grey, WHITE, WcagLevel::AA, TextSize::Normal));
+    assert!(passes_wcag(grey, WHITE, WcagLevel::AA, TextSize::Large));
+    assert!(!passes_wcag(grey, WHITE, WcagLevel::AAA, TextSize::Large));
+}
+
+#[test]
+fn test_text_size_from_css() {
+    assert_eq!(TextSize::from_css(16.0, false), TextSize::Normal);
+    assert_eq!(TextSize::from_css(16.0, true), TextSize::Normal);
+    assert_eq!(TextSize::from_css(19.0, true), TextSize::Large);
+    assert_eq!(TextSize::from_css(24.0, false), TextSize::Large);
+    assert_eq!(WcagLevel::AAA.minimum_ratio(TextSize::Normal), 7.0);
+}
+
+#[test]
+fn test_apca_reference_values() {
+    assert_close(apca_contrast(BLACK, WHITE), 106.04, 0.1);
+    assert_close(apca_contrast(WHITE, BLACK), -107.88, 0.1);
+    let grey = Color::from_rgb(0x88, 0x88, 0x88);
+    assert_close(apca_contrast(grey, WHITE), 63.06, 0.1);
+    assert_close(apca_contrast(WHITE, grey), -68.54, 0.1);
+    assert_eq!(apca_contrast(grey, grey), 0.0);
+}
+
+#[test]
+fn test_nearest_accessible() {
+    let grey = Color::from_rgb(0x99, 0x99, 0x99);
+
+    // Already passing colors come back unchanged
+    let aa_large = ContrastTarget::wcag(WcagLevel::AA, TextSize::Large);
+    assert_eq!(nearest_accessible(BLACK, WHITE, aa_large), Some(BLACK));
+
+    // Darkened just enough on white
+    let aa = ContrastTarget::wcag(WcagLevel::AA, TextSize::Normal);
+    let fixed = nearest_accessible(grey, WHITE, aa).unwrap();
+    let ratio = contrast_ratio(fixed, WHITE);
+    assert!(ratio >= 4.5);
+    assert!(ratio < 4.7, "adjusted further than needed: {ratio}");
+    assert!(fixed.to_oklch().l < grey.to_oklch().l);
+
+    // Lightened on black
+    let fixed = nearest_accessible(Color::from_rgb(60, 60, 200), BLACK, aa).unwrap();
+    assert!(contrast_ratio(fixed, BLACK) >= 4.5);
+
+    // Hue is kept
+    let red = Color::from_rgb(255, 80, 80);
+    let fixed = nearest_accessible(red, WHITE, aa).unwrap();
+    assert_close(fixed.to_oklch().h, red.to_oklch().h, 5.0);
+
+    // APCA targets work the same way
+    let apca = ContrastTarget::apca(TextSize::Normal);
+    let fixed = nearest_accessible(grey, WHITE, apca).unwrap();
+    assert!(apca_contrast(fixed, WHITE).abs() >= 75.0);
+
+    // Nothing reaches more than 21:1
+    assert_eq!(
+        nearest_accessible(grey, WHITE, ContrastTarget::Ratio(22.0)),
+        None
+    );
+}
+
+#[test]
+fn test_palette_roles() {
+    let palette = Hikari::palette();
+    assert_eq!(palette.roles().len(), 11);
+    assert_eq!(palette.role("text_secondary"), Some(palette.text_secondary));
+    assert_eq!(palette.role("missing"), None);
+}
+
+#[test]
+fn test_palette_audit() {
+    let mut palette = Hikari::palette();
+    palette.text_secondary = Color::from_rgb(0xaa, 0xaa, 0xaa);
+    let audit = palette.audit();
+
+    // Every foreground role is checked on both backgrounds
+    assert_eq!(audit.checks.len(), 16);
+
+    let failure = audit
+        .failures()
+        .find(|check| check.foreground == "text_secondary" && check.background == "surface")
+        .expect("text_secondary on surface should fail");
+    assert!(!failure.passes());
+    let suggestion = failure.suggestion.unwrap();
+    assert!(contrast_ratio(suggestion, palette.surface) >= 4.5);
+    assert!(failure.to_string().starts_with("text_secondary on surface"));
+
+    assert!(!audit.passes());
+    assert!(audit.to_string().contains("contrast checks fail"));
+
+    // Passing checks carry no suggestion
+    assert!(
+        audit
+            .checks
+            .iter()
+            .filter(|check| check.passes())
+            .all(|check| check.suggestion.is_none())
+    );
+
+    let dark = Tairitsu::palette().audit_at(WcagLevel::AAA);
+    assert!(dark.checks.iter().all(|check| check.ratio >= 1.0));
+}
+
+#[test]
+fn test_high_contrast_palette() {
+    for palette in [Hikari::palette(), Tairitsu::palette()] {
+        let high = palette.high_contrast();
+        assert_eq!(high.mode, palette.mode);
+        assert_eq!(high.surface, high.background);
+        assert_close(
+            contrast_ratio(high.text_primary, high.background),
+            21.0,
+            1e-9,
+        );
+        assert!(contrast_ratio(high.text_secondary, high.background) >= 7.0);
+        for role in [
+            "primary", "accent", "success", "warning", "danger", "border",
+        ] {
+            let color = high.role(role).unwrap();
+            assert!(contrast_ratio(color, high.background) >= HIGH_CONTRAST_NON_TEXT);
+        }
+        assert!(high.audit_at(WcagLevel::AAA).passes());
+    }
+
+    // Colors that already pass keep their hue
+    let mut palette = Hikari::palette();
+    palette.primary = Color::from_rgb(0, 0, 160);
+    assert_eq!(palette.high_contrast().primary, palette.primary);
+}
@@ -0,0 +1,169 @@
+// Tonal scales and seed-based palette generation.
+
+use hikari_palette::themes::ThemeMode;
+use hikari_palette::*;
+
+const SEEDS: [Color; 5] = [
+    Color::from_rgb_hex(0xee, 0xa2, 0xa4),
+    Color::from_rgb_hex(0x14, 0x4a, 0x74),
+    Color::from_rgb_hex(0x00, 0xb4, 0xd8),
+    Color::from_rgb_hex(0xff, 0xf1, 0x43),
+    Color::from_rgb_hex(0x80, 0x80, 0x80),
+];
+
+#[test]
+fn test_tonal_scale_is_ordered_and_keeps_hue() {
+    for seed in SEEDS {
+        let scale = TonalScale::from_seed(seed);
+        let lightness: Vec<f64> = scale.tones.iter().map(|c| c.to_oklch().l).collect();
+        assert!(
+            lightness.windows(2).all(|pair| pair[0] > pair[1]),
+            "scale of {} is not ordered: {lightness:?}",
+            seed.hex()
+        );
+        // The seed itself is one of the tones
+        assert!(scale.tones.contains(&seed));
+    }
+
+    let blue = Color::from_rgb(0x25, 0x63, 0xeb);
+    let scale = TonalScale::from_seed(blue);
+    let hue = blue.to_oklch().h;
+    for step in [300, 500, 700] {
+        let tone = scale.get(step).unwrap().to_oklch();
+        assert!(
+            (tone.h - hue).abs() < 4.0,
+            "step {step} drifted to {}",
+            tone.h
+        );
+    }
+    assert_eq!(scale.get(50), Some(scale.tones[0]));
+    assert_eq!(scale.get(55), None);
+    assert_eq!(
+        scale.iter().map(|(step, _)| step).collect::<Vec<_>>(),
+        TONE_STEPS.to_vec()
+    );
+}
+
+#[test]
+fn test_harmony_offsets() {
+    let seed = Color::from_rgb(0x25, 0x63, 0xeb);
+    let hue = seed.to_oklch().h;
+    let distance = |color: Color| {
+        let d = (color.to_oklch().h - hue).rem_euclid(360.0);
+        d.min(360.0 - d)
+    };
+
+    let (secondary, accent) = Harmony::Complementary.colors(seed);
+    assert!((distance(secondary) - 180.0).abs() < 10.0);
+    assert!((distance(accent) - 30.0).abs() < 10.0);
+
+    let (secondary, accent) = Harmony::Triadic.colors(seed);
+    assert!((distance(secondary) - 120.0).abs() < 10.0);
+    assert!((distance(accent) - 120.0).abs() < 10.0);
+
+    let (secondary, _) = Harmony::Analogous.colors(seed);
+    assert!((distance(secondary) - 30.0).abs() < 10.0);
+}
+
+#[test]
+fn test_generated_palettes_pass_audit() {
+    for seed in SEEDS {
+        for harmony in [
+            Harmony::Analogous,
+            Harmony::Complementary,
+            Harmony::Triadic,
+            Harmony::SplitComplementary,
+        ] {
+            let generator = PaletteGenerator::new(seed).with_harmony(harmony);
+            for generated in [generator.light(), generator.dark()] {
+                // The seed is kept as it is; every derived role passes
+                assert!(
+                    generated
+                        .audit
+                        .failures()
+                        .all(|check| check.foreground == "primary"),
+                    "{} {harmony:?} {:?}:\n{}",
+                    seed.hex(),
+                    generated.palette.mode,
+                    generated.audit
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_light_and_dark_variants() {
+    let generator = PaletteGenerator::new(Color::from_rgb(0x14, 0x4a, 0x74));
+    let light = generator.light();
+    let dark = generator.dark();
+    assert_eq!(light.palette.mode, ThemeMode::Light);
+    assert_eq!(dark.palette.mode, ThemeMode::Dark);
+    assert!(relative_luminance(light.palette.background) > 0.8);
+    assert!(relative_luminance(dark.palette.background) < 0.05);
+    // The seed is kept in light mode; the dark variant takes its nearest
+    // tone that passes on the dark backgrounds
+    let primary = generator.scales()[0].1;
+    assert_eq!(light.palette.primary, generator.seed);
+    assert_ne!(dark.palette.primary, generator.seed);
+    assert!(primary.tones.contains(&dark.palette.primary));
+    assert!(
+        dark.audit
+            .failures()
+            .all(|check| check.foreground != "primary")
+    );
+    assert_eq!(light.scales.len(), 7);
+    assert!(light.scale("neutral").is_some());
+}
+
+#[test]
+fn test_brand_colors() {
+    let brand = [
+        Color::from_rgb(0x14, 0x4a, 0x74),
+        Color::from_rgb(0xff, 0xc7, 0x73),
+        Color::from_rgb(0x0e, 0xb8, 0x40),
+    ];
+    let generator = PaletteGenerator::from_brand_colors(&brand).unwrap();
+    assert_eq!(generator.secondary, Some(brand[1]));
+    assert_eq!(generator.accent, Some(brand[2]));
+    assert!(PaletteGenerator::from_brand_colors(&[]).is_none());
+
+    let light = generator.light();
+    assert_eq!(light.palette.primary, brand[0]);
+    // The pale secondary fails on white but is kept; the audit reports it
+    assert_eq!(light.palette.secondary, brand[1]);
+    assert!(
+        light
+            .audit
+            .failures()
+            .any(|check| check.foreground == "secondary" && check.suggestion.is_some())
+    );
+    assert!(light.scale("secondary").unwrap().tones.contains(&brand[1]));
+
+    let aaa = PaletteGenerator::new(brand[0])
+        .with_level(WcagLevel::AAA)
+        .light();
+    assert!(contrast_ratio(aaa.palette.text_secondary, aaa.palette.surface) >= 7.0);
+}
+
+#[test]
+fn test_export_to_themes_toml() {
+    let toml = PaletteGenerator::new(Color::from_rgb(0xee, 0xa2, 0xa4))
+        .dark()
+        .to_toml("peony");
+    assert!(toml.starts_with("[palettes.\"peony\"]\nmode = \"dark\"\n"));
+    assert!(toml.contains("text_secondary = \"#"));
+    assert!(toml.contains("[palettes.\"peony\".scales]"));
+    let primary = toml
+        .lines()
+        .find(|line| line.starts_with("primary = ["))
+        .unwrap();
+    assert_eq!(primary.matches('#').count(), 11);
+    // Same lowercase hex style as data/themes.toml
+    assert_eq!(toml, toml.to_lowercase());
+
+    let toml = PaletteGenerator::new(Color::from_rgb(0xee, 0xa2, 0xa4))
+        .light()
+        .to_toml("my theme.v2");
+    assert!(toml.starts_with("[palettes.\"my theme.v2\"]\n"));
+}
@@ -0,0 +1,231 @@
+// Swatch import and export: TOML, ASE, GPL, CSS and W3C design tokens.
+
+use hikari_palette::*;
+
+fn sample() -> SwatchCollection {
+    SwatchCollection::new("brand")
+        .with_description("Brand colors")
+        .with_swatch("粉红", Color::from_rgb(0xff, 0xb3, 0xa7))
+        .with_swatch("Ocean Blue", Color::from_rgb(0x14, 0x4a, 0x74))
+        .with_swatch("ink", Color::from_rgb(0x26, 0x26, 0x26))
+}
+
+#[test]
+fn test_toml_round_trip() {
+    let toml = sample().to_toml();
+    assert!(toml.contains("[collection]\nname = \"brand\"\ndescription = \"Brand colors\"\n"));
+    assert!(toml.contains("[colors]\nu7c89_u7ea2 = \"#ffb3a7\"\nOcean_Blue = \"#144a74\"\n"));
+
+    let parsed = SwatchCollection::from_toml(&toml).unwrap();
+    assert_eq!(parsed.name, "brand");
+    assert_eq!(parsed.description.as_deref(), Some("Brand colors"));
+    assert_eq!(
+        parsed.get("Ocean_Blue"),
+        Some(Color::from_rgb(0x14, 0x4a, 0x74))
+    );
+    assert_eq!(parsed.swatches.len(), 3);
+
+    // The shipped data files parse
+    let chinese = SwatchCollection::from_toml(include_str!("../data/chinese.toml")).unwrap();
+    assert_eq!(chinese.name, "chinese");
+    assert_eq!(chinese.swatches.len(), 661);
+    assert_eq!(chinese.get("粉红"), Some(Color::from_rgb(0xff, 0xb3, 0xa7)));
+
+    assert!(SwatchCollection::from_toml("[colors]\nred = \"#ff\"").is_err());
+}
+
+#[test]
+fn test_toml_names_become_identifiers() {
+    let toml = SwatchCollection::new("odd")
+        .with_swatch("PANTONE 186 C", Color::from_rgb(200, 16, 46))
+        .with_swatch("500", Color::from_rgb(1, 2, 3))
+        .with_swatch("type", Color::from_rgb(4, 5, 6))
+        .with_swatch("red!", Color::from_rgb(7, 8, 9))
+        .with_swatch("red", Color::from_rgb(10, 11, 12))
+        .with_swatch("Self", Color::from_rgb(13, 14, 15))
+        .with_swatch("粉红 2", Color::from_rgb(16, 17, 18))
+        .to_toml();
+    for line in [
+        "PANTONE_186_C = ",
+        "_500 = ",
+        "type_ = ",
+        "red = \"#070809\"",
+        "red_2 = \"#0a0b0c\"",
+        "Self_ = ",
+        "u7c89_u7ea2_2 = ",
+    ] {
+        assert!(toml.contains(line), "missing `{line}` in\n{toml}");
+    }
+}
+
+#[test]
+fn test_ase_round_trip() {
+    let ase = sample().to_ase();
+    assert_eq!(&ase[..4], b"ASEF");
+    // Group start, three colors, group end
+    assert_eq!(u32::from_be_bytes([ase[8], ase[9], ase[10], ase[11]]), 5);
+
+    let parsed = SwatchCollection::from_ase(&ase).unwrap();
+    assert_eq!(parsed.name, "brand");
+    assert_eq!(parsed.swatches, sample().swatches);
+
+    assert!(SwatchCollection::from_ase(b"NOPE").is_err());
+    assert!(SwatchCollection::from_ase(&ase[..ase.len() - 3]).is_err());
+}
+
+#[test]
+fn test_ase_color_models() {
+    fn entry(name: &str, model: &[u8; 4], values: &[f32]) -> Vec<u8> {
+        let units: Vec<u16> = name.encode_utf16().chain([0]).collect();
+        let mut body = (units.len() as u16).to_be_bytes().to_vec();
+        units
+            .iter()
+            .for_each(|unit| body.extend_from_slice(&unit.to_be_bytes()));
+        body.extend_from_slice(model);
+        values
+            .iter()
+            .for_each(|value| body.extend_from_slice(&value.to_be_bytes()));
+        body.extend_from_slice(&2u16.to_be_bytes());
+        let mut block = 1u16.to_be_bytes().to_vec();
+        block.extend_from_slice(&(body.len() as u32).to_be_bytes());
+        block.extend_from_slice(&body);
+        block
+    }
+
+    let mut ase = b"ASEF\x00\x01\x00\x00\x00\x00\x00\x03".to_vec();
+    ase.extend(entry("cyan", b"CMYK", &[1.0, 0.0, 0.0, 0.0]));
+    ase.extend(entry("grey", b"Gray", &[0.5]));
+    ase.extend(entry("white", b"LAB ", &[1.0, 0.0, 0.0]));
+
+    let parsed = SwatchCollection::from_ase(&ase).unwrap();
+    assert_eq!(parsed.name, "");
+    assert_eq!(parsed.get("cyan"), Some(Color::from_rgb(0, 255, 255)));
+    assert_eq!(parsed.get("grey"), Some(Color::from_rgb(128, 128, 128)));
+    assert_eq!(parsed.get("white"), Some(Color::from_rgb(255, 255, 255)));
+}
+
+#[test]
+fn test_gpl() {
+    let gpl = "GIMP Palette\nName: Tango\nColumns: 3\n# comment\n\
+               252 233  79\tButter 1\n  0   0   0\n";
+    let parsed = SwatchCollection::from_gpl(gpl).unwrap();
+    assert_eq!(parsed.name, "Tango");
+    assert_eq!(parsed.get("Butter 1"), Some(Color::from_rgb(252, 233, 79)));
+    assert_eq!(parsed.get("color_2"), Some(Color::from_rgb(0, 0, 0)));
+
+    let round_trip = SwatchCollection::from_gpl(&sample().to_gpl()).unwrap();
+    assert_eq!(round_trip.name, "brand");
+    assert_eq!(round_trip.swatches, sample().swatches);
+
+    assert!(SwatchCollection::from_gpl("Name: x\n0 0 0").is_err());
+    assert!(SwatchCollection::from_gpl("GIMP Palette\n300 0 0 bad").is_err());
+}
+
+#[test]
+fn test_css() {
+    let css = "/* brand */\n:root {\n  --primary: #FFB3A7;\n  --ink: rgb(38, 38, 38);\n\
+               --half: rgb(100% 50% 0% / 0.5) !important;\n  --radius: 4px;\n  color: #fff;\n}";
+    let parsed = SwatchCollection::from_css(css).unwrap();
+    assert_eq!(parsed.swatches.len(), 3);
+    assert_eq!(
+        parsed.get("primary"),
+        Some(Color::from_rgb(0xff, 0xb3, 0xa7))
+    );
+    assert_eq!(parsed.get("ink"), Some(Color::from_rgb(38, 38, 38)));
+    assert_eq!(parsed.get("half"), Some(Color::from_rgb(255, 128, 0)));
+
+    let css = sample().to_css();
+    assert!(css.contains(":root {\n  --粉红: #ffb3a7;\n  --ocean-blue: #144a74;\n"));
+    let round_trip = SwatchCollection::from_css(&css).unwrap();
+    assert_eq!(
+        round_trip.get("ocean-blue"),
+        Some(Color::from_rgb(0x14, 0x4a, 0x74))
+    );
+
+    assert!(SwatchCollection::from_css("body { margin: 0 }").is_err());
+}
+
+#[test]
+fn test_design_tokens() {
+    let json = r##"{
+        "color": {
+            "$type": "color",
+            "brand": {
+                "primary": { "$value": "#ffb3a7" },
+                "ink": { "$value": { "colorSpace": "srgb", "components": [0.15, 0.15, 0.15] } }
+            },
+            "sky": { "$value": { "colorSpace": "srgb", "components": [0, 0, 0], "hex": "#87ceeb" } }
+        },
+        "spacing": { "small": { "$type": "dimension", "$value": "4px" } }
+    }"##;
+    let parsed = SwatchCollection::from_design_tokens(json).unwrap();
+    // Two top-level groups: names keep the whole path
+    assert_eq!(parsed.name, "");
+    assert_eq!(parsed.swatches.len(), 3);
+    assert_eq!(
+        parsed.get("color-brand-primary"),
+        Some(Color::from_rgb(0xff, 0xb3, 0xa7))
+    );
+    assert_eq!(
+        parsed.get("color-brand-ink"),
+        Some(Color::from_rgb(38, 38, 38))
+    );
+    assert_eq!(
+        parsed.get("color-sky"),
+        Some(Color::from_rgb(0x87, 0xce, 0xeb))
+    );
+
+    let tokens = sample().to_design_tokens();
+    let mut round_trip = SwatchCollection::from_design_tokens(&tokens).unwrap();
+    assert_eq!(round_trip.name, "brand");
+    assert_eq!(round_trip.description.as_deref(), Some("Brand colors"));
+    // Object keys are read sorted
+    let mut expected = sample().swatches;
+    expected.sort_by(|a, b| a.name.cmp(&b.name));
+    round_trip.swatches.sort_by(|a, b| a.name.cmp(&b.name));
+    assert_eq!(round_trip.swatches, expected);
+
+    assert!(SwatchCollection::from_design_tokens("[]").is_err());
+    assert!(
+        SwatchCollection::from_design_tokens(r#"{"a": {"$type": "color", "$value": "blue"}}"#)
+            .is_err()
+    );
+}
+
+#[test]
+fn test_formats_by_path() {
+    assert_eq!(
+        SwatchFormat::from_path("swatches.ASE"),
+        Some(SwatchFormat::Ase)
+    );
+    assert_eq!(
+        SwatchFormat::from_path("brand.tokens.json"),
+        Some(SwatchFormat::DesignTokens)
+    );
+    assert_eq!(SwatchFormat::from_path("README"), None);
+
+    let swatches = sample();
+    for format in [
+        SwatchFormat::Toml,
+        SwatchFormat::Ase,
+        SwatchFormat::Gpl,
+        SwatchFormat::DesignTokens,
+    ] {
+        let parsed = SwatchCollection::import(format, &swatches.export(format)).unwrap();
+        assert_eq!(parsed.name, "brand", "{format:?}");
+        assert_eq!(parsed.swatches.len(), 3, "{format:?}");
+    }
+}
+
+#[cfg(hikari_collection_tailwind)]
+#[test]
+fn test_export_enabled_collection() {
+    let tailwind = SwatchCollection::from_catalog("tailwind");
+    assert_eq!(tailwind.name, "tailwind");
+    assert_eq!(
+        tailwind.get("red_500"),
+        Some(Color::from_rgb(0xef, 0x44, 0x44))
+    );
+    let parsed = SwatchCollection::from_toml(&tailwind.to_toml()).unwrap();
+    assert_eq!(parsed, tailwind);
+}
@@ -10,6 +10,8 @@
 //! - **[`provider`]** - [`ThemeProvider`] component for theme context management
 //! - **[`context`]** - Theme context hooks and state management
 //! - **[`generated`]** - Auto-generated theme assets (Tailwind CSS, variables)
+//! - **[`tokens`]** - [`DesignTokens`] exported as CSS, SCSS, W3C JSON and a
+//!   Tailwind preset
 //!
 //! ## Supported Themes
 //!
@@ -210,6 +212,7 @@ pub mod context;
 pub mod prelude;
 pub mod provider;
 pub mod style_provider;
+pub mod tokens;
 
 pub use context::*;
 pub use provider::*;
@@ -217,3 +220,4 @@ pub use style_provider::{
     StyleConfig, StyleContext, StyleProvider, StyleProviderProps, try_use_style,
     use_component_class, use_style,
 };
+pub use tokens::*;
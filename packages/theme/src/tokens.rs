@@ -0,0 +1,697 @@
+//! Design tokens
+//!
+//! One model for the values the design system is built from — palette
+//! colors, spacing, radii, typography, shadows, motion durations and
+//! easings, blur, opacity and z-index — that can be written out for every
+//! consumer:
+//!
+//! - [`DesignTokens::to_css`] — custom properties (`--hi-spacing-md`), the
+//!   same names `foundation.scss` and [`ThemeProvider`](crate::ThemeProvider)
+//!   use
+//! - [`DesignTokens::to_scss`] — a `$hi-tokens` SCSS map
+//! - [`DesignTokens::to_json`] — W3C Design Tokens JSON
+//! - [`DesignTokens::to_tailwind_preset`] — a Tailwind preset whose values
+//!   point at the CSS variables, so theme switching keeps working
+//!
+//! ```rust,ignore
+//! use hikari_theme::{DesignTokens, Dimension, TokenGroup, TokenValue};
+//! use hikari_palette::themes::Hikari;
+//!
+//! let tokens = DesignTokens::from_palette(&Hikari::palette())
+//!     .with_token(TokenGroup::Spacing, "3xl", TokenValue::Dimension(Dimension::rem(4.0)));
+//! std::fs::write("tokens.json", tokens.to_json())?;
+//! ```
+
+use std::fmt::{self, Write};
+
+use hikari_palette::{Color, Palette};
+use serde_json::{Map, Value, json};
+
+/// Category of a token; decides its CSS variable prefix and where it goes
+/// in a Tailwind theme
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TokenGroup {
+    Color,
+    Spacing,
+    Radius,
+    FontFamily,
+    FontSize,
+    FontWeight,
+    LineHeight,
+    Shadow,
+    Duration,
+    Easing,
+    Blur,
+    Opacity,
+    ZIndex,
+}
+
+impl TokenGroup {
+    pub const ALL: [TokenGroup; 13] = [
+        TokenGroup::Color,
+        TokenGroup::Spacing,
+        TokenGroup::Radius,
+        TokenGroup::FontFamily,
+        TokenGroup::FontSize,
+        TokenGroup::FontWeight,
+        TokenGroup::LineHeight,
+        TokenGroup::Shadow,
+        TokenGroup::Duration,
+        TokenGroup::Easing,
+        TokenGroup::Blur,
+        TokenGroup::Opacity,
+        TokenGroup::ZIndex,
+    ];
+
+    /// Group name in the SCSS map and the JSON file
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TokenGroup::Color => "color",
+            TokenGroup::Spacing => "spacing",
+            TokenGroup::Radius => "radius",
+            TokenGroup::FontFamily => "font-family",
+            TokenGroup::FontSize => "font-size",
+            TokenGroup::FontWeight => "font-weight",
+            TokenGroup::LineHeight => "line-height",
+            TokenGroup::Shadow => "shadow",
+            TokenGroup::Duration => "duration",
+            TokenGroup::Easing => "easing",
+            TokenGroup::Blur => "blur",
+            TokenGroup::Opacity => "opacity",
+            TokenGroup::ZIndex => "z-index",
+        }
+    }
+
+    /// Prefix of the group's custom properties, after `--hi-`
+    pub fn css_prefix(&self) -> &'static str {
+        match self {
+            TokenGroup::Easing => "ease",
+            TokenGroup::ZIndex => "z",
+            _ => self.as_str(),
+        }
+    }
+
+    /// Key of the group in a Tailwind `theme`
+    pub fn tailwind_key(&self) -> &'static str {
+        match self {
+            TokenGroup::Color => "colors",
+            TokenGroup::Spacing => "spacing",
+            TokenGroup::Radius => "borderRadius",
+            TokenGroup::FontFamily => "fontFamily",
+            TokenGroup::FontSize => "fontSize",
+            TokenGroup::FontWeight => "fontWeight",
+            TokenGroup::LineHeight => "lineHeight",
+            TokenGroup::Shadow => "boxShadow",
+            TokenGroup::Duration => "transitionDuration",
+            TokenGroup::Easing => "transitionTimingFunction",
+            TokenGroup::Blur => "blur",
+            TokenGroup::Opacity => "opacity",
+            TokenGroup::ZIndex => "zIndex",
+        }
+    }
+}
+
+/// Unit of a [`Dimension`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DimensionUnit {
+    Px,
+    Rem,
+}
+
+impl DimensionUnit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DimensionUnit::Px => "px",
+            DimensionUnit::Rem => "rem",
+        }
+    }
+}
+
+/// A length such as `4px` or `0.25rem`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dimension {
+    pub value: f64,
+    pub unit: DimensionUnit,
+}
+
+impl Dimension {
+    pub fn px(value: f64) -> Self {
+        Self {
+            value,
+            unit: DimensionUnit::Px,
+        }
+    }
+
+    pub fn rem(value: f64) -> Self {
+        Self {
+            value,
+            unit: DimensionUnit::Rem,
+        }
+    }
+}
+
+impl fmt::Display for Dimension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.value == 0.0 {
+            return f.write_str("0");
+        }
+        write!(f, "{}{}", self.value, self.unit.as_str())
+    }
+}
+
+/// One layer of a box shadow
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShadowLayer {
+    pub offset_x: Dimension,
+    pub offset_y: Dimension,
+    pub blur: Dimension,
+    pub spread: Dimension,
+    pub color: Color,
+    /// Opacity of `color`, 0.0 to 1.0
+    pub alpha: f64,
+}
+
+impl ShadowLayer {
+    /// Drop shadow offset `y` pixels down, blurred by `blur` pixels
+    pub fn drop(y: f64, blur: f64, color: Color, alpha: f64) -> Self {
+        Self {
+            offset_x: Dimension::px(0.0),
+            offset_y: Dimension::px(y),
+            blur: Dimension::px(blur),
+            spread: Dimension::px(0.0),
+            color,
+            alpha,
+        }
+    }
+
+    /// Unblurred outline `spread` pixels wide
+    pub fn ring(spread: f64, color: Color, alpha: f64) -> Self {
+        Self {
+            offset_y: Dimension::px(0.0),
+            blur: Dimension::px(0.0),
+            spread: Dimension::px(spread),
+            ..Self::drop(0.0, 0.0, color, alpha)
+        }
+    }
+
+    /// The layer grown (or, if negative, shrunk) by `spread` pixels
+    pub fn with_spread(mut self, spread: f64) -> Self {
+        self.spread = Dimension::px(spread);
+        self
+    }
+}
+
+impl fmt::Display for ShadowLayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.offset_x, self.offset_y, self.blur)?;
+        if self.spread.value != 0.0 {
+            write!(f, " {}", self.spread)?;
+        }
+        write!(f, " {}", self.color.rgba(self.alpha))
+    }
+}
+
+/// Value of a token, typed as the W3C format types it
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenValue {
+    Color(Color),
+    Dimension(Dimension),
+    /// Milliseconds
+    Duration(u32),
+    CubicBezier([f64; 4]),
+    FontFamily(Vec<String>),
+    FontWeight(u16),
+    Number(f64),
+    Shadow(Vec<ShadowLayer>),
+}
+
+impl TokenValue {
+    /// `$type` of the value in W3C Design Tokens JSON
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            TokenValue::Color(_) => "color",
+            TokenValue::Dimension(_) => "dimension",
+            TokenValue::Duration(_) => "duration",
+            TokenValue::CubicBezier(_) => "cubicBezier",
+            TokenValue::FontFamily(_) => "fontFamily",
+            TokenValue::FontWeight(_) => "fontWeight",
+            TokenValue::Number(_) => "number",
+            TokenValue::Shadow(_) => "shadow",
+        }
+    }
+
+    /// The value as CSS
+    pub fn css(&self) -> String {
+        match self {
+            TokenValue::Color(color) => color.hex().to_lowercase(),
+            TokenValue::Dimension(dimension) => dimension.to_string(),
+            TokenValue::Duration(ms) => format!("{ms}ms"),
+            TokenValue::CubicBezier([x1, y1, x2, y2]) => {
+                format!("cubic-bezier({x1}, {y1}, {x2}, {y2})")
+            }
+            TokenValue::FontFamily(families) => families
+                .iter()
+                .map(|family| css_font_family(family))
+                .collect::<Vec<_>>()
+                .join(", "),
+            TokenValue::FontWeight(weight) => weight.to_string(),
+            TokenValue::Number(number) => number.to_string(),
+            TokenValue::Shadow(layers) => layers
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", "),
+        }
+    }
+
+    /// `$value` in W3C Design Tokens JSON
+    pub fn json(&self) -> Value {
+        let color = |color: &Color, alpha: f64| {
+            let (r, g, b) = color.rgb;
+            let mut value = json!({
+                "colorSpace": "srgb",
+                "components": [r as f64 / 255.0, g as f64 / 255.0, b as f64 / 255.0],
+                "hex": color.hex().to_lowercase(),
+            });
+            if alpha < 1.0 {
+                value["alpha"] = json!(alpha);
+            }
+            value
+        };
+        let dimension = |dimension: &Dimension| json!({ "value": dimension.value, "unit": dimension.unit.as_str() });
+        match self {
+            TokenValue::Color(value) => color(value, 1.0),
+            TokenValue::Dimension(value) => dimension(value),
+            TokenValue::Duration(ms) => json!({ "value": ms, "unit": "ms" }),
+            TokenValue::CubicBezier(points) => json!(points),
+            TokenValue::FontFamily(families) => json!(families),
+            TokenValue::FontWeight(weight) => json!(weight),
+            TokenValue::Number(number) => json!(number),
+            TokenValue::Shadow(layers) => Value::Array(
+                layers
+                    .iter()
+                    .map(|layer| {
+                        json!({
+                            "color": color(&layer.color, layer.alpha),
+                            "offsetX": dimension(&layer.offset_x),
+                            "offsetY": dimension(&layer.offset_y),
+                            "blur": dimension(&layer.blur),
+                            "spread": dimension(&layer.spread),
+                        })
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Quotes family names that are not a single identifier
+fn css_font_family(family: &str) -> String {
+    let generic = family
+        .chars()
+        .all(|ch| ch.is_ascii_alphanumeric() || ch == '-');
+    if generic {
+        family.to_string()
+    } else {
+        format!("\"{family}\"")
+    }
+}
+
+/// A named design token
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub group: TokenGroup,
+    pub name: String,
+    pub value: TokenValue,
+}
+
+/// The token set of a theme
+#[derive(Debug, Clone, PartialEq)]
+pub struct DesignTokens {
+    /// Prefix of CSS variables and the SCSS map, `"hi"` by default
+    pub prefix: String,
+    pub tokens: Vec<Token>,
+}
+
+impl Default for DesignTokens {
+    fn default() -> Self {
+        Self::defaults()
+    }
+}
+
+impl DesignTokens {
+    /// Empty set with the `hi` prefix
+    pub fn new() -> Self {
+        Self {
+            prefix: "hi".to_string(),
+            tokens: Vec::new(),
+        }
+    }
+
+    /// The non-color tokens of `foundation.scss`, `_tokens.scss`,
+    /// `base.scss` and `variables.scss`
+    ///
+    /// The stylesheets are written by hand; `tokens_tests.rs` checks that
+    /// their custom properties match these values.
+    pub fn defaults() -> Self {
+        use TokenGroup::{
+            Blur, Duration, Easing, FontFamily, FontSize, FontWeight, LineHeight, Opacity, Radius,
+            Shadow, Spacing, ZIndex,
+        };
+        let rem = |value| TokenValue::Dimension(Dimension::rem(value));
+        let px = |value| TokenValue::Dimension(Dimension::px(value));
+        let black = Color::from_rgb(0, 0, 0);
+        let white = Color::from_rgb(255, 255, 255);
+        let page = Color::from_rgb(240, 244, 248);
+        let primary = Color::from_rgb(238, 162, 164);
+        let danger = Color::from_rgb(255, 76, 0);
+        let drop = |y, blur, color, alpha| ShadowLayer::drop(y, blur, color, alpha);
+        let families = |names: &[&str]| {
+            TokenValue::FontFamily(names.iter().map(|name| name.to_string()).collect())
+        };
+
+        let mut tokens = Self::new();
+        for (name, value) in [
+            ("xs", 0.25),
+            ("sm", 0.5),
+            ("md", 1.0),
+            ("lg", 1.5),
+            ("xl", 2.0),
+            ("2xl", 3.0),
+        ] {
+            tokens.set(Spacing, name, rem(value));
+        }
+        for (name, value) in [
+            ("none", 0.0),
+            ("sm", 4.0),
+            ("md", 8.0),
+            ("lg", 12.0),
+            ("xl", 16.0),
+            ("full", 9999.0),
+        ] {
+            tokens.set(Radius, name, px(value));
+        }
+        tokens.set(
+            FontFamily,
+            "sans",
+            families(&[
+                "-apple-system",
+                "BlinkMacSystemFont",
+                "Segoe UI",
+                "Roboto",
+                "Helvetica Neue",
+                "Arial",
+                "sans-serif",
+            ]),
+        );
+        tokens.set(
+            FontFamily,
+            "mono",
+            families(&[
+                "SF Mono",
+                "Monaco",
+                "Cascadia Code",
+                "Roboto Mono",
+                "Consolas",
+                "Courier New",
+                "monospace",
+            ]),
+        );
+        for (name, value) in [
+            ("xs", 0.75),
+            ("sm", 0.875),
+            ("base", 1.0),
+            ("lg", 1.125),
+            ("xl", 1.25),
+            ("2xl", 1.5),
+            ("3xl", 1.875),
+        ] {
+            tokens.set(FontSize, name, rem(value));
+        }
+        for (name, value) in [
+            ("normal", 400),
+            ("medium", 500),
+            ("semibold", 600),
+            ("bold", 700),
+        ] {
+            tokens.set(FontWeight, name, TokenValue::FontWeight(value));
+        }
+        tokens.set(LineHeight, "tight", TokenValue::Number(1.25));
+        tokens.set(LineHeight, "normal", TokenValue::Number(1.5));
+        for (name, layers) in [
+            ("sm", vec![drop(1.0, 2.0, black, 0.05)]),
+            ("md", vec![drop(4.0, 6.0, black, 0.1).with_spread(-1.0)]),
+            ("lg", vec![drop(10.0, 15.0, black, 0.1).with_spread(-3.0)]),
+            ("xl", vec![drop(20.0, 25.0, black, 0.1).with_spread(-5.0)]),
+            (
+                "panel",
+                vec![
+                    drop(4.0, 32.0, black, 0.2),
+                    ShadowLayer::ring(1.0, white, 0.04),
+                ],
+            ),
+            ("elevated", vec![drop(8.0, 32.0, black, 0.15)]),
+            (
+                "modal",
+                vec![
+                    drop(20.0, 60.0, black, 0.25),
+                    ShadowLayer::ring(1.0, white, 0.05),
+                ],
+            ),
+            (
+                "dropdown",
+                vec![
+                    drop(4.0, 24.0, black, 0.12),
+                    ShadowLayer::ring(1.0, white, 0.04),
+                ],
+            ),
+            ("tooltip", vec![drop(2.0, 8.0, black, 0.2)]),
+            (
+                "focus",
+                vec![ShadowLayer::ring(3.0, Color::from_rgb(255, 107, 157), 0.12)],
+            ),
+            (
+                "focus-ring",
+                vec![
+                    ShadowLayer::ring(2.0, page, 1.0),
+                    ShadowLayer::ring(4.0, primary, 1.0),
+                ],
+            ),
+            (
+                "focus-ring-error",
+                vec![
+                    ShadowLayer::ring(2.0, page, 1.0),
+                    ShadowLayer::ring(4.0, danger, 1.0),
+                ],
+            ),
+            ("button", vec![drop(4.0, 14.0, primary, 0.35)]),
+            ("button-danger", vec![drop(4.0, 14.0, danger, 0.35)]),
+        ] {
+            tokens.set(Shadow, name, TokenValue::Shadow(layers));
+        }
+        for (name, value) in [
+            ("instant", 0),
+            ("fast", 150),
+            ("normal", 300),
+            ("slow", 500),
+            ("short", 150),
+            ("fade", 150),
+        ] {
+            tokens.set(Duration, name, TokenValue::Duration(value));
+        }
+        for (name, points) in [
+            ("default", [0.4, 0.0, 0.2, 1.0]),
+            ("in", [0.4, 0.0, 1.0, 1.0]),
+            ("out", [0.0, 0.0, 0.2, 1.0]),
+            ("in-out", [0.4, 0.0, 0.2, 1.0]),
+            ("bounce", [0.68, -0.55, 0.265, 1.55]),
+            ("elastic", [0.5, 1.5, 0.5, 1.0]),
+            ("out-expo", [0.16, 1.0, 0.3, 1.0]),
+            ("in-expo", [0.7, 0.0, 0.84, 0.0]),
+            ("spring", [0.34, 1.56, 0.64, 1.0]),
+        ] {
+            tokens.set(Easing, name, TokenValue::CubicBezier(points));
+        }
+        for (name, value) in [("xs", 4.0), ("sm", 8.0), ("md", 16.0), ("lg", 24.0)] {
+            tokens.set(Blur, name, px(value));
+        }
+        for (name, value) in [
+            ("faded", 0.45),
+            ("less", 0.85),
+            ("half", 0.92),
+            ("more", 0.96),
+        ] {
+            tokens.set(Opacity, name, TokenValue::Number(value));
+        }
+        for (name, value) in [
+            ("base", 0),
+            ("above", 1),
+            ("content", 10),
+            ("header", 30),
+            ("sidebar", 40),
+            ("floating", 50),
+            ("header-popup", 150),
+            ("modal", 1000),
+            ("modal-step", 2),
+            ("toast", 9999),
+            ("tooltip", 10000),
+            ("index-dropdown", 1000),
+            ("index-sticky", 1020),
+            ("index-fixed", 1030),
+            ("index-modal-backdrop", 1040),
+            ("index-modal", 1050),
+            ("index-popover", 1060),
+            ("index-tooltip", 1070),
+        ] {
+            tokens.set(ZIndex, name, TokenValue::Number(value as f64));
+        }
+        tokens
+    }
+
+    /// [`defaults`](Self::defaults) plus the palette's roles as colors
+    /// (`primary`, `text-primary`, …)
+    pub fn from_palette(palette: &Palette) -> Self {
+        let mut tokens = Self::new();
+        for (role, color) in palette.roles() {
+            tokens.set(
+                TokenGroup::Color,
+                &role.replace('_', "-"),
+                TokenValue::Color(color),
+            );
+        }
+        tokens.tokens.extend(Self::defaults().tokens);
+        tokens
+    }
+
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    pub fn with_token(mut self, group: TokenGroup, name: &str, value: TokenValue) -> Self {
+        self.set(group, name, value);
+        self
+    }
+
+    /// Replaces the token `name` of `group`, or adds it at the end
+    pub fn set(&mut self, group: TokenGroup, name: &str, value: TokenValue) {
+        match self
+            .tokens
+            .iter_mut()
+            .find(|token| token.group == group && token.name == name)
+        {
+            Some(token) => token.value = value,
+            None => self.tokens.push(Token {
+                group,
+                name: name.to_string(),
+                value,
+            }),
+        }
+    }
+
+    pub fn get(&self, group: TokenGroup, name: &str) -> Option<&TokenValue> {
+        self.tokens
+            .iter()
+            .find(|token| token.group == group && token.name == name)
+            .map(|token| &token.value)
+    }
+
+    pub fn group(&self, group: TokenGroup) -> impl Iterator<Item = &Token> {
+        self.tokens.iter().filter(move |token| token.group == group)
+    }
+
+    /// Custom property of a token, e.g. `--hi-spacing-md`
+    pub fn css_variable(&self, token: &Token) -> String {
+        format!(
+            "--{}-{}-{}",
+            self.prefix,
+            token.group.css_prefix(),
+            token.name
+        )
+    }
+
+    /// Every token as a custom property on `selector`
+    pub fn to_css(&self, selector: &str) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "{selector} {{");
+        for token in &self.tokens {
+            let _ = writeln!(
+                out,
+                "  {}: {};",
+                self.css_variable(token),
+                token.value.css()
+            );
+        }
+        let _ = writeln!(out, "}}");
+        out
+    }
+
+    /// A nested `$<prefix>-tokens` map, one sub-map per group
+    ///
+    /// Read values with `map.get($hi-tokens, spacing, md)`.
+    pub fn to_scss(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "${}-tokens: (", self.prefix);
+        for group in TokenGroup::ALL {
+            let mut tokens = self.group(group).peekable();
+            if tokens.peek().is_none() {
+                continue;
+            }
+            let _ = writeln!(out, "  {}: (", group.as_str());
+            for token in tokens {
+                let value = match &token.value {
+                    // Lists and commas need parentheses inside a map
+                    TokenValue::FontFamily(_) | TokenValue::Shadow(_) => {
+                        format!("({})", token.value.css())
+                    }
+                    _ => token.value.css(),
+                };
+                let _ = writeln!(out, "    \"{}\": {value},", token.name);
+            }
+            let _ = writeln!(out, "  ),");
+        }
+        let _ = writeln!(out, ");");
+        out
+    }
+
+    /// W3C Design Tokens JSON, one group per [`TokenGroup`]
+    pub fn to_json(&self) -> String {
+        let mut root = Map::new();
+        for group in TokenGroup::ALL {
+            let mut entries = Map::new();
+            for token in self.group(group) {
+                entries.insert(
+                    token.name.clone(),
+                    json!({ "$type": token.value.type_name(), "$value": token.value.json() }),
+                );
+            }
+            if !entries.is_empty() {
+                root.insert(group.as_str().to_string(), Value::Object(entries));
+            }
+        }
+        serde_json::to_string_pretty(&Value::Object(root)).unwrap_or_default()
+    }
+
+    /// A Tailwind preset (ES module) extending the theme with every token
+    ///
+    /// Values are `var(--hi-…, <value>)`, so utilities follow the active
+    /// theme's custom properties and still work without them.
+    pub fn to_tailwind_preset(&self) -> String {
+        let mut extend = Map::new();
+        for group in TokenGroup::ALL {
+            let mut entries = Map::new();
+            for token in self.group(group) {
+                let variable = format!("var({}, {})", self.css_variable(token), token.value.css());
+                entries.insert(token.name.clone(), Value::String(variable));
+            }
+            if !entries.is_empty() {
+                extend.insert(group.tailwind_key().to_string(), Value::Object(entries));
+            }
+        }
+        let preset = json!({ "theme": { "extend": extend } });
+        format!(
+            "/** @type {{import('tailwindcss').Config}} */\nexport default {};\n",
+            serde_json::to_string_pretty(&preset).unwrap_or_default()
+        )
+    }
+}
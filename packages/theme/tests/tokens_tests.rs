@@ -0,0 +1,222 @@
+// Design tokens and their CSS, SCSS, JSON and Tailwind output.
+
+use hikari_palette::themes::Hikari;
+use hikari_theme::*;
+use std::collections::{HashMap, HashSet};
+
+fn tokens() -> DesignTokens {
+    DesignTokens::from_palette(&Hikari::palette())
+}
+
+#[test]
+fn test_defaults_mirror_foundation_scss() {
+    let tokens = DesignTokens::defaults();
+    assert_eq!(
+        tokens.get(TokenGroup::Spacing, "md"),
+        Some(&TokenValue::Dimension(Dimension::rem(1.0)))
+    );
+    assert_eq!(
+        tokens.get(TokenGroup::Duration, "fast"),
+        Some(&TokenValue::Duration(150))
+    );
+    assert_eq!(tokens.group(TokenGroup::Color).count(), 0);
+    for group in TokenGroup::ALL.into_iter().skip(1) {
+        assert!(tokens.group(group).count() > 0, "{group:?} is empty");
+    }
+}
+
+/// Custom properties declared in `scss`, with `var()` references to other
+/// declarations in the same file resolved (references elsewhere are kept)
+fn declarations(scss: &str) -> Vec<(String, String)> {
+    let declared: Vec<(String, String)> = scss
+        .lines()
+        .filter_map(|line| {
+            let (name, value) = line.trim().strip_prefix("--")?.split_once(':')?;
+            let value = value.trim().strip_suffix(';')?;
+            Some((format!("--{name}"), value.to_string()))
+        })
+        .collect();
+    let lookup: HashMap<&str, &str> = declared
+        .iter()
+        .map(|(name, value)| (name.as_str(), value.as_str()))
+        .collect();
+    declared
+        .iter()
+        .map(|(name, value)| {
+            let mut value = value.clone();
+            let mut from = 0;
+            while let Some(start) = value[from..].find("var(").map(|at| from + at) {
+                let end = start + value[start..].find(')').unwrap();
+                match lookup.get(&value[start + 4..end]) {
+                    Some(resolved) => value.replace_range(start..=end, resolved),
+                    None => from = end,
+                }
+            }
+            (name.clone(), value)
+        })
+        .collect()
+}
+
+#[test]
+fn test_defaults_match_stylesheets() {
+    let tokens = DesignTokens::defaults();
+    let expected: HashMap<String, String> = tokens
+        .tokens
+        .iter()
+        .map(|token| (tokens.css_variable(token), token.value.css()))
+        .collect();
+    let prefixes: Vec<String> = TokenGroup::ALL
+        .iter()
+        .map(|group| format!("--hi-{}-", group.css_prefix()))
+        .collect();
+
+    let mut declared = HashSet::new();
+    for (file, scss) in [
+        ("_tokens.scss", include_str!("../styles/_tokens.scss")),
+        ("base.scss", include_str!("../styles/base.scss")),
+        ("foundation.scss", include_str!("../styles/foundation.scss")),
+    ] {
+        for (name, value) in declarations(scss) {
+            // Colors come from the palette; `--hi-shadow-color` is one of them
+            let modelled = prefixes.iter().any(|prefix| name.starts_with(prefix))
+                && !name.starts_with("--hi-color-")
+                && name != "--hi-shadow-color";
+            if modelled {
+                assert_eq!(expected.get(&name), Some(&value), "{name} in {file}");
+                declared.insert(name);
+            }
+        }
+    }
+
+    // Typography lives in `variables.scss` as SCSS variables
+    let typography = [
+        TokenGroup::FontFamily,
+        TokenGroup::FontSize,
+        TokenGroup::FontWeight,
+        TokenGroup::LineHeight,
+    ];
+    for token in &tokens.tokens {
+        if !typography.contains(&token.group) {
+            let name = tokens.css_variable(token);
+            assert!(declared.contains(&name), "{name} is not in the stylesheets");
+        }
+    }
+}
+
+#[test]
+fn test_palette_colors_and_overrides() {
+    let mut tokens = tokens();
+    assert_eq!(tokens.group(TokenGroup::Color).count(), 11);
+    assert_eq!(
+        tokens.get(TokenGroup::Color, "text-primary"),
+        Some(&TokenValue::Color(Hikari::palette().text_primary))
+    );
+
+    let count = tokens.tokens.len();
+    tokens.set(
+        TokenGroup::Spacing,
+        "md",
+        TokenValue::Dimension(Dimension::px(12.0)),
+    );
+    assert_eq!(tokens.tokens.len(), count);
+    let tokens = tokens.with_token(
+        TokenGroup::Spacing,
+        "3xl",
+        TokenValue::Dimension(Dimension::rem(4.0)),
+    );
+    assert_eq!(tokens.tokens.len(), count + 1);
+    assert_eq!(tokens.get(TokenGroup::Spacing, "md").unwrap().css(), "12px");
+}
+
+#[test]
+fn test_css_variables() {
+    let css = tokens().to_css(":root");
+    assert!(css.starts_with(":root {\n"));
+    for line in [
+        &format!(
+            "  --hi-color-primary: {};",
+            Hikari::palette().primary.hex().to_lowercase()
+        ),
+        "  --hi-spacing-xs: 0.25rem;",
+        "  --hi-radius-none: 0;",
+        "  --hi-duration-fast: 150ms;",
+        "  --hi-ease-default: cubic-bezier(0.4, 0, 0.2, 1);",
+        "  --hi-shadow-sm: 0 1px 2px rgba(0, 0, 0, 0.05);",
+        "  --hi-shadow-md: 0 4px 6px -1px rgba(0, 0, 0, 0.1);",
+        "  --hi-z-modal: 1000;",
+        "  --hi-font-family-mono: \"SF Mono\", Monaco,",
+        "  --hi-font-weight-semibold: 600;",
+        "  --hi-line-height-normal: 1.5;",
+    ] {
+        assert!(css.contains(line), "missing `{line}` in\n{css}");
+    }
+
+    let css = DesignTokens::defaults().with_prefix("app").to_css(".app");
+    assert!(css.contains("  --app-spacing-md: 1rem;"));
+}
+
+#[test]
+fn test_scss_map_compiles() {
+    let scss = tokens().to_scss();
+    assert!(scss.starts_with("$hi-tokens: (\n  color: (\n"));
+
+    let source = format!(
+        "@use \"sass:map\";\n{scss}\n.card {{\n  padding: map.get($hi-tokens, spacing, md);\n  \
+         transition-duration: map.get($hi-tokens, duration, fast);\n  \
+         font-family: map.get($hi-tokens, font-family, sans);\n  \
+         box-shadow: map.get($hi-tokens, shadow, md);\n}}\n"
+    );
+    let css = grass::from_string(source, &grass::Options::default()).unwrap();
+    assert!(css.contains("padding: 1rem;"), "{css}");
+    assert!(css.contains("transition-duration: 150ms;"), "{css}");
+    assert!(
+        css.contains("font-family: -apple-system, BlinkMacSystemFont, \"Segoe UI\""),
+        "{css}"
+    );
+    assert!(
+        css.contains("box-shadow: 0 4px 6px -1px rgba(0, 0, 0, 0.1);"),
+        "{css}"
+    );
+}
+
+#[test]
+fn test_w3c_json() {
+    let json: serde_json::Value = serde_json::from_str(&tokens().to_json()).unwrap();
+    assert_eq!(json["color"]["primary"]["$type"], "color");
+    assert_eq!(json["color"]["primary"]["$value"]["colorSpace"], "srgb");
+    assert_eq!(
+        json["spacing"]["md"]["$value"],
+        serde_json::json!({ "value": 1.0, "unit": "rem" })
+    );
+    assert_eq!(
+        json["duration"]["fast"]["$value"],
+        serde_json::json!({ "value": 150, "unit": "ms" })
+    );
+    assert_eq!(json["easing"]["in"]["$type"], "cubicBezier");
+    assert_eq!(json["font-weight"]["bold"]["$value"], 700);
+    let shadow = &json["shadow"]["sm"]["$value"][0];
+    assert_eq!(shadow["color"]["alpha"], 0.05);
+    assert_eq!(shadow["blur"]["value"], 2.0);
+}
+
+#[test]
+fn test_tailwind_preset() {
+    let preset = tokens().to_tailwind_preset();
+    assert!(preset.starts_with("/** @type {import('tailwindcss').Config} */\nexport default {"));
+    let (_, object) = preset.split_once("export default ").unwrap();
+    let object = object.trim_end().trim_end_matches(';');
+    let json: serde_json::Value = serde_json::from_str(object).unwrap();
+    let extend = &json["theme"]["extend"];
+    assert_eq!(extend["spacing"]["md"], "var(--hi-spacing-md, 1rem)");
+    assert_eq!(
+        extend["transitionTimingFunction"]["out"],
+        "var(--hi-ease-out, cubic-bezier(0, 0, 0.2, 1))"
+    );
+    assert!(
+        extend["colors"]["primary"]
+            .as_str()
+            .unwrap()
+            .starts_with("var(--hi-color-primary, #")
+    );
+    assert!(extend["borderRadius"]["full"].is_string());
+}